@@ -1,6 +1,8 @@
 use clap::{ArgAction, Parser, ValueEnum};
 use std::path::PathBuf;
 
+use crate::ui::keymap::KeymapPreset;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
 pub enum ExportFormat {
     Json,
@@ -23,14 +25,27 @@ pub struct Cli {
     #[clap(short, long)]
     pub dump: bool,
 
+    /// Show the rendered ebook, bold and italics kept, in $PAGER (default
+    /// `less -R`) instead of the reader
+    #[clap(long)]
+    pub pager: bool,
+
     /// Export persisted highlights for an ebook
     #[clap(long, value_name = "BOOK")]
     pub export_highlights: Option<PathBuf>,
 
+    /// Export bookmarks and highlights for an ebook as JSON
+    #[clap(long, value_name = "BOOK")]
+    pub export_annotations: Option<PathBuf>,
+
     /// Export reading statistics to a file
     #[clap(long, value_name = "PATH")]
     pub export_stats: Option<PathBuf>,
 
+    /// Import books, reading positions, and bookmarks from epy's states.db
+    #[clap(long, value_name = "STATES_DB")]
+    pub import_epy: Option<PathBuf>,
+
     /// Output format for --export-highlights or --export-stats
     #[clap(long, value_enum, default_value_t = ExportFormat::Json)]
     pub format: ExportFormat,
@@ -47,6 +62,24 @@ pub struct Cli {
     #[clap(long)]
     pub debug: bool,
 
+    /// Draw on the main screen so the last page stays in scrollback on quit
+    #[clap(long)]
+    pub no_altscreen: bool,
+
+    /// Print the saved reading state of EBOOK (default: the last-read book)
+    /// as JSON and exit
+    #[clap(long)]
+    pub print_state: bool,
+
+    /// Take newline-delimited JSON commands on stdin and answer each with
+    /// the reading state as JSON on stdout; the UI draws on /dev/tty
+    #[clap(long)]
+    pub rpc: bool,
+
+    /// Navigation key preset, applied on top of the config's `Keymap`
+    #[clap(long, value_enum, value_name = "PRESET", default_value_t = KeymapPreset::Default)]
+    pub keymap: KeymapPreset,
+
     /// Generate shell completions and exit
     #[clap(long, value_enum, value_name = "SHELL")]
     pub completions: Option<clap_complete::Shell>,
@@ -1,4 +1,5 @@
 use crate::settings::{CfgDefaultKeymaps, Keymap, Settings};
+use crate::ui::keymap::KeymapPreset;
 use eyre::{Result, eyre};
 use serde::Deserialize;
 use serde_json;
@@ -32,6 +33,8 @@ pub struct Config {
     pub settings: Settings,
     pub keymap: Keymap,
     keymap_user_dict: CfgDefaultKeymaps, // Used for building help menu text, will be private
+    /// Navigation preset from `--keymap`; lasts for the session only.
+    pub keymap_preset: KeymapPreset,
     filepath: PathBuf,
     fallback_error: Option<String>,
 }
@@ -71,6 +74,7 @@ impl Config {
             settings,
             keymap,
             keymap_user_dict,
+            keymap_preset: KeymapPreset::Default,
             filepath,
             fallback_error: None,
         })
@@ -96,6 +100,7 @@ impl Config {
             settings,
             keymap,
             keymap_user_dict,
+            keymap_preset: KeymapPreset::Default,
             filepath,
             fallback_error: None,
         })
@@ -108,6 +113,7 @@ impl Config {
             settings: Settings::default(),
             keymap: Keymap::default(),
             keymap_user_dict: CfgDefaultKeymaps::default(),
+            keymap_preset: KeymapPreset::Default,
             filepath,
             fallback_error: Some(load_error.into()),
         }
@@ -160,6 +166,7 @@ impl Config {
             settings,
             keymap,
             keymap_user_dict,
+            keymap_preset: KeymapPreset::Default,
             filepath,
             fallback_error: None,
         })
@@ -86,7 +86,7 @@ impl Cbz {
 
 /// Compare path names naturally: digit runs compare as numbers, everything
 /// else case-insensitively, so `page-2` sorts before `page-10`.
-fn natural_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+pub(super) fn natural_cmp(a: &str, b: &str) -> std::cmp::Ordering {
     let mut a_chars = a.chars().peekable();
     let mut b_chars = b.chars().peekable();
     loop {
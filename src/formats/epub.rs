@@ -1,8 +1,10 @@
 use super::{ChapterContent, Ebook, mime_from_extension};
 use crate::css::{StyledClasses, collect_styled_classes};
-use crate::models::{BookMetadata, TocEntry};
+use crate::models::{BookMetadata, Landmark, NarrationClip, TocEntry};
 use epub::doc::{EpubDoc, NavPoint};
 use eyre::Result;
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -11,9 +13,17 @@ pub struct Epub {
     doc: Option<EpubDoc<std::io::BufReader<std::fs::File>>>,
     contents: Vec<String>,
     toc: Vec<TocEntry>,
+    landmarks: Vec<Landmark>,
+    /// The OPF `<guide>` references, which the epub crate does not expose.
+    guide: Vec<GuideReference>,
+    /// Archive path of the SMIL media overlay for each chapter that has one.
+    media_overlays: HashMap<usize, String>,
     metadata: BookMetadata,
     raw_text_cache: HashMap<String, String>,
     styled_classes: StyledClasses,
+    /// Decompressed copy of a gzip-wrapped book (`.epub.gz`); `doc` reads
+    /// from it and the file is removed when the book is dropped.
+    decompressed: Option<tempfile::NamedTempFile>,
 }
 
 impl Epub {
@@ -23,12 +33,30 @@ impl Epub {
             doc: None,
             contents: Vec::new(),
             toc: Vec::new(),
+            landmarks: Vec::new(),
+            guide: Vec::new(),
+            media_overlays: HashMap::new(),
             metadata: BookMetadata::default(),
             raw_text_cache: HashMap::new(),
             styled_classes: StyledClasses::default(),
+            decompressed: None,
         }
     }
 
+    /// Path the zip parser should read: the book itself, or a temp copy
+    /// when the file on disk is gzip-compressed.
+    fn archive_path(&mut self) -> Result<PathBuf> {
+        if !super::has_gzip_magic(&self.path) {
+            return Ok(PathBuf::from(&self.path));
+        }
+        let mut decoder = flate2::read::GzDecoder::new(std::fs::File::open(&self.path)?);
+        let mut temp = tempfile::Builder::new().suffix(".epub").tempfile()?;
+        std::io::copy(&mut decoder, &mut temp)?;
+        let path = temp.path().to_path_buf();
+        self.decompressed = Some(temp);
+        Ok(path)
+    }
+
     fn resource_path_for_content_index(&self, index: usize) -> Option<String> {
         let doc = self.doc.as_ref()?;
         let spine_item = doc.spine.get(index)?;
@@ -94,6 +122,141 @@ impl Epub {
         }
     }
 
+    /// Archive path of the cover image. Tries the EPUB 3 `cover-image`
+    /// manifest property, then the EPUB 2 `<meta name="cover">` convention,
+    /// and finally the OPF `<guide>` cover reference (following a cover page
+    /// to the first image it shows).
+    pub fn cover_href(&mut self) -> Option<String> {
+        let doc = self.doc.as_mut()?;
+        let image_path = |doc: &EpubDoc<_>, id: &str| {
+            doc.resources
+                .get(id)
+                .filter(|r| r.mime.starts_with("image/"))
+                .map(|r| r.path.to_string_lossy().to_string())
+        };
+        let declared = [
+            doc.get_cover_id(),
+            doc.mdata("cover").map(|item| item.value.clone()),
+        ];
+        if let Some(path) = declared.iter().flatten().find_map(|id| image_path(doc, id)) {
+            return Some(path);
+        }
+
+        let reference = self
+            .guide
+            .iter()
+            .find(|reference| reference.kind.eq_ignore_ascii_case("cover"))?;
+        let target = reference.href.split('#').next()?.to_string();
+        let target_mime = doc
+            .resources
+            .values()
+            .find(|r| r.path.as_path() == std::path::Path::new(&target))
+            .map(|r| r.mime.clone())
+            .unwrap_or_else(|| mime_from_extension(&target));
+        if target_mime.starts_with("image/") {
+            return Some(target);
+        }
+
+        // The guide usually points at an XHTML cover page wrapping the image.
+        let page = doc.get_resource_by_path(&target)?;
+        let image = first_image_source(&page)?;
+        super::resolve_relative_resource(&image, Some(&target))
+    }
+
+    /// Landmarks from the EPUB 3 nav document's `landmarks` list, falling
+    /// back to the EPUB 2 OPF `<guide>`. Targets outside the spine are
+    /// dropped.
+    fn parse_landmarks(
+        doc: &mut EpubDoc<std::io::BufReader<std::fs::File>>,
+        guide: &[GuideReference],
+    ) -> Vec<Landmark> {
+        let links = Self::nav_landmark_links(doc)
+            .filter(|links| !links.is_empty())
+            .unwrap_or_else(|| {
+                guide
+                    .iter()
+                    .map(|reference| {
+                        (
+                            reference.kind.clone(),
+                            reference.title.clone(),
+                            reference.href.clone(),
+                        )
+                    })
+                    .collect()
+            });
+        links
+            .into_iter()
+            .filter_map(|(kind, label, target)| {
+                let (path, fragment) = target.split_once('#').unwrap_or((&target, ""));
+                let content_index = doc.resource_uri_to_chapter(&PathBuf::from(path))?;
+                let kind = match kind.to_ascii_lowercase().as_str() {
+                    "text" => "bodymatter".to_string(),
+                    other => other.to_string(),
+                };
+                Some(Landmark {
+                    kind,
+                    label,
+                    content_index,
+                    section: (!fragment.is_empty()).then(|| fragment.to_string()),
+                })
+            })
+            .collect()
+    }
+
+    /// `(type, label, archive path#fragment)` for each link in the nav
+    /// document's `<nav epub:type="landmarks">`.
+    fn nav_landmark_links(
+        doc: &mut EpubDoc<std::io::BufReader<std::fs::File>>,
+    ) -> Option<Vec<(String, String, String)>> {
+        let nav_id = doc.get_nav_id()?;
+        let nav_path = doc
+            .resources
+            .get(&nav_id)?
+            .path
+            .to_string_lossy()
+            .to_string();
+        let nav = doc.get_resource_by_path(&nav_path)?;
+        Some(
+            landmark_anchors(&nav)
+                .into_iter()
+                .filter_map(|(kind, href, label)| {
+                    Some((
+                        kind.split_whitespace().next()?.to_string(),
+                        label.trim().to_string(),
+                        Self::resolve_landmark_href(&href, &nav_path)?,
+                    ))
+                })
+                .collect(),
+        )
+    }
+
+    fn resolve_landmark_href(href: &str, base: &str) -> Option<String> {
+        let (path, fragment) = href.split_once('#').unwrap_or((href, ""));
+        let path = super::resolve_relative_resource(path, Some(base))?;
+        Some(if fragment.is_empty() {
+            path
+        } else {
+            format!("{path}#{fragment}")
+        })
+    }
+
+    /// Chapter index → SMIL path for each spine item whose manifest entry
+    /// names a `media-overlay`.
+    fn media_overlay_paths(
+        doc: &EpubDoc<std::io::BufReader<std::fs::File>>,
+        overlays: &[(String, String)],
+        contents: &[String],
+    ) -> HashMap<usize, String> {
+        overlays
+            .iter()
+            .filter_map(|(id, overlay)| {
+                let index = contents.iter().position(|content| content == id)?;
+                let smil = doc.resources.get(overlay)?;
+                Some((index, smil.path.to_string_lossy().to_string()))
+            })
+            .collect()
+    }
+
     fn get_raw_text(&mut self, content_id: &str) -> Result<String> {
         if let Some(content) = self.raw_text_cache.get(content_id) {
             return Ok(content.clone());
@@ -134,7 +297,8 @@ impl Ebook for Epub {
     }
 
     fn initialize(&mut self) -> Result<()> {
-        let mut doc = EpubDoc::new(&self.path)?;
+        let archive = self.archive_path()?;
+        let mut doc = EpubDoc::new(&archive)?;
 
         self.contents = doc
             .spine
@@ -157,9 +321,19 @@ impl Ebook for Epub {
             .map(|item| item.idref.clone())
             .collect();
 
+        let root_file = doc.root_file.to_string_lossy().to_string();
+        let package = doc
+            .get_resource_by_path(&root_file)
+            .map(|opf| PackageExtras::parse(&opf, &root_file))
+            .unwrap_or_default();
+
         let mut toc_entries = Vec::new();
         Self::append_navpoints(&mut toc_entries, &doc.toc, &doc, None);
         self.toc = toc_entries;
+        self.landmarks = Self::parse_landmarks(&mut doc, &package.guide);
+        self.guide = package.guide;
+        self.media_overlays =
+            Self::media_overlay_paths(&doc, &package.media_overlays, &self.contents);
 
         let mut metadata = BookMetadata::default();
         macro_rules! load_mdata {
@@ -178,6 +352,7 @@ impl Ebook for Epub {
         load_mdata!(format);
         load_mdata!(identifier);
         load_mdata!(source);
+        metadata.page_progression_direction = package.page_progression_direction;
         self.metadata = metadata;
 
         // Load every text/css resource and scan it for class-driven italic/bold
@@ -229,9 +404,8 @@ impl Ebook for Epub {
     }
 
     fn get_cover(&mut self) -> Option<(String, Vec<u8>)> {
-        let doc = self.doc.as_mut()?;
-        let (bytes, mime) = doc.get_cover()?;
-        Some((mime, bytes))
+        let href = self.cover_href()?;
+        self.get_resource(&href).ok()
     }
 
     fn content_index_for_href(&self, href: &str) -> Option<usize> {
@@ -244,16 +418,288 @@ impl Ebook for Epub {
         doc.resource_uri_to_chapter(&resource_path)
     }
 
+    fn landmarks(&self) -> &[Landmark] {
+        &self.landmarks
+    }
+
     fn styled_classes(&self) -> &StyledClasses {
         &self.styled_classes
     }
 
+    fn media_overlay(&mut self, index: usize) -> Vec<NarrationClip> {
+        let Some(smil_path) = self.media_overlays.get(&index).cloned() else {
+            return Vec::new();
+        };
+        self.doc
+            .as_mut()
+            .and_then(|doc| doc.get_resource_by_path(&smil_path))
+            .map(|smil| parse_smil(&smil, &smil_path))
+            .unwrap_or_default()
+    }
+
     fn cleanup(&mut self) -> Result<()> {
         self.doc = None;
         Ok(())
     }
 }
 
+/// One OPF `<guide>` reference, its href resolved to an archive path.
+struct GuideReference {
+    kind: String,
+    title: String,
+    href: String,
+}
+
+/// The parts of the OPF at archive path `root_file` that the epub crate
+/// does not expose, read in one pass.
+#[derive(Default)]
+struct PackageExtras {
+    guide: Vec<GuideReference>,
+    /// `(item id, media-overlay id)` for each manifest item with an overlay.
+    media_overlays: Vec<(String, String)>,
+    /// The spine's `page-progression-direction`, `ltr` or `rtl`.
+    page_progression_direction: Option<String>,
+}
+
+impl PackageExtras {
+    fn parse(opf: &[u8], root_file: &str) -> Self {
+        let mut extras = Self::default();
+        let mut reader = xml_reader(opf);
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(element) | Event::Empty(element)) => {
+                    match element.local_name().as_ref() {
+                        b"reference" => {
+                            if let Some(kind) = attribute(&element, &reader, b"type")
+                                && let Some(href) = attribute(&element, &reader, b"href")
+                                    .and_then(|href| Epub::resolve_landmark_href(&href, root_file))
+                            {
+                                extras.guide.push(GuideReference {
+                                    kind: kind.trim().to_string(),
+                                    title: attribute(&element, &reader, b"title")
+                                        .unwrap_or_default(),
+                                    href,
+                                });
+                            }
+                        }
+                        b"item" => {
+                            if let Some(id) = attribute(&element, &reader, b"id")
+                                && let Some(overlay) =
+                                    attribute(&element, &reader, b"media-overlay")
+                            {
+                                extras.media_overlays.push((id, overlay.trim().to_string()));
+                            }
+                        }
+                        b"spine" => {
+                            extras.page_progression_direction =
+                                attribute(&element, &reader, b"page-progression-direction")
+                                    .map(|direction| direction.trim().to_ascii_lowercase())
+                                    .filter(|direction| direction == "ltr" || direction == "rtl");
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(Event::Eof) | Err(_) => break,
+                _ => {}
+            }
+        }
+        extras
+    }
+}
+
+/// `(epub:type, href, text)` of each link in a nav document's
+/// `<nav epub:type="landmarks">`, nested markup included.
+fn landmark_anchors(nav: &[u8]) -> Vec<(String, String, String)> {
+    let mut reader = xml_reader(nav);
+    let mut anchors = Vec::new();
+    let mut nav_depth: usize = 0;
+    // Depth of the landmarks `<nav>` once it has opened.
+    let mut landmarks_depth = None;
+    let mut anchor: Option<(String, String, String)> = None;
+    loop {
+        let event = match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(event) => event,
+        };
+        match &event {
+            Event::Start(element) => match element.local_name().as_ref() {
+                b"nav" => {
+                    nav_depth += 1;
+                    let is_landmarks = attribute(element, &reader, b"type")
+                        .is_some_and(|kind| kind.split_whitespace().any(|k| k == "landmarks"));
+                    if landmarks_depth.is_none() && is_landmarks {
+                        landmarks_depth = Some(nav_depth);
+                    }
+                }
+                b"a" if landmarks_depth.is_some() => {
+                    anchor = attribute(element, &reader, b"type")
+                        .zip(attribute(element, &reader, b"href"))
+                        .map(|(kind, href)| (kind, href, String::new()));
+                }
+                _ => {}
+            },
+            Event::End(element) => match element.local_name().as_ref() {
+                b"nav" => {
+                    if landmarks_depth == Some(nav_depth) {
+                        break;
+                    }
+                    nav_depth = nav_depth.saturating_sub(1);
+                }
+                b"a" => anchors.extend(anchor.take()),
+                _ => {}
+            },
+            _ => {
+                if let Some((_, _, label)) = anchor.as_mut()
+                    && let Some(text) = event_text(&event, &reader)
+                {
+                    label.push_str(&text);
+                }
+            }
+        }
+    }
+    anchors
+}
+
+/// `src` (or SVG `xlink:href`) of the first `<img>`/`<image>` on an XHTML
+/// page.
+fn first_image_source(page: &[u8]) -> Option<String> {
+    let mut reader = xml_reader(page);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element) | Event::Empty(element))
+                if matches!(element.local_name().as_ref(), b"img" | b"image") =>
+            {
+                let source = attribute(&element, &reader, b"src")
+                    .or_else(|| attribute(&element, &reader, b"href"));
+                if source.is_some() {
+                    return source;
+                }
+            }
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => {}
+        }
+    }
+}
+
+/// A reader for a book's XML resources that tolerates mismatched end tags,
+/// which hand-made XHTML often has.
+fn xml_reader(xml: &[u8]) -> Reader<&[u8]> {
+    let mut reader = Reader::from_reader(xml);
+    reader.config_mut().check_end_names = false;
+    reader
+}
+
+/// Content of a text or entity-reference event, entities resolved.
+fn event_text(event: &Event, reader: &Reader<&[u8]>) -> Option<String> {
+    match event {
+        Event::Text(text) => reader
+            .decoder()
+            .decode(text.as_ref())
+            .ok()
+            .map(|text| text.into_owned()),
+        Event::GeneralRef(reference) => {
+            let name = reader.decoder().decode(reference.as_ref()).ok()?;
+            let encoded = format!("&{name};");
+            Some(
+                quick_xml::escape::unescape(&encoded)
+                    .map(|value| value.into_owned())
+                    .unwrap_or(encoded),
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Decoded value of the attribute with local name `name`, entities resolved.
+fn attribute(element: &BytesStart, reader: &Reader<&[u8]>, name: &[u8]) -> Option<String> {
+    element.attributes().flatten().find_map(|attr| {
+        if attr.key.local_name().as_ref() != name {
+            return None;
+        }
+        attr.decoded_and_normalized_value(quick_xml::XmlVersion::Implicit1_0, reader.decoder())
+            .ok()
+            .map(|value| value.to_string())
+    })
+}
+
+/// The `<par>` clips of a SMIL media overlay at archive path `smil_path`,
+/// in document order. Pars without a text fragment or an audio source are
+/// skipped.
+fn parse_smil(smil: &[u8], smil_path: &str) -> Vec<NarrationClip> {
+    let mut reader = xml_reader(smil);
+    let mut clips = Vec::new();
+    // Open pars, innermost last: the clip slot reserved at the start tag
+    // (keeping document order), the text src, and the audio element.
+    let mut pars: Vec<(usize, Option<String>, Option<BytesStart>)> = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element)) if element.local_name().as_ref() == b"par" => {
+                pars.push((clips.len(), None, None));
+                clips.push(None);
+            }
+            Ok(Event::Start(element) | Event::Empty(element)) => {
+                let Some((_, text, audio)) = pars.last_mut() else {
+                    continue;
+                };
+                match element.local_name().as_ref() {
+                    b"text" if text.is_none() => *text = attribute(&element, &reader, b"src"),
+                    b"audio" if audio.is_none() => *audio = Some(element),
+                    _ => {}
+                }
+            }
+            Ok(Event::End(element)) if element.local_name().as_ref() == b"par" => {
+                if let Some((slot, text, audio)) = pars.pop() {
+                    clips[slot] = narration_clip(text, audio, &reader, smil_path);
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+    clips.into_iter().flatten().collect()
+}
+
+/// The clip of one SMIL `<par>` from its `<text>` src and `<audio>` element.
+fn narration_clip(
+    text_src: Option<String>,
+    audio: Option<BytesStart>,
+    reader: &Reader<&[u8]>,
+    smil_path: &str,
+) -> Option<NarrationClip> {
+    let fragment = text_src?.split_once('#')?.1.to_string();
+    let audio = audio?;
+    let audio_src = attribute(&audio, reader, b"src")?;
+    Some(NarrationClip {
+        fragment,
+        audio: super::resolve_relative_resource(&audio_src, Some(smil_path))?,
+        clip_begin: attribute(&audio, reader, b"clipBegin")
+            .and_then(|value| parse_clock_value(&value))
+            .unwrap_or(0.0),
+        clip_end: attribute(&audio, reader, b"clipEnd").and_then(|value| parse_clock_value(&value)),
+    })
+}
+
+/// Seconds in a SMIL clock value: `h:mm:ss.fff`, `mm:ss.fff`, or a
+/// timecount such as `12.5s`, `500ms`, `2min`, `1h`, or a bare `3.2`.
+fn parse_clock_value(value: &str) -> Option<f64> {
+    let value = value.trim();
+    if value.contains(':') {
+        return value.split(':').try_fold(0.0, |total, part| {
+            Some(total * 60.0 + part.trim().parse::<f64>().ok()?)
+        });
+    }
+    let (number, scale) = [("ms", 0.001), ("min", 60.0), ("h", 3600.0), ("s", 1.0)]
+        .into_iter()
+        .find_map(|(unit, scale)| value.strip_suffix(unit).map(|number| (number, scale)))
+        .unwrap_or((value, 1.0));
+    number
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+        .map(|seconds| seconds * scale)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -360,6 +806,280 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_epub_cover_href_from_manifest_and_meta() -> Result<()> {
+        let mut epub3 = Epub::new("tests/fixtures/small.epub");
+        epub3.initialize()?;
+        let href = epub3.cover_href().expect("cover-image property");
+        assert!(href.ends_with("covers/9781449328030_lrg.jpg"));
+
+        let mut epub2 = Epub::new("tests/fixtures/meditations.epub");
+        epub2.initialize()?;
+        assert_eq!(
+            epub2.cover_href().as_deref(),
+            Some("OEBPS/6169536333098576604_2680-cover.png")
+        );
+        let (mime, bytes) = epub2.get_cover().expect("cover bytes");
+        assert_eq!(mime, "image/png");
+        assert!(!bytes.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_epub_cover_href_follows_guide_cover_page() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("guide.epub");
+        let file = std::fs::File::create(&path)?;
+        let mut writer = zip::ZipWriter::new(file);
+        let options = zip::write::SimpleFileOptions::default();
+        let entries = [
+            ("mimetype", "application/epub+zip"),
+            (
+                "META-INF/container.xml",
+                r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles><rootfile full-path="OPS/content.opf" media-type="application/oebps-package+xml"/></rootfiles>
+</container>"#,
+            ),
+            (
+                "OPS/content.opf",
+                r#"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="2.0" unique-identifier="id">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/"><dc:title>Guide</dc:title></metadata>
+  <manifest>
+    <item id="page" href="text/cover&amp;title.xhtml" media-type="application/xhtml+xml"/>
+    <item id="pic" href="images/front&amp;back.png" media-type="image/png"/>
+  </manifest>
+  <spine page-progression-direction="rtl"><itemref idref="page"/></spine>
+  <guide><reference type="cover" title="Cover" href="text/cover&amp;title.xhtml"/></guide>
+</package>"#,
+            ),
+            (
+                "OPS/text/cover&title.xhtml",
+                r#"<html><body><img src="../images/front&amp;back.png" alt="cover"/></body></html>"#,
+            ),
+            ("OPS/images/front&back.png", "png"),
+        ];
+        for (name, body) in entries {
+            writer.start_file(name, options)?;
+            std::io::Write::write_all(&mut writer, body.as_bytes())?;
+        }
+        writer.finish()?;
+
+        let mut epub = Epub::new(&path.to_string_lossy());
+        epub.initialize()?;
+        assert_eq!(
+            epub.cover_href().as_deref(),
+            Some("OPS/images/front&back.png")
+        );
+        assert_eq!(
+            epub.get_meta().page_progression_direction.as_deref(),
+            Some("rtl")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_epub_landmarks_from_nav_and_guide() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("landmarks.epub");
+        let file = std::fs::File::create(&path)?;
+        let mut writer = zip::ZipWriter::new(file);
+        let options = zip::write::SimpleFileOptions::default();
+        let entries = [
+            ("mimetype", "application/epub+zip"),
+            (
+                "META-INF/container.xml",
+                r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles><rootfile full-path="OPS/content.opf" media-type="application/oebps-package+xml"/></rootfiles>
+</container>"#,
+            ),
+            (
+                "OPS/content.opf",
+                r#"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="id">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/"><dc:title>Landmarks</dc:title></metadata>
+  <manifest>
+    <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+    <item id="cover" href="text/cover.xhtml" media-type="application/xhtml+xml"/>
+    <item id="copyright" href="text/copyright.xhtml" media-type="application/xhtml+xml"/>
+    <item id="ch1" href="text/ch1.xhtml" media-type="application/xhtml+xml"/>
+  </manifest>
+  <spine><itemref idref="cover"/><itemref idref="copyright"/><itemref idref="ch1"/></spine>
+  <guide><reference type="text" title="Ignored" href="text/copyright.xhtml"/></guide>
+</package>"#,
+            ),
+            (
+                "OPS/nav.xhtml",
+                r#"<html xmlns:epub="http://www.idpf.org/2007/ops"><body>
+<nav epub:type="toc"><ol><li><a href="text/ch1.xhtml">One</a></li></ol></nav>
+<nav epub:type="landmarks"><h2>Guide</h2><ol>
+  <li><a epub:type="cover" href="text/cover.xhtml">Cover</a><ol>
+    <li><a epub:type="bodymatter" href="text/ch1.xhtml#start"><span>Start &amp; Content</span></a></li>
+  </ol></li>
+</ol></nav>
+<nav epub:type="page-list"><ol><li><a href="text/ch1.xhtml">1</a></li></ol></nav>
+</body></html>"#,
+            ),
+            (
+                "OPS/text/cover.xhtml",
+                "<html><body><p>Cover</p></body></html>",
+            ),
+            (
+                "OPS/text/copyright.xhtml",
+                "<html><body><p>(c)</p></body></html>",
+            ),
+            (
+                "OPS/text/ch1.xhtml",
+                r#"<html><body><h1 id="start">One</h1></body></html>"#,
+            ),
+        ];
+        for (name, body) in entries {
+            writer.start_file(name, options)?;
+            std::io::Write::write_all(&mut writer, body.as_bytes())?;
+        }
+        writer.finish()?;
+
+        let mut epub = Epub::new(&path.to_string_lossy());
+        epub.initialize()?;
+        assert_eq!(
+            epub.landmarks(),
+            [
+                Landmark {
+                    kind: "cover".to_string(),
+                    label: "Cover".to_string(),
+                    content_index: 0,
+                    section: None,
+                },
+                Landmark {
+                    kind: "bodymatter".to_string(),
+                    label: "Start & Content".to_string(),
+                    content_index: 2,
+                    section: Some("start".to_string()),
+                },
+            ]
+        );
+
+        // EPUB 2 books only have the OPF guide.
+        let mut epub2 = Epub::new("tests/fixtures/meditations.epub");
+        epub2.initialize()?;
+        let kinds: Vec<&str> = epub2
+            .landmarks()
+            .iter()
+            .map(|landmark| landmark.kind.as_str())
+            .collect();
+        assert_eq!(kinds, ["cover"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_epub_media_overlay_clips_from_smil() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("narrated.epub");
+        let file = std::fs::File::create(&path)?;
+        let mut writer = zip::ZipWriter::new(file);
+        let options = zip::write::SimpleFileOptions::default();
+        let entries = [
+            ("mimetype", "application/epub+zip"),
+            (
+                "META-INF/container.xml",
+                r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles><rootfile full-path="OPS/content.opf" media-type="application/oebps-package+xml"/></rootfiles>
+</container>"#,
+            ),
+            (
+                "OPS/content.opf",
+                r#"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="id">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/"><dc:title>Narrated</dc:title></metadata>
+  <manifest>
+    <item id="ch1" href="text/ch1.xhtml" media-type="application/xhtml+xml" media-overlay="ch1-smil"/>
+    <item id="ch2" href="text/ch2.xhtml" media-type="application/xhtml+xml"/>
+    <item id="ch1-smil" href="smil/ch1.smil" media-type="application/smil+xml"/>
+    <item id="audio" href="audio/ch1.mp3" media-type="audio/mpeg"/>
+  </manifest>
+  <spine><itemref idref="ch1"/><itemref idref="ch2"/></spine>
+</package>"#,
+            ),
+            (
+                "OPS/text/ch1.xhtml",
+                r#"<html><body><p id="s1">The cat sat.</p><aside id="aside"><p id="s2">Aside.</p></aside><p id="s3">The end.</p></body></html>"#,
+            ),
+            (
+                "OPS/text/ch2.xhtml",
+                "<html><body><p>Silent.</p></body></html>",
+            ),
+            (
+                "OPS/smil/ch1.smil",
+                r#"<smil xmlns="http://www.w3.org/ns/SMIL" version="3.0"><body>
+  <seq id="seq1">
+    <par id="p1"><text src="../text/ch1.xhtml#s1"/>
+      <audio src="../audio/ch1.mp3" clipBegin="0:00:00.500" clipEnd="2.25s"/></par>
+    <seq id="aside" epub:textref="../text/ch1.xhtml#aside">
+      <par id="p2"><text src="../text/ch1.xhtml#s2"/>
+        <audio src="../audio/ch1&amp;notes.mp3" clipBegin="1s" clipEnd="2s"/></par>
+    </seq>
+    <par id="p3"><text src="../text/ch1.xhtml#s3"/>
+      <audio src="../audio/ch1.mp3" clipBegin="2.25s"/></par>
+  </seq>
+</body></smil>"#,
+            ),
+            ("OPS/audio/ch1.mp3", "mp3"),
+        ];
+        for (name, body) in entries {
+            writer.start_file(name, options)?;
+            std::io::Write::write_all(&mut writer, body.as_bytes())?;
+        }
+        writer.finish()?;
+
+        let mut epub = Epub::new(&path.to_string_lossy());
+        epub.initialize()?;
+        assert_eq!(
+            epub.media_overlay(0),
+            vec![
+                NarrationClip {
+                    fragment: "s1".to_string(),
+                    audio: "OPS/audio/ch1.mp3".to_string(),
+                    clip_begin: 0.5,
+                    clip_end: Some(2.25),
+                },
+                NarrationClip {
+                    fragment: "s2".to_string(),
+                    audio: "OPS/audio/ch1&notes.mp3".to_string(),
+                    clip_begin: 1.0,
+                    clip_end: Some(2.0),
+                },
+                NarrationClip {
+                    fragment: "s3".to_string(),
+                    audio: "OPS/audio/ch1.mp3".to_string(),
+                    clip_begin: 2.25,
+                    clip_end: None,
+                },
+            ]
+        );
+        assert!(epub.media_overlay(1).is_empty());
+        assert!(
+            Epub::new("tests/fixtures/small.epub")
+                .media_overlay(0)
+                .is_empty()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_smil_clock_values() {
+        assert_eq!(parse_clock_value("1:02:03.5"), Some(3723.5));
+        assert_eq!(parse_clock_value("02:03"), Some(123.0));
+        assert_eq!(parse_clock_value("12.5s"), Some(12.5));
+        assert_eq!(parse_clock_value("500ms"), Some(0.5));
+        assert_eq!(parse_clock_value("2min"), Some(120.0));
+        assert_eq!(parse_clock_value("1h"), Some(3600.0));
+        assert_eq!(parse_clock_value("3.2"), Some(3.2));
+        assert_eq!(parse_clock_value("soon"), None);
+    }
+
     #[test]
     fn test_epub_get_chapter_is_html_variant() -> Result<()> {
         let mut epub = Epub::new("tests/fixtures/small.epub");
@@ -0,0 +1,252 @@
+use super::{ChapterContent, Ebook, cbz::natural_cmp, mime_from_extension};
+use crate::models::{BookMetadata, TocEntry};
+use eyre::Result;
+use scraper::{Html, Selector};
+use std::collections::HashMap;
+
+const HTML_EXTENSIONS: [&str; 3] = ["html", "htm", "xhtml"];
+
+/// A folder of loose HTML/XHTML files read as one book, e.g. a scraped
+/// documentation set. Every top-level HTML file is a chapter, in natural
+/// name order; each chapter's `<title>` (or first `<h1>`) becomes its TOC
+/// label, and the folder name is the book title.
+pub struct HtmlDirBook {
+    path: String,
+    contents: Vec<String>,
+    toc: Vec<TocEntry>,
+    metadata: BookMetadata,
+    chapter_cache: HashMap<usize, String>,
+}
+
+impl HtmlDirBook {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            contents: Vec::new(),
+            toc: Vec::new(),
+            metadata: BookMetadata::default(),
+            chapter_cache: HashMap::new(),
+        }
+    }
+
+    fn is_html_file(path: &std::path::Path) -> bool {
+        path.is_file()
+            && !path
+                .file_name()
+                .is_some_and(|n| n.to_string_lossy().starts_with('.'))
+            && path
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(|e| e.to_ascii_lowercase())
+                .is_some_and(|ext| HTML_EXTENSIONS.contains(&ext.as_str()))
+    }
+
+    /// Resolve a book-relative path inside the folder; absolute paths and
+    /// paths escaping the folder are rejected so a book cannot read
+    /// arbitrary files.
+    fn read_relative(&self, path: &str) -> Option<Vec<u8>> {
+        let relative = std::path::Path::new(path);
+        if relative.is_absolute()
+            || relative
+                .components()
+                .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            return None;
+        }
+        std::fs::read(std::path::Path::new(&self.path).join(relative)).ok()
+    }
+
+    /// TOC label for a chapter: its `<title>`, else its first `<h1>`.
+    fn chapter_title(html: &str) -> Option<String> {
+        let document = Html::parse_document(html);
+        ["title", "h1"].iter().find_map(|tag| {
+            let selector = Selector::parse(tag).ok()?;
+            let text = document
+                .select(&selector)
+                .next()?
+                .text()
+                .collect::<Vec<_>>()
+                .join(" ");
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            (!text.is_empty()).then_some(text)
+        })
+    }
+}
+
+impl Ebook for HtmlDirBook {
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn contents(&self) -> &Vec<String> {
+        &self.contents
+    }
+
+    fn toc_entries(&self) -> &Vec<TocEntry> {
+        &self.toc
+    }
+
+    fn get_meta(&self) -> &BookMetadata {
+        &self.metadata
+    }
+
+    fn spine_href(&self, index: usize) -> Option<String> {
+        self.contents.get(index).cloned()
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        let dir = std::path::Path::new(&self.path);
+        let mut files: Vec<String> = std::fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| Self::is_html_file(path))
+            .filter_map(|path| path.file_name().map(|n| n.to_string_lossy().to_string()))
+            .collect();
+        files.sort_by(|a, b| natural_cmp(a, b));
+        if files.is_empty() {
+            eyre::bail!("Folder contains no HTML files: {}", self.path);
+        }
+
+        let mut toc = Vec::with_capacity(files.len());
+        for (index, name) in files.iter().enumerate() {
+            let bytes = std::fs::read(dir.join(name))?;
+            let html = String::from_utf8_lossy(&bytes).into_owned();
+            let label = Self::chapter_title(&html).unwrap_or_else(|| {
+                std::path::Path::new(name)
+                    .file_stem()
+                    .map(|s| s.to_string_lossy().to_string())
+                    .unwrap_or_else(|| name.clone())
+            });
+            toc.push(TocEntry {
+                label,
+                content_index: index,
+                section: None,
+            });
+            self.chapter_cache.insert(index, html);
+        }
+
+        let folder_name = dir
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.path.clone());
+        self.metadata = BookMetadata {
+            title: Some(folder_name),
+            format: Some("html".to_string()),
+            ..BookMetadata::default()
+        };
+        self.contents = files;
+        self.toc = toc;
+        Ok(())
+    }
+
+    fn get_chapter(&mut self, index: usize) -> Result<ChapterContent> {
+        if let Some(html) = self.chapter_cache.get(&index) {
+            return Ok(ChapterContent::Html(html.clone()));
+        }
+        let name = self
+            .contents
+            .get(index)
+            .ok_or_else(|| eyre::eyre!("Content not found"))?;
+        let bytes = self
+            .read_relative(name)
+            .ok_or_else(|| eyre::eyre!("Content not found"))?;
+        let html = String::from_utf8_lossy(&bytes).into_owned();
+        self.chapter_cache.insert(index, html.clone());
+        Ok(ChapterContent::Html(html))
+    }
+
+    fn get_resource(&mut self, path: &str) -> Result<(String, Vec<u8>)> {
+        let bytes = self
+            .read_relative(path)
+            .ok_or_else(|| eyre::eyre!("Image not found"))?;
+        Ok((mime_from_extension(path), bytes))
+    }
+
+    fn content_index_for_href(&self, href: &str) -> Option<usize> {
+        let path = href.split('#').next().unwrap_or("");
+        if path.is_empty() {
+            return None;
+        }
+        self.contents.iter().position(|name| name == path)
+    }
+
+    fn cleanup(&mut self) -> Result<()> {
+        self.chapter_cache.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &std::path::Path, name: &str, content: &str) {
+        std::fs::write(dir.join(name), content).unwrap();
+    }
+
+    #[test]
+    fn test_html_dir_chapters_toc_and_title() -> Result<()> {
+        let root = tempfile::tempdir()?;
+        let dir = root.path().join("rust-docs");
+        std::fs::create_dir(&dir)?;
+        write(
+            &dir,
+            "10-appendix.xhtml",
+            "<html><body><p>Appendix</p></body></html>",
+        );
+        write(
+            &dir,
+            "2-usage.html",
+            "<html><body><h1>Usage  Guide</h1><p>Use it.</p></body></html>",
+        );
+        write(
+            &dir,
+            "1-intro.html",
+            "<html><head><title>Introduction</title></head><body><h1>Ignored</h1></body></html>",
+        );
+        write(&dir, "notes.txt", "not a chapter");
+
+        let mut book = HtmlDirBook::new(&dir.to_string_lossy());
+        book.initialize()?;
+
+        assert_eq!(book.get_meta().title.as_deref(), Some("rust-docs"));
+        assert_eq!(
+            book.contents(),
+            &vec!["1-intro.html", "2-usage.html", "10-appendix.xhtml"]
+        );
+        let labels: Vec<&str> = book
+            .toc_entries()
+            .iter()
+            .map(|e| e.label.as_str())
+            .collect();
+        assert_eq!(labels, vec!["Introduction", "Usage Guide", "10-appendix"]);
+        assert_eq!(book.content_index_for_href("2-usage.html#top"), Some(1));
+        assert!(matches!(
+            book.get_chapter(1)?,
+            ChapterContent::Html(html) if html.contains("Use it.")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_html_dir_resources_stay_inside_folder() -> Result<()> {
+        let root = tempfile::tempdir()?;
+        let dir = root.path().join("book");
+        std::fs::create_dir_all(dir.join("img"))?;
+        write(&dir, "index.html", "<p>hi</p>");
+        write(&dir.join("img"), "a.png", "png");
+        write(root.path(), "secret.png", "nope");
+
+        let mut book = HtmlDirBook::new(&dir.to_string_lossy());
+        book.initialize()?;
+        assert_eq!(book.get_resource("img/a.png")?.0, "image/png");
+        assert!(book.get_resource("../secret.png").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_html_dir_without_html_fails() {
+        let root = tempfile::tempdir().unwrap();
+        let mut book = HtmlDirBook::new(&root.path().to_string_lossy());
+        assert!(book.initialize().is_err());
+    }
+}
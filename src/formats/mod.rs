@@ -8,17 +8,19 @@
 pub mod cbz;
 pub mod epub;
 pub mod fb2;
+pub mod html_dir;
 pub mod mobi;
 pub mod text;
 
 pub use cbz::Cbz;
 pub use epub::Epub;
 pub use fb2::Fb2;
+pub use html_dir::HtmlDirBook;
 pub use mobi::MobiBook;
 pub use text::{TextBook, TextKind};
 
 use crate::css::StyledClasses;
-use crate::models::{BookMetadata, TocEntry};
+use crate::models::{BookMetadata, Landmark, NarrationClip, TocEntry};
 use eyre::Result;
 use std::sync::LazyLock;
 
@@ -75,6 +77,16 @@ pub trait Ebook {
     fn content_index_for_href(&self, _href: &str) -> Option<usize> {
         None
     }
+    /// Structural landmarks (cover, bodymatter, ...). Only EPUBs declare
+    /// them.
+    fn landmarks(&self) -> &[Landmark] {
+        &[]
+    }
+    /// Recorded narration for the chapter at `index`, in reading order.
+    /// Only EPUB 3 books with SMIL media overlays have any.
+    fn media_overlay(&mut self, _index: usize) -> Vec<NarrationClip> {
+        Vec::new()
+    }
     /// Classes that CSS marks italic/bold, recovered during parsing.
     /// Only HTML-based formats with stylesheets have any.
     fn styled_classes(&self) -> &StyledClasses {
@@ -84,8 +96,14 @@ pub trait Ebook {
 }
 
 /// Open and initialize the right format backend for `path`, picked by file
-/// extension with a magic-bytes fallback for misnamed files.
+/// extension with a magic-bytes fallback for misnamed files. A directory is
+/// read as a folder of loose HTML chapters.
 pub fn open(path: &str) -> Result<Box<dyn Ebook>> {
+    if std::path::Path::new(path).is_dir() {
+        let mut book: Box<dyn Ebook> = Box::new(HtmlDirBook::new(path));
+        book.initialize()?;
+        return Ok(book);
+    }
     let extension = std::path::Path::new(path)
         .extension()
         .and_then(|e| e.to_str())
@@ -99,8 +117,9 @@ pub fn open(path: &str) -> Result<Box<dyn Ebook>> {
         "cbz" => Box::new(Cbz::new(path)),
         "fb2" => Box::new(Fb2::new(path)),
         "zip" if path.to_ascii_lowercase().ends_with(".fb2.zip") => Box::new(Fb2::new(path)),
+        "gz" if path.to_ascii_lowercase().ends_with(".epub.gz") => Box::new(Epub::new(path)),
         "mobi" | "azw" | "azw3" => Box::new(MobiBook::new(path)),
-        _ if has_zip_magic(path) => Box::new(Epub::new(path)),
+        _ if has_zip_magic(path) || has_gzip_magic(path) => Box::new(Epub::new(path)),
         _ => eyre::bail!("Unsupported ebook format: {}", path),
     };
     book.initialize()?;
@@ -116,6 +135,15 @@ fn has_zip_magic(path: &str) -> bool {
     file.read_exact(&mut magic).is_ok() && &magic == b"PK\x03\x04"
 }
 
+pub(crate) fn has_gzip_magic(path: &str) -> bool {
+    use std::io::Read;
+    let Ok(mut file) = std::fs::File::open(path) else {
+        return false;
+    };
+    let mut magic = [0u8; 2];
+    file.read_exact(&mut magic).is_ok() && magic == [0x1f, 0x8b]
+}
+
 /// Resolve an href/src relative to a content document path inside the book,
 /// normalizing `.` and `..` components. Leading `/` means book-root-relative.
 pub fn resolve_relative_resource(href: &str, base_content: Option<&str>) -> Option<String> {
@@ -229,6 +257,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_open_gzip_wrapped_epub() -> Result<()> {
+        use std::io::Write;
+        let dir = tempfile::tempdir()?;
+        let epub = std::fs::read("tests/fixtures/small.epub")?;
+        let plain = open("tests/fixtures/small.epub")?;
+        for name in ["small.epub.gz", "downloaded-book"] {
+            let path = dir.path().join(name);
+            let mut encoder = flate2::write::GzEncoder::new(
+                std::fs::File::create(&path)?,
+                flate2::Compression::default(),
+            );
+            encoder.write_all(&epub)?;
+            encoder.finish()?;
+            let book = open(&path.to_string_lossy())?;
+            assert_eq!(book.path(), path.to_string_lossy());
+            assert_eq!(book.contents(), plain.contents());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_fingerprint_text_all_variants() {
         for content in [
@@ -9,7 +9,9 @@ pub mod models;
 pub mod opds;
 pub mod parser;
 pub mod renderer;
+pub mod rpc;
 pub mod settings;
+pub mod sidecar;
 pub mod state;
 pub mod statistics;
 pub mod sync;
@@ -161,16 +161,27 @@ fn interpret_calibredb_output(success: bool, stdout: &str, stderr: &str) -> Resu
     }
 }
 
+/// Outcome of a library scan.
+pub struct LibraryScan {
+    /// Every ebook now in the cache.
+    pub books: Vec<ScannedBook>,
+    /// Configured directories and entries below them that could not be
+    /// read; their cached books are kept rather than pruned.
+    pub skipped: Vec<PathBuf>,
+}
+
 /// Scan the given directories (without following directory symlinks) and return every ebook
 /// found, refreshing the metadata cache and pruning entries for files that
 /// disappeared. Metadata comes from the cache when the file is unchanged,
 /// from a sibling Calibre `metadata.opf` when present, or from the EPUB
 /// itself otherwise.
-pub fn scan_library_directories(dirs: &[String], state: &State) -> Result<Vec<ScannedBook>> {
+pub fn scan_library_directories(dirs: &[String], state: &State) -> Result<LibraryScan> {
     let mut globally_seen: HashSet<String> = HashSet::new();
+    let mut skipped = Vec::new();
     for dir in dirs {
         let root = expand_tilde(dir);
         let Ok(root) = std::fs::canonicalize(&root) else {
+            skipped.push(root);
             continue;
         };
         let root_str = root.to_string_lossy().to_string();
@@ -185,7 +196,11 @@ pub fn scan_library_directories(dirs: &[String], state: &State) -> Result<Vec<Sc
             }
             let entry = match result {
                 Ok(e) => e,
-                Err(_) => {
+                Err(err) => {
+                    // Typically an unreadable directory: keep scanning the
+                    // rest, but skip pruning so its cached books survive.
+                    crate::logging::debug(format!("Library scan skipped an entry: {err}"));
+                    skipped.push(err.path().unwrap_or(&root).to_path_buf());
                     walk_succeeded = false;
                     continue;
                 }
@@ -282,7 +297,10 @@ pub fn scan_library_directories(dirs: &[String], state: &State) -> Result<Vec<Sc
             state.prune_library_root(&root_str, &seen_in_root)?;
         }
     }
-    state.get_scanned_library_files()
+    Ok(LibraryScan {
+        books: state.get_scanned_library_files()?,
+        skipped,
+    })
 }
 
 /// Read a Calibre catalog without taking locks or ever making the database
@@ -713,8 +731,9 @@ mod tests {
         std::fs::write(book_dir.join("War & Peace - Leo Tolstoy.mobi"), b"mobi").unwrap();
         std::fs::write(book_dir.join("War & Peace - Leo Tolstoy.fb2"), b"fb2").unwrap();
         let state = State::new_for_test();
-        let books =
-            scan_library_directories(&[dir.path().to_string_lossy().to_string()], &state).unwrap();
+        let books = scan_library_directories(&[dir.path().to_string_lossy().to_string()], &state)
+            .unwrap()
+            .books;
         assert_eq!(books.len(), 1);
         assert_eq!(books[0].title.as_deref(), Some("War & Peace"));
         assert_eq!(books[0].author.as_deref(), Some("Leo Tolstoy"));
@@ -732,8 +751,9 @@ mod tests {
         let dir = tempfile::TempDir::new().unwrap();
         std::fs::copy("tests/fixtures/small.epub", dir.path().join("small.epub")).unwrap();
         let state = State::new_for_test();
-        let books =
-            scan_library_directories(&[dir.path().to_string_lossy().to_string()], &state).unwrap();
+        let books = scan_library_directories(&[dir.path().to_string_lossy().to_string()], &state)
+            .unwrap()
+            .books;
         assert_eq!(books.len(), 1);
         // No metadata.opf, so metadata must come from inside the EPUB.
         assert!(books[0].title.is_some());
@@ -745,7 +765,7 @@ mod tests {
         let state = State::new_for_test();
         let dirs = [dir.path().to_string_lossy().to_string()];
 
-        let books = scan_library_directories(&dirs, &state).unwrap();
+        let books = scan_library_directories(&dirs, &state).unwrap().books;
         let filepath = books[0].filepath.clone();
         let mtime = file_mtime(Path::new(&filepath));
 
@@ -754,7 +774,7 @@ mod tests {
         state
             .upsert_library_file(&filepath, mtime, Some("Cached Title"), None)
             .unwrap();
-        let books = scan_library_directories(&dirs, &state).unwrap();
+        let books = scan_library_directories(&dirs, &state).unwrap().books;
         assert_eq!(books[0].title.as_deref(), Some("Cached Title"));
     }
 
@@ -766,11 +786,11 @@ mod tests {
         let state = State::new_for_test();
         let dirs = [dir.path().to_string_lossy().to_string()];
 
-        let books = scan_library_directories(&dirs, &state).unwrap();
+        let books = scan_library_directories(&dirs, &state).unwrap().books;
         assert_eq!(books.len(), 2);
 
         std::fs::remove_file(&extra).unwrap();
-        let books = scan_library_directories(&dirs, &state).unwrap();
+        let books = scan_library_directories(&dirs, &state).unwrap().books;
         assert_eq!(books.len(), 1);
         assert_eq!(state.get_scanned_library_files().unwrap().len(), 1);
     }
@@ -798,13 +818,13 @@ mod tests {
         let dir = make_calibre_dir();
         let state = State::new_for_test();
         let dirs = [dir.path().to_string_lossy().to_string()];
-        let first = scan_library_directories(&dirs, &state).unwrap();
+        let first = scan_library_directories(&dirs, &state).unwrap().books;
         assert_eq!(first[0].title.as_deref(), Some("War & Peace"));
         let opf = dir
             .path()
             .join("Leo Tolstoy/War & Peace (246)/metadata.opf");
         std::fs::write(&opf, SAMPLE_OPF.replace("War &amp; Peace", "Anna Karenina")).unwrap();
-        let second = scan_library_directories(&dirs, &state).unwrap();
+        let second = scan_library_directories(&dirs, &state).unwrap().books;
         assert_eq!(second[0].title.as_deref(), Some("Anna Karenina"));
     }
 
@@ -819,12 +839,39 @@ mod tests {
             unavailable.path().to_string_lossy().to_string(),
             available.path().to_string_lossy().to_string(),
         ];
-        assert_eq!(scan_library_directories(&dirs, &state).unwrap().len(), 2);
+        assert_eq!(
+            scan_library_directories(&dirs, &state).unwrap().books.len(),
+            2
+        );
         std::fs::remove_dir_all(unavailable.path()).unwrap();
         std::fs::remove_file(extra).unwrap();
-        let books = scan_library_directories(&dirs, &state).unwrap();
-        assert_eq!(books.len(), 1);
-        assert_eq!(books[0].title.as_deref(), Some("War & Peace"));
+        let scan = scan_library_directories(&dirs, &state).unwrap();
+        assert_eq!(scan.books.len(), 1);
+        assert_eq!(scan.books[0].title.as_deref(), Some("War & Peace"));
+        assert_eq!(scan.skipped, [unavailable.path()]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unreadable_subdirectory_is_reported_as_skipped() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::copy("tests/fixtures/small.epub", dir.path().join("small.epub")).unwrap();
+        let locked = dir.path().join("locked");
+        std::fs::create_dir(&locked).unwrap();
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o000)).unwrap();
+        if std::fs::read_dir(&locked).is_ok() {
+            // Running as root: permissions are not enforced.
+            return;
+        }
+        let state = State::new_for_test();
+        let scan =
+            scan_library_directories(&[dir.path().to_string_lossy().to_string()], &state).unwrap();
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o755)).unwrap();
+        assert_eq!(scan.books.len(), 1);
+        assert_eq!(scan.skipped.len(), 1);
+        assert!(scan.skipped[0].ends_with("locked"));
     }
 
     fn make_calibre_database(root: &Path) {
@@ -881,8 +928,9 @@ mod tests {
         make_calibre_database(dir.path());
 
         let state = State::new_for_test();
-        let books =
-            scan_library_directories(&[dir.path().to_string_lossy().to_string()], &state).unwrap();
+        let books = scan_library_directories(&[dir.path().to_string_lossy().to_string()], &state)
+            .unwrap()
+            .books;
         assert_eq!(books.len(), 1);
         assert_eq!(books[0].title.as_deref(), Some("Guards! Guards!"));
         assert_eq!(books[0].author.as_deref(), Some("Terry Pratchett"));
@@ -903,8 +951,9 @@ mod tests {
             .execute("CREATE TABLE unrelated (id INTEGER)", [])
             .unwrap();
         let state = State::new_for_test();
-        let books =
-            scan_library_directories(&[dir.path().to_string_lossy().to_string()], &state).unwrap();
+        let books = scan_library_directories(&[dir.path().to_string_lossy().to_string()], &state)
+            .unwrap()
+            .books;
         assert_eq!(books.len(), 1);
         assert_eq!(books[0].title.as_deref(), Some("War & Peace"));
     }
@@ -5,13 +5,15 @@ use repy::{
     formats::{self, Ebook},
     logging::{self, LogLevel},
     renderer,
+    rpc::RpcChannel,
     state::State,
     statistics,
-    ui::reader::Reader,
+    ui::{reader::Reader, terminal::HeadlessBackend},
 };
 
 use clap::{CommandFactory, Parser};
-use eyre::Result;
+use eyre::{Result, WrapErr};
+use std::collections::HashMap;
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
@@ -36,8 +38,15 @@ fn main() -> Result<()> {
     if std::env::var_os("REPY_CLI_ECHO").is_some() {
         println!("history: {}", cli.history);
         println!("dump: {}", cli.dump);
+        println!("pager: {}", cli.pager);
         println!("export_highlights: {:?}", cli.export_highlights);
+        println!("export_annotations: {:?}", cli.export_annotations);
         println!("export_stats: {:?}", cli.export_stats);
+        println!("import_epy: {:?}", cli.import_epy);
+        println!("no_altscreen: {}", cli.no_altscreen);
+        println!("print_state: {}", cli.print_state);
+        println!("rpc: {}", cli.rpc);
+        println!("keymap: {:?}", cli.keymap);
         println!("ebook: {:?}", cli.ebook);
         return Ok(());
     }
@@ -47,11 +56,20 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    if let Some(book) = cli.export_annotations.as_ref() {
+        export_annotations(book)?;
+        return Ok(());
+    }
+
     if let Some(path) = cli.export_stats.as_ref() {
         export_statistics(path, cli.format)?;
         return Ok(());
     }
 
+    if let Some(path) = cli.import_epy.as_ref() {
+        return import_epy(path);
+    }
+
     if cli.history {
         return print_history();
     }
@@ -79,7 +97,9 @@ fn main() -> Result<()> {
     run_with_config(&cli, config)
 }
 
-fn run_with_config(cli: &Cli, config: Config) -> Result<()> {
+fn run_with_config(cli: &Cli, mut config: Config) -> Result<()> {
+    config.keymap_preset = cli.keymap;
+
     // Handle different CLI modes
     if cli.dump {
         let Some(arg) = cli.ebook.first() else {
@@ -89,9 +109,37 @@ fn run_with_config(cli: &Cli, config: Config) -> Result<()> {
         return dump_content(&resolve_ebook_arg(arg)?);
     }
 
+    if cli.pager {
+        let Some(arg) = cli.ebook.first() else {
+            eprintln!("Error: provide an ebook path, history number, or pattern to page");
+            std::process::exit(1);
+        };
+        return page_content(&resolve_ebook_arg(arg)?);
+    }
+
+    if cli.print_state {
+        let filepath = match cli.ebook.first() {
+            Some(arg) => resolve_ebook_arg(arg)?,
+            None => match State::new()?.get_last_read()? {
+                Some(filepath) => filepath,
+                None => {
+                    eprintln!("Error: provide an ebook path; the reading history is empty");
+                    std::process::exit(1);
+                }
+            },
+        };
+        return print_state(&filepath, config);
+    }
+
+    let rpc = if cli.rpc {
+        Some(RpcChannel::stdio()?)
+    } else {
+        None
+    };
+
     if let Some(arg) = cli.ebook.first() {
         match resolve_ebook_arg(arg) {
-            Ok(filepath) => run_tui_with_file(&filepath, config)?,
+            Ok(filepath) => run_tui_with_file(&filepath, config, !cli.no_altscreen, rpc)?,
             Err(err) => {
                 eprintln!("Error: {}", err);
                 std::process::exit(1);
@@ -99,7 +147,7 @@ fn run_with_config(cli: &Cli, config: Config) -> Result<()> {
         }
     } else {
         // TUI mode without a file (reopen last-read book)
-        run_tui(config)?;
+        run_tui(config, !cli.no_altscreen, rpc)?;
     }
 
     Ok(())
@@ -175,20 +223,55 @@ fn print_history() -> Result<()> {
     Ok(())
 }
 
-fn run_tui(config: Config) -> Result<()> {
+fn run_tui(config: Config, alternate_screen: bool, rpc: Option<RpcChannel>) -> Result<()> {
     let mut reader = Reader::new(config)?;
+    reader.set_rpc_channel(rpc);
     // When started without an explicit file, mimic `epy` by
     // reopening the last-read book at its saved position if available.
-    reader.load_last_ebook_if_any()?;
-    reader.run()
+    reader.open_last_ebook_on_start()?;
+    reader.run(alternate_screen)
 }
 
-fn run_tui_with_file(filepath: &str, config: Config) -> Result<()> {
+fn run_tui_with_file(
+    filepath: &str,
+    config: Config,
+    alternate_screen: bool,
+    rpc: Option<RpcChannel>,
+) -> Result<()> {
     let mut reader = Reader::new(config)?;
-    if let Err(e) = reader.load_ebook(filepath) {
-        eprintln!("Warning: Could not load ebook: {}", e);
+    reader.set_rpc_channel(rpc);
+    reader.open_on_start(filepath);
+    reader.run(alternate_screen)
+}
+
+/// Print the reading state of `filepath` as JSON without changing anything:
+/// a headless reader opens the book against an in-memory copy of its saved
+/// position, with the open/close hooks and progress sync switched off.
+fn print_state(filepath: &str, mut config: Config) -> Result<()> {
+    let path = std::fs::canonicalize(filepath)
+        .unwrap_or_else(|_| filepath.into())
+        .to_string_lossy()
+        .to_string();
+    let book = formats::open(&path)?;
+    let memory = State::in_memory()?;
+    if let Some(saved) = State::new()?.get_last_reading_state(book.as_ref())? {
+        memory.set_last_reading_state(book.as_ref(), &saved)?;
     }
-    reader.run()
+
+    config.settings.on_open = None;
+    config.settings.on_close = None;
+    config.settings.kosync_server = None;
+    // Lay the page out as the current terminal would show it (80x24 when
+    // there is none, e.g. from a status-bar script).
+    let (width, height) = crossterm::terminal::size().unwrap_or((80, 24));
+    let backend = HeadlessBackend::new(width, height);
+    let mut reader = Reader::with_backend(config, backend, memory, None)?;
+    reader.load_ebook(&path)?;
+    let snapshot = reader
+        .reading_snapshot()
+        .ok_or_else(|| eyre::eyre!("could not open {path}"))?;
+    println!("{}", serde_json::to_string_pretty(&snapshot)?);
+    Ok(())
 }
 
 fn dump_content(filepath: &str) -> Result<()> {
@@ -212,6 +295,38 @@ fn dump_content(filepath: &str) -> Result<()> {
     Ok(())
 }
 
+/// Pipe the rendered book into `$PAGER`, `less -R` when unset. `LESS=R` is
+/// set for a bare `less` so the bold and italic escapes come through.
+fn page_content(filepath: &str) -> Result<()> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut book = formats::open(filepath)?;
+    let structures = renderer::parse_book(book.as_mut(), 80, None, None)?;
+    let text = renderer::ansi_text(&structures);
+
+    let pager = std::env::var("PAGER")
+        .ok()
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| "less -R".to_string());
+    let mut parts = pager.split_whitespace();
+    let program = parts.next().unwrap_or("less");
+    let mut command = Command::new(program);
+    command.args(parts).stdin(Stdio::piped());
+    if std::env::var_os("LESS").is_none() {
+        command.env("LESS", "R");
+    }
+    let mut child = command
+        .spawn()
+        .wrap_err_with(|| format!("failed to start pager `{pager}`"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The pager may quit before reading everything; that is not an error.
+        let _ = stdin.write_all(text.as_bytes());
+    }
+    child.wait()?;
+    Ok(())
+}
+
 fn export_highlights(filepath: &std::path::Path, format: ExportFormat) -> Result<()> {
     let path = filepath.to_string_lossy();
     let mut book = formats::open(&path)?;
@@ -235,6 +350,63 @@ fn export_highlights(filepath: &std::path::Path, format: ExportFormat) -> Result
     Ok(())
 }
 
+/// Version of the `--export-annotations` JSON layout; bump when fields change
+/// incompatibly.
+const ANNOTATIONS_SCHEMA_VERSION: u32 = 1;
+
+fn export_annotations(filepath: &std::path::Path) -> Result<()> {
+    // Bookmarks are keyed by the canonical path the reader stores.
+    let path = std::fs::canonicalize(filepath)
+        .unwrap_or_else(|_| filepath.to_path_buf())
+        .to_string_lossy()
+        .to_string();
+    let mut book = formats::open(&path)?;
+    let identity = annotations::derive_book_identity(book.as_mut())?;
+    let db = State::new()?;
+    let mut highlights = db.list_highlights(&identity.book_id)?;
+    highlights.sort_by_key(|h| (h.content_index, h.approx_offset));
+    let mut bookmarks = db.get_bookmarks(book.as_ref())?;
+    bookmarks.sort_by_key(|(_, state)| (state.content_index, state.row));
+
+    let meta = book.get_meta();
+    let payload = serde_json::json!({
+        "schema_version": ANNOTATIONS_SCHEMA_VERSION,
+        "book": {
+            "title": meta.title,
+            "author": meta.creator,
+            "filepath": path,
+            "book_id": identity.book_id,
+        },
+        "bookmarks": bookmarks
+            .iter()
+            .map(|(name, state)| serde_json::json!({
+                "name": name,
+                "content_index": state.content_index,
+                "source_offset": state.source_offset,
+                "row": state.row,
+                "textwidth": state.textwidth,
+                "progress": state.rel_pctg,
+            }))
+            .collect::<Vec<_>>(),
+        "highlights": highlights
+            .iter()
+            .map(|h| serde_json::json!({
+                "id": h.id,
+                "content_index": h.content_index,
+                "spine_href": h.spine_href,
+                "offset": h.approx_offset,
+                "text": h.exact,
+                "note": h.comment,
+                "color": h.color,
+                "created_at": h.created_at,
+                "updated_at": h.updated_at,
+            }))
+            .collect::<Vec<_>>(),
+    });
+    println!("{}", serde_json::to_string_pretty(&payload)?);
+    Ok(())
+}
+
 fn export_statistics(filepath: &std::path::Path, format: ExportFormat) -> Result<()> {
     let stats = State::new()?.get_reading_statistics_export()?;
     if stats.global.total.sessions == 0 {
@@ -248,6 +420,35 @@ fn export_statistics(filepath: &std::path::Path, format: ExportFormat) -> Result
     Ok(())
 }
 
+/// Import an epy `states.db`, locating each saved epy row in the book parsed
+/// at epy's width so positions survive repy's different wrapping.
+fn import_epy(epy_db: &std::path::Path) -> Result<()> {
+    type Parsed = Option<Vec<repy::models::TextStructure>>;
+    let mut parsed: HashMap<(String, usize), Parsed> = HashMap::new();
+    let summary = State::new()?.import_epy_database(epy_db, |filepath, position| {
+        let structures = parsed
+            .entry((filepath.to_string(), position.textwidth))
+            .or_insert_with(|| {
+                let mut book = formats::open(filepath).ok()?;
+                renderer::parse_book(book.as_mut(), position.textwidth, None, None).ok()
+            })
+            .as_ref()?;
+        let source_map = &structures.get(position.content_index)?.source_map;
+        source_map
+            .row_spans
+            .get(position.row)
+            .map(|&(start, _)| start as usize)
+    })?;
+    println!(
+        "Imported {} books and {} bookmarks from {} ({} already in repy's history)",
+        summary.books,
+        summary.bookmarks,
+        epy_db.display(),
+        summary.skipped
+    );
+    Ok(())
+}
+
 /// Render highlights as Markdown grouped by chapter, in reading order.
 fn highlights_to_markdown(book: &dyn Ebook, highlights: &[repy::models::Highlight]) -> String {
     use std::fmt::Write;
@@ -35,18 +35,25 @@ pub enum WindowType {
     OpdsDetails,
     Search,
     Links,
+    Pages,
+    ChapterSource,
     Metadata,
+    Messages,
     Settings,
     SettingsTextInput,
     Images,
     ImageView,
     Statistics,
     Visual,
+    DictionaryPresets,
     DictionaryCommandInput,
     Highlights,
     HighlightCommentEditor,
     ConfirmDeleteHighlight,
     ConfirmSyncProgress,
+    ConfirmRelink,
+    ConfirmResume,
+    ConfirmOpenLinkedBook,
     LinkPreview,
 }
 
@@ -78,6 +85,10 @@ pub struct ReadingStatistics {
     pub longest_streak_days: usize,
     pub estimated_book_minutes_left: Option<i64>,
     pub estimated_chapter_minutes_left: Option<i64>,
+    /// Rolling words per minute over the recent part of the current session.
+    pub session_words_per_minute: Option<f64>,
+    /// Minutes to finish the book at `session_words_per_minute`.
+    pub session_book_minutes_left: Option<i64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -133,6 +144,31 @@ pub struct BookMetadata {
     pub format: Option<String>,
     pub identifier: Option<String>,
     pub source: Option<String>,
+    /// `ltr` or `rtl` from the EPUB spine's `page-progression-direction`.
+    pub page_progression_direction: Option<String>,
+}
+
+/// Primary language subtags written right-to-left.
+const RTL_LANGUAGES: [&str; 11] = [
+    "ar", "ckb", "dv", "fa", "he", "iw", "ps", "sd", "ug", "ur", "yi",
+];
+
+impl BookMetadata {
+    /// Whether the book reads right-to-left: an explicit page progression
+    /// direction wins, otherwise the language decides.
+    pub fn is_rtl(&self) -> bool {
+        if let Some(direction) = self.page_progression_direction.as_deref() {
+            return direction.eq_ignore_ascii_case("rtl");
+        }
+        self.language.as_deref().is_some_and(|language| {
+            let primary = language
+                .split(['-', '_'])
+                .next()
+                .unwrap_or("")
+                .to_ascii_lowercase();
+            RTL_LANGUAGES.contains(&primary.as_str())
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -142,6 +178,8 @@ pub struct LibraryItem {
     pub title: Option<String>,
     pub author: Option<String>,
     pub reading_progress: Option<f32>,
+    /// Marked as finished by the reader (kept across later reads).
+    pub finished: bool,
 }
 
 /// An ebook file found by the library directory scanner.
@@ -203,6 +241,7 @@ pub struct LibraryEntry {
     pub history_filepath: Option<String>,
     pub last_read: Option<DateTime<Utc>>,
     pub reading_progress: Option<f32>,
+    pub finished: bool,
     /// False for history entries whose file no longer exists on disk.
     pub on_disk: bool,
 }
@@ -263,7 +302,48 @@ impl LibrarySortMode {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// Library view restricted by reading status. Unread means no progress yet;
+/// a finished flag wins over any recorded progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LibraryProgressFilter {
+    #[default]
+    All,
+    Unread,
+    InProgress,
+    Finished,
+}
+
+impl LibraryProgressFilter {
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Unread,
+            Self::Unread => Self::InProgress,
+            Self::InProgress => Self::Finished,
+            Self::Finished => Self::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Unread => "unread",
+            Self::InProgress => "in progress",
+            Self::Finished => "finished",
+        }
+    }
+
+    pub fn matches(self, entry: &LibraryEntry) -> bool {
+        let started = entry.reading_progress.is_some_and(|p| p > 0.0);
+        match self {
+            Self::All => true,
+            Self::Unread => !entry.finished && !started,
+            Self::InProgress => !entry.finished && started,
+            Self::Finished => entry.finished,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ReadingState {
     pub content_index: usize,
     /// Chapter-local character offset in the normalized source text.
@@ -272,6 +352,8 @@ pub struct ReadingState {
     pub row: usize,
     pub rel_pctg: Option<f32>,
     pub section: Option<String>,
+    /// TTS engine chosen for this book; `None` uses `preferred_tts_engine`.
+    pub tts_engine: Option<String>,
 }
 
 impl Default for ReadingState {
@@ -283,10 +365,26 @@ impl Default for ReadingState {
             row: 0,
             rel_pctg: None,
             section: None,
+            tts_engine: None,
         }
     }
 }
 
+/// "Now reading" summary for status bars, written by `J` and printed by
+/// `--print-state`: the saved position plus what it means on screen.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReadingSnapshot {
+    pub file: String,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    #[serde(flatten)]
+    pub reading_state: ReadingState,
+    pub chapter: Option<String>,
+    pub percent: u32,
+    /// The lines on screen, chapter-break markers left out.
+    pub visible_text: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct SearchData {
     pub direction: Direction,
@@ -433,6 +531,32 @@ pub struct TocEntry {
     pub section: Option<String>,
 }
 
+/// A structural landmark from the EPUB 3 nav `landmarks` list or the
+/// EPUB 2 OPF `<guide>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Landmark {
+    /// Landmark type in EPUB 3 terms, e.g. `cover`, `toc` or `bodymatter`
+    /// (the guide's `text` reference is reported as `bodymatter`).
+    pub kind: String,
+    pub label: String,
+    pub content_index: usize,
+    pub section: Option<String>,
+}
+
+/// One `<par>` of an EPUB 3 media overlay: the text element it narrates
+/// and the stretch of recorded audio that reads it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NarrationClip {
+    /// `id` of the narrated element in the chapter (the `<text src>` fragment).
+    pub fragment: String,
+    /// Archive path of the audio file.
+    pub audio: String,
+    /// Clip start in seconds.
+    pub clip_begin: f64,
+    /// Clip end in seconds; `None` plays to the end of the file.
+    pub clip_end: Option<f64>,
+}
+
 /// Per-chapter bidirectional projection between wrapped rows and char offsets
 /// into the normalized chapter source text.
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -676,6 +800,9 @@ impl SourceMap {
 pub struct TextStructure {
     pub text_lines: Vec<String>,
     pub image_maps: HashMap<usize, String>,
+    /// Descriptive `alt` text of images that have one, keyed like
+    /// `image_maps`. Filename-only or generic ("image") alts are left out.
+    pub image_alts: HashMap<usize, String>,
     pub section_rows: HashMap<String, usize>,
     /// Chapter-local source offsets for section and anchor ids.
     pub section_offsets: HashMap<String, usize>,
@@ -732,6 +859,24 @@ mod tests {
         assert_eq!(metadata.format, None);
         assert_eq!(metadata.identifier, None);
         assert_eq!(metadata.source, None);
+        assert_eq!(metadata.page_progression_direction, None);
+        assert!(!metadata.is_rtl());
+    }
+
+    #[test]
+    fn test_book_metadata_is_rtl() {
+        let with = |language: Option<&str>, direction: Option<&str>| BookMetadata {
+            language: language.map(str::to_string),
+            page_progression_direction: direction.map(str::to_string),
+            ..BookMetadata::default()
+        };
+        assert!(with(Some("ar"), None).is_rtl());
+        assert!(with(Some("he-IL"), None).is_rtl());
+        assert!(with(Some("FA"), None).is_rtl());
+        assert!(!with(Some("en"), None).is_rtl());
+        assert!(!with(Some("art"), None).is_rtl());
+        assert!(with(Some("en"), Some("rtl")).is_rtl());
+        assert!(!with(Some("ar"), Some("ltr")).is_rtl());
     }
 
     #[test]
@@ -746,6 +891,7 @@ mod tests {
             format: Some("epub".to_string()),
             identifier: Some("test-id".to_string()),
             source: Some("test-source".to_string()),
+            page_progression_direction: None,
         };
 
         assert_eq!(metadata.title, Some("Test Book".to_string()));
@@ -768,6 +914,7 @@ mod tests {
             title: Some("Test Book".to_string()),
             author: Some("Test Author".to_string()),
             reading_progress: Some(0.5),
+            finished: false,
         };
 
         assert_eq!(item.last_read, now);
@@ -797,6 +944,7 @@ mod tests {
             row: 100,
             rel_pctg: Some(0.75),
             section: Some("chapter-2".to_string()),
+            tts_engine: None,
         };
 
         assert_eq!(state.content_index, 5);
@@ -1020,6 +1168,7 @@ mod tests {
             source_map: Default::default(),
             text_lines: vec!["Line 1 of text".to_string(), "Line 2 of text".to_string()],
             image_maps,
+            image_alts: std::collections::HashMap::new(),
             section_rows,
             section_offsets: std::collections::HashMap::new(),
             formatting,
@@ -1148,6 +1297,7 @@ mod tests {
             format: None,
             identifier: None,
             source: None,
+            page_progression_direction: None,
         };
 
         let cloned = original.clone();
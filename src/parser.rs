@@ -27,6 +27,8 @@ static RE_IMG_SRC: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#"src=["']([^"']+)["']"#).unwrap());
 static RE_IMG_ALT: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#"alt=["']([^"']*)["']"#).unwrap());
+/// Attribute `preprocess_images` stores an image's descriptive alt in.
+const IMAGE_DESCRIPTION_ATTR: &str = "data-repy-description";
 static RE_IMG_TITLE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#"title=["']([^"']*)["']"#).unwrap());
 static RE_SVG_BLOCK: LazyLock<Regex> =
@@ -240,6 +242,7 @@ pub fn parse_html_with_styles_and_typography(
 
     // Extract structure information using the parsed fragment
     let image_maps = extract_images(&fragment, starting_line, &plain_text)?;
+    let image_alts = extract_image_alts(&fragment, starting_line, &plain_text);
     let (section_offsets, section_rows) = extract_sections(
         &fragment,
         &section_ids.unwrap_or_default(),
@@ -254,6 +257,7 @@ pub fn parse_html_with_styles_and_typography(
     Ok(TextStructure {
         text_lines: plain_text,
         image_maps,
+        image_alts,
         section_rows,
         section_offsets,
         formatting,
@@ -893,39 +897,61 @@ fn html_to_plain_text(html: &str, width: usize) -> Result<Vec<String>> {
     Ok(lines)
 }
 
-/// Extract image information from HTML and map to text lines
-fn extract_images(
-    fragment: &Html,
-    starting_line: usize,
+/// `<img>` elements that produced a placeholder line, paired with that
+/// line's index in `text_lines`, in document order.
+fn placeholder_images<'a>(
+    fragment: &'a Html,
     text_lines: &[String],
-) -> Result<HashMap<usize, String>> {
-    let mut images = HashMap::new();
+) -> Vec<(usize, scraper::ElementRef<'a>)> {
     let img_selector = Selector::parse("img").unwrap();
+    let mut elements = fragment
+        .select(&img_selector)
+        .filter(|element| element.value().attr("src").is_some())
+        .peekable();
 
-    // Get all image sources in order
-    let mut image_sources: Vec<String> = Vec::new();
-    for element in fragment.select(&img_selector) {
-        if let Some(src) = element.value().attr("src") {
-            image_sources.push(src.to_string());
-        }
-    }
-
-    // Find image placeholders in text lines and map them
-    let mut image_idx = 0;
+    // Check for [Image: ...] or [[Image: ...]] pattern
+    // html2text wraps alt in [], and our alt is [Image: ...], so it becomes [[Image: ...]]
+    let mut images = Vec::new();
     for (line_num, line) in text_lines.iter().enumerate() {
-        if image_idx >= image_sources.len() {
+        if elements.peek().is_none() {
             break;
         }
-
-        // Check for [Image: ...] or [[Image: ...]] pattern
-        // html2text wraps alt in [], and our alt is [Image: ...], so it becomes [[Image: ...]]
         if line.contains("[Image:") || line.contains("[[Image:") {
-            images.insert(starting_line + line_num, image_sources[image_idx].clone());
-            image_idx += 1;
+            images.push((line_num, elements.next().unwrap()));
         }
     }
+    images
+}
 
-    Ok(images)
+/// Extract image information from HTML and map to text lines
+fn extract_images(
+    fragment: &Html,
+    starting_line: usize,
+    text_lines: &[String],
+) -> Result<HashMap<usize, String>> {
+    Ok(placeholder_images(fragment, text_lines)
+        .into_iter()
+        .filter_map(|(line_num, element)| {
+            let src = element.value().attr("src")?;
+            Some((starting_line + line_num, src.to_string()))
+        })
+        .collect())
+}
+
+/// Descriptive alt text recorded by `preprocess_images`, keyed like
+/// [`extract_images`].
+fn extract_image_alts(
+    fragment: &Html,
+    starting_line: usize,
+    text_lines: &[String],
+) -> HashMap<usize, String> {
+    placeholder_images(fragment, text_lines)
+        .into_iter()
+        .filter_map(|(line_num, element)| {
+            let alt = element.value().attr(IMAGE_DESCRIPTION_ATTR)?.trim();
+            (!alt.is_empty()).then(|| (starting_line + line_num, alt.to_string()))
+        })
+        .collect()
 }
 
 /// Resolve the representative text for an element, falling back to child headings,
@@ -2210,6 +2236,41 @@ mod tests {
         assert_eq!(images.len(), 0);
     }
 
+    #[test]
+    fn test_image_alts_keep_descriptions_only() {
+        let html = r#"<p>Intro</p>
+        <p><img src="barn.png" alt="A red barn &amp; silo"></p>
+        <p><img src="logo.png" alt=""></p>
+        <p><img src="map.png" title="Figure 2" alt="Map of the valley"></p>"#;
+        let result = parse_html(html, Some(80), None, 3).unwrap();
+        let mut alts: Vec<(&str, &str)> = result
+            .image_alts
+            .iter()
+            .map(|(row, alt)| (result.image_maps[row].as_str(), alt.as_str()))
+            .collect();
+        alts.sort_unstable();
+        assert_eq!(
+            alts,
+            vec![
+                ("barn.png", "A red barn & silo"),
+                ("map.png", "Map of the valley"),
+            ]
+        );
+        // The title still wins for the visible placeholder.
+        assert!(
+            result
+                .text_lines
+                .iter()
+                .any(|l| l.contains("[Image: Figure 2]"))
+        );
+        assert!(
+            result
+                .text_lines
+                .iter()
+                .any(|l| l.contains("[Image: logo.png]"))
+        );
+    }
+
     #[test]
     fn test_extract_sections() {
         let html = r#"<h1 id="chapter1">Chapter 1</h1>"#;
@@ -3157,7 +3218,12 @@ fn preprocess_images(html: &str) -> String {
                     format!(r#"{} alt="{}""#, attrs_str, new_alt_text)
                 };
 
-                format!("<img {}>", new_attrs)
+                // Keep the original description for the images list and
+                // TTS; the placeholder above may show the title instead.
+                match alt.filter(|a| !a.trim().is_empty() && a.to_lowercase() != "image") {
+                    Some(a) => format!(r#"<img {IMAGE_DESCRIPTION_ATTR}="{a}" {new_attrs}>"#),
+                    None => format!("<img {}>", new_attrs),
+                }
             } else {
                 caps[0].to_string()
             }
@@ -9,6 +9,24 @@ use crate::parser::{InlineImageOptions, TypographyOptions, parse_html_with_style
 use eyre::Result;
 use std::collections::{HashMap, HashSet};
 
+/// How the padding inserted between chapters is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChapterBreakOptions {
+    /// Blank lines before the break marker.
+    pub blank_lines: usize,
+    /// Follow the marker with the next chapter's TOC title.
+    pub title_banner: bool,
+}
+
+impl Default for ChapterBreakOptions {
+    fn default() -> Self {
+        Self {
+            blank_lines: 1,
+            title_banner: false,
+        }
+    }
+}
+
 /// Parse a single chapter into a wrapped [`TextStructure`].
 pub fn parse_chapter(
     ebook: &mut dyn Ebook,
@@ -75,6 +93,7 @@ pub fn parse_book(
         page_height,
         inline_image_rows,
         TypographyOptions::default(),
+        ChapterBreakOptions::default(),
     )
 }
 
@@ -84,6 +103,7 @@ pub fn parse_book_with_typography(
     page_height: Option<usize>,
     inline_image_rows: Option<usize>,
     typography: TypographyOptions,
+    chapter_break: ChapterBreakOptions,
 ) -> Result<Vec<TextStructure>> {
     let mut all_content = Vec::new();
     let mut starting_line = 0;
@@ -102,7 +122,13 @@ pub fn parse_book_with_typography(
             && index + 1 < total_chapters
         {
             let total_lines = starting_line + parsed_content.text_lines.len();
-            let break_lines = build_chapter_break(page_height, total_lines);
+            let next_title = next_chapter_title(ebook, index);
+            let break_lines = build_chapter_break(
+                page_height,
+                total_lines,
+                chapter_break,
+                next_title.as_deref(),
+            );
             parsed_content.text_lines.extend(break_lines);
         }
         starting_line += parsed_content.text_lines.len();
@@ -198,10 +224,31 @@ fn collect_image_dimensions(
     dimensions
 }
 
-pub fn build_chapter_break(page_height: usize, total_lines: usize) -> Vec<String> {
-    let mut lines = Vec::new();
-    lines.push(String::new());
+/// TOC label of the chapter after `index`, for the chapter-break banner.
+pub fn next_chapter_title(ebook: &dyn Ebook, index: usize) -> Option<String> {
+    ebook
+        .toc_entries()
+        .iter()
+        .find(|entry| entry.content_index == index + 1)
+        .map(|entry| entry.label.trim().to_string())
+        .filter(|label| !label.is_empty())
+}
+
+/// Lines appended after a chapter: blank lines, the break marker, the
+/// optional next-chapter banner, then padding up to the next page boundary.
+pub fn build_chapter_break(
+    page_height: usize,
+    total_lines: usize,
+    options: ChapterBreakOptions,
+    next_title: Option<&str>,
+) -> Vec<String> {
+    let mut lines = vec![String::new(); options.blank_lines];
     lines.push(CHAPTER_BREAK_MARKER.to_string());
+    if options.title_banner
+        && let Some(title) = next_title
+    {
+        lines.push(title.to_string());
+    }
     if page_height == 0 {
         return lines;
     }
@@ -215,6 +262,57 @@ pub fn build_chapter_break(page_height: usize, total_lines: usize) -> Vec<String
     lines
 }
 
+/// The parsed book as plain lines with bold and italic runs marked by ANSI
+/// escapes, for `--pager`. Chapters are separated by a blank line, as in
+/// `--dump`.
+pub fn ansi_text(structures: &[TextStructure]) -> String {
+    let mut out = String::new();
+    let mut row = 0;
+    for (index, structure) in structures.iter().enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        for line in &structure.text_lines {
+            let chars: Vec<char> = line.chars().collect();
+            // Bit 0 is bold (attr 1), bit 1 italic (attr 2).
+            let mut flags = vec![0u32; chars.len()];
+            for style in structure
+                .formatting
+                .iter()
+                .filter(|style| style.row as usize == row)
+            {
+                let start = (style.col as usize).min(chars.len());
+                let end = (start + style.n_letters as usize).min(chars.len());
+                for flag in &mut flags[start..end] {
+                    *flag |= style.attr & 0b11;
+                }
+            }
+            let mut current = 0;
+            for (ch, &flag) in chars.iter().zip(&flags) {
+                if flag != current {
+                    if current != 0 {
+                        out.push_str("\x1b[0m");
+                    }
+                    if flag & 0b01 != 0 {
+                        out.push_str("\x1b[1m");
+                    }
+                    if flag & 0b10 != 0 {
+                        out.push_str("\x1b[3m");
+                    }
+                    current = flag;
+                }
+                out.push(*ch);
+            }
+            if current != 0 {
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+            row += 1;
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,6 +324,37 @@ mod tests {
         Ok(epub)
     }
 
+    #[test]
+    fn test_ansi_text_marks_bold_and_italic_runs() {
+        use crate::models::InlineStyle;
+
+        let first = TextStructure {
+            text_lines: vec!["a bold word".to_string()],
+            formatting: vec![InlineStyle {
+                row: 0,
+                col: 2,
+                n_letters: 4,
+                attr: 1,
+            }],
+            ..Default::default()
+        };
+        // Rows count on across chapters, like the reader's board.
+        let second = TextStructure {
+            text_lines: vec!["in italics".to_string()],
+            formatting: vec![InlineStyle {
+                row: 1,
+                col: 3,
+                n_letters: 7,
+                attr: 2,
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            ansi_text(&[first, second]),
+            "a \x1b[1mbold\x1b[0m word\n\nin \x1b[3mitalics\x1b[0m\n"
+        );
+    }
+
     #[test]
     fn test_parse_chapter_small() -> Result<()> {
         let mut epub = small_epub()?;
@@ -472,7 +601,7 @@ mod tests {
 
     #[test]
     fn test_build_chapter_break_pads_to_page() {
-        let lines = build_chapter_break(10, 13);
+        let lines = build_chapter_break(10, 13, ChapterBreakOptions::default(), None);
         // 2 marker lines + padding to the next multiple of 10
         assert_eq!(lines.len(), 2 + 5);
         assert_eq!(lines[1], CHAPTER_BREAK_MARKER);
@@ -480,6 +609,26 @@ mod tests {
 
     #[test]
     fn test_build_chapter_break_zero_height() {
-        assert_eq!(build_chapter_break(0, 42).len(), 2);
+        assert_eq!(
+            build_chapter_break(0, 42, ChapterBreakOptions::default(), Some("Two")).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_build_chapter_break_blank_lines_and_banner() {
+        let options = ChapterBreakOptions {
+            blank_lines: 3,
+            title_banner: true,
+        };
+        let lines = build_chapter_break(10, 13, options, Some("Chapter Two"));
+        assert_eq!(lines[..3], ["", "", ""]);
+        assert_eq!(lines[3], CHAPTER_BREAK_MARKER);
+        assert_eq!(lines[4], "Chapter Two");
+        assert_eq!((13 + lines.len()) % 10, 0);
+
+        // No banner when the next chapter has no TOC entry.
+        let lines = build_chapter_break(0, 13, options, None);
+        assert_eq!(lines.len(), 4);
     }
 }
@@ -0,0 +1,169 @@
+//! `--rpc` control mode: other programs drive the reader by writing
+//! newline-delimited JSON commands to its stdin and read one JSON reply per
+//! command from its stdout.
+//!
+//! Commands are read on a background thread and handed to the event loop
+//! through a channel, which it drains without blocking between keypresses.
+//! The terminal UI moves to `/dev/tty` so that stdout carries only replies.
+
+use eyre::{Result, WrapErr};
+use serde::Deserialize;
+use std::io::{BufRead, Write};
+use std::sync::mpsc::{self, Receiver};
+
+/// One request, tagged by its `cmd` field, e.g. `{"cmd":"goto","row":100}`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+pub enum RpcCommand {
+    /// Jump to a 0-based `row`, a 1-based `chapter`, or a `percent` of the
+    /// book; exactly one of them must be given.
+    Goto {
+        row: Option<usize>,
+        chapter: Option<usize>,
+        percent: Option<f64>,
+    },
+    NextPage,
+    PrevPage,
+    NextChapter,
+    PrevChapter,
+    Start,
+    End,
+    /// Reply with the current state without changing anything.
+    State,
+    Quit,
+}
+
+/// Parse one line of input. Errors are meant to be sent back as-is.
+pub fn parse_command(line: &str) -> std::result::Result<RpcCommand, String> {
+    serde_json::from_str(line).map_err(|err| format!("invalid command: {err}"))
+}
+
+/// Reply for a command that could not be carried out.
+pub fn error_reply(message: &str) -> serde_json::Value {
+    serde_json::json!({ "ok": false, "error": message })
+}
+
+/// The reader's end of the control channel.
+pub struct RpcChannel {
+    commands: Receiver<String>,
+    replies: Box<dyn Write>,
+}
+
+impl RpcChannel {
+    /// A channel fed by `commands` that writes replies to `replies`.
+    pub fn new(commands: Receiver<String>, replies: Box<dyn Write>) -> Self {
+        Self { commands, replies }
+    }
+
+    /// Take over stdin and stdout. The original stdout is kept for replies
+    /// and the terminal UI is pointed at `/dev/tty` instead; keys are read
+    /// from `/dev/tty` already once stdin is not a terminal.
+    #[cfg(unix)]
+    pub fn stdio() -> Result<Self> {
+        use std::io::IsTerminal;
+        use std::os::fd::{AsRawFd, FromRawFd};
+
+        if std::io::stdin().is_terminal() {
+            eyre::bail!("--rpc reads commands from stdin; pipe them in");
+        }
+        let tty = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/tty")
+            .wrap_err("--rpc needs a controlling terminal to draw on")?;
+        // SAFETY: plain descriptor duplication; the duplicate is owned by the
+        // `File` below and fd 1 stays open, now referring to the terminal.
+        let replies = unsafe {
+            let replies = libc::dup(libc::STDOUT_FILENO);
+            if replies < 0 || libc::dup2(tty.as_raw_fd(), libc::STDOUT_FILENO) < 0 {
+                return Err(std::io::Error::last_os_error())
+                    .wrap_err("could not move stdout aside for --rpc");
+            }
+            std::fs::File::from_raw_fd(replies)
+        };
+
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in std::io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if !line.trim().is_empty() && tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(Self::new(rx, Box::new(replies)))
+    }
+
+    #[cfg(not(unix))]
+    pub fn stdio() -> Result<Self> {
+        eyre::bail!("--rpc is only supported on Unix")
+    }
+
+    /// The next pending command line, if any; never blocks. Once stdin is
+    /// closed this keeps returning `None` and the keyboard still works.
+    pub fn try_recv(&self) -> Option<String> {
+        self.commands.try_recv().ok()
+    }
+
+    /// Write `reply` as one line and flush it, so a reader on the other end
+    /// of a pipe sees it straight away.
+    pub fn reply(&mut self, reply: &serde_json::Value) -> std::io::Result<()> {
+        writeln!(self.replies, "{reply}")?;
+        self.replies.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands_parse_by_their_cmd_tag() {
+        assert_eq!(
+            parse_command(r#"{"cmd":"goto","row":100}"#),
+            Ok(RpcCommand::Goto {
+                row: Some(100),
+                chapter: None,
+                percent: None
+            })
+        );
+        assert_eq!(
+            parse_command(r#"{"cmd":"next_chapter"}"#),
+            Ok(RpcCommand::NextChapter)
+        );
+        assert_eq!(parse_command(r#" {"cmd":"state"} "#), Ok(RpcCommand::State));
+        assert!(parse_command(r#"{"cmd":"fly"}"#).is_err());
+        assert!(parse_command(r#"{"cmd":"goto","row":-1}"#).is_err());
+        assert!(parse_command("next_chapter").is_err());
+    }
+
+    #[test]
+    fn replies_are_written_one_per_line() {
+        #[derive(Clone, Default)]
+        struct Shared(std::rc::Rc<std::cell::RefCell<Vec<u8>>>);
+        impl Write for Shared {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.borrow_mut().write(buf)
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let (tx, rx) = mpsc::channel();
+        let out = Shared::default();
+        let mut channel = RpcChannel::new(rx, Box::new(out.clone()));
+        assert_eq!(channel.try_recv(), None);
+        tx.send(r#"{"cmd":"state"}"#.to_string()).unwrap();
+        drop(tx);
+        assert_eq!(channel.try_recv().as_deref(), Some(r#"{"cmd":"state"}"#));
+        assert_eq!(channel.try_recv(), None);
+
+        channel.reply(&error_reply("no book is open")).unwrap();
+        channel.reply(&serde_json::json!({ "ok": true })).unwrap();
+        assert_eq!(
+            String::from_utf8(out.0.borrow().clone()).unwrap(),
+            "{\"error\":\"no book is open\",\"ok\":false}\n{\"ok\":true}\n"
+        );
+    }
+}
@@ -14,6 +14,32 @@ pub const VIEWER_PRESET_LIST: &[&str] = &[
 
 pub const DICT_PRESET_LIST: &[&str] = &["dict", "sdcv", "wkdict"];
 
+/// A named dictionary program and the command that runs it. `auto` tries
+/// the presets in list order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DictionaryPreset {
+    pub name: String,
+    /// Command template; `%q` is replaced by the looked-up text, which is
+    /// appended as the last argument when `%q` is absent.
+    pub command: String,
+}
+
+impl DictionaryPreset {
+    /// The built-in presets, in `DICT_PRESET_LIST` order.
+    pub fn defaults() -> Vec<Self> {
+        DICT_PRESET_LIST
+            .iter()
+            .map(|&name| Self {
+                name: name.to_string(),
+                command: match name {
+                    "sdcv" => "sdcv -n %q".to_string(),
+                    _ => format!("{name} %q"),
+                },
+            })
+            .collect()
+    }
+}
+
 pub const TTS_PRESET_LIST: &[&str] = &["purr", "edge-tts", "trans"];
 pub const DEFAULT_KOSYNC_SERVER: &str = "https://sync.koreader.rocks";
 
@@ -21,6 +47,13 @@ pub const DEFAULT_KOSYNC_SERVER: &str = "https://sync.koreader.rocks";
 /// for the `=` width reset.
 pub const DEFAULT_TEXT_WIDTH: usize = 80;
 
+/// Page-turn animation length when `page_scroll_animation_ms` is absent.
+pub const DEFAULT_PAGE_SCROLL_ANIMATION_MS: u64 = 100;
+
+/// Starting point when the TTS rate is first adjusted in Settings; both
+/// espeak and say speak at about 175 words per minute by default.
+pub const DEFAULT_TTS_RATE: u32 = 175;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OpdsCatalogConfig {
     pub name: String,
@@ -68,6 +101,34 @@ impl InlineImages {
     }
 }
 
+/// English Wikipedia edition consulted first for all-ASCII lookups; the
+/// other one is tried when it has no summary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum AsciiWikipedia {
+    /// Simple English Wikipedia.
+    #[default]
+    Simple,
+    En,
+}
+
+impl AsciiWikipedia {
+    /// Wikipedia language code, also used as the settings label.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Simple => "simple",
+            Self::En => "en",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Simple => Self::En,
+            Self::En => Self::Simple,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "kebab-case")]
 pub enum ParagraphStyle {
@@ -122,21 +183,145 @@ impl LineSpacing {
     }
 }
 
+/// What the `show_line_numbers` gutter counts from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum LineNumberStyle {
+    /// Rows from the start of the book.
+    #[default]
+    Absolute,
+    /// Rows from the start of the current chapter.
+    Chapter,
+    /// Rows from the last print-page marker; chapters without one count
+    /// from the chapter start.
+    Page,
+}
+
+impl LineNumberStyle {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Absolute => "absolute",
+            Self::Chapter => "chapter",
+            Self::Page => "page",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Absolute => Self::Chapter,
+            Self::Chapter => Self::Page,
+            Self::Page => Self::Absolute,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Settings {
     pub default_viewer: String,
     pub dictionary_client: String,
+    /// Programs that `dictionary_client` can name; "auto" tries them in order.
+    pub dictionary_presets: Vec<DictionaryPreset>,
+    /// With `dictionary_client` "auto", show every client's output under its
+    /// own header instead of stopping at the first one that answers.
+    pub aggregate_dictionaries: bool,
+    /// Wikipedia edition for all-ASCII lookups (`simple` or `en`).
+    pub ascii_wikipedia: AsciiWikipedia,
+    /// Command that receives copied text on stdin when the system clipboard
+    /// is unavailable, e.g. `wl-copy` or `xclip -selection clipboard`.
+    pub clipboard_command: Option<String>,
+    /// What `y` copies: `%s` selected text, `%t` title, `%a` author,
+    /// `%c` chapter, `%p` percent read. Empty copies the bare text.
+    pub yank_template: String,
+    /// File that `W` appends the visible page to, with a chapter and
+    /// percent header per capture.
+    pub capture_file: Option<String>,
+    /// File that `J` writes the current reading state to as JSON;
+    /// `reading-state.json` in the config directory when unset.
+    pub state_file: Option<String>,
+    /// Command run (detached) after a book opens: `%f` file, `%p` percent
+    /// read, `%t` title, `%%` a literal percent sign.
+    pub on_open: Option<String>,
+    /// Command run (detached) when a book is closed, by quitting or by
+    /// opening another; same placeholders as `on_open`.
+    pub on_close: Option<String>,
     pub show_progress_indicator: bool,
     pub page_scroll_animation: bool,
+    /// Length of the page-turn scroll animation in milliseconds.
+    pub page_scroll_animation_ms: u64,
     pub mouse_support: bool,
+    /// Collect leading digits as a count (`5j`, `3t`); when off, a bare
+    /// digit jumps to that tenth of the book instead.
+    pub count_prefix_enabled: bool,
+    /// Let search match image placeholders (`[Image: …]`) as well as prose.
+    pub search_placeholders: bool,
+    /// Keep every search match highlighted while paging through the book;
+    /// when off, matches show only on the page holding the current hit.
+    pub hlsearch: bool,
+    /// Match curly quotes, dashes, and ligatures (`’`, `—`, `ﬁ`) by their
+    /// plain ASCII forms in search, in both the query and the book.
+    pub normalize_search: bool,
+    /// Offer to open another ebook that a link points to (`vol2.epub`)
+    /// when the file sits next to the current book.
+    pub open_linked_books: bool,
     pub color_theme: ColorTheme,
     pub seamless_between_chapters: bool,
+    /// Blank lines inserted before each chapter break.
+    pub chapter_break_blank_lines: usize,
+    /// Draw chapter breaks as a centered horizontal rule instead of `***`.
+    pub chapter_break_rule: bool,
+    /// Show the next chapter's title as a banner below the break.
+    pub chapter_break_title: bool,
+    /// Reading ruler: dim every line outside a band of rows.
+    pub focus_mode: bool,
+    /// Lines of context kept bright on each side of the ruler's center.
+    pub focus_lines: usize,
+    /// Bold the first part of each word ("bionic reading").
+    pub bionic_reading: bool,
     pub preferred_tts_engine: Option<String>,
     pub tts_engine_args: Vec<String>,
+    /// Speaking rate in words per minute for the built-in `espeak`/`say`
+    /// engines; `None` keeps the engine's own default.
+    pub tts_rate: Option<u32>,
+    /// Voice name passed to `espeak`/`say` with `-v`.
+    pub tts_voice: Option<String>,
+    /// Read images that have descriptive alt text as "Image: …".
+    pub tts_read_images: bool,
+    /// Say "Chapter: Title" where each table-of-contents entry starts.
+    pub tts_announce_chapters: bool,
+    /// Pause reading aloud at the end of each chapter until `!` is pressed.
+    pub tts_stop_at_chapter: bool,
+    /// Play an EPUB's recorded narration (media overlays) on `!` instead of
+    /// synthetic speech, where the chapter has one.
+    pub play_narration: bool,
+    /// Command that plays one narration clip, with `{file}`, `{start}` and
+    /// `{end}` (seconds) filled in; `None` uses mpv or ffplay.
+    pub narration_player: Option<String>,
     pub width: Option<usize>,
+    /// Terminal columns kept blank left/right of the reading area; the text
+    /// is centered in what remains.
+    pub left_margin: usize,
+    pub right_margin: usize,
+    /// Lines of context kept above and below the cursor in cursor mode,
+    /// like vim's `scrolloff`.
+    pub scrolloff: usize,
     pub show_line_numbers: bool,
+    pub line_number_style: LineNumberStyle,
     pub show_top_bar: bool,
+    /// Draw a frame around the reading text.
+    pub content_border: bool,
+    /// Lead the top bar with the current chapter's TOC title.
+    pub show_chapter_title: bool,
+    /// Show this session's reading pace and the time left in the book at
+    /// that pace in the top bar.
+    pub show_eta: bool,
+    /// Name the terminal window or tab after the book and chapter being
+    /// read; the previous title comes back on quit.
+    pub set_terminal_title: bool,
+    /// Label the top bar with the EPUB's print page numbers (its page-list);
+    /// off, or for books without one, count screen pages at the current
+    /// text width instead.
+    pub prefer_epub_page_numbers: bool,
     /// Directories scanned for ebooks by the library window (`~` expands to
     /// the home directory). A Calibre library root works as-is.
     pub library_directories: Vec<String>,
@@ -157,6 +342,29 @@ pub struct Settings {
     pub line_spacing: LineSpacing,
     /// Expand eligible prose lines to the configured text width.
     pub justify_text: bool,
+    /// Ask before jumping to the saved position of a book that has not been
+    /// read for a day; declining starts from the beginning.
+    pub resume_prompt: bool,
+    /// Open a book read for the first time at its body matter (the EPUB
+    /// `bodymatter` landmark, else the first TOC entry) instead of row 0.
+    pub skip_front_matter: bool,
+    /// Mark a library book finished once its last page has been shown.
+    pub auto_mark_finished: bool,
+    /// Seconds between pages in the `a` slideshow; 0 turns it off.
+    pub slideshow_interval_secs: u64,
+    /// Keep the reading position in a `<book>.repy.json` file next to the
+    /// book too, and resume from it when it is newer than the local one.
+    pub sync_sidecar: bool,
+    /// Seconds between automatic saves of a changed reading position; 0
+    /// saves only on quit, book switch, and `Ctrl+s`.
+    pub autosave_interval_secs: u64,
+    /// Minutes after which a reading session is over; 0 turns it off.
+    pub session_limit_minutes: u64,
+    /// Pages turned forward after which a reading session is over; 0 turns
+    /// it off.
+    pub session_limit_pages: usize,
+    /// Save and quit when the session is over, instead of only saying so.
+    pub session_limit_quit: bool,
     /// KOReader-compatible progress sync credentials.
     pub kosync_server: Option<String>,
     pub kosync_username: Option<String>,
@@ -167,20 +375,59 @@ impl Settings {
     pub fn merge(&mut self, other: Self) {
         self.default_viewer = other.default_viewer;
         self.dictionary_client = other.dictionary_client;
+        if !other.dictionary_presets.is_empty() {
+            self.dictionary_presets = other.dictionary_presets;
+        }
+        self.aggregate_dictionaries = other.aggregate_dictionaries;
+        self.ascii_wikipedia = other.ascii_wikipedia;
+        self.clipboard_command = other.clipboard_command;
+        self.yank_template = other.yank_template;
+        self.capture_file = other.capture_file;
+        self.state_file = other.state_file;
+        self.on_open = other.on_open;
+        self.on_close = other.on_close;
         self.show_progress_indicator = other.show_progress_indicator;
         self.page_scroll_animation = other.page_scroll_animation;
+        self.page_scroll_animation_ms = other.page_scroll_animation_ms;
         self.mouse_support = other.mouse_support;
+        self.count_prefix_enabled = other.count_prefix_enabled;
+        self.search_placeholders = other.search_placeholders;
+        self.hlsearch = other.hlsearch;
+        self.normalize_search = other.normalize_search;
+        self.open_linked_books = other.open_linked_books;
         self.color_theme = other.color_theme;
         self.seamless_between_chapters = other.seamless_between_chapters;
+        self.chapter_break_blank_lines = other.chapter_break_blank_lines;
+        self.chapter_break_rule = other.chapter_break_rule;
+        self.chapter_break_title = other.chapter_break_title;
+        self.focus_mode = other.focus_mode;
+        self.focus_lines = other.focus_lines;
+        self.bionic_reading = other.bionic_reading;
         if other.preferred_tts_engine.is_some() {
             self.preferred_tts_engine = other.preferred_tts_engine;
         }
         if !other.tts_engine_args.is_empty() {
             self.tts_engine_args = other.tts_engine_args;
         }
+        self.tts_rate = other.tts_rate;
+        self.tts_voice = other.tts_voice;
+        self.tts_read_images = other.tts_read_images;
+        self.tts_announce_chapters = other.tts_announce_chapters;
+        self.tts_stop_at_chapter = other.tts_stop_at_chapter;
+        self.play_narration = other.play_narration;
+        self.narration_player = other.narration_player;
         self.width = other.width;
+        self.left_margin = other.left_margin;
+        self.right_margin = other.right_margin;
+        self.scrolloff = other.scrolloff;
         self.show_line_numbers = other.show_line_numbers;
+        self.line_number_style = other.line_number_style;
         self.show_top_bar = other.show_top_bar;
+        self.content_border = other.content_border;
+        self.show_chapter_title = other.show_chapter_title;
+        self.show_eta = other.show_eta;
+        self.set_terminal_title = other.set_terminal_title;
+        self.prefer_epub_page_numbers = other.prefer_epub_page_numbers;
         if !other.library_directories.is_empty() {
             self.library_directories = other.library_directories;
         }
@@ -193,10 +440,37 @@ impl Settings {
         self.paragraph_style = other.paragraph_style;
         self.line_spacing = other.line_spacing;
         self.justify_text = other.justify_text;
+        self.resume_prompt = other.resume_prompt;
+        self.skip_front_matter = other.skip_front_matter;
+        self.auto_mark_finished = other.auto_mark_finished;
+        self.slideshow_interval_secs = other.slideshow_interval_secs;
+        self.sync_sidecar = other.sync_sidecar;
+        self.autosave_interval_secs = other.autosave_interval_secs;
+        self.session_limit_minutes = other.session_limit_minutes;
+        self.session_limit_pages = other.session_limit_pages;
+        self.session_limit_quit = other.session_limit_quit;
         self.kosync_server = other.kosync_server;
         self.kosync_username = other.kosync_username;
         self.kosync_password = other.kosync_password;
     }
+
+    /// The `(name, command template)` pairs a lookup tries, in order.
+    /// `dictionary_client` is "auto" (every preset), a preset name, or a
+    /// custom template, which is also its own name.
+    pub fn dictionary_commands(&self) -> Vec<(String, String)> {
+        let client = self.dictionary_client.trim();
+        if client.is_empty() || client == "auto" {
+            return self
+                .dictionary_presets
+                .iter()
+                .map(|preset| (preset.name.clone(), preset.command.clone()))
+                .collect();
+        }
+        match self.dictionary_presets.iter().find(|p| p.name == client) {
+            Some(preset) => vec![(preset.name.clone(), preset.command.clone())],
+            None => vec![(client.to_string(), client.to_string())],
+        }
+    }
 }
 
 impl Default for Settings {
@@ -204,16 +478,53 @@ impl Default for Settings {
         Self {
             default_viewer: "auto".to_string(),
             dictionary_client: "auto".to_string(),
+            dictionary_presets: DictionaryPreset::defaults(),
+            aggregate_dictionaries: false,
+            ascii_wikipedia: AsciiWikipedia::Simple,
+            clipboard_command: None,
+            yank_template: "%s".to_string(),
+            capture_file: None,
+            state_file: None,
+            on_open: None,
+            on_close: None,
             show_progress_indicator: true,
             page_scroll_animation: true,
+            page_scroll_animation_ms: DEFAULT_PAGE_SCROLL_ANIMATION_MS,
             mouse_support: false,
+            count_prefix_enabled: true,
+            search_placeholders: false,
+            hlsearch: true,
+            normalize_search: true,
+            open_linked_books: true,
             color_theme: ColorTheme::Default,
             seamless_between_chapters: false,
+            chapter_break_blank_lines: 1,
+            chapter_break_rule: false,
+            chapter_break_title: false,
+            focus_mode: false,
+            focus_lines: 1,
+            bionic_reading: false,
             preferred_tts_engine: Some("purr".to_string()),
             tts_engine_args: Vec::new(),
+            tts_rate: None,
+            tts_voice: None,
+            tts_read_images: true,
+            tts_announce_chapters: false,
+            tts_stop_at_chapter: false,
+            play_narration: true,
+            narration_player: None,
             width: None,
+            left_margin: 0,
+            right_margin: 0,
+            scrolloff: 0,
             show_line_numbers: false,
+            line_number_style: LineNumberStyle::default(),
             show_top_bar: true,
+            content_border: false,
+            show_chapter_title: false,
+            show_eta: false,
+            set_terminal_title: false,
+            prefer_epub_page_numbers: true,
             library_directories: Vec::new(),
             opds_catalogs: vec![OpdsCatalogConfig::default()],
             opds_download_directory: None,
@@ -222,6 +533,15 @@ impl Default for Settings {
             paragraph_style: ParagraphStyle::default(),
             line_spacing: LineSpacing::default(),
             justify_text: false,
+            resume_prompt: false,
+            skip_front_matter: false,
+            auto_mark_finished: false,
+            slideshow_interval_secs: 0,
+            sync_sidecar: false,
+            autosave_interval_secs: 60,
+            session_limit_minutes: 0,
+            session_limit_pages: 0,
+            session_limit_quit: false,
             kosync_server: Some(DEFAULT_KOSYNC_SERVER.to_string()),
             kosync_username: None,
             kosync_password: None,
@@ -407,13 +727,61 @@ mod tests {
         let settings = Settings::default();
         assert_eq!(settings.default_viewer, "auto");
         assert_eq!(settings.dictionary_client, "auto");
+        assert_eq!(settings.dictionary_presets, DictionaryPreset::defaults());
         assert!(settings.show_progress_indicator);
         assert!(settings.page_scroll_animation);
+        assert_eq!(
+            settings.page_scroll_animation_ms,
+            DEFAULT_PAGE_SCROLL_ANIMATION_MS
+        );
         assert!(!settings.mouse_support);
+        assert!(settings.count_prefix_enabled);
+        assert!(!settings.search_placeholders);
+        assert!(settings.hlsearch);
+        assert!(settings.normalize_search);
+        assert!(settings.open_linked_books);
         assert_eq!(settings.color_theme, ColorTheme::Default);
         assert!(!settings.seamless_between_chapters);
+        assert_eq!(settings.chapter_break_blank_lines, 1);
+        assert!(!settings.chapter_break_rule);
+        assert!(!settings.chapter_break_title);
+        assert!(!settings.focus_mode);
+        assert_eq!(settings.focus_lines, 1);
+        assert!(!settings.bionic_reading);
+        assert!(!settings.resume_prompt);
+        assert!(!settings.skip_front_matter);
+        assert_eq!(settings.left_margin, 0);
+        assert_eq!(settings.right_margin, 0);
+        assert_eq!(settings.scrolloff, 0);
+        assert!(!settings.aggregate_dictionaries);
+        assert_eq!(settings.ascii_wikipedia, AsciiWikipedia::Simple);
+        assert!(!settings.auto_mark_finished);
+        assert_eq!(settings.slideshow_interval_secs, 0);
+        assert!(!settings.sync_sidecar);
+        assert_eq!(settings.autosave_interval_secs, 60);
+        assert_eq!(settings.session_limit_minutes, 0);
+        assert_eq!(settings.session_limit_pages, 0);
+        assert!(!settings.session_limit_quit);
+        assert_eq!(settings.clipboard_command, None);
+        assert_eq!(settings.yank_template, "%s");
+        assert_eq!(settings.capture_file, None);
+        assert_eq!(settings.state_file, None);
+        assert_eq!(settings.on_open, None);
+        assert_eq!(settings.on_close, None);
         assert_eq!(settings.preferred_tts_engine, Some("purr".to_string()));
         assert!(settings.tts_engine_args.is_empty());
+        assert_eq!(settings.tts_rate, None);
+        assert_eq!(settings.tts_voice, None);
+        assert!(settings.tts_read_images);
+        assert!(!settings.tts_announce_chapters);
+        assert!(!settings.tts_stop_at_chapter);
+        assert!(settings.play_narration);
+        assert_eq!(settings.narration_player, None);
+        assert!(!settings.show_chapter_title);
+        assert!(!settings.content_border);
+        assert!(!settings.show_eta);
+        assert!(!settings.set_terminal_title);
+        assert!(settings.prefer_epub_page_numbers);
     }
 
     #[test]
@@ -432,6 +800,17 @@ mod tests {
         assert_eq!(InlineImages::Shown.next(), InlineImages::Placeholder);
     }
 
+    #[test]
+    fn test_line_number_style_default_and_parse() {
+        assert_eq!(
+            Settings::default().line_number_style,
+            LineNumberStyle::Absolute
+        );
+        let parsed: Settings = serde_json::from_str(r#"{"line_number_style": "page"}"#).unwrap();
+        assert_eq!(parsed.line_number_style, LineNumberStyle::Page);
+        assert_eq!(LineNumberStyle::Page.next(), LineNumberStyle::Absolute);
+    }
+
     #[test]
     fn test_typography_settings_defaults_and_roundtrip() {
         let defaults = Settings::default();
@@ -697,4 +1076,46 @@ mod tests {
         assert!(DICT_PRESET_LIST.contains(&"dict"));
         assert!(DICT_PRESET_LIST.len() > 0);
     }
+
+    #[test]
+    fn dictionary_client_resolves_to_preset_commands() {
+        let mut settings = Settings::default();
+        let names: Vec<String> = settings
+            .dictionary_commands()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, DICT_PRESET_LIST);
+
+        settings.dictionary_presets[1].command = "sdcv -n -u Webster %q".to_string();
+        settings.dictionary_client = "sdcv".to_string();
+        assert_eq!(
+            settings.dictionary_commands(),
+            vec![("sdcv".to_string(), "sdcv -n -u Webster %q".to_string())]
+        );
+
+        settings.dictionary_client = "mydict --brief %q".to_string();
+        assert_eq!(
+            settings.dictionary_commands(),
+            vec![(
+                "mydict --brief %q".to_string(),
+                "mydict --brief %q".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn dictionary_presets_round_trip_through_config() {
+        let mut settings = Settings::default();
+        settings.dictionary_presets.reverse();
+        let json = serde_json::to_string(&settings).unwrap();
+        let mut loaded = Settings::default();
+        loaded.merge(serde_json::from_str(&json).unwrap());
+        assert_eq!(loaded.dictionary_presets[0].name, "wkdict");
+
+        // An empty list would leave "auto" with nothing to try.
+        let mut loaded = Settings::default();
+        loaded.merge(serde_json::from_str(r#"{"dictionary_presets": []}"#).unwrap());
+        assert_eq!(loaded.dictionary_presets, DictionaryPreset::defaults());
+    }
 }
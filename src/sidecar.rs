@@ -0,0 +1,158 @@
+//! Reading-position sidecar files: a small JSON file written next to the
+//! ebook so that two machines sharing a synced folder can pick up each
+//! other's position without a sync server.
+
+use chrono::{DateTime, Utc};
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::models::ReadingState;
+
+const SIDECAR_SUFFIX: &str = ".repy.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SidecarPosition {
+    /// Identity hash of the book the position belongs to, so a sidecar left
+    /// behind by a different file at the same path is ignored.
+    pub book_id: String,
+    pub content_index: usize,
+    #[serde(default)]
+    pub source_offset: Option<usize>,
+    pub textwidth: usize,
+    pub row: usize,
+    #[serde(default)]
+    pub rel_pctg: Option<f32>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SidecarPosition {
+    pub fn new(book_id: &str, reading_state: &ReadingState, updated_at: DateTime<Utc>) -> Self {
+        Self {
+            book_id: book_id.to_string(),
+            content_index: reading_state.content_index,
+            source_offset: reading_state.source_offset,
+            textwidth: reading_state.textwidth,
+            row: reading_state.row,
+            rel_pctg: reading_state.rel_pctg,
+            updated_at,
+        }
+    }
+
+    /// Whether `reading_state` is already at this position, e.g. because
+    /// this machine wrote the sidecar.
+    pub fn is_at(&self, reading_state: &ReadingState) -> bool {
+        self.content_index == reading_state.content_index
+            && self.source_offset == reading_state.source_offset
+            && self.textwidth == reading_state.textwidth
+            && self.row == reading_state.row
+    }
+
+    /// The position as a reading state, keeping `base`'s other fields (such
+    /// as the per-book TTS engine).
+    pub fn apply_to(&self, base: &ReadingState) -> ReadingState {
+        ReadingState {
+            content_index: self.content_index,
+            source_offset: self.source_offset,
+            textwidth: self.textwidth,
+            row: self.row,
+            rel_pctg: self.rel_pctg,
+            ..base.clone()
+        }
+    }
+}
+
+/// `book.epub` -> `book.epub.repy.json`, in the same directory.
+pub fn sidecar_path(book_path: &str) -> PathBuf {
+    PathBuf::from(format!(
+        "{}{}",
+        book_path.trim_end_matches(['/', '\\']),
+        SIDECAR_SUFFIX
+    ))
+}
+
+/// The sidecar for `book_path`, or `None` when there is none or it belongs
+/// to another book.
+pub fn read(book_path: &str, book_id: &str) -> Result<Option<SidecarPosition>> {
+    let path = sidecar_path(book_path);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let text =
+        std::fs::read_to_string(&path).wrap_err_with(|| format!("reading {}", path.display()))?;
+    let position: SidecarPosition =
+        serde_json::from_str(&text).wrap_err_with(|| format!("parsing {}", path.display()))?;
+    Ok((position.book_id == book_id).then_some(position))
+}
+
+/// Write the sidecar through a temporary file and a rename, so a folder sync
+/// never picks up a half-written file.
+pub fn write(book_path: &str, position: &SidecarPosition) -> Result<()> {
+    let path = sidecar_path(book_path);
+    let tmp = temp_path(&path);
+    std::fs::write(&tmp, serde_json::to_string_pretty(position)?)
+        .wrap_err_with(|| format!("writing {}", tmp.display()))?;
+    std::fs::rename(&tmp, &path).wrap_err_with(|| format!("writing {}", path.display()))?;
+    Ok(())
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn position(book_id: &str) -> SidecarPosition {
+        SidecarPosition {
+            book_id: book_id.to_string(),
+            content_index: 3,
+            source_offset: Some(120),
+            textwidth: 72,
+            row: 410,
+            rel_pctg: Some(0.25),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 1, 12, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_sidecar_path_sits_next_to_the_book() {
+        assert_eq!(
+            sidecar_path("/books/Meditations.epub"),
+            PathBuf::from("/books/Meditations.epub.repy.json")
+        );
+        assert_eq!(
+            sidecar_path("/books/site/"),
+            PathBuf::from("/books/site.repy.json")
+        );
+    }
+
+    #[test]
+    fn test_sidecar_round_trip_and_book_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let book = dir.path().join("book.epub");
+        let book = book.to_str().unwrap();
+
+        assert_eq!(read(book, "abc").unwrap(), None);
+        write(book, &position("abc")).unwrap();
+        assert_eq!(read(book, "abc").unwrap(), Some(position("abc")));
+        assert_eq!(read(book, "other").unwrap(), None);
+        assert!(!temp_path(&sidecar_path(book)).exists());
+    }
+
+    #[test]
+    fn test_apply_to_keeps_other_reading_state_fields() {
+        let base = ReadingState {
+            tts_engine: Some("espeak".to_string()),
+            ..ReadingState::default()
+        };
+        let state = position("abc").apply_to(&base);
+        assert_eq!(state.row, 410);
+        assert_eq!(state.source_offset, Some(120));
+        assert_eq!(state.tts_engine.as_deref(), Some("espeak"));
+    }
+}
@@ -6,7 +6,7 @@ use crate::models::{
 use crate::theme::ColorTheme;
 use chrono::{DateTime, Local, NaiveDate, Utc};
 use eyre::Result;
-use rusqlite::{Connection, OptionalExtension, params};
+use rusqlite::{Connection, OpenFlags, OptionalExtension, params};
 use std::collections::BTreeSet;
 
 // Re-use the get_app_data_prefix from config.rs
@@ -16,6 +16,15 @@ pub struct State {
     conn: Connection,
 }
 
+/// Counts reported by [`State::import_epy_database`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct EpyImportSummary {
+    pub books: usize,
+    /// Books already in repy's history, left untouched.
+    pub skipped: usize,
+    pub bookmarks: usize,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct JumpHistoryEntrySerde {
     #[serde(default)]
@@ -54,6 +63,7 @@ impl From<JumpHistoryEntrySerde> for ReadingState {
             textwidth: entry.textwidth,
             rel_pctg: entry.rel_pctg,
             section: None,
+            tts_engine: None,
         }
     }
 }
@@ -159,98 +169,54 @@ impl State {
         Ok(Self { conn })
     }
 
+    /// A state database that lives only in memory, for headless readers
+    /// that must not touch the user's reading history.
+    pub fn in_memory() -> Result<Self> {
+        let conn = Connection::open_in_memory()?;
+        Self::init_db(&conn)?;
+        Ok(Self { conn })
+    }
+
     /// Create a new in-memory state for testing.
     #[cfg(test)]
     pub fn new_for_test() -> Self {
-        let conn = Connection::open_in_memory().unwrap();
-        Self::init_db(&conn).unwrap();
-        Self { conn }
-    }
-
+        Self::in_memory().unwrap()
+    }
+
+    /// Schema migrations in order: entry `i` upgrades a database whose
+    /// `PRAGMA user_version` is `i` to version `i + 1`. Append new ones;
+    /// never edit or reorder a released migration.
+    const MIGRATIONS: &[fn(&Connection) -> Result<()>] = &[
+        Self::migrate_v1,
+        Self::migrate_v2,
+        Self::migrate_v3,
+        Self::migrate_v4,
+        Self::migrate_v5,
+        Self::migrate_v6,
+        Self::migrate_v7,
+        Self::migrate_v8,
+        Self::migrate_v9,
+        Self::migrate_v10,
+        Self::migrate_v11,
+    ];
+
+    /// Schema version of a fully migrated database.
+    pub const SCHEMA_VERSION: i64 = Self::MIGRATIONS.len() as i64;
+
+    /// Apply every migration newer than the stored `user_version`, each in
+    /// its own transaction so a failure leaves the last good version intact.
     fn init_db(conn: &Connection) -> Result<()> {
         conn.execute_batch("PRAGMA foreign_keys = ON;")?;
         let current_version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
-        if current_version < 1 {
-            conn.execute_batch("BEGIN IMMEDIATE TRANSACTION;")?;
-            if let Err(err) = Self::migrate_v1(conn).and_then(|_| {
-                conn.pragma_update(None, "user_version", 1)
-                    .map_err(Into::into)
-            }) {
-                let _ = conn.execute_batch("ROLLBACK;");
-                return Err(err);
-            }
-            conn.execute_batch("COMMIT;")?;
-        }
-        if current_version < 2 {
-            conn.execute_batch("BEGIN IMMEDIATE TRANSACTION;")?;
-            if let Err(err) = Self::migrate_v2(conn).and_then(|_| {
-                conn.pragma_update(None, "user_version", 2)
-                    .map_err(Into::into)
-            }) {
-                let _ = conn.execute_batch("ROLLBACK;");
-                return Err(err);
-            }
-            conn.execute_batch("COMMIT;")?;
-        }
-        if current_version < 3 {
-            conn.execute_batch("BEGIN IMMEDIATE TRANSACTION;")?;
-            if let Err(err) = Self::migrate_v3(conn).and_then(|_| {
-                conn.pragma_update(None, "user_version", 3)
-                    .map_err(Into::into)
-            }) {
-                let _ = conn.execute_batch("ROLLBACK;");
-                return Err(err);
-            }
-            conn.execute_batch("COMMIT;")?;
-        }
-        if current_version < 4 {
-            conn.execute_batch("BEGIN IMMEDIATE TRANSACTION;")?;
-            if let Err(err) = Self::migrate_v4(conn).and_then(|_| {
-                conn.pragma_update(None, "user_version", 4)
-                    .map_err(Into::into)
-            }) {
-                let _ = conn.execute_batch("ROLLBACK;");
-                return Err(err);
-            }
-            conn.execute_batch("COMMIT;")?;
-        }
-        if current_version < 5 {
-            conn.execute_batch("BEGIN IMMEDIATE TRANSACTION;")?;
-            if let Err(err) = Self::migrate_v5(conn).and_then(|_| {
-                conn.pragma_update(None, "user_version", 5)
-                    .map_err(Into::into)
-            }) {
-                let _ = conn.execute_batch("ROLLBACK;");
-                return Err(err);
-            }
-            conn.execute_batch("COMMIT;")?;
-        }
-        if current_version < 6 {
-            conn.execute_batch("BEGIN IMMEDIATE TRANSACTION;")?;
-            if let Err(err) = Self::migrate_v6(conn).and_then(|_| {
-                conn.pragma_update(None, "user_version", 6)
-                    .map_err(Into::into)
-            }) {
-                let _ = conn.execute_batch("ROLLBACK;");
-                return Err(err);
-            }
-            conn.execute_batch("COMMIT;")?;
-        }
-        if current_version < 7 {
-            conn.execute_batch("BEGIN IMMEDIATE TRANSACTION;")?;
-            if let Err(err) = Self::migrate_v7(conn).and_then(|_| {
-                conn.pragma_update(None, "user_version", 7)
-                    .map_err(Into::into)
-            }) {
-                let _ = conn.execute_batch("ROLLBACK;");
-                return Err(err);
-            }
-            conn.execute_batch("COMMIT;")?;
-        }
-        if current_version < 8 {
+        for (index, migrate) in Self::MIGRATIONS
+            .iter()
+            .enumerate()
+            .skip(current_version.max(0) as usize)
+        {
+            let version = index as i64 + 1;
             conn.execute_batch("BEGIN IMMEDIATE TRANSACTION;")?;
-            if let Err(err) = Self::migrate_v8(conn).and_then(|_| {
-                conn.pragma_update(None, "user_version", 8)
+            if let Err(err) = migrate(conn).and_then(|_| {
+                conn.pragma_update(None, "user_version", version)
                     .map_err(Into::into)
             }) {
                 let _ = conn.execute_batch("ROLLBACK;");
@@ -481,6 +447,24 @@ impl State {
         Ok(())
     }
 
+    fn migrate_v9(conn: &Connection) -> Result<()> {
+        // KOReader-style partial MD5 of the file, so a history entry whose
+        // file was moved can be matched against files found elsewhere.
+        conn.execute_batch("ALTER TABLE library ADD COLUMN content_hash TEXT;")?;
+        Ok(())
+    }
+
+    fn migrate_v10(conn: &Connection) -> Result<()> {
+        conn.execute_batch("ALTER TABLE library ADD COLUMN finished INTEGER NOT NULL DEFAULT 0;")?;
+        Ok(())
+    }
+
+    fn migrate_v11(conn: &Connection) -> Result<()> {
+        // Per-book TTS engine override, NULL for "use the global setting".
+        conn.execute_batch("ALTER TABLE reading_states ADD COLUMN tts_engine TEXT;")?;
+        Ok(())
+    }
+
     /// Return cached (title, author) for a scanned file if the cache row
     /// matches the file's current modification time.
     pub fn cached_library_file(
@@ -664,7 +648,7 @@ impl State {
 
     pub fn get_from_history(&self) -> Result<Vec<LibraryItem>> {
         let mut stmt = self.conn.prepare(
-            "SELECT last_read, filepath, title, author, reading_progress FROM library ORDER BY last_read DESC",
+            "SELECT last_read, filepath, title, author, reading_progress, finished FROM library ORDER BY last_read DESC",
         )?;
 
         let library_items_iter = stmt.query_map([], |row| {
@@ -674,6 +658,7 @@ impl State {
                 title: row.get(2)?,
                 author: row.get(3)?,
                 reading_progress: row.get(4)?,
+                finished: row.get(5)?,
             })
         })?;
 
@@ -726,17 +711,17 @@ impl State {
 
         if !new_exists {
             tx.execute(
-                "INSERT INTO reading_states (filepath, content_index, source_offset, textwidth, row, rel_pctg, color_theme)
-                 SELECT ?, content_index, source_offset, textwidth, row, rel_pctg, color_theme FROM reading_states WHERE filepath=?",
+                "INSERT INTO reading_states (filepath, content_index, source_offset, textwidth, row, rel_pctg, color_theme, tts_engine)
+                 SELECT ?, content_index, source_offset, textwidth, row, rel_pctg, color_theme, tts_engine FROM reading_states WHERE filepath=?",
                 params![new_path, old_path],
             )?;
         }
 
-        let old_library: Option<(String, Option<String>, Option<String>, Option<f32>)> = tx
+        let old_library: Option<(String, Option<String>, Option<String>, Option<f32>, bool)> = tx
             .query_row(
-                "SELECT last_read, title, author, reading_progress FROM library WHERE filepath=?",
+                "SELECT last_read, title, author, reading_progress, finished FROM library WHERE filepath=?",
                 params![old_path],
-                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
             )
             .optional()?;
         let new_library: Option<(String, Option<String>, Option<String>, Option<f32>)> = tx
@@ -753,29 +738,30 @@ impl State {
             _ => false,
         };
 
-        if let Some((last_read, title, author, reading_progress)) = old_library {
+        if let Some((last_read, title, author, reading_progress, finished)) = old_library {
             if should_promote_old {
                 if new_library.is_some() {
                     tx.execute(
-                        "UPDATE library SET last_read=?, title=?, author=?, reading_progress=? WHERE filepath=?",
-                        params![last_read, title, author, reading_progress, new_path],
+                        "UPDATE library SET last_read=?, title=?, author=?, reading_progress=?, finished=? WHERE filepath=?",
+                        params![last_read, title, author, reading_progress, finished, new_path],
                     )?;
                 } else {
                     tx.execute(
-                        "INSERT INTO library (last_read, filepath, title, author, reading_progress) VALUES (?, ?, ?, ?, ?)",
-                        params![last_read, new_path, title, author, reading_progress],
+                        "INSERT INTO library (last_read, filepath, title, author, reading_progress, finished) VALUES (?, ?, ?, ?, ?, ?)",
+                        params![last_read, new_path, title, author, reading_progress, finished],
                     )?;
                 }
                 tx.execute(
-                    "INSERT INTO reading_states (filepath, content_index, source_offset, textwidth, row, rel_pctg, color_theme)
-                     SELECT ?, content_index, source_offset, textwidth, row, rel_pctg, color_theme FROM reading_states WHERE filepath=?
+                    "INSERT INTO reading_states (filepath, content_index, source_offset, textwidth, row, rel_pctg, color_theme, tts_engine)
+                     SELECT ?, content_index, source_offset, textwidth, row, rel_pctg, color_theme, tts_engine FROM reading_states WHERE filepath=?
                      ON CONFLICT(filepath) DO UPDATE SET
                         content_index=excluded.content_index,
                         source_offset=excluded.source_offset,
                         textwidth=excluded.textwidth,
                         row=excluded.row,
                         rel_pctg=excluded.rel_pctg,
-                        color_theme=excluded.color_theme",
+                        color_theme=excluded.color_theme,
+                        tts_engine=excluded.tts_engine",
                     params![new_path, old_path],
                 )?;
             }
@@ -834,7 +820,7 @@ impl State {
         ebook: &dyn crate::formats::Ebook,
     ) -> Result<Option<ReadingState>> {
         let mut stmt = self.conn.prepare(
-            "SELECT content_index, source_offset, textwidth, row, rel_pctg FROM reading_states WHERE filepath=?",
+            "SELECT content_index, source_offset, textwidth, row, rel_pctg, tts_engine FROM reading_states WHERE filepath=?",
         )?;
         let result = stmt.query_row(params![ebook.path()], |row| {
             Ok(ReadingState {
@@ -844,6 +830,7 @@ impl State {
                 row: row.get(3)?,
                 rel_pctg: row.get(4)?,
                 section: None,
+                tts_engine: row.get(5)?,
             })
         });
 
@@ -860,14 +847,15 @@ impl State {
         reading_state: &ReadingState,
     ) -> Result<()> {
         self.conn.execute(
-            "INSERT INTO reading_states (filepath, content_index, source_offset, textwidth, row, rel_pctg)
-             VALUES (?, ?, ?, ?, ?, ?)
+            "INSERT INTO reading_states (filepath, content_index, source_offset, textwidth, row, rel_pctg, tts_engine)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
              ON CONFLICT(filepath) DO UPDATE SET
                 content_index=excluded.content_index,
                 source_offset=excluded.source_offset,
                 textwidth=excluded.textwidth,
                 row=excluded.row,
-                rel_pctg=excluded.rel_pctg",
+                rel_pctg=excluded.rel_pctg,
+                tts_engine=excluded.tts_engine",
             params![
                 ebook.path(),
                 reading_state.content_index,
@@ -875,6 +863,7 @@ impl State {
                 reading_state.textwidth,
                 reading_state.row,
                 reading_state.rel_pctg,
+                reading_state.tts_engine,
             ],
         )?;
         Ok(())
@@ -911,12 +900,7 @@ impl State {
         name: &str,
         reading_state: &ReadingState,
     ) -> Result<()> {
-        use sha1::{Digest, Sha1};
-        let mut hasher = Sha1::new();
-        hasher.update(format!("{}{}", ebook.path(), name).as_bytes());
-        let hash = hasher.finalize();
-        let id = &hex::encode(hash)[..10];
-
+        let id = Self::bookmark_id(ebook.path(), name);
         self.conn.execute(
             "INSERT INTO bookmarks (id, filepath, name, content_index, source_offset, textwidth, row, rel_pctg) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
             params![
@@ -933,6 +917,135 @@ impl State {
         Ok(())
     }
 
+    fn bookmark_id(filepath: &str, name: &str) -> String {
+        use sha1::{Digest, Sha1};
+        let mut hasher = Sha1::new();
+        hasher.update(format!("{}{}", filepath, name).as_bytes());
+        hex::encode(hasher.finalize())[..10].to_string()
+    }
+
+    /// Copy books, reading positions, and bookmarks from an epy `states.db`,
+    /// skipping books repy already has a reading state for.
+    ///
+    /// epy stores rows at its own text width, counted from the start of the
+    /// chapter, so `locate` maps each position to a chapter source offset
+    /// when the book can be parsed; otherwise the position falls back to the
+    /// start of its chapter.
+    pub fn import_epy_database(
+        &self,
+        epy_db: &std::path::Path,
+        mut locate: impl FnMut(&str, &ReadingState) -> Option<usize>,
+    ) -> Result<EpyImportSummary> {
+        let epy = Connection::open_with_flags(epy_db, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        type EpyBook = (
+            String,
+            Option<String>,
+            Option<String>,
+            Option<f32>,
+            Option<String>,
+            Option<usize>,
+            Option<usize>,
+            Option<usize>,
+        );
+        let books: Vec<EpyBook> = epy
+            .prepare(
+                "SELECT l.filepath, l.title, l.author, l.reading_progress, l.last_read,
+                        r.content_index, r.textwidth, r.row
+                 FROM library l LEFT JOIN reading_states r ON r.filepath = l.filepath
+                 ORDER BY l.last_read",
+            )?
+            .query_map([], |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                    row.get(6)?,
+                    row.get(7)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        let mut bookmarks_stmt = epy.prepare(
+            "SELECT name, content_index, textwidth, row FROM bookmarks WHERE filepath=?",
+        )?;
+
+        let mut summary = EpyImportSummary::default();
+        let tx = self.conn.unchecked_transaction()?;
+        for (filepath, title, author, progress, last_read, content_index, textwidth, row) in books {
+            let known = tx
+                .query_row(
+                    "SELECT 1 FROM reading_states WHERE filepath=?",
+                    params![filepath],
+                    |_| Ok(()),
+                )
+                .optional()?
+                .is_some();
+            if known {
+                summary.skipped += 1;
+                continue;
+            }
+
+            let mut reading_state = ReadingState {
+                content_index: content_index.unwrap_or(0),
+                textwidth: textwidth.unwrap_or(crate::settings::DEFAULT_TEXT_WIDTH),
+                row: row.unwrap_or(0),
+                rel_pctg: progress,
+                ..ReadingState::default()
+            };
+            reading_state.source_offset = Some(locate(&filepath, &reading_state).unwrap_or(0));
+            tx.execute(
+                "INSERT INTO reading_states (filepath, content_index, source_offset, textwidth, row, rel_pctg)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+                params![
+                    filepath,
+                    reading_state.content_index,
+                    reading_state.source_offset,
+                    reading_state.textwidth,
+                    reading_state.row,
+                    reading_state.rel_pctg,
+                ],
+            )?;
+            tx.execute(
+                "INSERT OR IGNORE INTO library (last_read, filepath, title, author, reading_progress)
+                 VALUES (COALESCE(?, datetime('now')), ?, ?, ?, ?)",
+                params![last_read, filepath, title, author, progress],
+            )?;
+            summary.books += 1;
+
+            let bookmarks: Vec<(String, usize, usize, usize)> = bookmarks_stmt
+                .query_map(params![filepath], |row| {
+                    Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+                })?
+                .collect::<rusqlite::Result<_>>()?;
+            for (name, content_index, textwidth, row) in bookmarks {
+                let mut bookmark = ReadingState {
+                    content_index,
+                    textwidth,
+                    row,
+                    ..ReadingState::default()
+                };
+                bookmark.source_offset = Some(locate(&filepath, &bookmark).unwrap_or(0));
+                summary.bookmarks += tx.execute(
+                    "INSERT OR IGNORE INTO bookmarks (id, filepath, name, content_index, source_offset, textwidth, row)
+                     VALUES (?, ?, ?, ?, ?, ?, ?)",
+                    params![
+                        Self::bookmark_id(&filepath, &name),
+                        filepath,
+                        name,
+                        bookmark.content_index,
+                        bookmark.source_offset,
+                        bookmark.textwidth,
+                        bookmark.row,
+                    ],
+                )?;
+            }
+        }
+        tx.commit()?;
+        Ok(summary)
+    }
+
     pub fn delete_bookmark(&self, ebook: &dyn crate::formats::Ebook, name: &str) -> Result<()> {
         self.conn.execute(
             "DELETE FROM bookmarks WHERE filepath=? AND name=?",
@@ -975,6 +1088,7 @@ impl State {
                     row: row.get(4)?,
                     rel_pctg: row.get(5)?,
                     section: None,
+                    tts_engine: None,
                 },
             ))
         })?;
@@ -1090,6 +1204,7 @@ impl State {
                     row: row.get(4)?,
                     rel_pctg: row.get(5)?,
                     section: None,
+                    tts_engine: None,
                 },
             ))
         })?;
@@ -1160,6 +1275,8 @@ impl State {
             longest_streak_days,
             estimated_book_minutes_left: None,
             estimated_chapter_minutes_left: None,
+            session_words_per_minute: None,
+            session_book_minutes_left: None,
         })
     }
 
@@ -1287,12 +1404,80 @@ impl State {
     ) -> Result<()> {
         let metadata = &ebook.get_meta();
         self.conn.execute(
-            "INSERT OR REPLACE INTO library (filepath, title, author, reading_progress) VALUES (?, ?, ?, ?)",
-            params![ebook.path(), metadata.title, metadata.creator, reading_progress],
+            "INSERT INTO library (filepath, title, author, reading_progress) VALUES (?, ?, ?, ?)
+             ON CONFLICT(filepath) DO UPDATE SET
+                last_read=datetime('now'),
+                title=excluded.title,
+                author=excluded.author,
+                reading_progress=excluded.reading_progress",
+            params![
+                ebook.path(),
+                metadata.title,
+                metadata.creator,
+                reading_progress
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn set_library_content_hash(&self, filepath: &str, content_hash: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE library SET content_hash=? WHERE filepath=?",
+            params![content_hash, filepath],
+        )?;
+        Ok(())
+    }
+
+    pub fn set_library_finished(&self, filepath: &str, finished: bool) -> Result<()> {
+        self.conn.execute(
+            "UPDATE library SET finished=? WHERE filepath=?",
+            params![finished, filepath],
         )?;
         Ok(())
     }
 
+    /// When the book at `filepath` was last opened, if it is in the library.
+    pub fn library_last_read(&self, filepath: &str) -> Result<Option<DateTime<Utc>>> {
+        let result = self
+            .conn
+            .query_row(
+                "SELECT last_read FROM library WHERE filepath=?",
+                params![filepath],
+                |row| row.get::<_, Option<DateTime<Utc>>>(0),
+            )
+            .optional()?;
+        Ok(result.flatten())
+    }
+
+    pub fn library_content_hash(&self, filepath: &str) -> Result<Option<String>> {
+        let result = self
+            .conn
+            .query_row(
+                "SELECT content_hash FROM library WHERE filepath=?",
+                params![filepath],
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .optional()?;
+        Ok(result.flatten())
+    }
+
+    /// Every other path the state knows about — scanned library files and
+    /// history entries — as candidates for relinking a moved book.
+    pub fn relink_candidate_paths(&self, exclude: &str) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT filepath FROM library_files WHERE filepath != ?1
+             UNION
+             SELECT filepath FROM library WHERE filepath != ?1
+             ORDER BY filepath",
+        )?;
+        let rows = stmt.query_map(params![exclude], |row| row.get::<_, String>(0))?;
+        let mut paths = Vec::new();
+        for row in rows {
+            paths.push(row?);
+        }
+        Ok(paths)
+    }
+
     /// Find the most-recently-read library filepath that holds the same book
     /// (by `book_id` via `book_aliases`) but is stored under a path different
     /// from `current_path`. Used to recognise that an ebook opened from a new
@@ -1675,11 +1860,12 @@ mod tests {
         assert!(columns.contains(&"textwidth".to_string()));
         assert!(columns.contains(&"color_theme".to_string()));
         assert!(columns.contains(&"source_offset".to_string()));
+        assert!(columns.contains(&"tts_engine".to_string()));
 
         let version: i64 = conn
             .query_row("PRAGMA user_version", [], |row| row.get(0))
             .unwrap();
-        assert_eq!(version, 8);
+        assert_eq!(version, 11);
     }
 
     fn sample_identity(book_id: &str) -> BookIdentity {
@@ -1830,7 +2016,7 @@ mod tests {
         let version: i64 = conn
             .query_row("PRAGMA user_version", [], |row| row.get(0))
             .unwrap();
-        assert_eq!(version, 8);
+        assert_eq!(version, 11);
 
         let row: i64 = conn
             .query_row(
@@ -1871,6 +2057,138 @@ mod tests {
         assert_eq!(state.list_highlights(&identity.book_id).unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_import_epy_database_copies_history_positions_and_bookmarks() {
+        let dir = TempDir::new().unwrap();
+        let epy_path = dir.path().join("states.db");
+        {
+            // epy's schema is the one repy started from.
+            let epy = Connection::open(&epy_path).unwrap();
+            State::migrate_v1(&epy).unwrap();
+            for (path, row, progress) in [("/books/a.epub", 12, 0.4), ("/books/b.epub", 3, 0.1)] {
+                epy.execute(
+                    "INSERT INTO reading_states (filepath, content_index, textwidth, row, rel_pctg)
+                     VALUES (?, 2, 70, ?, 0.5)",
+                    params![path, row],
+                )
+                .unwrap();
+                epy.execute(
+                    "INSERT INTO library (last_read, filepath, title, author, reading_progress)
+                     VALUES ('2023-01-02 03:04:05', ?, 'Title', 'Author', ?)",
+                    params![path, progress],
+                )
+                .unwrap();
+            }
+            epy.execute(
+                "INSERT INTO bookmarks (id, filepath, name, content_index, textwidth, row, rel_pctg)
+                 VALUES ('x', '/books/a.epub', 'quote', 1, 70, 7, 0.2)",
+                [],
+            )
+            .unwrap();
+        }
+
+        let state = State::new_for_test();
+        let ebook = MockEbook::new("/books/b.epub", "Title", "Author");
+        let existing = ReadingState {
+            row: 99,
+            ..ReadingState::default()
+        };
+        state.set_last_reading_state(&ebook, &existing).unwrap();
+
+        let mut located = Vec::new();
+        let summary = state
+            .import_epy_database(&epy_path, |filepath, position| {
+                located.push((filepath.to_string(), position.content_index, position.row));
+                (position.content_index == 2).then_some(345)
+            })
+            .unwrap();
+        assert_eq!(
+            summary,
+            EpyImportSummary {
+                books: 1,
+                skipped: 1,
+                bookmarks: 1,
+            }
+        );
+        assert_eq!(
+            located,
+            vec![
+                ("/books/a.epub".to_string(), 2, 12),
+                ("/books/a.epub".to_string(), 1, 7)
+            ]
+        );
+
+        let imported = MockEbook::new("/books/a.epub", "Title", "Author");
+        let reading_state = state.get_last_reading_state(&imported).unwrap().unwrap();
+        assert_eq!(reading_state.content_index, 2);
+        assert_eq!(reading_state.source_offset, Some(345));
+        assert_eq!(reading_state.textwidth, 70);
+        assert_eq!(reading_state.rel_pctg, Some(0.4));
+        let bookmarks = state.get_bookmarks(&imported).unwrap();
+        assert_eq!(bookmarks.len(), 1);
+        assert_eq!(bookmarks[0].0, "quote");
+        // Unlocatable positions fall back to the start of their chapter.
+        assert_eq!(bookmarks[0].1.source_offset, Some(0));
+        assert_eq!(
+            state.get_last_reading_state(&ebook).unwrap().unwrap().row,
+            99
+        );
+        assert!(
+            state
+                .get_from_history()
+                .unwrap()
+                .iter()
+                .any(|item| item.filepath == "/books/a.epub")
+        );
+    }
+
+    #[test]
+    fn test_migration_from_intermediate_version_applies_only_newer_steps() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys = ON;").unwrap();
+        for migrate in &State::MIGRATIONS[..8] {
+            migrate(&conn).unwrap();
+        }
+        conn.pragma_update(None, "user_version", 8).unwrap();
+        conn.execute(
+            "INSERT INTO reading_states (filepath, content_index, textwidth, row, rel_pctg)
+             VALUES (?, ?, ?, ?, ?)",
+            params!["/old.epub", 2, 80, 40, 0.25],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO library (filepath, title, author, reading_progress)
+             VALUES (?, ?, ?, ?)",
+            params!["/old.epub", "Old Book", "Someone", 0.25],
+        )
+        .unwrap();
+
+        State::init_db(&conn).unwrap();
+        // Re-running on an up-to-date database is a no-op.
+        State::init_db(&conn).unwrap();
+
+        let version: i64 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, State::SCHEMA_VERSION);
+        let (row, tts_engine): (i64, Option<String>) = conn
+            .query_row(
+                "SELECT row, tts_engine FROM reading_states WHERE filepath=?",
+                params!["/old.epub"],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((row, tts_engine), (40, None));
+        let (finished, content_hash): (bool, Option<String>) = conn
+            .query_row(
+                "SELECT finished, content_hash FROM library WHERE filepath=?",
+                params!["/old.epub"],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((finished, content_hash), (false, None));
+    }
+
     #[test]
     fn test_migration_from_v7_adds_nullable_source_offsets() {
         let conn = Connection::open_in_memory().unwrap();
@@ -1914,7 +2232,7 @@ mod tests {
         let version: i64 = conn
             .query_row("PRAGMA user_version", [], |row| row.get(0))
             .unwrap();
-        assert_eq!(version, 8);
+        assert_eq!(version, 11);
 
         let state = State { conn };
         let ebook = MockEbook::new("/legacy-v7.epub", "Legacy", "Author");
@@ -1952,6 +2270,28 @@ mod tests {
         assert_eq!(highlights[0].id, "h-keep");
     }
 
+    #[test]
+    fn test_book_tts_engine_persists_with_reading_state() {
+        let state = State::new_for_test();
+        let ebook = MockEbook::new("/tmp/book.epub", "Title", "Author");
+        let mut reading_state = ReadingState {
+            tts_engine: Some("say -v Tingting".to_string()),
+            ..ReadingState::default()
+        };
+        state
+            .set_last_reading_state(&ebook, &reading_state)
+            .unwrap();
+        let stored = state.get_last_reading_state(&ebook).unwrap().unwrap();
+        assert_eq!(stored.tts_engine.as_deref(), Some("say -v Tingting"));
+
+        reading_state.tts_engine = None;
+        state
+            .set_last_reading_state(&ebook, &reading_state)
+            .unwrap();
+        let stored = state.get_last_reading_state(&ebook).unwrap().unwrap();
+        assert_eq!(stored.tts_engine, None);
+    }
+
     #[test]
     fn test_book_theme_jump_history_and_marks_persist() {
         let state = State::new_for_test();
@@ -1963,6 +2303,7 @@ mod tests {
             row: 42,
             rel_pctg: Some(0.4),
             section: None,
+            tts_engine: None,
         };
         state
             .set_last_reading_state(&ebook, &reading_state)
@@ -2193,6 +2534,7 @@ mod tests {
             row: 0,
             rel_pctg: None,
             section: None,
+            tts_engine: None,
         };
         state
             .set_last_reading_state(&ebook1, &default_state)
@@ -2257,6 +2599,7 @@ mod tests {
             row: 42,
             rel_pctg: Some(0.678),
             section: None,
+            tts_engine: None,
         };
         state.set_last_reading_state(&ebook, &new_state).unwrap();
 
@@ -2275,6 +2618,7 @@ mod tests {
             row: 100,
             rel_pctg: Some(0.890),
             section: None,
+            tts_engine: None,
         };
         state
             .set_last_reading_state(&ebook, &updated_state)
@@ -2303,6 +2647,7 @@ mod tests {
             row: 0,
             rel_pctg: None,
             section: None,
+            tts_engine: None,
         };
         state
             .set_last_reading_state(&ebook, &initial_state)
@@ -2315,6 +2660,7 @@ mod tests {
             row: 15,
             rel_pctg: Some(0.2),
             section: None,
+            tts_engine: None,
         };
         let state2 = ReadingState {
             content_index: 5,
@@ -2323,6 +2669,7 @@ mod tests {
             row: 42,
             rel_pctg: Some(0.5),
             section: None,
+            tts_engine: None,
         };
 
         state.insert_bookmark(&ebook, "Chapter 1", &state1).unwrap();
@@ -2385,6 +2732,7 @@ mod tests {
             row: 10,
             rel_pctg: None,
             section: None,
+            tts_engine: None,
         };
 
         let default_state = ReadingState {
@@ -2394,6 +2742,7 @@ mod tests {
             row: 0,
             rel_pctg: None,
             section: None,
+            tts_engine: None,
         };
         state
             .set_last_reading_state(&ebook1, &default_state)
@@ -2434,6 +2783,7 @@ mod tests {
             row: 10,
             rel_pctg: Some(0.1),
             section: None,
+            tts_engine: None,
         };
         state
             .set_last_reading_state(&ebook, &reading_state)
@@ -2492,6 +2842,7 @@ mod tests {
             row: 0,
             rel_pctg: None,
             section: None,
+            tts_engine: None,
         };
         state
             .set_last_reading_state(&ebook, &default_state)
@@ -2510,6 +2861,78 @@ mod tests {
         assert_eq!(history[0].reading_progress, None);
     }
 
+    #[test]
+    fn test_library_content_hash_survives_updates_and_lists_candidates() {
+        let (state, _temp_dir) = setup_test_state();
+        let ebook = MockEbook::new("/old/place/book.epub", "Book", "Author");
+        state
+            .set_last_reading_state(&ebook, &ReadingState::default())
+            .unwrap();
+        state.update_library(&ebook, Some(0.1)).unwrap();
+        state
+            .set_library_content_hash(ebook.path(), "abc123")
+            .unwrap();
+        state.update_library(&ebook, Some(0.5)).unwrap();
+        assert_eq!(
+            state.library_content_hash(ebook.path()).unwrap().as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(state.library_content_hash("/unknown.epub").unwrap(), None);
+
+        state
+            .upsert_library_file("/new/place/book.epub", 1, Some("Book"), Some("Author"))
+            .unwrap();
+        assert_eq!(
+            state.relink_candidate_paths(ebook.path()).unwrap(),
+            vec!["/new/place/book.epub".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_library_finished_flag_survives_reads_and_moves() {
+        let (mut state, _temp_dir) = setup_test_state();
+        let ebook = MockEbook::new("/books/done.epub", "Book", "Author");
+        state
+            .set_last_reading_state(&ebook, &ReadingState::default())
+            .unwrap();
+        state.update_library(&ebook, Some(0.2)).unwrap();
+        assert!(!state.get_from_history().unwrap()[0].finished);
+
+        state.set_library_finished(ebook.path(), true).unwrap();
+        state.update_library(&ebook, Some(0.4)).unwrap();
+        assert!(state.get_from_history().unwrap()[0].finished);
+
+        state
+            .reconcile_filepath(ebook.path(), "/shelf/done.epub")
+            .unwrap();
+        let history = state.get_from_history().unwrap();
+        assert_eq!(history[0].filepath, "/shelf/done.epub");
+        assert!(history[0].finished);
+    }
+
+    #[test]
+    fn test_library_last_read_reads_stored_timestamp() {
+        let (state, _temp_dir) = setup_test_state();
+        let ebook = MockEbook::new("/books/resume.epub", "Book", "Author");
+        assert_eq!(state.library_last_read(ebook.path()).unwrap(), None);
+        state
+            .set_last_reading_state(&ebook, &ReadingState::default())
+            .unwrap();
+        state.update_library(&ebook, Some(0.3)).unwrap();
+        state
+            .conn
+            .execute(
+                "UPDATE library SET last_read='2024-03-01 08:30:00' WHERE filepath=?",
+                params![ebook.path()],
+            )
+            .unwrap();
+        let last_read = state.library_last_read(ebook.path()).unwrap().unwrap();
+        assert_eq!(
+            last_read.format("%Y-%m-%d %H:%M:%S").to_string(),
+            "2024-03-01 08:30:00"
+        );
+    }
+
     #[test]
     fn test_reconcile_filepath_moves_entries() {
         let (mut state, _temp_dir) = setup_test_state();
@@ -2523,6 +2946,7 @@ mod tests {
             row: 5,
             rel_pctg: Some(0.2),
             section: None,
+            tts_engine: None,
         };
         state
             .set_last_reading_state(&old_ebook, &reading_state)
@@ -2561,6 +2985,7 @@ mod tests {
             row: 10,
             rel_pctg: Some(0.1),
             section: None,
+            tts_engine: None,
         };
         state.set_last_reading_state(&ebook, &state1).unwrap();
 
@@ -2571,6 +2996,7 @@ mod tests {
             row: 50,
             rel_pctg: Some(0.5),
             section: None,
+            tts_engine: None,
         };
         state.set_last_reading_state(&ebook, &state2).unwrap();
 
@@ -2596,6 +3022,7 @@ mod tests {
             row: 10,
             rel_pctg: Some(0.1),
             section: None,
+            tts_engine: None,
         };
         let state2 = ReadingState {
             content_index: 2,
@@ -2604,6 +3031,7 @@ mod tests {
             row: 20,
             rel_pctg: Some(0.2),
             section: None,
+            tts_engine: None,
         };
         let state3 = ReadingState {
             content_index: 3,
@@ -2612,6 +3040,7 @@ mod tests {
             row: 30,
             rel_pctg: Some(0.3),
             section: None,
+            tts_engine: None,
         };
 
         state.set_last_reading_state(&ebook1, &state1).unwrap();
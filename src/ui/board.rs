@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::models::{CHAPTER_BREAK_MARKER, HighlightRange, InlineStyle, LinkEntry, TextStructure};
+use crate::settings::LineNumberStyle;
 use crate::theme::Theme;
 use crate::ui::reader::ApplicationState;
 
@@ -130,7 +131,6 @@ impl Board {
         theme: &Theme,
     ) {
         let height = area.height as usize;
-        let _width = area.width as usize;
 
         let (start_line, end_line) =
             Self::visible_window_for(text_structure, state, content_start_rows, height);
@@ -148,6 +148,13 @@ impl Board {
             };
         let cursor_pos = state.ui_state.visual_cursor;
         let formatting = &text_structure.formatting;
+        // Right-to-left books get right-aligned lines; the characters keep
+        // their logical order (no bidi reordering).
+        let text_alignment = if state.ui_state.is_rtl {
+            Alignment::Right
+        } else {
+            Alignment::Left
+        };
 
         // Build per-line character-range lists that overlay the visual-mode
         // `/`-search matches on top of the existing reader-mode search matches.
@@ -195,12 +202,24 @@ impl Board {
             .get(state.ui_state.selected_search_result)
             .map(|result| result.per_row.as_slice())
             .unwrap_or(&[]);
+        // Without `hlsearch`, matches only show on the page the last jump
+        // landed on; paging away from the current hit leaves the text plain.
+        let show_search_matches = !state.ui_state.search_highlights_hidden
+            && (state.config.settings.hlsearch
+                || current_hit_ranges
+                    .iter()
+                    .any(|&(row, _, _)| (start_line..end_line).contains(&row)));
+        let current_hit_ranges = if show_search_matches {
+            current_hit_ranges
+        } else {
+            &[]
+        };
 
         // Keep annotation markers outside the paragraph that contains the
         // book text. Prepending the marker as a span makes it participate in
         // Paragraph wrapping, which can push a full-width line onto an extra
         // visual row and misalign every line below it.
-        let has_highlight_gutter = !state.ui_state.highlights.is_empty() && area.width > 0;
+        let has_highlight_gutter = Self::has_highlight_gutter(state) && area.width > 0;
         let text_area = if has_highlight_gutter {
             Rect {
                 x: area.x.saturating_add(1),
@@ -210,6 +229,7 @@ impl Board {
         } else {
             area
         };
+        let number_width = Self::line_number_width(state.config.settings.show_line_numbers);
 
         if has_highlight_gutter {
             let marker_lines: Vec<Line> = (start_line..end_line)
@@ -232,6 +252,15 @@ impl Board {
             frame.render_widget(Paragraph::new(marker_lines), gutter_area);
         }
 
+        let line_number_starts = if state.config.settings.show_line_numbers {
+            self.line_number_starts(
+                state.config.settings.line_number_style,
+                content_start_rows.unwrap_or(&[]),
+            )
+        } else {
+            Vec::new()
+        };
+
         let visible_lines: Vec<Line> = text_structure
             .text_lines
             .get(start_line..end_line)
@@ -243,16 +272,44 @@ impl Board {
                 let mut spans = Vec::new();
 
                 if line == CHAPTER_BREAK_MARKER {
+                    if state.config.settings.chapter_break_rule {
+                        let rule_width = (text_area.width as usize / 3).max(3);
+                        return Line::styled(
+                            "─".repeat(rule_width),
+                            Style::default().fg(theme.muted_fg),
+                        )
+                        .alignment(Alignment::Center);
+                    }
                     return Line::raw("***").alignment(Alignment::Center);
                 }
 
+                // The next chapter's title, placed right after the marker
+                // when `chapter_break_title` is on.
+                if state.config.settings.chapter_break_title
+                    && !line.is_empty()
+                    && line_num
+                        .checked_sub(1)
+                        .and_then(|prev| text_structure.text_lines.get(prev))
+                        .is_some_and(|prev| prev == CHAPTER_BREAK_MARKER)
+                {
+                    return Line::styled(
+                        line.as_str(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )
+                    .alignment(Alignment::Center);
+                }
+
                 if text_structure.image_maps.contains_key(&line_num) {
                     return Line::raw(line).alignment(Alignment::Center);
                 }
 
-                if state.config.settings.show_line_numbers {
+                if number_width > 0 {
                     spans.push(Span::styled(
-                        format!("{:>4} ", line_num + 1),
+                        format!(
+                            "{:>digits$} ",
+                            Self::display_line_number(line_num, &line_number_starts),
+                            digits = number_width - 1
+                        ),
                         Style::default().fg(theme.muted_fg),
                     ));
                 }
@@ -264,6 +321,7 @@ impl Board {
                         .ui_state
                         .search_matches
                         .get(&line_num)
+                        .filter(|_| show_search_matches)
                         .cloned()
                         .unwrap_or_default();
                     if let Some(extra) = visual_match_ranges.get(line_num - start_line) {
@@ -324,6 +382,14 @@ impl Board {
                     .collect()
                 };
 
+                // Bionic reading is a pure style overlay on the rendered
+                // characters, so column-based maps stay untouched.
+                let line_spans = if state.config.settings.bionic_reading {
+                    Self::apply_bionic_emphasis(line_spans, line)
+                } else {
+                    line_spans
+                };
+
                 let line_is_empty = line.is_empty();
                 let cursor_on_line = cursor_pos
                     .map(|(cursor_row, _)| cursor_row == line_num)
@@ -356,7 +422,7 @@ impl Board {
                                     .map(|(_, cursor_col)| cursor_col),
                             ));
                         }
-                        return Line::from(spans);
+                        return Line::from(spans).alignment(text_alignment);
                     }
                 }
 
@@ -370,14 +436,53 @@ impl Board {
                     } else {
                         spans.extend(Self::apply_cursor_range(line_spans, cursor_col));
                     }
-                    return Line::from(spans);
+                    return Line::from(spans).alignment(text_alignment);
                 }
 
                 spans.extend(line_spans);
-                Line::from(spans)
+                Line::from(spans).alignment(text_alignment)
             })
             .collect();
 
+        // Reading ruler: dim everything outside the focus band.
+        let visible_lines: Vec<Line> = if state.config.settings.focus_mode {
+            let band_start = start_line + state.ui_state.focus_band_top;
+            let band_end = band_start + 2 * state.config.settings.focus_lines;
+            visible_lines
+                .into_iter()
+                .enumerate()
+                .map(|(i, line)| {
+                    if (band_start..=band_end).contains(&(start_line + i)) {
+                        line
+                    } else {
+                        line.patch_style(Style::default().add_modifier(Modifier::DIM))
+                    }
+                })
+                .collect()
+        } else {
+            visible_lines
+        };
+        let visible_lines: Vec<Line> = if state.ui_state.is_rtl {
+            visible_lines
+        } else {
+            // One offset for the whole page, so the columns of a table or
+            // code block stay lined up however wide each row is.
+            let text_width = text_area.width as usize;
+            let offset = state
+                .ui_state
+                .horizontal_scroll
+                .min(Self::horizontal_overflow(
+                    text_structure,
+                    state,
+                    start_line,
+                    end_line,
+                    text_width,
+                ));
+            visible_lines
+                .into_iter()
+                .map(|line| Self::scroll_wide_line(line, offset, number_width, text_width, theme))
+                .collect()
+        };
         // `text_lines` are already wrapped by the parser to the configured
         // reading width. Wrapping them again here creates extra visual rows
         // that have no corresponding row in formatting, highlight, cursor,
@@ -387,6 +492,139 @@ impl Board {
         frame.render_widget(paragraph, text_area);
     }
 
+    /// Columns the line-number gutter takes: a right-aligned number and a
+    /// space, or nothing when line numbers are hidden.
+    pub fn line_number_width(show_line_numbers: bool) -> usize {
+        if show_line_numbers { 5 } else { 0 }
+    }
+
+    /// Whether a one-column annotation marker gutter sits left of the text.
+    fn has_highlight_gutter(state: &ApplicationState) -> bool {
+        !state.ui_state.highlights.is_empty()
+    }
+
+    /// How far `<` / `>` can usefully scroll the page shown in a reading
+    /// area `area_width` columns wide and `height` rows tall: the overflow
+    /// of its widest line, measured the same way [`Board::render`] lays it out.
+    pub fn max_horizontal_scroll(
+        &self,
+        state: &ApplicationState,
+        content_start_rows: Option<&[usize]>,
+        area_width: usize,
+        height: usize,
+    ) -> usize {
+        let Some(text_structure) = &self.text_structure else {
+            return 0;
+        };
+        let (start_line, end_line) =
+            Self::visible_window_for(text_structure, state, content_start_rows, height);
+        let gutter = usize::from(Self::has_highlight_gutter(state));
+        Self::horizontal_overflow(
+            text_structure,
+            state,
+            start_line,
+            end_line,
+            area_width.saturating_sub(gutter),
+        )
+    }
+
+    /// Overflow of the widest left-aligned row in `[start_line, end_line)`
+    /// past `text_width`, line numbers included. Chapter breaks and images
+    /// are centered and never scroll; right-to-left pages don't scroll.
+    fn horizontal_overflow(
+        text_structure: &TextStructure,
+        state: &ApplicationState,
+        start_line: usize,
+        end_line: usize,
+        text_width: usize,
+    ) -> usize {
+        use unicode_width::UnicodeWidthStr;
+
+        if state.ui_state.is_rtl {
+            return 0;
+        }
+        let number_width = Self::line_number_width(state.config.settings.show_line_numbers);
+        text_structure
+            .text_lines
+            .get(start_line..end_line)
+            .unwrap_or(&[])
+            .iter()
+            .enumerate()
+            .filter(|(i, line)| {
+                line.as_str() != CHAPTER_BREAK_MARKER
+                    && !text_structure.image_maps.contains_key(&(start_line + i))
+            })
+            .map(|(_, line)| {
+                (UnicodeWidthStr::width(line.as_str()) + number_width).saturating_sub(text_width)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Shift a line left by `offset` columns (already clamped to the page's
+    /// widest overflow), keeping its first `prefix_width` columns (the line
+    /// number) in place. Every row moves by the same amount so columns stay
+    /// aligned; centered chapter breaks and images, and anything when
+    /// unscrolled and fitting, are returned untouched. A `›` in the last
+    /// column marks text still clipped on the right.
+    fn scroll_wide_line<'a>(
+        line: Line<'a>,
+        offset: usize,
+        prefix_width: usize,
+        width: usize,
+        theme: &Theme,
+    ) -> Line<'a> {
+        use unicode_width::UnicodeWidthChar;
+
+        if (offset == 0 && line.width() <= width)
+            || line.alignment == Some(Alignment::Center)
+            || width <= prefix_width + 1
+        {
+            return line;
+        }
+        let alignment = line.alignment;
+        let line_style = line.style;
+        let body_width = width - prefix_width;
+        let clipped_right = line.width().saturating_sub(prefix_width) > offset + body_width;
+        let keep = if clipped_right {
+            body_width - 1
+        } else {
+            body_width
+        };
+
+        let mut spans: Vec<Span<'a>> = Vec::new();
+        let mut column = 0usize;
+        for span in line.spans {
+            let mut text = String::new();
+            for ch in span.content.chars() {
+                let ch_width = ch.width().unwrap_or(0);
+                if column < prefix_width {
+                    text.push(ch);
+                } else {
+                    let body_column = column - prefix_width;
+                    if body_column >= offset && body_column + ch_width <= offset + keep {
+                        text.push(ch);
+                    } else if body_column < offset && body_column + ch_width > offset {
+                        // A wide character straddling the left edge.
+                        text.push_str(&" ".repeat(body_column + ch_width - offset));
+                    }
+                }
+                column += ch_width;
+            }
+            if !text.is_empty() {
+                spans.push(Span::styled(text, span.style));
+            }
+        }
+        if clipped_right {
+            let kept: usize = spans.iter().map(Span::width).sum();
+            spans.push(Span::raw(" ".repeat((width - 1).saturating_sub(kept))));
+            spans.push(Span::styled("›", Style::default().fg(theme.muted_fg)));
+        }
+        let mut scrolled = Line::from(spans).style(line_style);
+        scrolled.alignment = alignment;
+        scrolled
+    }
+
     fn apply_visual_selection_range(
         spans: Vec<Span<'static>>,
         sel_col_start: usize,
@@ -433,6 +671,36 @@ impl Board {
         })
     }
 
+    /// Bold the leading ~40% of every word (runs of alphanumerics) in `line`.
+    fn apply_bionic_emphasis(spans: Vec<Span<'static>>, line: &str) -> Vec<Span<'static>> {
+        let chars: Vec<char> = line.chars().collect();
+        let mut emphasized = vec![false; chars.len()];
+        let mut col = 0;
+        while col < chars.len() {
+            if !chars[col].is_alphanumeric() {
+                col += 1;
+                continue;
+            }
+            let word_end = (col..chars.len())
+                .find(|&i| !chars[i].is_alphanumeric())
+                .unwrap_or(chars.len());
+            let bold_len = ((word_end - col) * 2).div_ceil(5);
+            emphasized[col..col + bold_len].fill(true);
+            col = word_end;
+        }
+        if !emphasized.contains(&true) {
+            return spans;
+        }
+
+        Self::map_span_char_ranges(spans, |start, _end, style| {
+            if emphasized.get(start).copied().unwrap_or(false) {
+                style.add_modifier(Modifier::BOLD)
+            } else {
+                style
+            }
+        })
+    }
+
     fn map_span_char_ranges<F>(spans: Vec<Span<'static>>, mut style_for: F) -> Vec<Span<'static>>
     where
         F: FnMut(usize, usize, Style) -> Style,
@@ -677,6 +945,29 @@ impl Board {
             .unwrap_or(0)
     }
 
+    /// Characters that make up a word for cursor motions and lookups.
+    pub fn is_word_char(ch: char) -> bool {
+        ch.is_alphanumeric() || ch == '_'
+    }
+
+    /// The word under `(row, col)` and its char range `start..end` on the
+    /// row, or `None` when that character is not a word character.
+    pub fn word_at(&self, row: usize, col: usize) -> Option<(String, usize, usize)> {
+        let chars: Vec<char> = self.get_line(row)?.chars().collect();
+        if !chars.get(col).copied().is_some_and(Self::is_word_char) {
+            return None;
+        }
+        let start = chars[..col]
+            .iter()
+            .rposition(|&ch| !Self::is_word_char(ch))
+            .map_or(0, |i| i + 1);
+        let end = chars[col..]
+            .iter()
+            .position(|&ch| !Self::is_word_char(ch))
+            .map_or(chars.len(), |i| col + i);
+        Some((chars[start..end].iter().collect(), start, end))
+    }
+
     pub fn link_count_in_range(&self, start: usize, end: usize) -> usize {
         self.text_structure
             .as_ref()
@@ -741,6 +1032,13 @@ impl Board {
             .and_then(|ts| ts.image_maps.get(&line).cloned())
     }
 
+    /// Descriptive `alt` text of the image whose placeholder is on `line`.
+    pub fn image_alt(&self, line: usize) -> Option<&str> {
+        self.text_structure
+            .as_ref()
+            .and_then(|ts| ts.image_alts.get(&line).map(String::as_str))
+    }
+
     pub fn is_typography_spacing_row(&self, row: usize) -> bool {
         self.text_structure
             .as_ref()
@@ -754,6 +1052,13 @@ impl Board {
             .unwrap_or(&[])
     }
 
+    /// Whether the book carries print page numbers (pagebreak markers).
+    pub fn has_print_pages(&self) -> bool {
+        self.text_structure
+            .as_ref()
+            .is_some_and(|ts| !ts.pagebreak_map.is_empty())
+    }
+
     /// Returns the page label at or before `row`, or None if the book has no pagebreak markers.
     pub fn current_page_label(&self, row: usize) -> Option<&str> {
         let map = &self.text_structure.as_ref()?.pagebreak_map;
@@ -766,6 +1071,75 @@ impl Board {
             .map(|(_, v)| v.as_str())
     }
 
+    /// Sorted rows the line-number gutter restarts from under `style`;
+    /// empty for absolute numbering.
+    pub fn line_number_starts(
+        &self,
+        style: LineNumberStyle,
+        content_start_rows: &[usize],
+    ) -> Vec<usize> {
+        let mut starts = match style {
+            LineNumberStyle::Absolute => return Vec::new(),
+            LineNumberStyle::Chapter => content_start_rows.to_vec(),
+            LineNumberStyle::Page => {
+                let mut starts: Vec<usize> = self
+                    .page_anchors()
+                    .into_iter()
+                    .map(|(row, _)| row)
+                    .collect();
+                starts.extend_from_slice(content_start_rows);
+                starts
+            }
+        };
+        starts.sort_unstable();
+        starts.dedup();
+        starts
+    }
+
+    /// The 1-based number shown for `line` when numbering restarts at each
+    /// of `starts` (see [`Board::line_number_starts`]).
+    pub fn display_line_number(line: usize, starts: &[usize]) -> usize {
+        let start = match starts.partition_point(|&start| start <= line) {
+            0 => 0,
+            i => starts[i - 1],
+        };
+        line - start + 1
+    }
+
+    /// EPUB print-page markers as `(row, label)`, in reading order.
+    pub fn page_anchors(&self) -> Vec<(usize, &str)> {
+        let Some(ts) = &self.text_structure else {
+            return Vec::new();
+        };
+        let mut anchors: Vec<(usize, &str)> = ts
+            .pagebreak_map
+            .iter()
+            .map(|(&row, label)| (row, label.as_str()))
+            .collect();
+        anchors.sort_by_key(|&(row, _)| row);
+        anchors
+    }
+
+    /// Screens of `page_height` rows it takes to show the whole book; 0
+    /// for an empty book or a zero page height.
+    pub fn total_pages(&self, page_height: usize) -> usize {
+        if page_height == 0 {
+            return 0;
+        }
+        self.total_lines().div_ceil(page_height)
+    }
+
+    /// Synthetic `p.N/M` label counting screens of `page_height` rows from
+    /// the start of the book, for books read without print page numbers.
+    pub fn screen_page_label(&self, start_line: usize, page_height: usize) -> Option<String> {
+        let total = self.total_pages(page_height);
+        if total == 0 {
+            return None;
+        }
+        let page = start_line / page_height + 1;
+        Some(format!("p.{}/{}", page.min(total), total))
+    }
+
     pub fn get_selected_text_range(&self, start: (usize, usize), end: (usize, usize)) -> String {
         let Some(text_structure) = &self.text_structure else {
             return String::new();
@@ -944,6 +1318,7 @@ mod tests {
     use super::*;
     use crate::models::{HighlightColor, HighlightRange, TextStructure};
     use crate::theme::ColorTheme;
+    use ratatui::style::Color;
     use std::collections::HashMap;
 
     #[test]
@@ -958,6 +1333,7 @@ mod tests {
             source_map: Default::default(),
             text_lines: vec!["Line 1".to_string(), "Line 2".to_string()],
             image_maps: HashMap::new(),
+            image_alts: HashMap::new(),
             section_rows: HashMap::new(),
             section_offsets: HashMap::new(),
             formatting: vec![],
@@ -974,6 +1350,54 @@ mod tests {
         assert!(board.text_structure.is_some());
     }
 
+    #[test]
+    fn page_labels_use_print_pages_or_count_screens() {
+        let text_structure = TextStructure {
+            text_lines: vec![String::new(); 45],
+            pagebreak_map: HashMap::from([(0, "i".to_string()), (12, "1".to_string())]),
+            ..Default::default()
+        };
+        let board = Board::new().with_text_structure(text_structure);
+
+        assert!(board.has_print_pages());
+        assert!(!Board::new().has_print_pages());
+        assert_eq!(board.current_page_label(11), Some("i"));
+        assert_eq!(board.current_page_label(20), Some("1"));
+        assert_eq!(board.screen_page_label(0, 20).as_deref(), Some("p.1/3"));
+        assert_eq!(board.screen_page_label(25, 20).as_deref(), Some("p.2/3"));
+        assert_eq!(board.screen_page_label(44, 20).as_deref(), Some("p.3/3"));
+        assert_eq!(Board::new().screen_page_label(0, 20), None);
+        assert_eq!(board.total_pages(20), 3);
+        assert_eq!(board.total_pages(0), 0);
+        assert_eq!(Board::new().total_pages(20), 0);
+        assert_eq!(board.page_anchors(), vec![(0, "i"), (12, "1")]);
+    }
+
+    #[test]
+    fn line_numbers_restart_per_chapter_or_page() {
+        let text_structure = TextStructure {
+            text_lines: vec![String::new(); 45],
+            pagebreak_map: HashMap::from([(3, "1".to_string()), (12, "2".to_string())]),
+            ..Default::default()
+        };
+        let board = Board::new().with_text_structure(text_structure);
+        let chapters = [0, 20];
+
+        let absolute = board.line_number_starts(LineNumberStyle::Absolute, &chapters);
+        assert_eq!(Board::display_line_number(24, &absolute), 25);
+
+        let chapter = board.line_number_starts(LineNumberStyle::Chapter, &chapters);
+        assert_eq!(Board::display_line_number(5, &chapter), 6);
+        assert_eq!(Board::display_line_number(24, &chapter), 5);
+
+        // Pages restart the count, and so does a chapter with no page marker.
+        let page = board.line_number_starts(LineNumberStyle::Page, &chapters);
+        assert_eq!(page, vec![0, 3, 12, 20]);
+        assert_eq!(Board::display_line_number(2, &page), 3);
+        assert_eq!(Board::display_line_number(12, &page), 1);
+        assert_eq!(Board::display_line_number(24, &page), 5);
+    }
+
     #[test]
     fn test_image_block_containing() {
         let mut image_block_rows = HashMap::new();
@@ -982,6 +1406,7 @@ mod tests {
             source_map: Default::default(),
             text_lines: vec![String::new(); 30],
             image_maps: HashMap::new(),
+            image_alts: HashMap::new(),
             section_rows: HashMap::new(),
             section_offsets: HashMap::new(),
             formatting: vec![],
@@ -1017,6 +1442,7 @@ mod tests {
                 "Line 3".to_string(),
             ],
             image_maps: HashMap::new(),
+            image_alts: HashMap::new(),
             section_rows: HashMap::new(),
             section_offsets: HashMap::new(),
             formatting: vec![],
@@ -1041,6 +1467,7 @@ mod tests {
             source_map: Default::default(),
             text_lines: vec!["Line 1".to_string(), "Line 2".to_string()],
             image_maps: HashMap::new(),
+            image_alts: HashMap::new(),
             section_rows: HashMap::new(),
             section_offsets: HashMap::new(),
             formatting: vec![],
@@ -1067,6 +1494,7 @@ mod tests {
             source_map: Default::default(),
             text_lines: vec!["Line 1".to_string(), "Line 2".to_string()],
             image_maps: HashMap::new(),
+            image_alts: HashMap::new(),
             section_rows: HashMap::new(),
             section_offsets: HashMap::new(),
             formatting: vec![],
@@ -1092,6 +1520,7 @@ mod tests {
             source_map: Default::default(),
             text_lines: vec!["New line".to_string()],
             image_maps: HashMap::new(),
+            image_alts: HashMap::new(),
             section_rows: HashMap::new(),
             section_offsets: HashMap::new(),
             formatting: vec![],
@@ -1294,11 +1723,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scroll_wide_line_shifts_only_overflowing_text() {
+        let theme = Theme::for_color_theme(ColorTheme::Default);
+        let text = |line: &Line| -> String {
+            line.spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect()
+        };
+
+        let short = Board::scroll_wide_line(Line::raw("short"), 0, 0, 10, &theme);
+        assert_eq!(text(&short), "short");
+
+        let wide = || Line::raw("abcdefghijklmnop");
+        let unscrolled = Board::scroll_wide_line(wide(), 0, 0, 10, &theme);
+        assert_eq!(text(&unscrolled), "abcdefghi›");
+        let scrolled = Board::scroll_wide_line(wide(), 3, 0, 10, &theme);
+        assert_eq!(text(&scrolled), "defghijkl›");
+        // At the full overflow the line ends flush with the right edge.
+        let flush = Board::scroll_wide_line(wide(), 6, 0, 10, &theme);
+        assert_eq!(text(&flush), "ghijklmnop");
+        // A narrower row moves by the same offset as its wider neighbours.
+        let narrow = Board::scroll_wide_line(Line::raw("abcdefgh"), 3, 0, 10, &theme);
+        assert_eq!(text(&narrow), "defgh");
+
+        let numbered = Line::from(vec![Span::raw("   1 "), Span::raw("abcdefghijklmnop")]);
+        let numbered = Board::scroll_wide_line(numbered, 2, 5, 10, &theme);
+        assert_eq!(text(&numbered), "   1 cdef›");
+    }
+
+    #[test]
+    fn test_bionic_emphasis_bolds_word_prefixes() {
+        let line = "Reading is fun, a-b";
+        let spans = vec![
+            Span::raw("Reading is "),
+            Span::styled("fun, a-b", Style::default().fg(Color::Red)),
+        ];
+        let spans = Board::apply_bionic_emphasis(spans, line);
+        let bold: String = spans
+            .iter()
+            .filter(|span| span.style.add_modifier.contains(Modifier::BOLD))
+            .map(|span| span.content.as_ref())
+            .collect();
+        // ceil(40%) of 7, 2, 3, 1 and 1 characters.
+        assert_eq!(bold, "Reaifuab");
+        let text: String = spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(text, line);
+        assert!(
+            spans
+                .iter()
+                .filter(|span| span.content == "u")
+                .all(|span| span.style.fg == Some(Color::Red))
+        );
+    }
+
     fn board_from_lines(lines: &[&str]) -> Board {
         let text_structure = TextStructure {
             source_map: Default::default(),
             text_lines: lines.iter().map(|s| s.to_string()).collect(),
             image_maps: HashMap::new(),
+            image_alts: HashMap::new(),
             section_rows: HashMap::new(),
             section_offsets: HashMap::new(),
             formatting: vec![],
@@ -1312,6 +1797,18 @@ mod tests {
         Board::new().with_text_structure(text_structure)
     }
 
+    #[test]
+    fn test_word_at_returns_word_and_char_range() {
+        let board = board_from_lines(&["«Café_au lait», dit-il."]);
+        assert_eq!(board.word_at(0, 3), Some(("Café_au".to_string(), 1, 8)));
+        assert_eq!(board.word_at(0, 1), Some(("Café_au".to_string(), 1, 8)));
+        assert_eq!(board.word_at(0, 12), Some(("lait".to_string(), 9, 13)));
+        assert_eq!(board.word_at(0, 21), Some(("il".to_string(), 20, 22)));
+        assert_eq!(board.word_at(0, 8), None);
+        assert_eq!(board.word_at(0, 99), None);
+        assert_eq!(board.word_at(5, 0), None);
+    }
+
     #[test]
     fn test_content_fraction_endpoints_and_monotonic() {
         let board = board_from_lines(&["alpha", "bravo", "charlie", "delta"]);
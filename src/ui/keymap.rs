@@ -0,0 +1,657 @@
+//! Normal-mode key bindings as data. The reader dispatches reading keys
+//! through [`KeyBindings`] and the Help window lists the same table, so
+//! remaps from the config's `Keymap` section show up in both.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::settings::CfgDefaultKeymaps;
+
+/// A command reachable from normal reading mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    TtsPrevSentence,
+    TtsNextSentence,
+    LineUp,
+    LineDown,
+    PageUp,
+    PageDown,
+    HalfPageUp,
+    HalfPageDown,
+    NextChapter,
+    PrevChapter,
+    ChapterStart,
+    ChapterEnd,
+    PrevParagraph,
+    NextParagraph,
+    BookStart,
+    BookEnd,
+    BeginReading,
+    Cover,
+    /// Prefix of `zz` / `zt` / `zb`.
+    ScrollCommand,
+    /// Prefixes of `]m` / `[m`.
+    BracketForward,
+    BracketBack,
+    JumpBack,
+    JumpForward,
+    SetMark,
+    JumpToMark,
+    Search,
+    NextHit,
+    PrevHit,
+    ClearSearchHighlights,
+    Highlights,
+    Enlarge,
+    Shrink,
+    ResetWidth,
+    ScrollLeft,
+    ScrollRight,
+    ToggleTopBar,
+    Slideshow,
+    ToggleFocusMode,
+    FocusUp,
+    FocusDown,
+    SwitchColor,
+    TableOfContents,
+    PrintPages,
+    Bookmarks,
+    Links,
+    Images,
+    AllImages,
+    Metadata,
+    ChapterSource,
+    ChapterSourceView,
+    CapturePage,
+    WriteState,
+    SaveNow,
+    Library,
+    Statistics,
+    Messages,
+    Settings,
+    CursorMode,
+    TtsToggle,
+    AnnouncePosition,
+    Help,
+    Quit,
+}
+
+impl Action {
+    /// Help window description.
+    pub const fn description(self) -> &'static str {
+        match self {
+            Action::TtsPrevSentence => "Previous sentence",
+            Action::TtsNextSentence => "Next sentence",
+            Action::LineUp => "Line Up",
+            Action::LineDown => "Line Down",
+            Action::PageUp => "Page Up",
+            Action::PageDown => "Page Down",
+            Action::HalfPageUp => "Half Page Up",
+            Action::HalfPageDown => "Half Page Down",
+            Action::NextChapter => "Next Chapter",
+            Action::PrevChapter => "Prev Chapter",
+            Action::ChapterStart => "Chapter Start",
+            Action::ChapterEnd => "Chapter End",
+            Action::PrevParagraph => "Prev Paragraph",
+            Action::NextParagraph => "Next Paragraph",
+            Action::BookStart => "Book Start",
+            Action::BookEnd => "Book End",
+            Action::BeginReading => "Begin Reading (body matter landmark)",
+            Action::Cover => "Cover (cover landmark)",
+            Action::ScrollCommand => "Scroll Current Line",
+            Action::BracketForward => "Next Bookmark Prefix",
+            Action::BracketBack => "Previous Bookmark Prefix",
+            Action::JumpBack => "Jump Back",
+            Action::JumpForward => "Jump Forward",
+            Action::SetMark => "Set Mark <c> (a-z, A-Z, 0-9)",
+            Action::JumpToMark => "Jump To Mark <c>",
+            Action::Search => "Start Search (matches update as you type)",
+            Action::NextHit => "Next Hit",
+            Action::PrevHit => "Previous Hit",
+            Action::ClearSearchHighlights => "Clear Search Highlights (n / N bring them back)",
+            Action::Highlights => "Highlights List",
+            Action::Enlarge => "Increase Width",
+            Action::Shrink => "Decrease Width",
+            Action::ResetWidth => "Reset Width",
+            Action::ScrollLeft => "Scroll Wide Lines Left",
+            Action::ScrollRight => "Scroll Wide Lines Right",
+            Action::ToggleTopBar => "Toggle Top Bar",
+            Action::Slideshow => "Slideshow (start/pause)",
+            Action::ToggleFocusMode => "Toggle Reading Ruler",
+            Action::FocusUp => "Move Reading Ruler Up",
+            Action::FocusDown => "Move Reading Ruler Down",
+            Action::SwitchColor => "Cycle Color Theme",
+            Action::TableOfContents => "Table Of Contents",
+            Action::PrintPages => "Print Pages (EPUB page-list)",
+            Action::Bookmarks => "Bookmarks",
+            Action::Links => "Links on Page (Enter previews internal links)",
+            Action::Images => "Images on Page (Enter shows in-terminal, o external)",
+            Action::AllImages => "All Images in Book (Enter jumps, v shows)",
+            Action::Metadata => "Metadata",
+            Action::ChapterSource => "Open Chapter Source in $EDITOR",
+            Action::ChapterSourceView => "Chapter HTML Source (toggle)",
+            Action::CapturePage => "Append Page to Capture File",
+            Action::WriteState => "Write Reading State as JSON",
+            Action::SaveNow => "Save Reading Position Now",
+            Action::Library => "Library (history + scanned directories)",
+            Action::Statistics => "Reading Statistics",
+            Action::Messages => "Recent Messages",
+            Action::Settings => "Settings",
+            Action::CursorMode => "Cursor Mode",
+            Action::TtsToggle => "Toggle TTS (Read Aloud)",
+            Action::AnnouncePosition => "Speak Current Position",
+            Action::Help => "Help",
+            Action::Quit => "Quit / Close Window",
+        }
+    }
+
+    /// Sentence stepping shares `Left`/`Right` with page turns and only
+    /// applies while reading aloud.
+    fn only_while_tts(self) -> bool {
+        matches!(self, Action::TtsPrevSentence | Action::TtsNextSentence)
+    }
+}
+
+/// Built-in alternatives to the default hjkl navigation, picked with
+/// `--keymap` at startup and applied on top of the `Keymap` remaps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum KeymapPreset {
+    #[default]
+    Default,
+    /// `w`/`s` scroll, `a`/`d` turn pages; slideshow moves to `e` and
+    /// Settings to `x`.
+    Wasd,
+    /// Letters no longer navigate; only arrows, page keys, and Ctrl keys do.
+    ArrowsOnly,
+    /// `Ctrl+n`/`Ctrl+p` scroll, `Ctrl+v`/`Alt+v` turn pages, and
+    /// `Alt+<`/`Alt+>` go to the book's start and end.
+    Emacs,
+}
+
+/// One key of a binding: a key code, optionally with Ctrl or Alt held.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Key {
+    code: KeyCode,
+    ctrl: bool,
+    alt: bool,
+}
+
+impl Key {
+    const fn plain(code: KeyCode) -> Self {
+        Self {
+            code,
+            ctrl: false,
+            alt: false,
+        }
+    }
+
+    const fn ch(c: char) -> Self {
+        Self::plain(KeyCode::Char(c))
+    }
+
+    const fn ctrl(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            ctrl: true,
+            alt: false,
+        }
+    }
+
+    const fn alt(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            ctrl: false,
+            alt: true,
+        }
+    }
+
+    /// Parse a `Keymap` config value: a single character, `Ctrl+<c>`,
+    /// `Alt+<c>`, or a named key such as `Space`, `Tab` or `PageDown`.
+    pub fn parse(binding: &str) -> Option<Self> {
+        if let Some(rest) = binding.strip_prefix("Ctrl+") {
+            return single_char(rest).map(|c| Self::ctrl(c.to_ascii_lowercase()));
+        }
+        if let Some(rest) = binding.strip_prefix("Alt+") {
+            return single_char(rest).map(Self::alt);
+        }
+        let code = match binding {
+            "Space" => KeyCode::Char(' '),
+            "Tab" => KeyCode::Tab,
+            "Enter" => KeyCode::Enter,
+            "Up" => KeyCode::Up,
+            "Down" => KeyCode::Down,
+            "Left" => KeyCode::Left,
+            "Right" => KeyCode::Right,
+            "PageUp" => KeyCode::PageUp,
+            "PageDown" => KeyCode::PageDown,
+            "Home" => KeyCode::Home,
+            "End" => KeyCode::End,
+            _ => KeyCode::Char(single_char(binding)?),
+        };
+        Some(Self::plain(code))
+    }
+
+    fn matches(&self, event: &KeyEvent) -> bool {
+        if event.code != self.code {
+            return false;
+        }
+        match self.code {
+            KeyCode::Char(_) if self.ctrl => event.modifiers.contains(KeyModifiers::CONTROL),
+            KeyCode::Char(_) if self.alt => {
+                event.modifiers.contains(KeyModifiers::ALT)
+                    && !event.modifiers.contains(KeyModifiers::CONTROL)
+            }
+            KeyCode::Char(_) => {
+                !event.modifiers.contains(KeyModifiers::CONTROL)
+                    && !event.modifiers.contains(KeyModifiers::ALT)
+            }
+            _ => true,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        let name = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Up => "Up".to_string(),
+            KeyCode::Down => "Down".to_string(),
+            KeyCode::Left => "Left".to_string(),
+            KeyCode::Right => "Right".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            code => format!("{code:?}"),
+        };
+        if self.ctrl {
+            format!("Ctrl+{name}")
+        } else if self.alt {
+            format!("Alt+{name}")
+        } else {
+            name
+        }
+    }
+}
+
+fn single_char(text: &str) -> Option<char> {
+    let mut chars = text.chars();
+    let c = chars.next()?;
+    chars.next().is_none().then_some(c)
+}
+
+/// The normal-mode binding table, with user remaps applied.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBindings {
+    bindings: Vec<(Action, Vec<Key>)>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::new(&CfgDefaultKeymaps::default())
+    }
+}
+
+impl KeyBindings {
+    /// Build the table from the user keymap. A remappable action's
+    /// configured key replaces its default letter; fixed keys such as the
+    /// arrows always stay bound. Unparseable values keep the default.
+    pub fn new(keymap: &CfgDefaultKeymaps) -> Self {
+        let user = |binding: &str, default: char| Key::parse(binding).unwrap_or(Key::ch(default));
+        let bindings = vec![
+            // Listed before the page turns that share `Left`/`Right`.
+            (
+                Action::TtsPrevSentence,
+                vec![Key::ch(','), Key::plain(KeyCode::Left)],
+            ),
+            (
+                Action::TtsNextSentence,
+                vec![Key::ch('.'), Key::plain(KeyCode::Right)],
+            ),
+            (
+                Action::LineUp,
+                vec![user(&keymap.scroll_up, 'k'), Key::plain(KeyCode::Up)],
+            ),
+            (
+                Action::LineDown,
+                vec![user(&keymap.scroll_down, 'j'), Key::plain(KeyCode::Down)],
+            ),
+            (
+                Action::PageUp,
+                vec![
+                    user(&keymap.page_up, 'h'),
+                    Key::plain(KeyCode::Left),
+                    Key::plain(KeyCode::PageUp),
+                ],
+            ),
+            (
+                Action::PageDown,
+                vec![
+                    user(&keymap.page_down, 'l'),
+                    Key::plain(KeyCode::Right),
+                    Key::ch(' '),
+                    Key::plain(KeyCode::PageDown),
+                ],
+            ),
+            (Action::HalfPageUp, vec![Key::ctrl('u')]),
+            (Action::HalfPageDown, vec![Key::ctrl('d')]),
+            (
+                Action::NextChapter,
+                vec![user(&keymap.next_chapter, 'L'), Key::ctrl('n')],
+            ),
+            (
+                Action::PrevChapter,
+                vec![user(&keymap.prev_chapter, 'H'), Key::ctrl('p')],
+            ),
+            (
+                Action::ChapterStart,
+                vec![user(&keymap.beginning_of_ch, 'g')],
+            ),
+            (Action::ChapterEnd, vec![user(&keymap.end_of_ch, 'G')]),
+            (Action::PrevParagraph, vec![Key::ch('{')]),
+            (Action::NextParagraph, vec![Key::ch('}')]),
+            (Action::BookStart, vec![Key::plain(KeyCode::Home)]),
+            (Action::BookEnd, vec![Key::plain(KeyCode::End)]),
+            (Action::BeginReading, vec![Key::ch('b')]),
+            (Action::Cover, vec![Key::ch('C')]),
+            (Action::ScrollCommand, vec![Key::ch('z')]),
+            (Action::BracketForward, vec![Key::ch(']')]),
+            (Action::BracketBack, vec![Key::ch('[')]),
+            (Action::JumpBack, vec![Key::ctrl('o')]),
+            (
+                Action::JumpForward,
+                vec![Key::ctrl('i'), Key::plain(KeyCode::Tab)],
+            ),
+            (Action::SetMark, vec![user(&keymap.mark_position, 'm')]),
+            (
+                Action::JumpToMark,
+                vec![user(&keymap.jump_to_position, '`')],
+            ),
+            (Action::Search, vec![user(&keymap.regex_search, '/')]),
+            (Action::NextHit, vec![Key::ch('n')]),
+            (Action::PrevHit, vec![Key::ch('p'), Key::ch('N')]),
+            (Action::ClearSearchHighlights, vec![Key::ctrl('l')]),
+            (Action::Highlights, vec![user(&keymap.show_highlights, 'A')]),
+            (Action::Enlarge, vec![user(&keymap.enlarge, '+')]),
+            (Action::Shrink, vec![user(&keymap.shrink, '-')]),
+            (Action::ResetWidth, vec![user(&keymap.set_width, '=')]),
+            (Action::ScrollLeft, vec![Key::ch('<')]),
+            (Action::ScrollRight, vec![Key::ch('>')]),
+            (Action::ToggleTopBar, vec![Key::ch('T')]),
+            (Action::Slideshow, vec![Key::ch('a')]),
+            (Action::ToggleFocusMode, vec![Key::ch('F')]),
+            (Action::FocusUp, vec![Key::ch('(')]),
+            (Action::FocusDown, vec![Key::ch(')')]),
+            (Action::SwitchColor, vec![user(&keymap.switch_color, 'c')]),
+            (
+                Action::TableOfContents,
+                vec![user(&keymap.table_of_contents, 't')],
+            ),
+            (Action::PrintPages, vec![Key::ch('P')]),
+            (Action::Bookmarks, vec![user(&keymap.show_bookmarks, 'B')]),
+            (Action::Links, vec![Key::ch('u')]),
+            (Action::Images, vec![user(&keymap.open_image, 'o')]),
+            (Action::AllImages, vec![Key::ch('O')]),
+            (Action::Metadata, vec![Key::ch('i')]),
+            (Action::ChapterSource, vec![Key::ch('S')]),
+            (Action::ChapterSourceView, vec![Key::ch('X')]),
+            (Action::CapturePage, vec![Key::ch('W')]),
+            (Action::WriteState, vec![Key::ch('J')]),
+            (Action::SaveNow, vec![Key::ctrl('s')]),
+            (Action::Library, vec![user(&keymap.library, 'r')]),
+            (Action::Statistics, vec![Key::ch('R')]),
+            (Action::Messages, vec![Key::ch('E')]),
+            (Action::Settings, vec![Key::ch('s')]),
+            (Action::CursorMode, vec![Key::ch('v')]),
+            (Action::TtsToggle, vec![user(&keymap.tts_toggle, '!')]),
+            (Action::AnnouncePosition, vec![Key::ctrl('g')]),
+            (Action::Help, vec![user(&keymap.help, '?')]),
+            (Action::Quit, vec![user(&keymap.quit, 'q')]),
+        ];
+        Self { bindings }
+    }
+
+    /// Build the table from the user keymap, then swap in `preset`'s
+    /// navigation keys.
+    pub fn with_preset(keymap: &CfgDefaultKeymaps, preset: KeymapPreset) -> Self {
+        let mut bindings = Self::new(keymap);
+        let page_keys = |first: Option<Key>, rest: &[Key]| {
+            first.into_iter().chain(rest.iter().copied()).collect()
+        };
+        let back = [Key::plain(KeyCode::Left), Key::plain(KeyCode::PageUp)];
+        let forward = [
+            Key::plain(KeyCode::Right),
+            Key::ch(' '),
+            Key::plain(KeyCode::PageDown),
+        ];
+        match preset {
+            KeymapPreset::Default => {}
+            KeymapPreset::Wasd => {
+                bindings.rebind(Action::LineUp, vec![Key::ch('w'), Key::plain(KeyCode::Up)]);
+                bindings.rebind(
+                    Action::LineDown,
+                    vec![Key::ch('s'), Key::plain(KeyCode::Down)],
+                );
+                bindings.rebind(Action::PageUp, page_keys(Some(Key::ch('a')), &back));
+                bindings.rebind(Action::PageDown, page_keys(Some(Key::ch('d')), &forward));
+                bindings.rebind(Action::Slideshow, vec![Key::ch('e')]);
+                bindings.rebind(Action::Settings, vec![Key::ch('x')]);
+            }
+            KeymapPreset::ArrowsOnly => {
+                bindings.rebind(Action::LineUp, vec![Key::plain(KeyCode::Up)]);
+                bindings.rebind(Action::LineDown, vec![Key::plain(KeyCode::Down)]);
+                bindings.rebind(Action::PageUp, page_keys(None, &back));
+                bindings.rebind(Action::PageDown, page_keys(None, &forward));
+                bindings.rebind(Action::NextChapter, vec![Key::ctrl('n')]);
+                bindings.rebind(Action::PrevChapter, vec![Key::ctrl('p')]);
+            }
+            KeymapPreset::Emacs => {
+                bindings.rebind(
+                    Action::LineUp,
+                    vec![Key::ctrl('p'), Key::plain(KeyCode::Up)],
+                );
+                bindings.rebind(
+                    Action::LineDown,
+                    vec![Key::ctrl('n'), Key::plain(KeyCode::Down)],
+                );
+                bindings.rebind(Action::PageUp, page_keys(Some(Key::alt('v')), &back));
+                bindings.rebind(Action::PageDown, page_keys(Some(Key::ctrl('v')), &forward));
+                // Chapters keep their letters; Ctrl+n/p now scroll.
+                let chapter_letter = |bindings: &Self, action| {
+                    bindings
+                        .keys(action)
+                        .iter()
+                        .copied()
+                        .filter(|key| !key.ctrl)
+                        .collect()
+                };
+                let next = chapter_letter(&bindings, Action::NextChapter);
+                let prev = chapter_letter(&bindings, Action::PrevChapter);
+                bindings.rebind(Action::NextChapter, next);
+                bindings.rebind(Action::PrevChapter, prev);
+                bindings.rebind(
+                    Action::BookStart,
+                    vec![Key::plain(KeyCode::Home), Key::alt('<')],
+                );
+                bindings.rebind(
+                    Action::BookEnd,
+                    vec![Key::plain(KeyCode::End), Key::alt('>')],
+                );
+            }
+        }
+        bindings
+    }
+
+    fn rebind(&mut self, action: Action, keys: Vec<Key>) {
+        if let Some((_, bound)) = self.bindings.iter_mut().find(|(bound, _)| *bound == action) {
+            *bound = keys;
+        }
+    }
+
+    /// The action bound to `event`, if any. The first matching entry wins.
+    pub fn action_for(&self, event: &KeyEvent, tts_active: bool) -> Option<Action> {
+        self.bindings
+            .iter()
+            .filter(|(action, _)| tts_active || !action.only_while_tts())
+            .find(|(_, keys)| keys.iter().any(|key| key.matches(event)))
+            .map(|(action, _)| *action)
+    }
+
+    pub fn keys(&self, action: Action) -> &[Key] {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == action)
+            .map_or(&[], |(_, keys)| keys.as_slice())
+    }
+
+    pub fn actions(&self) -> impl Iterator<Item = Action> + '_ {
+        self.bindings.iter().map(|(action, _)| *action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn default_bindings_dispatch_reading_keys() {
+        let bindings = KeyBindings::default();
+        assert_eq!(
+            bindings.action_for(&press(KeyCode::Char('j')), false),
+            Some(Action::LineDown)
+        );
+        assert_eq!(
+            bindings.action_for(&press(KeyCode::Char('o')), false),
+            Some(Action::Images)
+        );
+        assert_eq!(
+            bindings.action_for(
+                &KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL),
+                false
+            ),
+            Some(Action::JumpBack)
+        );
+        assert_eq!(bindings.action_for(&press(KeyCode::Char('x')), false), None);
+    }
+
+    #[test]
+    fn sentence_keys_only_apply_while_reading_aloud() {
+        let bindings = KeyBindings::default();
+        assert_eq!(
+            bindings.action_for(&press(KeyCode::Left), false),
+            Some(Action::PageUp)
+        );
+        assert_eq!(
+            bindings.action_for(&press(KeyCode::Left), true),
+            Some(Action::TtsPrevSentence)
+        );
+        assert_eq!(bindings.action_for(&press(KeyCode::Char('.')), false), None);
+    }
+
+    #[test]
+    fn user_remap_replaces_the_default_letter() {
+        let keymap = CfgDefaultKeymaps {
+            scroll_down: "J".to_string(),
+            quit: "Ctrl+Q".to_string(),
+            help: "not a key".to_string(),
+            ..CfgDefaultKeymaps::default()
+        };
+        let bindings = KeyBindings::new(&keymap);
+        assert_eq!(
+            bindings.action_for(&press(KeyCode::Char('J')), false),
+            Some(Action::LineDown)
+        );
+        assert_eq!(bindings.action_for(&press(KeyCode::Char('j')), false), None);
+        assert_eq!(
+            bindings.action_for(
+                &KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL),
+                false
+            ),
+            Some(Action::Quit)
+        );
+        // Arrows stay bound, and an unparseable value keeps the default.
+        assert_eq!(
+            bindings.action_for(&press(KeyCode::Down), false),
+            Some(Action::LineDown)
+        );
+        assert_eq!(
+            bindings.action_for(&press(KeyCode::Char('?')), false),
+            Some(Action::Help)
+        );
+    }
+
+    #[test]
+    fn presets_swap_navigation_keys() {
+        let keymap = CfgDefaultKeymaps::default();
+        let wasd = KeyBindings::with_preset(&keymap, KeymapPreset::Wasd);
+        assert_eq!(
+            wasd.action_for(&press(KeyCode::Char('d')), false),
+            Some(Action::PageDown)
+        );
+        assert_eq!(
+            wasd.action_for(&press(KeyCode::Char('s')), false),
+            Some(Action::LineDown)
+        );
+        assert_eq!(
+            wasd.action_for(&press(KeyCode::Char('x')), false),
+            Some(Action::Settings)
+        );
+        assert_eq!(wasd.action_for(&press(KeyCode::Char('l')), false), None);
+
+        let arrows = KeyBindings::with_preset(&keymap, KeymapPreset::ArrowsOnly);
+        assert_eq!(arrows.action_for(&press(KeyCode::Char('j')), false), None);
+        assert_eq!(arrows.action_for(&press(KeyCode::Char('L')), false), None);
+        assert_eq!(
+            arrows.action_for(&press(KeyCode::Down), false),
+            Some(Action::LineDown)
+        );
+
+        let emacs = KeyBindings::with_preset(&keymap, KeymapPreset::Emacs);
+        let with = |c, modifiers| KeyEvent::new(KeyCode::Char(c), modifiers);
+        assert_eq!(
+            emacs.action_for(&with('n', KeyModifiers::CONTROL), false),
+            Some(Action::LineDown)
+        );
+        assert_eq!(
+            emacs.action_for(&with('v', KeyModifiers::CONTROL), false),
+            Some(Action::PageDown)
+        );
+        assert_eq!(
+            emacs.action_for(&with('v', KeyModifiers::ALT), false),
+            Some(Action::PageUp)
+        );
+        assert_eq!(
+            emacs.action_for(&with('>', KeyModifiers::ALT | KeyModifiers::SHIFT), false),
+            Some(Action::BookEnd)
+        );
+        assert_eq!(
+            emacs.action_for(&press(KeyCode::Char('v')), false),
+            Some(Action::CursorMode)
+        );
+        let labels: Vec<String> = emacs
+            .keys(Action::NextChapter)
+            .iter()
+            .map(Key::label)
+            .collect();
+        assert_eq!(labels, ["L"]);
+    }
+
+    #[test]
+    fn key_labels() {
+        let labels: Vec<String> = KeyBindings::default()
+            .keys(Action::PageDown)
+            .iter()
+            .map(Key::label)
+            .collect();
+        assert_eq!(labels, ["l", "Right", "Space", "PgDn"]);
+        assert_eq!(Key::ctrl('o').label(), "Ctrl+o");
+        assert_eq!(
+            Key::parse("Alt+v").map(|key| key.label()).as_deref(),
+            Some("Alt+v")
+        );
+    }
+}
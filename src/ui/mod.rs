@@ -1,4 +1,6 @@
 pub mod board;
 pub mod graphics;
+pub mod keymap;
 pub mod reader;
+pub mod terminal;
 pub mod windows;
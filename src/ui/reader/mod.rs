@@ -3,7 +3,7 @@ use regex::Regex;
 use serde::Deserialize;
 use serde_json::Value;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::io;
 use std::rc::Rc;
 use std::time::{Duration, Instant};
@@ -17,7 +17,7 @@ use ratatui::{
     backend::{Backend, CrosstermBackend},
     layout::{Constraint, Layout, Rect},
     style::Style,
-    text::Line,
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 
@@ -27,27 +27,43 @@ use crate::formats::Ebook;
 use crate::logging;
 use crate::models::{
     BookIdentity, BookMetadata, CHAPTER_BREAK_MARKER, Direction as AppDirection, Highlight,
-    HighlightColor, HighlightRange, LibraryEntry, LibraryItem, LibrarySortMode, LinkEntry,
-    ReadingState, ReadingStatistics, ScannedBook, SearchData, SourceMap, SourceOffsetBias,
-    TextStructure, TocEntry, WindowType,
+    HighlightColor, HighlightRange, LibraryEntry, LibraryItem, LibraryProgressFilter,
+    LibrarySortMode, LinkEntry, NarrationClip, ReadingSnapshot, ReadingState, ReadingStatistics,
+    ScannedBook, SearchData, SourceMap, SourceOffsetBias, TextStructure, TocEntry, WindowType,
 };
 use crate::opds;
 use crate::parser::TypographyOptions;
-use crate::renderer::{self, build_chapter_break};
+use crate::renderer::{self, ChapterBreakOptions, build_chapter_break};
+use crate::rpc::{self, RpcChannel, RpcCommand};
 use crate::settings::{
-    DEFAULT_KOSYNC_SERVER, DEFAULT_TEXT_WIDTH, DICT_PRESET_LIST, InlineImages, LineSpacing,
-    ParagraphStyle,
+    AsciiWikipedia, DEFAULT_KOSYNC_SERVER, DEFAULT_TEXT_WIDTH, DEFAULT_TTS_RATE, InlineImages,
+    LineSpacing, ParagraphStyle, Settings, TTS_PRESET_LIST,
 };
+use crate::sidecar::{self, SidecarPosition};
 use crate::state::State;
 use crate::sync::{self, KosyncConfig, RemoteProgress};
 use crate::theme::{ColorTheme, Theme};
 use crate::ui::board::Board;
 use crate::ui::graphics::Graphics;
+use crate::ui::keymap::{Action, KeyBindings};
+use crate::ui::terminal::{self, TerminalGuard};
 use crate::ui::windows::{
-    bookmarks::BookmarksWindow, dictionary::DictionaryWindow, fuzzy_filter_indices,
-    help::HelpWindow, images::ImagesWindow, library::LibraryWindow, links::LinksWindow,
-    metadata::MetadataWindow, opds::OpdsWindow, search::SearchWindow, settings::SettingsWindow,
-    statistics::StatisticsWindow, toc::TocWindow,
+    bookmarks::BookmarksWindow,
+    dictionary::{DictionaryView, DictionaryWindow},
+    fuzzy_filter_indices,
+    help::HelpWindow,
+    images::ImagesWindow,
+    library::LibraryWindow,
+    links::LinksWindow,
+    messages::MessagesWindow,
+    metadata::MetadataWindow,
+    opds::OpdsWindow,
+    pages::PagesWindow,
+    search::{SearchEntry, SearchWindow},
+    settings::SettingsWindow,
+    source::SourceWindow,
+    statistics::StatisticsWindow,
+    toc::TocWindow,
 };
 use ratatui_image::protocol::StatefulProtocol;
 
@@ -56,12 +72,64 @@ const READING_IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
 /// the terminal size is unknown or smaller than a typical screen.
 const READING_JUMP_MIN_THRESHOLD_ROWS: usize = 50;
 const DEFAULT_READING_WPM: f64 = 250.0;
+/// How far back the session pace shown by `show_eta` looks, so it follows the
+/// current stretch of reading rather than the whole session.
+const SESSION_PACE_WINDOW: Duration = Duration::from_secs(10 * 60);
+/// Reading time needed before the session pace is trusted.
+const SESSION_PACE_MIN_SPAN: Duration = Duration::from_secs(30);
 /// Max book-fraction gap allowed between a KOReader XPointer's resolved row and
 /// the percentage reported alongside it before we distrust the XPointer (e.g.
 /// a spine-index/DocFragment mismatch) and fall back to the percentage.
 const KOSYNC_XPOINTER_TOLERANCE: f64 = 0.08;
 /// How long the library selection must rest before its cover is loaded.
 const LIBRARY_COVER_DEBOUNCE: Duration = Duration::from_millis(150);
+/// Slack when comparing a sidecar's `updated_at` with the library's
+/// `last_read`: the library stores whole seconds and is written just before
+/// the sidecar, so a sidecar only counts as newer beyond this.
+const SIDECAR_CLOCK_SLACK_SECS: i64 = 2;
+/// Where `J` writes the reading state when `state_file` is unset, inside
+/// the config directory.
+const DEFAULT_STATE_FILE: &str = "reading-state.json";
+/// Books untouched for at least this long get the resume prompt on open.
+const RESUME_PROMPT_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+/// Soft hyphen and zero-width characters, dropped from lookup queries.
+const LOOKUP_INVISIBLE_CHARS: &[char] = &[
+    '\u{00AD}', '\u{200B}', '\u{200C}', '\u{200D}', '\u{2060}', '\u{FEFF}',
+];
+/// Punctuation trimmed from either end of a lookup query: ASCII, curly
+/// quotes and guillemets, dashes, ellipsis, and CJK brackets and stops.
+const LOOKUP_EDGE_PUNCTUATION: &[char] = &[
+    '.', ',', ';', ':', '!', '?', '"', '\'', '(', ')', '[', ']', '{', '}', '<', '>', '*', '_', '-',
+    '/', '\\', '`', '~', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{201E}', '\u{00AB}',
+    '\u{00BB}', '\u{2039}', '\u{203A}', '\u{2026}', '\u{2013}', '\u{2014}', '\u{00A1}', '\u{00BF}',
+    '\u{3001}', '\u{3002}', '\u{300C}', '\u{300D}', '\u{300E}', '\u{300F}', '\u{FF08}', '\u{FF09}',
+    '\u{FF0C}', '\u{FF01}', '\u{FF1F}',
+];
+/// Upper bound on intermediate frames drawn for one animated page turn.
+const PAGE_ANIMATION_MAX_FRAMES: usize = 8;
+/// xterm window operations that save and restore the window title on the
+/// terminal's title stack.
+const TITLE_PUSH: &str = "\x1b[22;0t";
+const TITLE_POP: &str = "\x1b[23;0t";
+/// Status messages kept for the Messages window (`E`).
+const MESSAGE_HISTORY_LIMIT: usize = 50;
+/// Columns shifted per `<` / `>` press on wide lines.
+const HORIZONTAL_SCROLL_STEP: usize = 8;
+/// Slideshow intervals (seconds) the Settings entry cycles through; 0 is off.
+const SLIDESHOW_INTERVALS: [u64; 5] = [0, 5, 10, 20, 30];
+/// Autosave intervals (seconds) the Settings entry cycles through; 0 is off.
+const AUTOSAVE_INTERVALS: [u64; 5] = [0, 15, 30, 60, 300];
+/// Reading-session lengths (minutes) the Settings entry cycles through; 0 is off.
+const SESSION_LIMIT_MINUTES: [u64; 6] = [0, 10, 15, 20, 30, 60];
+/// Reading-session page counts the Settings entry cycles through; 0 is off.
+const SESSION_LIMIT_PAGES: [usize; 5] = [0, 10, 20, 50, 100];
+/// How close to the session limit the top bar starts counting down.
+const SESSION_COUNTDOWN: Duration = Duration::from_secs(5 * 60);
+const SESSION_COUNTDOWN_PAGES: usize = 5;
+/// File types a link may point to for `open_linked_books` to offer them.
+const LINKED_BOOK_EXTENSIONS: [&str; 6] = ["epub", "fb2", "mobi", "azw", "azw3", "cbz"];
+/// Chapter layouts kept at other widths so `+` / `-` back to one is instant.
+const CHAPTER_WRAP_CACHE_LIMIT: usize = 8;
 
 fn previous_grapheme_boundary(text: &str, cursor: usize) -> usize {
     use unicode_segmentation::UnicodeSegmentation;
@@ -125,16 +193,39 @@ fn wrapped_cursor_position(text: &str, cursor: usize, wrap_width: u16) -> (u16,
     )
 }
 
-/// Columns drawn beside the wrapped text: 5 for the line-number margin
+/// Columns drawn beside the wrapped text: the line-number margin
 /// ("9999 ") and 1 for the highlight marker column.
 fn reader_gutter_width(show_line_numbers: bool, has_highlights: bool) -> usize {
-    let mut width = if show_line_numbers { 5 } else { 0 };
+    let mut width = Board::line_number_width(show_line_numbers);
     if has_highlights {
         width += 1;
     }
     width
 }
 
+/// Terminal rows not given to text: the top bar and its gap, the bottom gap,
+/// and the top and bottom of the content border.
+fn reader_chrome_rows(settings: &Settings) -> u16 {
+    let top = if settings.show_top_bar { 1 + 2 } else { 0 };
+    let border = if settings.content_border { 2 } else { 0 };
+    top + 2 + border
+}
+
+/// Text rows on a page for a terminal `height` rows tall, once the
+/// reader's chrome is taken out.
+fn page_rows(height: u16, settings: &Settings) -> usize {
+    height.saturating_sub(reader_chrome_rows(settings)) as usize
+}
+
+/// Fewest text rows and columns worth laying a page out in; below either
+/// the reader shows a "Terminal too small" notice instead.
+const MIN_PAGE_ROWS: u16 = 3;
+const MIN_TERMINAL_COLS: u16 = 20;
+
+fn terminal_too_small(width: u16, height: u16, settings: &Settings) -> bool {
+    width < MIN_TERMINAL_COLS || page_rows(height, settings) < MIN_PAGE_ROWS as usize
+}
+
 /// The width text is wrapped to, shared by the parse and render paths so
 /// justified lines exactly fill the drawn text area. The gutter is carved
 /// out before centering, padding keeps at least 5 columns per side, and the
@@ -153,6 +244,351 @@ fn compute_wrap_width(term_width: usize, textwidth: usize, gutter_width: usize)
         .max(20)
 }
 
+/// Coarse "N days ago" wording for an elapsed time of at least a day.
+fn format_time_ago(elapsed: chrono::TimeDelta) -> String {
+    let days = elapsed.num_days().max(1);
+    let (count, unit) = match days {
+        0..=13 => (days, "day"),
+        14..=59 => (days / 7, "week"),
+        60..=729 => (days / 30, "month"),
+        _ => (days / 365, "year"),
+    };
+    if count == 1 {
+        format!("1 {unit} ago")
+    } else {
+        format!("{count} {unit}s ago")
+    }
+}
+
+/// Where a citation points inside the book: print pages from the EPUB
+/// page-list, or the percentage read when the book has none.
+enum CitationLocation {
+    Pages(String, String),
+    Percent(u32),
+}
+
+/// A selection in quotes followed by an APA-style reference, e.g.
+/// `Aurelius, M. (2004). Meditations, Penguin, p. 41.` A missing date reads
+/// `n.d.` as APA has it.
+fn format_citation(text: &str, meta: Option<&BookMetadata>, location: &CitationLocation) -> String {
+    let field = |value: Option<&String>| {
+        value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let author = meta.and_then(|meta| field(meta.creator.as_ref()));
+    let title = meta.and_then(|meta| field(meta.title.as_ref()));
+    let publisher = meta.and_then(|meta| field(meta.publisher.as_ref()));
+    let year = meta
+        .and_then(|meta| meta.date.as_deref())
+        .and_then(|date| {
+            let digits: String = date.trim().chars().take(4).collect();
+            (digits.len() == 4 && digits.chars().all(|c| c.is_ascii_digit())).then_some(digits)
+        })
+        .unwrap_or_else(|| "n.d.".to_string());
+
+    let location = match location {
+        CitationLocation::Pages(first, last) if first == last => format!("p. {first}"),
+        CitationLocation::Pages(first, last) => format!("pp. {first}–{last}"),
+        CitationLocation::Percent(percent) => format!("{percent}%"),
+    };
+    let source: Vec<String> = [title, publisher, Some(location)]
+        .into_iter()
+        .flatten()
+        .collect();
+    let reference = match author {
+        Some(author) => format!("{author} ({year}). {}.", source.join(", ")),
+        None => format!("({year}). {}.", source.join(", ")),
+    };
+
+    let quoted = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    format!("\u{201c}{quoted}\u{201d}\n\n{reference}")
+}
+
+/// A selection as a Markdown blockquote, followed by an attribution line
+/// naming the book and chapter when they are known.
+fn format_markdown_quote(text: &str, title: Option<&str>, chapter: Option<&str>) -> String {
+    let mut quote: String = text
+        .trim()
+        .lines()
+        .map(|line| {
+            let line = line.trim_end();
+            if line.is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let attribution: Vec<&str> = [title, chapter]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+    if !attribution.is_empty() {
+        quote.push_str("\n\n— ");
+        quote.push_str(&attribution.join(", "));
+    }
+    quote
+}
+
+/// Index into the sorted `rows` of the first one after `current` (or the
+/// last one before it), and whether that wrapped around the book's end.
+fn step_target(rows: &[usize], current: usize, forward: bool) -> Option<(usize, bool)> {
+    if rows.is_empty() {
+        return None;
+    }
+    let found = if forward {
+        rows.iter().position(|&row| row > current)
+    } else {
+        rows.iter().rposition(|&row| row < current)
+    };
+    Some(match found {
+        Some(index) => (index, false),
+        None if forward => (0, true),
+        None => (rows.len() - 1, true),
+    })
+}
+
+/// What `Ctrl+g` speaks: "Chapter, 42 percent. <first line on screen>".
+fn position_announcement(chapter: Option<&str>, percent: u32, line: Option<&str>) -> String {
+    let mut text = match chapter.map(str::trim).filter(|c| !c.is_empty()) {
+        Some(chapter) => format!("{chapter}, {percent} percent."),
+        None => format!("{percent} percent."),
+    };
+    if let Some(line) = line.map(str::trim).filter(|l| !l.is_empty()) {
+        text.push(' ');
+        text.push_str(line);
+    }
+    text
+}
+
+/// Words per minute across `samples` (running `(time, words)` totals), or
+/// `None` until they span `SESSION_PACE_MIN_SPAN` with some words read.
+fn rolling_words_per_minute(samples: &VecDeque<(Instant, usize)>, now: Instant) -> Option<f64> {
+    let (first_at, first_words) = *samples.front()?;
+    let (_, last_words) = *samples.back()?;
+    let span = now.checked_duration_since(first_at)?;
+    let words = last_words.saturating_sub(first_words);
+    if span < SESSION_PACE_MIN_SPAN || words == 0 {
+        return None;
+    }
+    Some(words as f64 * 60.0 / span.as_secs_f64())
+}
+
+/// Image placeholders as `(row, src)` pairs ordered by row.
+fn sorted_images(image_maps: &HashMap<usize, String>) -> Vec<(usize, String)> {
+    let mut images: Vec<(usize, String)> = image_maps
+        .iter()
+        .map(|(&row, src)| (row, src.clone()))
+        .collect();
+    images.sort_unstable_by_key(|&(row, _)| row);
+    images
+}
+
+/// One `W` capture: a `## Chapter (42%)` header, the page's lines with
+/// trailing blank lines dropped, and a blank separator line.
+fn format_page_capture(chapter: Option<&str>, percent: u32, lines: &[&str]) -> String {
+    let header = match chapter.map(str::trim).filter(|c| !c.is_empty()) {
+        Some(chapter) => format!("## {chapter} ({percent}%)"),
+        None => format!("## {percent}%"),
+    };
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |i| i + 1);
+    let mut out = header;
+    out.push_str("\n\n");
+    for line in &lines[..end] {
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out.push('\n');
+    out
+}
+
+/// Clean selected text into a dictionary or Wikipedia query: NFC-composed,
+/// without zero-width characters or soft hyphens, and whitespace collapsed.
+/// A single word also loses its surrounding punctuation and quotes
+/// (`"word,"` looks up `word`, `co-op` stays whole); a phrase is kept as
+/// selected.
+fn clean_lookup_query(text: &str) -> String {
+    use unicode_normalization::UnicodeNormalization;
+
+    let composed: String = text
+        .nfc()
+        .filter(|c| !LOOKUP_INVISIBLE_CHARS.contains(c))
+        .collect();
+    let words: Vec<&str> = composed.split_whitespace().collect();
+    match words.as_slice() {
+        [word] => word.trim_matches(LOOKUP_EDGE_PUNCTUATION).to_string(),
+        _ => words.join(" "),
+    }
+}
+
+/// The plain-ASCII stand-in `normalize_search` matches for a typographic
+/// character: curly quotes, dashes, and Latin ligatures.
+fn search_fold(c: char) -> Option<&'static str> {
+    Some(match c {
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => "'",
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' => "\"",
+        '\u{2010}'..='\u{2015}' | '\u{2212}' => "-",
+        '\u{FB00}' => "ff",
+        '\u{FB01}' => "fi",
+        '\u{FB02}' => "fl",
+        '\u{FB03}' => "ffi",
+        '\u{FB04}' => "ffl",
+        '\u{FB05}' | '\u{FB06}' => "st",
+        _ => return None,
+    })
+}
+
+/// `text` with every [`search_fold`] character replaced, plus the source
+/// character index each folded character came from (one past the end at
+/// the tail). `None` when there is nothing to fold, the common case.
+fn fold_search_text(text: &str) -> Option<(String, Vec<usize>)> {
+    if !text.chars().any(|c| search_fold(c).is_some()) {
+        return None;
+    }
+    let mut folded = String::with_capacity(text.len());
+    let mut origin = Vec::with_capacity(text.len() + 1);
+    let mut count = 0;
+    for (index, c) in text.chars().enumerate() {
+        match search_fold(c) {
+            Some(replacement) => {
+                folded.push_str(replacement);
+                origin.extend(std::iter::repeat_n(index, replacement.chars().count()));
+            }
+            None => {
+                folded.push(c);
+                origin.push(index);
+            }
+        }
+        count = index + 1;
+    }
+    origin.push(count);
+    Some((folded, origin))
+}
+
+/// Window title for `set_terminal_title`: `Book — Chapter`, or the book
+/// alone outside any chapter. Control characters from the book's metadata
+/// are dropped so they cannot end the escape sequence early.
+fn terminal_title_text(book: &str, chapter: Option<&str>) -> String {
+    let title = match chapter.map(str::trim).filter(|chapter| !chapter.is_empty()) {
+        Some(chapter) => format!("{} — {}", book.trim(), chapter),
+        None => book.trim().to_string(),
+    };
+    title.chars().filter(|c| !c.is_control()).collect()
+}
+
+/// Expand one argument of an `on_open` / `on_close` hook: `%f` file,
+/// `%p` percent read, `%t` title, `%%` a literal percent sign. Unknown
+/// fields are left as written.
+fn render_hook_arg(arg: &str, file: &str, percent: u32, title: &str) -> String {
+    let mut out = String::with_capacity(arg.len());
+    let mut chars = arg.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('f') => out.push_str(file),
+            Some('p') => out.push_str(&percent.to_string()),
+            Some('t') => out.push_str(title),
+            Some('%') => out.push('%'),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
+
+/// Expand a `yank_template`: `%s` selection, `%t` title, `%a` author,
+/// `%c` chapter, `%p` percent read, `%%` a literal percent sign. Unknown
+/// fields are left as written; missing metadata expands to nothing.
+fn render_yank_template(
+    template: &str,
+    text: &str,
+    title: Option<&str>,
+    author: Option<&str>,
+    chapter: Option<&str>,
+    percent: u32,
+) -> String {
+    let mut out = String::with_capacity(template.len() + text.len());
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('s') => out.push_str(text),
+            Some('t') => out.push_str(title.unwrap_or_default().trim()),
+            Some('a') => out.push_str(author.unwrap_or_default().trim()),
+            Some('c') => out.push_str(chapter.unwrap_or_default().trim()),
+            Some('p') => out.push_str(&format!("{percent}%")),
+            Some('%') => out.push('%'),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
+
+/// The TTS preset after `current` in the Settings cycle; a custom engine
+/// moves on to the first preset.
+fn next_tts_preset(current: &str) -> String {
+    let index = TTS_PRESET_LIST.iter().position(|&preset| preset == current);
+    let next = index.map_or(0, |i| (i + 1) % TTS_PRESET_LIST.len());
+    TTS_PRESET_LIST[next].to_string()
+}
+
+/// Program and arguments for an inline (speak-directly) TTS engine. A `{}`
+/// template is expanded as written; a bare `espeak`/`espeak-ng` or `say`
+/// gets the configured rate and voice as flags before the text.
+fn tts_inline_command(
+    engine: &str,
+    text: &str,
+    rate: Option<u32>,
+    voice: Option<&str>,
+) -> Option<(String, Vec<String>)> {
+    if engine.contains("{}") {
+        let expanded = engine.replace("{}", text);
+        let mut parts = expanded.split_whitespace().map(str::to_string);
+        let program = parts.next()?;
+        return Some((program, parts.collect()));
+    }
+    let program_name = std::path::Path::new(engine)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(engine);
+    let rate_flag = match program_name {
+        "espeak" | "espeak-ng" => Some("-s"),
+        "say" => Some("-r"),
+        _ => None,
+    };
+    let mut args = Vec::new();
+    if let Some(rate_flag) = rate_flag {
+        if let Some(rate) = rate {
+            args.extend([rate_flag.to_string(), rate.to_string()]);
+        }
+        if let Some(voice) = voice.map(str::trim).filter(|voice| !voice.is_empty()) {
+            args.extend(["-v".to_string(), voice.to_string()]);
+        }
+    }
+    args.push(text.to_string());
+    Some((engine.to_string(), args))
+}
+
 /// Application state that encompasses all UI and reading state
 #[derive(Debug, Clone)]
 pub struct ApplicationState {
@@ -210,6 +646,16 @@ impl ApplicationState {
             .unwrap_or(self.config.settings.color_theme)
     }
 
+    /// The book's own TTS engine when one was picked for it, else the
+    /// global `preferred_tts_engine`.
+    pub fn effective_tts_engine(&self) -> Option<&str> {
+        self.reading_state.tts_engine.as_deref().or(self
+            .config
+            .settings
+            .preferred_tts_engine
+            .as_deref())
+    }
+
     pub fn record_jump(&mut self, current: ReadingState) {
         // If we are in the middle of history (index < len), truncate the future
         if self.jump_history_index < self.jump_history.len() {
@@ -283,13 +729,19 @@ pub struct UiState {
     pub show_metadata: bool,
     pub show_statistics: bool,
     pub show_dictionary: bool,
+    pub show_chapter_source: bool,
     pub show_settings: bool,
     pub show_highlights: bool,
+    pub show_messages: bool,
+    pub show_pages: bool,
     pub search_query: String,
     /// True once Enter confirmed the query (j/k then navigate results).
     pub search_committed: bool,
     /// Reader row when the search window opened; restored on Esc while typing.
     pub search_origin_row: usize,
+    /// Restrict `/` matches to the chapter containing `search_origin_row`
+    /// (toggled with Tab in the search window).
+    pub search_chapter_only: bool,
     /// Persisted search history, most recent first (loaded when `/` opens).
     pub search_history: Vec<String>,
     /// Position while browsing history with Up/Down (None = editing draft).
@@ -297,10 +749,18 @@ pub struct UiState {
     /// The query being typed before history browsing started.
     pub search_history_draft: String,
     pub search_results: Vec<SearchResult>,
+    /// Highlight ranges per rendered row, as `(start, end)` character
+    /// columns (not byte offsets) so they line up with `Board::render`.
     pub search_matches: HashMap<usize, Vec<(usize, usize)>>,
+    /// Set by `Ctrl+l` to stop drawing search matches until the next
+    /// search or `n` / `N`, which keep working meanwhile.
+    pub search_highlights_hidden: bool,
     pub selected_search_result: usize,
     pub toc_entries: Vec<TocEntry>,
     pub toc_selected_index: usize,
+    /// EPUB print pages listed by the Pages window (`P`).
+    pub page_anchors: Vec<PageAnchor>,
+    pub pages_selected_index: usize,
     /// True while the user is typing a `/`-filter query in a list window.
     pub list_filter_active: bool,
     /// The fuzzy-filter query for the currently open list window.
@@ -326,11 +786,25 @@ pub struct UiState {
     pub links: Vec<LinkEntry>,
     pub links_selected_index: usize,
     pub link_preview: Option<LinkEntry>,
-    pub images_list: Vec<(usize, String)>,
+    /// Active `t` tour of the links window's notes (Enter steps, Esc ends).
+    pub link_tour: Option<LinkTour>,
+    /// When a running `a` slideshow turns the next page; `None` when paused.
+    pub slideshow_next: Option<Instant>,
+    /// When this reading session began, for `session_limit_minutes`; `None`
+    /// until reading starts, or resumes after an idle break.
+    pub session_started: Option<Instant>,
+    /// Pages turned forward this session, for `session_limit_pages`.
+    pub session_page_turns: usize,
+    /// The session limit has been reached and acted on.
+    pub session_over: bool,
+    pub images_list: Vec<ImageEntry>,
     pub images_selected_index: usize,
+    /// The Images window lists the whole book (`O`) rather than the page.
+    pub images_book_wide: bool,
     pub library_items: Vec<LibraryEntry>,
     pub library_selected_index: usize,
     pub library_sort_mode: LibrarySortMode,
+    pub library_progress_filter: LibraryProgressFilter,
     /// Whether the selected book's metadata details are shown in the Library
     /// window. Cover decoding remains lazy because it can make navigation sluggish.
     pub library_cover_visible: bool,
@@ -356,7 +830,22 @@ pub struct UiState {
     pub dictionary_definition: String,
     pub dictionary_client_used: String,
     pub dictionary_scroll_offset: u16,
+    /// Articles offered when a Wikipedia lookup lands on a disambiguation
+    /// page, and the highlighted one.
+    pub dictionary_disambiguation: Option<WikipediaDisambiguation>,
+    pub dictionary_option_index: usize,
+    /// Archive path and pretty-printed markup shown by the chapter source
+    /// overlay (`X`).
+    pub chapter_source_href: String,
+    pub chapter_source: String,
+    pub chapter_source_scroll_offset: u16,
     pub dictionary_command_query: String,
+    /// The preset whose command `DictionaryCommandInput` edits; `None`
+    /// edits the custom `dictionary_client` template.
+    pub dictionary_command_preset: Option<usize>,
+    /// Selected row of the dictionary presets view: "auto", then each
+    /// preset, then the custom command.
+    pub dictionary_preset_selected_index: usize,
     pub settings_input_field: Option<String>,
     pub settings_input_buffer: String,
     pub settings_selected_index: usize,
@@ -368,6 +857,10 @@ pub struct UiState {
     /// Warnings and errors stay until a key dismisses them; info toasts
     /// auto-expire.
     pub message_persistent: bool,
+    /// The last `MESSAGE_HISTORY_LIMIT` messages, oldest first.
+    pub message_history: VecDeque<LoggedMessage>,
+    /// Selection in the Messages window, counted from the newest message.
+    pub messages_selected_index: usize,
     pub visual_anchor: Option<(usize, usize)>,
     pub visual_cursor: Option<(usize, usize)>,
     pub help_scroll_offset: u16,
@@ -375,6 +868,8 @@ pub struct UiState {
     /// Per-line underline ranges for the TTS chunk being read.
     /// Maps line_num -> (start_col, end_col_exclusive) in characters.
     pub tts_underline_ranges: HashMap<usize, (usize, usize)>,
+    /// `(chunk number, chunk count)` of the current TTS run, 1-based.
+    pub tts_progress: Option<(usize, usize)>,
     pub tts_converting: bool,
     pub tts_anim_frame: usize,
     /// True while the user is typing a `/`-search query inside cursor/selection mode.
@@ -389,11 +884,39 @@ pub struct UiState {
     /// keypress becomes the find target. Stores the count typed before the
     /// motion key (e.g. `2` in `2fa`) so it survives the intermediate key.
     pub pending_visual_find: Option<(VisualFindDirection, u32)>,
+    /// Set after `i` in cursor/selection mode; `s` or `p` next selects the
+    /// inner sentence or paragraph.
+    pub pending_visual_text_object: bool,
     pub pending_mark_command: Option<PendingMarkCommand>,
+    /// Set after `z` in the reader; the next key picks the scroll command.
+    pub pending_z_command: bool,
+    /// Set after `]` (true) or `[` (false); `m` next steps to the following
+    /// or preceding bookmark.
+    pub pending_bracket_command: Option<bool>,
     /// Remote KOReader progress awaiting the jump prompt: `(percentage, device,
     /// resolved target row)`. The row is precomputed at pull time — from the
     /// XPointer when possible, otherwise the content percentage.
     pub pending_sync_progress: Option<(f64, String, usize)>,
+    /// A missing library entry and the file found with the same content
+    /// hash, awaiting the relink prompt: `(old_path, new_path)`.
+    pub pending_relink: Option<(String, String)>,
+    /// Saved progress and last-read time of a just-opened book, awaiting the
+    /// resume prompt (`resume_prompt` setting).
+    pub pending_resume: Option<(f32, DateTime<Utc>)>,
+    /// Another ebook a followed link points to, awaiting the open prompt.
+    pub pending_linked_book: Option<String>,
+    /// The open book reads right-to-left: lines are right-aligned and
+    /// `h`/`l` page turns are swapped.
+    pub is_rtl: bool,
+    /// Columns scrolled off the left of lines wider than the reading area
+    /// (tables, preformatted code), adjusted with `<` / `>`.
+    pub horizontal_scroll: usize,
+    /// Rows below the top of the page where the reading ruler's bright
+    /// band starts (`focus_mode`), moved with `[` / `]`.
+    pub focus_band_top: usize,
+    /// TOC title of the chapter at the top of the page, refreshed before
+    /// each draw while `show_chapter_title` is on.
+    pub chapter_title: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -429,6 +952,7 @@ impl UiState {
         }
         self.search_results = results;
         self.selected_search_result = 0;
+        self.search_highlights_hidden = false;
     }
 
     fn clear_search_results(&mut self) {
@@ -448,19 +972,26 @@ impl UiState {
             show_metadata: false,
             show_statistics: false,
             show_dictionary: false,
+            show_chapter_source: false,
             show_settings: false,
             show_highlights: false,
+            show_messages: false,
+            show_pages: false,
             search_query: String::new(),
             search_committed: false,
             search_origin_row: 0,
+            search_chapter_only: false,
             search_history: Vec::new(),
             search_history_index: None,
             search_history_draft: String::new(),
             search_results: Vec::new(),
             search_matches: HashMap::new(),
+            search_highlights_hidden: false,
             selected_search_result: 0,
             toc_entries: Vec::new(),
             toc_selected_index: 0,
+            page_anchors: Vec::new(),
+            pages_selected_index: 0,
             list_filter_active: false,
             list_filter_query: String::new(),
             list_filter_indices: None,
@@ -481,11 +1012,18 @@ impl UiState {
             links: Vec::new(),
             links_selected_index: 0,
             link_preview: None,
+            link_tour: None,
+            slideshow_next: None,
+            session_started: None,
+            session_page_turns: 0,
+            session_over: false,
             images_list: Vec::new(),
             images_selected_index: 0,
+            images_book_wide: false,
             library_items: Vec::new(),
             library_selected_index: 0,
             library_sort_mode: LibrarySortMode::default(),
+            library_progress_filter: LibraryProgressFilter::default(),
             library_cover_visible: false,
             library_scanning: false,
             opds_feed: None,
@@ -506,7 +1044,14 @@ impl UiState {
             dictionary_definition: String::new(),
             dictionary_client_used: String::new(),
             dictionary_scroll_offset: 0,
+            dictionary_disambiguation: None,
+            dictionary_option_index: 0,
+            chapter_source_href: String::new(),
+            chapter_source: String::new(),
+            chapter_source_scroll_offset: 0,
             dictionary_command_query: String::new(),
+            dictionary_command_preset: None,
+            dictionary_preset_selected_index: 0,
             settings_input_field: None,
             settings_input_buffer: String::new(),
             settings_selected_index: 0,
@@ -516,11 +1061,14 @@ impl UiState {
             message_type: MessageType::Info,
             message_time: None,
             message_persistent: false,
+            message_history: VecDeque::new(),
+            messages_selected_index: 0,
             visual_anchor: None,
             visual_cursor: None,
             help_scroll_offset: 0,
             tts_active: false,
             tts_underline_ranges: HashMap::new(),
+            tts_progress: None,
             tts_converting: false,
             tts_anim_frame: 0,
             visual_search_input_active: false,
@@ -528,18 +1076,53 @@ impl UiState {
             visual_search_matches: Vec::new(),
             visual_search_selected: 0,
             pending_visual_find: None,
+            pending_visual_text_object: false,
             pending_mark_command: None,
+            pending_z_command: false,
+            pending_bracket_command: None,
             pending_sync_progress: None,
+            pending_relink: None,
+            pending_linked_book: None,
+            pending_resume: None,
+            is_rtl: false,
+            horizontal_scroll: 0,
+            focus_band_top: 0,
+            chapter_title: None,
         }
     }
 
     pub fn set_message(&mut self, message: String, message_type: MessageType) {
+        // A message repeated back to back (progress ticks, retried
+        // actions) only refreshes its timestamp in the history.
+        match self.message_history.back_mut() {
+            Some(last) if last.text == message && last.message_type == message_type => {
+                last.time = Local::now();
+            }
+            _ => {
+                if self.message_history.len() == MESSAGE_HISTORY_LIMIT {
+                    self.message_history.pop_front();
+                }
+                self.message_history.push_back(LoggedMessage {
+                    time: Local::now(),
+                    message_type: message_type.clone(),
+                    text: message.clone(),
+                });
+            }
+        }
         self.message = Some(message);
         self.message_persistent = matches!(message_type, MessageType::Warning | MessageType::Error);
         self.message_type = message_type;
         self.message_time = Some(Instant::now());
     }
 
+    /// Start the `session_limit_*` count over: for a new book, or once an
+    /// idle break has ended the reading session.
+    pub fn restart_session_limit(&mut self) {
+        self.session_started = None;
+        self.session_page_turns = 0;
+        self.session_over = false;
+    }
+
     pub fn clear_message(&mut self) {
         self.message = None;
         self.message_time = None;
@@ -605,12 +1188,18 @@ impl UiState {
                 self.show_metadata = false;
                 self.show_statistics = false;
                 self.show_dictionary = false;
+                self.show_chapter_source = false;
                 self.show_settings = false;
                 self.show_highlights = false;
+                self.show_messages = false;
+                self.show_pages = false;
                 self.visual_anchor = None;
                 self.visual_cursor = None;
                 self.pending_visual_find = None;
+                self.pending_visual_text_object = false;
                 self.pending_mark_command = None;
+                self.pending_z_command = false;
+                self.pending_bracket_command = None;
                 self.link_preview = None;
             }
             WindowType::Help => {
@@ -629,11 +1218,20 @@ impl UiState {
             }
             WindowType::Search => self.show_search = true,
             WindowType::Links => self.show_links = true,
+            WindowType::Pages => self.show_pages = true,
+            WindowType::ChapterSource => {
+                self.show_chapter_source = true;
+                self.chapter_source_scroll_offset = 0;
+            }
             WindowType::Images => self.show_images = true,
             WindowType::ImageView => {
                 self.show_images = false;
             }
             WindowType::Metadata => self.show_metadata = true,
+            WindowType::Messages => {
+                self.show_messages = true;
+                self.messages_selected_index = 0;
+            }
             WindowType::Statistics => self.show_statistics = true,
             WindowType::Dictionary => {
                 self.show_dictionary = true;
@@ -644,7 +1242,7 @@ impl UiState {
                 self.show_settings = false;
             }
             WindowType::Visual => {}
-            WindowType::DictionaryCommandInput => {
+            WindowType::DictionaryPresets | WindowType::DictionaryCommandInput => {
                 self.show_settings = false;
             }
             WindowType::Highlights => self.show_highlights = true,
@@ -655,6 +1253,13 @@ impl UiState {
                 self.show_highlights = false;
             }
             WindowType::ConfirmSyncProgress => {}
+            WindowType::ConfirmRelink => {
+                self.show_library = false;
+            }
+            WindowType::ConfirmResume => {}
+            WindowType::ConfirmOpenLinkedBook => {
+                self.show_links = false;
+            }
             WindowType::LinkPreview => {
                 self.show_links = false;
             }
@@ -669,9 +1274,51 @@ pub enum MessageType {
     Error,
 }
 
+/// A guided walk through the internal-link targets of one page: each note
+/// is shown in turn, then the reader returns to where the tour started.
+#[derive(Debug, Clone)]
+pub struct LinkTour {
+    pub targets: Vec<usize>,
+    pub index: usize,
+    pub origin: ReadingState,
+}
+
+/// One row of the Images window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageEntry {
+    /// Placeholder row of the image.
+    pub row: usize,
+    pub src: String,
+    /// Descriptive `alt` text, when the book provides one.
+    pub alt: Option<String>,
+    /// Chapter label, filled in for the book-wide list only.
+    pub chapter: Option<String>,
+}
+
+/// An EPUB print page (page-list entry) and where it starts in the text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageAnchor {
+    pub label: String,
+    pub row: usize,
+    /// The rendered line the page starts on, trimmed.
+    pub preview: String,
+}
+
+/// A status message as recorded for the Messages window.
+#[derive(Debug, Clone)]
+pub struct LoggedMessage {
+    pub time: DateTime<Local>,
+    pub message_type: MessageType,
+    pub text: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub preview: String,
+    /// Rendered rows just above and below the hit, shown in the results
+    /// list to tell look-alike matches apart.
+    pub context_before: Option<String>,
+    pub context_after: Option<String>,
     pub content_index: usize,
     pub source_start: usize,
     pub source_end: usize,
@@ -693,6 +1340,15 @@ pub struct DictionaryResult {
     pub word: String,
     pub definition: Result<String, String>,
     pub client: String,
+    pub disambiguation: Option<WikipediaDisambiguation>,
+}
+
+/// Article titles linked from a Wikipedia disambiguation page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WikipediaDisambiguation {
+    /// Language code (or test base URL) the titles belong to.
+    pub language: String,
+    pub titles: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -701,6 +1357,12 @@ struct WikipediaLookupResult {
     summary: String,
 }
 
+#[derive(Debug, Clone)]
+enum WikipediaLookup {
+    Summary(WikipediaLookupResult),
+    Disambiguation(WikipediaDisambiguation),
+}
+
 #[derive(Debug, Deserialize)]
 struct WikipediaSummaryResponse {
     query: Option<WikipediaQueryData>,
@@ -729,18 +1391,53 @@ struct WikipediaSearchHit {
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum SettingItem {
     ShowLineNumbers,
+    LineNumberStyle,
     MouseSupport,
+    CountPrefix,
     PageScrollAnimation,
     ShowProgressIndicator,
     SeamlessBetweenChapters,
+    ChapterBreakBlankLines,
+    ChapterBreakRule,
+    ChapterBreakTitle,
     InlineImages,
     ParagraphStyle,
     LineSpacing,
     JustifyText,
+    FocusMode,
+    FocusLines,
+    BionicReading,
+    ResumePrompt,
+    SkipFrontMatter,
+    AutoMarkFinished,
+    SlideshowInterval,
+    SyncSidecar,
+    AutosaveInterval,
+    SessionLimitMinutes,
+    SessionLimitPages,
+    SessionLimitQuit,
+    SearchPlaceholders,
+    Hlsearch,
+    NormalizeSearch,
+    OpenLinkedBooks,
     DictionaryClient,
+    AggregateDictionaries,
+    AsciiWikipedia,
+    ClipboardCommand,
+    DefaultTtsEngine,
     TtsEngine,
+    TtsRate,
+    TtsReadImages,
+    TtsAnnounceChapters,
+    TtsStopAtChapter,
+    PlayNarration,
     Width,
     ShowTopBar,
+    ContentBorder,
+    ShowChapterTitle,
+    ShowEta,
+    SetTerminalTitle,
+    PreferEpubPageNumbers,
     ColorTheme,
     KosyncPullNow,
     KosyncServer,
@@ -758,22 +1455,67 @@ const SETTINGS_SECTIONS: &[(&str, &[SettingItem])] = &[
         "Display",
         &[
             SettingItem::ShowLineNumbers,
+            SettingItem::LineNumberStyle,
             SettingItem::ShowProgressIndicator,
             SettingItem::ShowTopBar,
+            SettingItem::ContentBorder,
+            SettingItem::ShowChapterTitle,
+            SettingItem::ShowEta,
+            SettingItem::PreferEpubPageNumbers,
             SettingItem::PageScrollAnimation,
             SettingItem::SeamlessBetweenChapters,
+            SettingItem::ChapterBreakBlankLines,
+            SettingItem::ChapterBreakRule,
+            SettingItem::ChapterBreakTitle,
             SettingItem::InlineImages,
             SettingItem::ParagraphStyle,
             SettingItem::LineSpacing,
             SettingItem::JustifyText,
+            SettingItem::FocusMode,
+            SettingItem::FocusLines,
+            SettingItem::BionicReading,
             SettingItem::Width,
             SettingItem::ColorTheme,
+            SettingItem::SetTerminalTitle,
         ],
     ),
-    ("Input", &[SettingItem::MouseSupport]),
+    (
+        "Reading",
+        &[
+            SettingItem::ResumePrompt,
+            SettingItem::SkipFrontMatter,
+            SettingItem::AutoMarkFinished,
+            SettingItem::SlideshowInterval,
+            SettingItem::SyncSidecar,
+            SettingItem::AutosaveInterval,
+            SettingItem::SessionLimitMinutes,
+            SettingItem::SessionLimitPages,
+            SettingItem::SessionLimitQuit,
+            SettingItem::SearchPlaceholders,
+            SettingItem::Hlsearch,
+            SettingItem::NormalizeSearch,
+            SettingItem::OpenLinkedBooks,
+        ],
+    ),
+    (
+        "Input",
+        &[SettingItem::MouseSupport, SettingItem::CountPrefix],
+    ),
     (
         "Tools",
-        &[SettingItem::DictionaryClient, SettingItem::TtsEngine],
+        &[
+            SettingItem::DictionaryClient,
+            SettingItem::AggregateDictionaries,
+            SettingItem::AsciiWikipedia,
+            SettingItem::ClipboardCommand,
+            SettingItem::DefaultTtsEngine,
+            SettingItem::TtsEngine,
+            SettingItem::TtsRate,
+            SettingItem::TtsReadImages,
+            SettingItem::TtsAnnounceChapters,
+            SettingItem::TtsStopAtChapter,
+            SettingItem::PlayNarration,
+        ],
     ),
     (
         "KOReader Sync",
@@ -819,6 +1561,9 @@ enum EdgeTtsPlayer {
 }
 
 const TTS_PREFETCH_WINDOW: usize = 4;
+/// Bounds for the Settings TTS rate stepper, in words per minute.
+const MIN_TTS_RATE: i32 = 80;
+const MAX_TTS_RATE: i32 = 450;
 
 impl EdgeTtsPlayer {
     fn program(&self) -> &'static str {
@@ -840,6 +1585,72 @@ impl EdgeTtsPlayer {
             ],
         }
     }
+    /// Arguments that play `begin..end` seconds of `path`, or to the end of
+    /// the file when `end` is `None`.
+    fn clip_args(&self, path: &std::path::Path, begin: f64, end: Option<f64>) -> Vec<String> {
+        let mut args = self.args(path);
+        let file = args.pop().unwrap_or_default();
+        match self {
+            Self::Mpv => {
+                args.push(format!("--start={begin:.3}"));
+                if let Some(end) = end {
+                    args.push(format!("--end={end:.3}"));
+                }
+            }
+            Self::Ffplay => {
+                args.extend(["-ss".into(), format!("{begin:.3}")]);
+                if let Some(end) = end {
+                    args.extend(["-t".into(), format!("{:.3}", (end - begin).max(0.0))]);
+                }
+            }
+        }
+        args.push(file);
+        args
+    }
+}
+
+/// Stand-in clip length for a `narration_player` template's `{end}` when a
+/// clip runs to the end of its file: a day, well past any recording.
+const NARRATION_OPEN_END_SECS: f64 = 86_400.0;
+
+/// Program and arguments for a `narration_player` template playing `clip`
+/// from `audio`. The template is split into words before `{file}`,
+/// `{start}`, and `{end}` are filled in, so a path with spaces stays one
+/// argument.
+fn narration_player_command(
+    template: &str,
+    audio: &std::path::Path,
+    clip: &NarrationClip,
+) -> Option<(String, Vec<String>)> {
+    let file = audio.to_string_lossy();
+    let start = format!("{:.3}", clip.clip_begin);
+    let end = clip
+        .clip_end
+        .unwrap_or(clip.clip_begin + NARRATION_OPEN_END_SECS);
+    let end = format!("{end:.3}");
+    let mut parts = template.split_whitespace().map(|word| {
+        word.replace("{file}", &file)
+            .replace("{start}", &start)
+            .replace("{end}", &end)
+    });
+    let program = parts.next()?;
+    Some((program, parts.collect()))
+}
+
+/// Which of the book lifecycle hooks to run.
+#[derive(Clone, Copy)]
+enum BookHook {
+    Open,
+    Close,
+}
+
+impl BookHook {
+    fn setting_name(self) -> &'static str {
+        match self {
+            Self::Open => "on_open",
+            Self::Close => "on_close",
+        }
+    }
 }
 
 /// A single TTS chunk: the text to speak, the first display line it
@@ -849,6 +1660,9 @@ struct TtsChunk {
     first_line: usize,
     /// line_num → (start_col, end_col_exclusive) in display characters
     underline: HashMap<usize, (usize, usize)>,
+    /// Recorded narration that reads this chunk, played instead of
+    /// synthesizing `text`.
+    clip: Option<NarrationClip>,
 }
 
 struct ActiveReadingSession {
@@ -861,6 +1675,9 @@ struct ActiveReadingSession {
     max_counted_row: usize,
     rows: usize,
     words: usize,
+    /// `(time, words)` samples of the running word count, trimmed to
+    /// `SESSION_PACE_WINDOW`, for the rolling pace.
+    pace_samples: VecDeque<(Instant, usize)>,
 }
 
 /// DB-derived reading statistics cached off the per-keypress path; refreshed
@@ -875,8 +1692,22 @@ struct CachedStatistics {
     streaks_with_day: (usize, usize),
 }
 
+/// A chapter laid out at a width it is not currently shown at. Rows in a
+/// structure are absolute, so it only fits back at the same starting line
+/// and chapter-break page height.
+struct CachedChapterWrap {
+    chapter: usize,
+    text_width: usize,
+    starting_line: usize,
+    page_height: Option<usize>,
+    structure: TextStructure,
+}
+
 enum TtsWorkerCommand {
     UpdatePlaybackIndex(usize),
+    /// Playback jumped to this chunk, possibly backwards, so conversion
+    /// restarts there.
+    Seek(usize),
     Stop,
 }
 
@@ -912,6 +1743,9 @@ enum OpdsWorkerEvent {
 pub struct Reader<B: Backend = CrosstermBackend<io::Stdout>> {
     state: Rc<RefCell<ApplicationState>>,
     terminal: Terminal<B>,
+    /// The normal-mode bindings with the user's keymap and preset applied,
+    /// built once: neither can change while the reader runs.
+    key_bindings: KeyBindings,
     db_state: State,
     board: Board,
     clipboard: Option<Clipboard>,
@@ -919,6 +1753,9 @@ pub struct Reader<B: Backend = CrosstermBackend<io::Stdout>> {
     content_start_rows: Vec<usize>,
     /// Per-chapter text structures for incremental rebuilds
     chapter_text_structures: Vec<TextStructure>,
+    /// Every image placeholder in the book as `(row, src)`, in reading
+    /// order. Refreshed whenever the chapter structures are combined.
+    book_images: Vec<(usize, String)>,
     /// Text width used for the current chapter structures
     current_text_width: Option<usize>,
     /// Inline-image row cap used for the current chapter structures
@@ -928,9 +1765,17 @@ pub struct Reader<B: Backend = CrosstermBackend<io::Stdout>> {
     /// Typography used for every cached chapter; a mismatch requires a
     /// full-book rebuild because all subsequent absolute rows move.
     current_typography: TypographyOptions,
+    /// Chapter-break layout used for the cached chapters; like typography,
+    /// a change moves every later row and forces a full rebuild.
+    current_chapter_break: ChapterBreakOptions,
+    /// Recently replaced chapter layouts, oldest first; see
+    /// [`CachedChapterWrap`].
+    chapter_wrap_cache: Vec<CachedChapterWrap>,
     dictionary_res_rx: Option<std::sync::mpsc::Receiver<DictionaryResult>>,
     /// Signals that the background library scan finished (cache updated).
-    library_scan_rx: Option<std::sync::mpsc::Receiver<()>>,
+    /// Paths the background library scan could not read, sent when it ends.
+    library_scan_rx: Option<std::sync::mpsc::Receiver<Vec<std::path::PathBuf>>>,
+    library_scan_is_manual: bool,
     opds_rx: Option<std::sync::mpsc::Receiver<OpdsWorkerEvent>>,
     opds_request_id: u64,
     opds_catalog_index: Option<usize>,
@@ -957,6 +1802,9 @@ pub struct Reader<B: Backend = CrosstermBackend<io::Stdout>> {
     tts_current_audio_path: Option<std::path::PathBuf>,
     /// Converted audio chunks that are ready to play, keyed by chunk index.
     tts_ready_audio: HashMap<usize, std::path::PathBuf>,
+    /// Narration audio extracted from the book into the TTS temp dir,
+    /// keyed by archive path.
+    narration_audio: HashMap<String, std::path::PathBuf>,
     /// Background worker command channel for bounded chunk conversion.
     tts_worker_tx: Option<std::sync::mpsc::Sender<TtsWorkerCommand>>,
     /// Background worker event channel delivering ready/failed conversion results.
@@ -967,6 +1815,9 @@ pub struct Reader<B: Backend = CrosstermBackend<io::Stdout>> {
     tts_temp_dir: Option<std::path::PathBuf>,
     /// Active reading-statistics session, flushed on idle, book switch, or quit.
     reading_session: Option<ActiveReadingSession>,
+    /// When and at which row `persist_state` last saved the position, for
+    /// autosave.
+    last_saved: Option<(Instant, usize)>,
     /// Cached DB-side reading statistics; see [`CachedStatistics`].
     cached_statistics: Option<CachedStatistics>,
     /// Terminal graphics capability (kitty/iTerm2/sixel/halfblocks), probed lazily.
@@ -991,9 +1842,28 @@ pub struct Reader<B: Backend = CrosstermBackend<io::Stdout>> {
     /// rendered. Some terminal graphics protocols do not become visible until
     /// the following draw.
     library_cover_redraw_pending: bool,
+    /// Cover render protocol for the Metadata window. The outer `None` means
+    /// not loaded yet; `Some(None)` marks a book without a usable cover.
+    metadata_cover: Option<Option<StatefulProtocol>>,
     kosync_pull_rx:
         Option<std::sync::mpsc::Receiver<(String, eyre::Result<Option<RemoteProgress>>)>>,
     kosync_pull_is_manual: bool,
+    /// Page turn `(from_row, to_row)` waiting to be animated by the run loop.
+    page_animation: Option<(usize, usize)>,
+    /// Whether `run` drew on the alternate screen. Without it (`--no-altscreen`)
+    /// the last page stays in the terminal's scrollback after quitting.
+    alternate_screen: bool,
+    /// Book for `run` to open once the terminal is set up, so its loading
+    /// screen is shown rather than a blank terminal.
+    startup_book: Option<String>,
+    /// Set while `run` owns the terminal; only then does `load_ebook` draw
+    /// its loading screen.
+    terminal_active: bool,
+    /// `--rpc` command channel, drained by the event loop.
+    rpc: Option<RpcChannel>,
+    /// Title last given to the terminal by `set_terminal_title`; while set,
+    /// the terminal's own title waits on its title stack.
+    terminal_title: Option<String>,
 }
 
 /// Full-screen in-terminal image viewer state (`WindowType::ImageView`).
@@ -1010,11 +1880,18 @@ struct ImageViewState {
 impl Reader {
     /// Create a new Reader instance
     pub fn new(config: Config) -> eyre::Result<Self> {
-        let mut reader =
-            Self::with_backend(config, CrosstermBackend::new(io::stdout()), State::new()?)?;
+        let mut reader = Self::with_backend(
+            config,
+            CrosstermBackend::new(io::stdout()),
+            State::new()?,
+            Clipboard::new().ok(),
+        )?;
         // Only a real terminal can answer the graphics capability query;
         // `with_backend` (used by tests) leaves graphics disabled.
         reader.graphics = Graphics::new();
+        if reader.clipboard.is_none() {
+            logging::warn("System clipboard unavailable; copying needs clipboard_command");
+        }
         Ok(reader)
     }
 }
@@ -1105,37 +1982,26 @@ where
         Ok((program, processed_parts))
     }
 
+    /// Each client's output under a `== client ==` header, blank-line separated.
+    fn join_dictionary_outputs(outputs: &[(String, String)]) -> String {
+        outputs
+            .iter()
+            .map(|(client, text)| format!("== {client} ==\n\n{text}"))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
     fn run_dictionary_client(
-        client: &str,
+        template: &str,
         query: &str,
         timeout: Duration,
     ) -> eyre::Result<std::process::Output> {
-        let mut child = match client {
-            "sdcv" => std::process::Command::new("sdcv")
-                .arg("-n")
-                .arg(query)
-                .stdout(std::process::Stdio::piped())
-                .stderr(std::process::Stdio::piped())
-                .spawn()?,
-            "dict" => std::process::Command::new("dict")
-                .arg(query)
-                .stdout(std::process::Stdio::piped())
-                .stderr(std::process::Stdio::piped())
-                .spawn()?,
-            "wkdict" => std::process::Command::new("wkdict")
-                .arg(query)
-                .stdout(std::process::Stdio::piped())
-                .stderr(std::process::Stdio::piped())
-                .spawn()?,
-            template => {
-                let (program, args) = Self::build_dictionary_command(template, query)?;
-                std::process::Command::new(program)
-                    .args(args)
-                    .stdout(std::process::Stdio::piped())
-                    .stderr(std::process::Stdio::piped())
-                    .spawn()?
-            }
-        };
+        let (program, args) = Self::build_dictionary_command(template, query)?;
+        let mut child = std::process::Command::new(program)
+            .args(args)
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()?;
 
         let start = Instant::now();
         loop {
@@ -1156,12 +2022,13 @@ where
     }
 
     /// Detect the Wikipedia language code based on the script of the query text.
-    /// ASCII text is treated as English and uses Simple English Wikipedia.
+    /// ASCII text is treated as English and uses the `ascii` edition (Simple
+    /// English Wikipedia by default).
     /// Non-ASCII text is mapped to the appropriate language Wikipedia.
-    fn detect_wikipedia_language(query: &str) -> String {
+    fn detect_wikipedia_language(query: &str, ascii: AsciiWikipedia) -> String {
         let trimmed = query.trim();
         if trimmed.is_ascii() {
-            return "simple".to_string();
+            return ascii.code().to_string();
         }
         // Detect language from the dominant non-ASCII script
         for ch in trimmed.chars() {
@@ -1196,7 +2063,16 @@ where
                 _ => "en".to_string(),
             };
         }
-        "simple".to_string()
+        ascii.code().to_string()
+    }
+
+    /// The other English edition to retry on when `language` has no summary.
+    fn alternate_english_wikipedia(language: &str) -> Option<&'static str> {
+        match language {
+            "simple" => Some("en"),
+            "en" => Some("simple"),
+            _ => None,
+        }
     }
 
     fn build_wikipedia_page_url(language: &str, title: &str) -> eyre::Result<String> {
@@ -1222,11 +2098,67 @@ where
         }
     }
 
+    fn wikipedia_client(
+        language: &str,
+        timeout: Duration,
+    ) -> eyre::Result<reqwest::blocking::Client> {
+        let mut builder = reqwest::blocking::Client::builder()
+            .timeout(timeout)
+            .user_agent("repy");
+        if language.starts_with("http://127.0.0.1") || language.starts_with("http://localhost") {
+            builder = builder.no_proxy();
+        }
+        Ok(builder.build()?)
+    }
+
+    fn wikipedia_error_message(err: &eyre::Report, timeout: Duration) -> String {
+        let message = err.to_string();
+        if message.contains("timed out") {
+            format!("Wikipedia query timed out after {}s", timeout.as_secs())
+        } else {
+            format!("Wikipedia lookup failed.\n\n{}", message)
+        }
+    }
+
     fn fetch_wikipedia_summary(
         client: &reqwest::blocking::Client,
         language: &str,
         title: &str,
     ) -> eyre::Result<Option<WikipediaLookupResult>> {
+        let parsed = Self::query_wikipedia_page(client, language, title)?;
+        Self::parse_wikipedia_summary_response(&parsed, language, title)
+    }
+
+    /// Summary of `title`, or the articles its disambiguation page lists.
+    fn fetch_wikipedia_page(
+        client: &reqwest::blocking::Client,
+        language: &str,
+        title: &str,
+    ) -> eyre::Result<Option<WikipediaLookup>> {
+        let parsed = Self::query_wikipedia_page(client, language, title)?;
+        if let Some(result) = Self::parse_wikipedia_summary_response(&parsed, language, title)? {
+            return Ok(Some(WikipediaLookup::Summary(result)));
+        }
+        let Some(page_title) = Self::parse_wikipedia_disambiguation_title(&parsed) else {
+            return Ok(None);
+        };
+        let titles = Self::fetch_wikipedia_disambiguation_titles(client, language, &page_title)?;
+        if titles.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(WikipediaLookup::Disambiguation(
+            WikipediaDisambiguation {
+                language: language.to_string(),
+                titles,
+            },
+        )))
+    }
+
+    fn query_wikipedia_page(
+        client: &reqwest::blocking::Client,
+        language: &str,
+        title: &str,
+    ) -> eyre::Result<WikipediaSummaryResponse> {
         let summary_url = Self::wikipedia_api_url(language);
         let response = client
             .get(summary_url)
@@ -1242,10 +2174,64 @@ where
             ])
             .send()?
             .error_for_status()?;
+        Ok(response.json()?)
+    }
+
+    /// Article links (main namespace only) of the disambiguation page `title`.
+    fn fetch_wikipedia_disambiguation_titles(
+        client: &reqwest::blocking::Client,
+        language: &str,
+        title: &str,
+    ) -> eyre::Result<Vec<String>> {
+        let links_url = Self::wikipedia_api_url(language);
+        let response = client
+            .get(links_url)
+            .query(&[
+                ("action", "query"),
+                ("format", "json"),
+                ("prop", "links"),
+                ("plnamespace", "0"),
+                ("pllimit", "max"),
+                ("titles", title),
+            ])
+            .send()?
+            .error_for_status()?;
         let parsed: WikipediaSummaryResponse = response.json()?;
-        Ok(Self::parse_wikipedia_summary_response(
-            &parsed, language, title,
-        )?)
+        Ok(Self::extract_wikipedia_link_titles(&parsed))
+    }
+
+    fn extract_wikipedia_link_titles(parsed: &WikipediaSummaryResponse) -> Vec<String> {
+        let Some(pages_obj) = parsed
+            .query
+            .as_ref()
+            .and_then(|query| query.pages.as_object())
+        else {
+            return Vec::new();
+        };
+        pages_obj
+            .values()
+            .filter_map(|page| page.get("links").and_then(Value::as_array))
+            .flatten()
+            .filter_map(|link| link.get("title").and_then(Value::as_str))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Title of the disambiguation page in `parsed`, if that is what was hit.
+    fn parse_wikipedia_disambiguation_title(parsed: &WikipediaSummaryResponse) -> Option<String> {
+        let pages_obj = parsed.query.as_ref()?.pages.as_object()?;
+        pages_obj.values().find_map(|page| {
+            let is_disambiguation = page
+                .get("pageprops")
+                .and_then(Value::as_object)
+                .is_some_and(|pp| pp.contains_key("disambiguation"));
+            if !is_disambiguation {
+                return None;
+            }
+            page.get("title")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        })
     }
 
     fn parse_wikipedia_summary_response(
@@ -1334,27 +2320,30 @@ where
             .unwrap_or_default()
     }
 
+    /// Summary for `query`: a direct hit on `language`, then on
+    /// `fallback_language`, then the best of `language`'s search results.
+    /// A direct hit on a disambiguation page yields its article list instead.
     fn wikipedia_lookup_summary(
         query: &str,
         language: &str,
+        fallback_language: Option<&str>,
         timeout: Duration,
-    ) -> eyre::Result<WikipediaLookupResult> {
-        let mut builder = reqwest::blocking::Client::builder()
-            .timeout(timeout)
-            .user_agent("repy");
-        if language.starts_with("http://127.0.0.1") || language.starts_with("http://localhost") {
-            builder = builder.no_proxy();
-        }
-        let client = builder.build()?;
+    ) -> eyre::Result<WikipediaLookup> {
+        let client = Self::wikipedia_client(language, timeout)?;
 
-        if let Some(result) = Self::fetch_wikipedia_summary(&client, language, query)? {
-            return Ok(result);
+        if let Some(found) = Self::fetch_wikipedia_page(&client, language, query)? {
+            return Ok(found);
+        }
+        if let Some(fallback) = fallback_language
+            && let Some(found) = Self::fetch_wikipedia_page(&client, fallback, query)?
+        {
+            return Ok(found);
         }
 
         let candidates = Self::search_wikipedia_titles(&client, language, query, 3)?;
         for candidate in candidates {
             if let Some(result) = Self::fetch_wikipedia_summary(&client, language, &candidate)? {
-                return Ok(result);
+                return Ok(WikipediaLookup::Summary(result));
             }
         }
 
@@ -1378,26 +2367,41 @@ where
         }
     }
 
-    /// Build a Reader on top of an arbitrary backend, e.g. `TestBackend` in tests.
-    fn with_backend(config: Config, backend: B, db_state: State) -> eyre::Result<Self> {
+    /// Build a Reader on top of an arbitrary backend, e.g. ratatui's
+    /// `TestBackend`, so key handling can be driven without a terminal.
+    /// Pass `None` for `clipboard` where there is no system clipboard;
+    /// copying then falls back to `clipboard_command`.
+    pub fn with_backend(
+        config: Config,
+        backend: B,
+        db_state: State,
+        clipboard: Option<Clipboard>,
+    ) -> eyre::Result<Self> {
         let terminal = Terminal::new(backend)?;
+        let key_bindings =
+            KeyBindings::with_preset(config.keymap_user_dict(), config.keymap_preset);
 
         let app_state = ApplicationState::new(config);
 
         Ok(Self {
             state: Rc::new(RefCell::new(app_state)),
             terminal,
+            key_bindings,
             db_state,
             board: Board::new(),
-            clipboard: Clipboard::new().ok(),
+            clipboard,
             ebook: None,
             content_start_rows: Vec::new(),
             chapter_text_structures: Vec::new(),
+            book_images: Vec::new(),
             current_text_width: None,
             current_inline_image_rows: None,
             current_typography: TypographyOptions::default(),
+            current_chapter_break: ChapterBreakOptions::default(),
+            chapter_wrap_cache: Vec::new(),
             dictionary_res_rx: None,
             library_scan_rx: None,
+            library_scan_is_manual: false,
             opds_rx: None,
             opds_request_id: 0,
             opds_catalog_index: None,
@@ -1412,11 +2416,13 @@ where
             tts_audio_player: None,
             tts_current_audio_path: None,
             tts_ready_audio: HashMap::new(),
+            narration_audio: HashMap::new(),
             tts_worker_tx: None,
             tts_worker_rx: None,
             tts_current_engine: String::new(),
             tts_temp_dir: None,
             reading_session: None,
+            last_saved: None,
             cached_statistics: None,
             graphics: Graphics::disabled(),
             image_view: None,
@@ -1425,13 +2431,26 @@ where
             library_covers: HashMap::new(),
             library_cover_pending: None,
             library_cover_redraw_pending: false,
+            metadata_cover: None,
             kosync_pull_rx: None,
             kosync_pull_is_manual: false,
+            page_animation: None,
+            alternate_screen: true,
+            startup_book: None,
+            terminal_active: false,
+            rpc: None,
+            terminal_title: None,
         })
     }
 
-    /// Extract the current UI state into a single frame draw.
-    fn draw(&mut self) -> eyre::Result<()> {
+    /// Render one frame of the current state.
+    pub fn draw(&mut self) -> eyre::Result<()> {
+        if self.terminal_too_small() {
+            let state = self.state.borrow();
+            self.terminal
+                .draw(|frame| Self::render_too_small(frame, &state))?;
+            return Ok(());
+        }
         let state = self.state.clone();
         // Precompute inline-image placements while `self` is still free
         // (the closure below holds disjoint field borrows).
@@ -1453,7 +2472,17 @@ where
                 .visible_window(&state_ref, Some(&self.content_start_rows), self.page_size())
                 .0
         };
-        let library_cover = if self.state.borrow().ui_state.show_library
+        let chapter_title = if self.state.borrow().config.settings.show_chapter_title {
+            self.toc_label_for_row(visible_start)
+        } else {
+            None
+        };
+        self.state.borrow_mut().ui_state.chapter_title = chapter_title;
+        let cover = if self.state.borrow().ui_state.show_metadata && !message_visible {
+            self.metadata_cover
+                .as_mut()
+                .and_then(|cover| cover.as_mut())
+        } else if self.state.borrow().ui_state.show_library
             && self.state.borrow().ui_state.library_cover_visible
             && !message_visible
         {
@@ -1472,7 +2501,8 @@ where
                 &state_ref,
                 &self.board,
                 &self.content_start_rows,
-                library_cover,
+                &self.key_bindings,
+                cover,
             );
             if !inline_blocks.is_empty() {
                 Self::render_inline_images(
@@ -1493,15 +2523,45 @@ where
         Ok(())
     }
 
-    /// Render the full-screen in-terminal image viewer over the whole frame.
-    fn render_image_view(frame: &mut Frame, state: &ApplicationState, view: &mut ImageViewState) {
+    fn terminal_too_small(&self) -> bool {
+        self.terminal.size().is_ok_and(|size| {
+            terminal_too_small(
+                size.width,
+                size.height,
+                &self.state.borrow().config.settings,
+            )
+        })
+    }
+
+    /// Stand-in for every view while the terminal cannot fit a page; the
+    /// next resize to a usable size draws the reader again.
+    fn render_too_small(frame: &mut Frame, state: &ApplicationState) {
         let theme = state.theme();
         let area = frame.area();
         frame.render_widget(Clear, area);
-        let block = Block::default()
-            .title(format!(" {} ", view.title))
-            .title_bottom(" Esc/q/Enter close · o external viewer ")
-            .borders(Borders::ALL)
+        frame.render_widget(Block::default().style(theme.base_style()), area);
+        let line = Rect {
+            y: area.y + area.height / 2,
+            height: area.height.min(1),
+            ..area
+        };
+        frame.render_widget(
+            Paragraph::new("Terminal too small")
+                .alignment(ratatui::layout::Alignment::Center)
+                .style(theme.base_style().fg(theme.warning_fg)),
+            line,
+        );
+    }
+
+    /// Render the full-screen in-terminal image viewer over the whole frame.
+    fn render_image_view(frame: &mut Frame, state: &ApplicationState, view: &mut ImageViewState) {
+        let theme = state.theme();
+        let area = frame.area();
+        frame.render_widget(Clear, area);
+        let block = Block::default()
+            .title(format!(" {} ", view.title))
+            .title_bottom(" Esc/q/Enter close · o external viewer ")
+            .borders(Borders::ALL)
             .style(theme.base_style());
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -1522,16 +2582,130 @@ where
         );
     }
 
-    /// Load the most recently read ebook, if any, using the database
-    pub fn load_last_ebook_if_any(&mut self) -> eyre::Result<()> {
+    /// The application state: reading position, open windows, messages.
+    pub fn app_state(&self) -> std::cell::Ref<'_, ApplicationState> {
+        self.state.borrow()
+    }
+
+    /// The terminal backend, e.g. a `TestBackend` to inspect after `draw`.
+    pub fn backend(&self) -> &B {
+        self.terminal.backend()
+    }
+
+    /// Take `--rpc` commands from `channel` while `run` is looping.
+    pub fn set_rpc_channel(&mut self, channel: Option<RpcChannel>) {
+        self.rpc = channel;
+    }
+
+    /// Carry out one `--rpc` command line through the same methods the
+    /// navigation keys use, and return the JSON reply: the reading state
+    /// afterwards, or an error.
+    pub fn handle_rpc_line(&mut self, line: &str) -> Value {
+        let command = match rpc::parse_command(line) {
+            Ok(command) => command,
+            Err(err) => return rpc::error_reply(&err),
+        };
+        if self.ebook.is_none() && !matches!(command, RpcCommand::State | RpcCommand::Quit) {
+            return rpc::error_reply("no book is open");
+        }
+        match command {
+            RpcCommand::Goto {
+                row: Some(row),
+                chapter: None,
+                percent: None,
+            } => {
+                let last = self.board.total_lines().saturating_sub(1);
+                self.record_jump_position();
+                self.state.borrow_mut().reading_state.row = row.min(last);
+                self.sync_reading_content_index();
+            }
+            RpcCommand::Goto {
+                row: None,
+                chapter: Some(chapter),
+                percent: None,
+            } => self.goto_chapter_number(chapter),
+            RpcCommand::Goto {
+                row: None,
+                chapter: None,
+                percent: Some(percent),
+            } => self.goto_fraction((percent / 100.0).clamp(0.0, 1.0)),
+            RpcCommand::Goto { .. } => {
+                return rpc::error_reply("goto takes exactly one of row, chapter, or percent");
+            }
+            RpcCommand::NextPage => self.turn_page(AppDirection::PageDown, 1),
+            RpcCommand::PrevPage => self.turn_page(AppDirection::PageUp, 1),
+            RpcCommand::NextChapter => self.next_chapter(),
+            RpcCommand::PrevChapter => self.previous_chapter(),
+            RpcCommand::Start => self.goto_start(),
+            RpcCommand::End => self.goto_end(),
+            RpcCommand::State => {}
+            RpcCommand::Quit => self.state.borrow_mut().should_quit = true,
+        }
+        self.rpc_state()
+    }
+
+    /// The `--rpc` reply describing where the reader is.
+    fn rpc_state(&self) -> Value {
+        let Some(ebook) = self.ebook.as_ref() else {
+            return serde_json::json!({ "ok": true, "file": null });
+        };
+        let state = self.state.borrow();
+        let row = state.reading_state.row;
+        serde_json::json!({
+            "ok": true,
+            "file": ebook.path(),
+            "title": ebook.get_meta().title,
+            "row": row,
+            "total_rows": self.board.total_lines(),
+            "content_index": state.reading_state.content_index,
+            "chapter": self.toc_label_for_row(row),
+            "percent": (self.board.content_fraction(row) * 100.0).round() as u32,
+            "quitting": state.should_quit,
+        })
+    }
+
+    /// Open `path` once `run` has set up the terminal.
+    pub fn open_on_start(&mut self, path: &str) {
+        self.startup_book = Some(path.to_string());
+    }
+
+    /// Open the last-read book, if it still exists, once `run` starts.
+    pub fn open_last_ebook_on_start(&mut self) -> eyre::Result<()> {
         if let Some(filepath) = self.db_state.get_last_read()?
             && std::path::Path::new(&filepath).exists()
         {
-            self.load_ebook(&filepath)?;
+            self.startup_book = Some(filepath);
         }
         Ok(())
     }
 
+    /// Draw a "Loading <title>…" screen before the blocking parse in
+    /// `load_ebook`, so a big book does not look like a hang.
+    fn draw_loading(&mut self, title: &str) -> eyre::Result<()> {
+        if !self.terminal_active {
+            return Ok(());
+        }
+        let theme = self.state.borrow().theme();
+        let text = format!("Loading {title}…");
+        self.terminal.draw(|frame| {
+            let area = frame.area();
+            frame.render_widget(Clear, area);
+            frame.render_widget(Block::default().style(theme.base_style()), area);
+            let line = Rect {
+                y: area.y + area.height / 2,
+                height: area.height.min(1),
+                ..area
+            };
+            frame.render_widget(
+                Paragraph::new(text)
+                    .alignment(ratatui::layout::Alignment::Center)
+                    .style(theme.base_style().fg(theme.info_fg)),
+                line,
+            );
+        })?;
+        Ok(())
+    }
+
     pub fn load_ebook(&mut self, path: &str) -> eyre::Result<()> {
         // Save the outgoing book's position first; otherwise switching books
         // through the library loses everything read since the last quit.
@@ -1544,6 +2718,16 @@ where
         }
 
         let mut epub = crate::formats::open(&normalized_path)?;
+        self.run_book_hook(BookHook::Close);
+        let title = epub.get_meta().title.clone().unwrap_or_else(|| {
+            std::path::Path::new(&normalized_path)
+                .file_name()
+                .map_or_else(
+                    || normalized_path.clone(),
+                    |name| name.to_string_lossy().into_owned(),
+                )
+        });
+        self.draw_loading(&title)?;
         let identity = annotations::derive_book_identity(epub.as_mut())?;
         let alias_conflict = self
             .db_state
@@ -1589,8 +2773,24 @@ where
                 .width
                 .unwrap_or(DEFAULT_TEXT_WIDTH)
         };
+        // A newer position written by another machine wins; the text width
+        // stays the local one.
+        let db_state = match self.newer_sidecar_position(
+            &normalized_path,
+            &identity.book_id,
+            db_state.as_ref(),
+        ) {
+            Some(position) => {
+                self.state.borrow_mut().ui_state.set_message(
+                    "Resumed from the position in the sidecar file".to_string(),
+                    MessageType::Info,
+                );
+                Some(position.apply_to(&db_state.unwrap_or_default()))
+            }
+            None => db_state,
+        };
 
-        let term_width = self.term_width();
+        let term_width = self.reading_area_width();
         // Highlights are loaded into ui_state only after parsing, so ask the
         // DB now whether this book shows the highlight gutter — otherwise the
         // first render would need a full re-wrap.
@@ -1608,19 +2808,23 @@ where
         let page_height = self.chapter_break_page_height();
         let inline_image_rows = self.inline_image_max_rows();
         let typography = self.typography_options();
+        let chapter_break = self.chapter_break_options();
         let all_content = renderer::parse_book_with_typography(
             epub.as_mut(),
             text_width,
             page_height,
             inline_image_rows,
             typography,
+            chapter_break,
         )?;
 
         // Store per-chapter structures for incremental rebuilds
         self.chapter_text_structures = all_content;
+        self.chapter_wrap_cache.clear();
         self.current_text_width = Some(text_width);
         self.current_inline_image_rows = inline_image_rows;
         self.current_typography = typography;
+        self.current_chapter_break = chapter_break;
 
         let mut combined_text_structure = TextStructure::default();
         let mut content_start_rows = Vec::with_capacity(self.chapter_text_structures.len());
@@ -1634,6 +2838,9 @@ where
             combined_text_structure
                 .image_maps
                 .extend(ts.image_maps.clone());
+            combined_text_structure
+                .image_alts
+                .extend(ts.image_alts.clone());
             combined_text_structure
                 .section_rows
                 .extend(ts.section_rows.clone());
@@ -1654,6 +2861,7 @@ where
                 .typography_spacing_rows
                 .extend(ts.typography_spacing_rows.iter().copied());
         }
+        self.book_images = sorted_images(&combined_text_structure.image_maps);
 
         self.board.update_text_structure(combined_text_structure);
         self.ebook = Some(epub);
@@ -1662,12 +2870,19 @@ where
         // Add the book to library immediately upon opening
         if let Some(epub) = self.ebook.as_ref() {
             // First, persist the reading state and update library
+            let first_open = db_state.is_none();
             let mut reading_state = if let Some(s) = db_state {
                 s.clone()
             } else {
                 ReadingState::default()
             };
             reading_state.row = self.restore_row(&reading_state, textwidth);
+            if first_open
+                && self.state.borrow().config.settings.skip_front_matter
+                && let Some(start) = self.body_matter_start()
+            {
+                reading_state.row = Self::row_from_start(start);
+            }
             reading_state.textwidth = textwidth;
             if let Some((content_index, source_offset)) =
                 self.source_position_for_row(reading_state.row)
@@ -1687,10 +2902,16 @@ where
                 .get_marks(epub.as_ref())?
                 .into_iter()
                 .collect();
+            // Read before update_library stamps the entry with the current time.
+            let resume_prompt = self.resume_prompt_for(&normalized_path, &reading_state);
             // Preserve any existing reading progress rather than resetting it to
             // 0% on open; only a brand-new book starts at 0.0.
             self.db_state
                 .update_library(epub.as_ref(), reading_state.rel_pctg.or(Some(0.0)))?;
+            if let Ok(content_hash) = sync::document_id(&normalized_path) {
+                self.db_state
+                    .set_library_content_hash(&normalized_path, &content_hash)?;
+            }
 
             // Now update the UI state
             let session_book_id = identity.book_id.clone();
@@ -1701,6 +2922,11 @@ where
             state.jump_history_index = jump_history_index.min(state.jump_history.len());
             state.marks = marks;
             state.ui_state.metadata = Some(epub.get_meta().clone());
+            state.ui_state.is_rtl = epub.get_meta().is_rtl();
+            state.ui_state.horizontal_scroll = 0;
+            state.ui_state.link_tour = None;
+            state.ui_state.slideshow_next = None;
+            state.ui_state.restart_session_limit();
             state.ui_state.metadata_filepath = Some(normalized_path.clone());
             state.ui_state.book_identity = Some(identity);
             state.ui_state.toc_entries = epub.toc_entries().clone();
@@ -1711,9 +2937,15 @@ where
             }
             let session_row = state.reading_state.row;
             drop(state);
+            self.last_saved = Some((Instant::now(), session_row));
             self.start_reading_session(session_book_id, session_row);
             self.refresh_statistics_snapshot()?;
             self.refresh_highlights()?;
+            if let Some(pending) = resume_prompt {
+                let mut state = self.state.borrow_mut();
+                state.ui_state.pending_resume = Some(pending);
+                state.ui_state.open_window(WindowType::ConfirmResume);
+            }
             if alias_conflict {
                 self.state.borrow_mut().ui_state.set_message(
                     "This path previously pointed to a different EPUB identity; highlights were kept separate."
@@ -1724,9 +2956,159 @@ where
         }
 
         self.start_kosync_pull(false);
+        self.run_book_hook(BookHook::Open);
         Ok(())
     }
 
+    /// The open book's title, or its file name without extension when the
+    /// metadata has none.
+    fn book_title(&self) -> Option<String> {
+        let ebook = self.ebook.as_ref()?;
+        Some(ebook.get_meta().title.clone().unwrap_or_else(|| {
+            std::path::Path::new(ebook.path())
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        }))
+    }
+
+    /// Start the `on_open` / `on_close` command for the current book in the
+    /// background. Failures are logged, never shown or fatal.
+    fn run_book_hook(&self, hook: BookHook) {
+        let template = {
+            let settings = &self.state.borrow().config.settings;
+            match hook {
+                BookHook::Open => settings.on_open.clone(),
+                BookHook::Close => settings.on_close.clone(),
+            }
+        };
+        let (Some(template), Some(ebook)) = (
+            template.filter(|template| !template.trim().is_empty()),
+            self.ebook.as_ref(),
+        ) else {
+            return;
+        };
+        let file = ebook.path().to_string();
+        let title = self.book_title().unwrap_or_default();
+        let row = self.state.borrow().reading_state.row;
+        let percent = (self.board.content_fraction(row) * 100.0).round() as u32;
+        let name = hook.setting_name();
+        let parts = match Self::split_dictionary_command_template(&template) {
+            Ok(parts) => parts,
+            Err(err) => {
+                logging::warn(format!("{name} hook: {err}"));
+                return;
+            }
+        };
+        let mut args = parts
+            .into_iter()
+            .map(|(part, _)| render_hook_arg(&part, &file, percent, title.trim()));
+        let Some(program) = args.next() else {
+            return;
+        };
+        let mut cmd = std::process::Command::new(&program);
+        cmd.args(args)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null());
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            unsafe {
+                cmd.pre_exec(|| {
+                    libc::setsid();
+                    Ok(())
+                });
+            }
+        }
+        match cmd.spawn() {
+            Ok(mut child) => {
+                std::thread::spawn(move || match child.wait() {
+                    Ok(status) if !status.success() => {
+                        logging::warn(format!("{name} hook `{program}` exited with {status}"));
+                    }
+                    Ok(_) => {}
+                    Err(err) => logging::warn(format!("{name} hook `{program}` failed: {err}")),
+                });
+            }
+            Err(err) => logging::warn(format!("{name} hook `{program}` failed to start: {err}")),
+        }
+    }
+
+    /// Progress and last-read time to offer in the resume prompt, when the
+    /// setting is on and the book was left mid-way more than
+    /// [`RESUME_PROMPT_AFTER`] ago.
+    fn resume_prompt_for(
+        &self,
+        path: &str,
+        reading_state: &ReadingState,
+    ) -> Option<(f32, DateTime<Utc>)> {
+        if !self.state.borrow().config.settings.resume_prompt || reading_state.row == 0 {
+            return None;
+        }
+        let last_read = self.db_state.library_last_read(path).ok().flatten()?;
+        let idle = (Utc::now() - last_read).to_std().ok()?;
+        if idle < RESUME_PROMPT_AFTER {
+            return None;
+        }
+        let progress = reading_state
+            .rel_pctg
+            .unwrap_or_else(|| self.board.content_fraction(reading_state.row) as f32);
+        Some((progress, last_read))
+    }
+
+    /// The sidecar position for this book when `sync_sidecar` is on and it
+    /// was written after the book was last read here to a different spot
+    /// than `saved`.
+    fn newer_sidecar_position(
+        &mut self,
+        path: &str,
+        book_id: &str,
+        saved: Option<&ReadingState>,
+    ) -> Option<SidecarPosition> {
+        if !self.state.borrow().config.settings.sync_sidecar {
+            return None;
+        }
+        let position = match sidecar::read(path, book_id) {
+            Ok(position) => position?,
+            Err(err) => {
+                self.state.borrow_mut().ui_state.set_message(
+                    format!("Sidecar position ignored: {err}"),
+                    MessageType::Warning,
+                );
+                return None;
+            }
+        };
+        if saved.is_some_and(|saved| position.is_at(saved)) {
+            return None;
+        }
+        let last_read = self.db_state.library_last_read(path).ok().flatten();
+        last_read
+            .is_none_or(|last_read| {
+                position.updated_at.timestamp() > last_read.timestamp() + SIDECAR_CLOCK_SLACK_SECS
+            })
+            .then_some(position)
+    }
+
+    fn write_sidecar(&self, path: &str, reading_state: &ReadingState) {
+        let state = self.state.borrow();
+        if !state.config.settings.sync_sidecar {
+            return;
+        }
+        let Some(identity) = state.ui_state.book_identity.as_ref() else {
+            return;
+        };
+        let position = SidecarPosition::new(&identity.book_id, reading_state, Utc::now());
+        let result = sidecar::write(path, &position);
+        drop(state);
+        if let Err(err) = result {
+            self.state.borrow_mut().ui_state.set_message(
+                format!("Sidecar position not saved: {err}"),
+                MessageType::Warning,
+            );
+        }
+    }
+
     fn kosync_config(&self) -> Option<KosyncConfig> {
         let state = self.state.borrow();
         let settings = &state.config.settings;
@@ -1760,6 +3142,25 @@ where
         self.kosync_pull_is_manual = manual;
     }
 
+    /// Answer every `--rpc` command waiting on the channel. Commands count
+    /// as reading activity like keys do; a reply that cannot be written
+    /// (the other end went away) turns the channel off.
+    fn poll_rpc(&mut self) -> eyre::Result<()> {
+        while let Some(line) = self.rpc.as_ref().and_then(RpcChannel::try_recv) {
+            self.close_idle_reading_session()?;
+            let previous_row = self.state.borrow().reading_state.row;
+            let reply = self.handle_rpc_line(&line);
+            self.record_reading_activity(previous_row)?;
+            if let Some(channel) = self.rpc.as_mut()
+                && let Err(err) = channel.reply(&reply)
+            {
+                logging::warn(format!("--rpc: could not write reply: {err}"));
+                self.rpc = None;
+            }
+        }
+        Ok(())
+    }
+
     fn poll_kosync(&mut self) {
         let result = self
             .kosync_pull_rx
@@ -1868,6 +3269,11 @@ where
     }
 
     fn start_reading_session(&mut self, book_id: String, row: usize) {
+        self.state
+            .borrow_mut()
+            .ui_state
+            .session_started
+            .get_or_insert_with(Instant::now);
         let now = Utc::now();
         self.reading_session = Some(ActiveReadingSession {
             book_id,
@@ -1877,6 +3283,7 @@ where
             max_counted_row: row,
             rows: 0,
             words: 0,
+            pace_samples: VecDeque::from([(Instant::now(), 0)]),
         });
     }
 
@@ -1907,6 +3314,7 @@ where
         // End at the last recorded activity; the idle gap itself was not
         // reading time.
         let ended_at = session.last_activity_at;
+        self.state.borrow_mut().ui_state.restart_session_limit();
         self.finish_reading_session(ended_at)
     }
 
@@ -1956,6 +3364,13 @@ where
                 session.rows += rows;
                 session.words += words;
                 session.max_counted_row = current_row;
+                let now = Instant::now();
+                session.pace_samples.push_back((now, session.words));
+                while session.pace_samples.len() > 1
+                    && session.pace_samples[1].0 + SESSION_PACE_WINDOW < now
+                {
+                    session.pace_samples.pop_front();
+                }
             }
             session.last_activity = Instant::now();
             session.last_activity_at = Utc::now();
@@ -2031,6 +3446,14 @@ where
             self.board.total_lines(),
             wpm,
         );
+        stats.session_words_per_minute = self
+            .reading_session
+            .as_ref()
+            .filter(|session| book_id.as_deref() == Some(session.book_id.as_str()))
+            .and_then(|session| rolling_words_per_minute(&session.pace_samples, Instant::now()));
+        stats.session_book_minutes_left = stats.session_words_per_minute.and_then(|wpm| {
+            self.estimated_minutes_left_for_range(self.current_row(), self.board.total_lines(), wpm)
+        });
 
         self.state.borrow_mut().ui_state.statistics = stats;
         Ok(())
@@ -2098,27 +3521,108 @@ where
             self.db_state
                 .set_last_reading_state(epub.as_ref(), &to_save)?;
             self.db_state.update_library(epub.as_ref(), rel_pctg)?;
+            self.write_sidecar(epub.path(), &to_save);
+            let last_page_shown = reading_state.row + self.page_size() >= total_lines;
+            if total_lines > 0
+                && last_page_shown
+                && self.state.borrow().config.settings.auto_mark_finished
+            {
+                self.db_state.set_library_finished(epub.path(), true)?;
+            }
             let (jump_history, jump_history_index) = {
                 let state = self.state.borrow();
                 (state.jump_history.clone(), state.jump_history_index)
             };
             self.db_state
                 .set_jump_history(epub.as_ref(), &jump_history, jump_history_index)?;
+            self.last_saved = Some((Instant::now(), reading_state.row));
+        }
+        Ok(())
+    }
+
+    /// Time left before a changed position is due for autosave, or `None`
+    /// when there is nothing to save or autosave is off.
+    fn autosave_due_in(&self) -> Option<Duration> {
+        let state = self.state.borrow();
+        let secs = state.config.settings.autosave_interval_secs;
+        if secs == 0 || self.ebook.is_none() {
+            return None;
+        }
+        match self.last_saved {
+            Some((_, row)) if row == state.reading_state.row => None,
+            Some((at, _)) => Some(Duration::from_secs(secs).saturating_sub(at.elapsed())),
+            None => Some(Duration::ZERO),
+        }
+    }
+
+    /// Save a changed position once `autosave_interval_secs` have passed
+    /// since the last save.
+    fn tick_autosave(&mut self) -> eyre::Result<()> {
+        if self.autosave_due_in() == Some(Duration::ZERO) {
+            self.persist_state()?;
+        }
+        Ok(())
+    }
+
+    /// Time and forward page turns left in this reading session; each is
+    /// `None` when its limit is off or the session is already over.
+    fn session_limit_left(state: &ApplicationState) -> (Option<Duration>, Option<usize>) {
+        if state.ui_state.session_over {
+            return (None, None);
+        }
+        let settings = &state.config.settings;
+        let time = (settings.session_limit_minutes > 0).then(|| {
+            Duration::from_secs(settings.session_limit_minutes * 60).saturating_sub(
+                state
+                    .ui_state
+                    .session_started
+                    .map_or(Duration::ZERO, |started| started.elapsed()),
+            )
+        });
+        let pages = (settings.session_limit_pages > 0).then(|| {
+            settings
+                .session_limit_pages
+                .saturating_sub(state.ui_state.session_page_turns)
+        });
+        (time, pages)
+    }
+
+    /// Save the position once the session limit is reached, then quit or
+    /// leave a sticky "session over" notice, per `session_limit_quit`.
+    fn tick_session_limit(&mut self) -> eyre::Result<()> {
+        let (time, pages) = Self::session_limit_left(&self.state.borrow());
+        if self.ebook.is_none() || (time != Some(Duration::ZERO) && pages != Some(0)) {
+            return Ok(());
+        }
+        self.persist_state()?;
+        let mut state = self.state.borrow_mut();
+        state.ui_state.session_over = true;
+        if state.config.settings.session_limit_quit {
+            state.should_quit = true;
+        } else {
+            state.ui_state.set_message(
+                "Session over — position saved. Time for a rest".to_string(),
+                MessageType::Warning,
+            );
         }
         Ok(())
     }
 }
 
 impl Reader {
-    /// Run the main application loop
-    pub fn run(&mut self) -> eyre::Result<()> {
+    /// Run the event loop until quit. With `alternate_screen` off, the
+    /// reader draws on the main screen (like `less -X`) and leaves its last
+    /// frame in place on exit.
+    pub fn run(&mut self, alternate_screen: bool) -> eyre::Result<()> {
+        self.alternate_screen = alternate_screen;
         // Initialize terminal
+        let mut guard = TerminalGuard::new(alternate_screen);
+        terminal::install_termination_handler();
         crossterm::terminal::enable_raw_mode()?;
-        crossterm::execute!(
-            io::stdout(),
-            crossterm::terminal::EnterAlternateScreen,
-            crossterm::event::EnableBracketedPaste
-        )?;
+        if alternate_screen {
+            crossterm::execute!(io::stdout(), crossterm::terminal::EnterAlternateScreen)?;
+        }
+        crossterm::execute!(io::stdout(), crossterm::event::EnableBracketedPaste)?;
         // Capture the mouse only when the setting is on, so that native
         // terminal selection/copy keeps working otherwise.
         if self.state.borrow().config.settings.mouse_support {
@@ -2127,11 +3631,80 @@ impl Reader {
 
         self.terminal.clear()?;
         self.terminal.hide_cursor()?;
+        self.terminal_active = true;
+
+        if let Some(path) = self.startup_book.take()
+            && let Err(e) = self.load_ebook(&path)
+        {
+            self.state
+                .borrow_mut()
+                .ui_state
+                .set_message(format!("Could not load ebook: {e}"), MessageType::Error);
+        }
+
+        self.run_event_loop()?;
+
+        // Cleanup terminal
+        if self.terminal_title.take().is_some() {
+            io::Write::write_all(&mut io::stdout(), TITLE_POP.as_bytes())?;
+        }
+        if self.alternate_screen {
+            self.terminal.clear()?;
+            crossterm::execute!(io::stdout(), crossterm::terminal::LeaveAlternateScreen)?;
+        } else {
+            // Keep the last frame and continue below it
+            let bottom = self.terminal.size()?.height.saturating_sub(1);
+            self.terminal.set_cursor_position((0, bottom))?;
+            println!("\r");
+        }
+        self.terminal.show_cursor()?;
+        crossterm::execute!(
+            io::stdout(),
+            crossterm::event::DisableMouseCapture,
+            crossterm::event::DisableBracketedPaste
+        )?;
+        crossterm::terminal::disable_raw_mode()?;
+        guard.disarm();
+
+        Ok(())
+    }
+}
+
+impl<B: Backend> Reader<B>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    /// Run the event loop on a terminal that is already set up until quit
+    /// or a termination signal, then save the position and close the book.
+    pub fn run_event_loop(&mut self) -> eyre::Result<()> {
+        if let Err(err) = self.event_loop() {
+            // After SIGHUP the terminal is gone and every draw, title write,
+            // or poll fails; that must not cost the reading position.
+            if !terminal::termination_requested() {
+                return Err(err);
+            }
+            logging::debug(format!("Terminal failed while quitting: {err}"));
+        }
+        self.terminal_active = false;
+
+        // Stop TTS if it's still running
+        self.stop_tts();
+
+        // Persist the reading state first, so nothing after it can lose it
+        self.persist_state()?;
+        if let Err(err) = self.finish_reading_session(Utc::now()) {
+            logging::warn(format!("Could not record the reading session: {err}"));
+        }
+        self.run_book_hook(BookHook::Close);
+        Ok(())
+    }
 
+    fn event_loop(&mut self) -> eyre::Result<()> {
         // Main event loop
         loop {
             let state = self.state.borrow();
-            if state.should_quit {
+            // A termination signal quits like `q`, so the position is saved.
+            if state.should_quit || terminal::termination_requested() {
                 break;
             }
             drop(state);
@@ -2164,6 +3737,8 @@ impl Reader {
                         Ok(def) => def,
                         Err(err) => err,
                     };
+                    state.ui_state.dictionary_disambiguation = res.disambiguation;
+                    state.ui_state.dictionary_option_index = 0;
                     state.ui_state.dictionary_loading = false;
                     self.dictionary_res_rx = None;
                 }
@@ -2172,12 +3747,15 @@ impl Reader {
             // Check for library scan completion (the worker already updated
             // the SQLite cache; refresh the window from it).
             if let Some(rx) = &self.library_scan_rx {
-                if rx.try_recv().is_ok() {
+                if let Ok(skipped) = rx.try_recv() {
                     self.library_scan_rx = None;
                     self.state.borrow_mut().ui_state.library_scanning = false;
                     if self.state.borrow().ui_state.show_library {
                         self.rebuild_library_entries()?;
                     }
+                    if std::mem::take(&mut self.library_scan_is_manual) {
+                        self.report_skipped_library_paths(&skipped);
+                    }
                 }
             }
 
@@ -2260,10 +3838,12 @@ impl Reader {
                 }
             }
 
+            self.poll_rpc()?;
             self.tts_poll_worker()?;
             self.poll_calibre_import()?;
             self.poll_kosync();
             self.poll_library_cover();
+            self.poll_metadata_cover();
             self.poll_inline_images();
 
             // Check for TTS paragraph completion → advance to next paragraph
@@ -2279,11 +3859,20 @@ impl Reader {
                 }
             }
 
+            self.tick_slideshow()?;
+            self.tick_autosave()?;
+            self.tick_session_limit()?;
+            self.animate_page_turn()?;
+
             // Render UI
+            self.update_terminal_title()?;
             self.draw()?;
 
             // Poll with timeout so we can re-render when messages expire or for animation
-            let poll_timeout = if self.library_cover_pending.is_some()
+            let poll_timeout = if self.rpc.is_some() {
+                // Commands arrive on a channel, not as terminal events.
+                Duration::from_millis(50)
+            } else if self.library_cover_pending.is_some()
                 || self.library_cover_redraw_pending
                 || self.inline_images_pending
             {
@@ -2325,7 +3914,23 @@ impl Reader {
                 }
             };
 
-            if !crossterm::event::poll(poll_timeout)? {
+            // A running slideshow wakes up for its next page.
+            let poll_timeout = match self.state.borrow().ui_state.slideshow_next {
+                Some(next) => poll_timeout.min(next.saturating_duration_since(Instant::now())),
+                None => poll_timeout,
+            };
+            // An unsaved position wakes up for its autosave.
+            let poll_timeout = match self.autosave_due_in() {
+                Some(due) => poll_timeout.min(due),
+                None => poll_timeout,
+            };
+            // A timed session wakes up for its countdown and its end.
+            let poll_timeout = match Self::session_limit_left(&self.state.borrow()).0 {
+                Some(left) if left <= SESSION_COUNTDOWN => poll_timeout.min(Duration::from_secs(1)),
+                Some(left) => poll_timeout.min(left - SESSION_COUNTDOWN),
+                None => poll_timeout,
+            };
+            if !terminal::poll_event(poll_timeout)? {
                 continue;
             }
 
@@ -2338,6 +3943,7 @@ impl Reader {
                             let previous_row = self.state.borrow().reading_state.row;
                             self.handle_key_event(key)?;
                             self.record_reading_activity(previous_row)?;
+                            self.animate_page_turn()?;
                         }
                     }
                     Event::Paste(text) => {
@@ -2367,49 +3973,108 @@ impl Reader {
                         };
                         if let Some(textwidth) = textwidth {
                             self.rebuild_text_structure_with_textwidth(textwidth)?;
+                            self.tts_relayout_chunks();
                         }
                     }
                     _ => {}
                 }
             }
         }
-
-        self.finish_reading_session(Utc::now())?;
-
-        // Stop TTS if it's still running
-        self.stop_tts();
-
-        // Persist current reading state to the database before cleaning up
-        self.persist_state()?;
-
-        // Cleanup terminal
-        self.terminal.clear()?;
-        self.terminal.show_cursor()?;
-        crossterm::execute!(
-            io::stdout(),
-            crossterm::terminal::LeaveAlternateScreen,
-            crossterm::event::DisableMouseCapture,
-            crossterm::event::DisableBracketedPaste
-        )?;
-        crossterm::terminal::disable_raw_mode()?;
-
         Ok(())
     }
-}
 
-impl<B: Backend> Reader<B>
-where
-    B::Error: std::error::Error + Send + Sync + 'static,
-{
-    /// Handle keyboard input events
-    fn handle_key_event(&mut self, key: KeyEvent) -> eyre::Result<()> {
-        let (message_dismissed, key_consumed) = {
-            let mut state = self.state.borrow_mut();
-            if state.ui_state.message.is_some() && state.ui_state.message_persistent {
-                // A sticky warning/error: this key only dismisses it.
-                state.ui_state.clear_message();
-                (true, true)
-            } else if state.ui_state.message.is_some()
+    /// Keep the terminal title on the current book and chapter while
+    /// `set_terminal_title` is on. The terminal's own title is pushed on its
+    /// title stack before the first change and popped back when the setting
+    /// is turned off (and on quit).
+    fn update_terminal_title(&mut self) -> eyre::Result<()> {
+        let wanted = if self.state.borrow().config.settings.set_terminal_title {
+            self.book_title().map(|book| {
+                let row = self.state.borrow().reading_state.row;
+                terminal_title_text(&book, self.toc_label_for_row(row).as_deref())
+            })
+        } else {
+            None
+        };
+        if wanted == self.terminal_title {
+            return Ok(());
+        }
+        let mut stdout = io::stdout();
+        match &wanted {
+            Some(title) => {
+                if self.terminal_title.is_none() {
+                    io::Write::write_all(&mut stdout, TITLE_PUSH.as_bytes())?;
+                }
+                crossterm::execute!(stdout, crossterm::terminal::SetTitle(title))?;
+            }
+            None => {
+                io::Write::write_all(&mut stdout, TITLE_POP.as_bytes())?;
+                io::Write::flush(&mut stdout)?;
+            }
+        }
+        self.terminal_title = wanted;
+        Ok(())
+    }
+
+    /// Play back a queued page turn by redrawing intermediate rows over
+    /// `page_scroll_animation_ms`. Pending input cuts the animation short so
+    /// the next key is handled right away; the final row is always restored.
+    fn animate_page_turn(&mut self) -> eyre::Result<()> {
+        let Some((from, to)) = self.page_animation.take() else {
+            return Ok(());
+        };
+        let duration =
+            Duration::from_millis(self.state.borrow().config.settings.page_scroll_animation_ms);
+        let distance = from.abs_diff(to);
+        let frames = distance.min(PAGE_ANIMATION_MAX_FRAMES);
+        if duration.is_zero() || frames < 2 {
+            return Ok(());
+        }
+        let frame_time = duration / frames as u32;
+
+        let mut result = Ok(());
+        for frame in 1..frames {
+            let offset = distance * frame / frames;
+            let row = if to > from {
+                from + offset
+            } else {
+                from - offset
+            };
+            self.state.borrow_mut().reading_state.row = row;
+            if let Err(error) = self.draw() {
+                result = Err(error);
+                break;
+            }
+            if terminal::termination_requested() {
+                break;
+            }
+            match terminal::poll_event(frame_time) {
+                Ok(false) => {}
+                Ok(true) => break,
+                Err(error) => {
+                    result = Err(error.into());
+                    break;
+                }
+            }
+        }
+        self.state.borrow_mut().reading_state.row = to;
+        result
+    }
+}
+
+impl<B: Backend> Reader<B>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    /// Handle keyboard input events
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> eyre::Result<()> {
+        let (message_dismissed, key_consumed) = {
+            let mut state = self.state.borrow_mut();
+            if state.ui_state.message.is_some() && state.ui_state.message_persistent {
+                // A sticky warning/error: this key only dismisses it.
+                state.ui_state.clear_message();
+                (true, true)
+            } else if state.ui_state.message.is_some()
                 && state.ui_state.active_window == WindowType::Reader
             {
                 state.ui_state.clear_message();
@@ -2427,7 +4092,10 @@ where
             return Ok(());
         }
 
-        if self.handle_pending_mark_key(key)? {
+        if self.handle_pending_mark_key(key)?
+            || self.handle_pending_z_key(key)
+            || self.handle_pending_bracket_key(key)?
+        {
             let mut state = self.state.borrow_mut();
             state.count_prefix.clear();
             return Ok(());
@@ -2436,7 +4104,18 @@ where
         // Handle count prefix (number repetition)
         // Only capture digits if we are in a mode that supports it (Reader or Visual)
         let active_window = self.state.borrow().ui_state.active_window.clone();
-        if matches!(active_window, WindowType::Reader | WindowType::Visual)
+        let count_prefix_enabled = self.state.borrow().config.settings.count_prefix_enabled;
+        if !count_prefix_enabled
+            && active_window == WindowType::Reader
+            && let KeyCode::Char(c) = key.code
+            && let Some(tenth) = c.to_digit(10)
+        {
+            // Without counts, a bare digit jumps to that tenth of the book.
+            self.goto_fraction(tenth as f64 / 10.0);
+            return Ok(());
+        }
+        if count_prefix_enabled
+            && matches!(active_window, WindowType::Reader | WindowType::Visual)
             && let KeyCode::Char(c) = key.code
             && c.is_ascii_digit()
         {
@@ -2447,6 +4126,17 @@ where
             return Ok(());
         }
 
+        // Esc abandons a mistyped count without doing anything else.
+        if key.code == KeyCode::Esc
+            && matches!(active_window, WindowType::Reader | WindowType::Visual)
+        {
+            let mut state = self.state.borrow_mut();
+            if !state.count_prefix.is_empty() {
+                state.count_prefix.clear();
+                return Ok(());
+            }
+        }
+
         // Determine repetition count
         let repeat_count = {
             let state = self.state.borrow();
@@ -2473,6 +4163,9 @@ where
             WindowType::HighlightCommentEditor => self.handle_highlight_comment_editor_keys(key)?,
             WindowType::ConfirmDeleteHighlight => self.handle_confirm_delete_highlight_keys(key)?,
             WindowType::ConfirmSyncProgress => self.handle_confirm_sync_progress_keys(key)?,
+            WindowType::ConfirmRelink => self.handle_confirm_relink_keys(key)?,
+            WindowType::ConfirmResume => self.handle_confirm_resume_keys(key)?,
+            WindowType::ConfirmOpenLinkedBook => self.handle_confirm_linked_book_keys(key)?,
             WindowType::Library => self.handle_library_mode_keys(key, repeat_count)?,
             WindowType::OpdsCatalogs => self.handle_opds_catalog_keys(key, repeat_count)?,
             WindowType::OpdsFeed | WindowType::OpdsDetails => {
@@ -2486,8 +4179,14 @@ where
             WindowType::ImageView => self.handle_image_view_keys(key)?,
             WindowType::Help => self.handle_help_mode_keys(key, repeat_count)?,
             WindowType::Metadata => self.handle_modal_close_keys(key)?,
+            WindowType::Messages => self.handle_messages_mode_keys(key, repeat_count)?,
+            WindowType::Pages => self.handle_pages_mode_keys(key, repeat_count)?,
             WindowType::Statistics => self.handle_modal_close_keys(key)?,
             WindowType::Dictionary => self.handle_dictionary_mode_keys(key, repeat_count)?,
+            WindowType::ChapterSource => self.handle_chapter_source_keys(key, repeat_count)?,
+            WindowType::DictionaryPresets => {
+                self.handle_dictionary_presets_keys(key, repeat_count)?
+            }
             WindowType::DictionaryCommandInput => self.handle_dictionary_command_input_keys(key)?,
             WindowType::SettingsTextInput => self.handle_settings_text_input_keys(key)?,
             _ => self.handle_normal_mode_keys(key, repeat_count)?,
@@ -2502,119 +4201,157 @@ where
         Ok(())
     }
 
-    /// Handle keys in normal reading mode
+    /// Handle keys in normal reading mode, dispatching through the
+    /// `KeyBindings` table that the Help window also lists.
     fn handle_normal_mode_keys(&mut self, key: KeyEvent, repeat_count: u32) -> eyre::Result<()> {
-        match key.code {
+        if self.state.borrow().ui_state.link_tour.is_some() {
+            match key.code {
+                KeyCode::Enter => {
+                    self.advance_link_tour();
+                    return Ok(());
+                }
+                KeyCode::Esc => {
+                    self.finish_link_tour();
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+        if self.state.borrow().ui_state.slideshow_next.is_some() {
+            match key.code {
+                KeyCode::Char('+') => {
+                    self.adjust_slideshow_interval(1);
+                    return Ok(());
+                }
+                KeyCode::Char('-') => {
+                    self.adjust_slideshow_interval(-1);
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+        let tts_active = self.state.borrow().ui_state.tts_active;
+        if tts_active && key.code == KeyCode::Esc {
+            self.stop_tts();
+            return Ok(());
+        }
+        let Some(action) = self.key_bindings.action_for(&key, tts_active) else {
+            return Ok(());
+        };
+        // With no room for a page, only quitting makes sense.
+        if action != Action::Quit && self.terminal_too_small() {
+            return Ok(());
+        }
+        match action {
             // Jump History
-            KeyCode::Char('o') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Action::JumpBack => {
                 self.jump_back();
             }
-            KeyCode::Tab => {
-                self.jump_forward();
-            }
-            KeyCode::Char('i') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Action::JumpForward => {
                 self.jump_forward();
             }
 
             // Navigation
-            KeyCode::Char('j') | KeyCode::Down => {
+            Action::LineDown => {
+                let page = self.page_size();
                 for _ in 0..repeat_count {
-                    self.move_cursor(AppDirection::Down);
+                    self.move_cursor(AppDirection::Down, page);
                 }
             }
-            KeyCode::Char('k') | KeyCode::Up => {
+            Action::LineUp => {
+                let page = self.page_size();
                 for _ in 0..repeat_count {
-                    self.move_cursor(AppDirection::Up);
+                    self.move_cursor(AppDirection::Up, page);
                 }
             }
-            KeyCode::Char('h') | KeyCode::Left => {
-                for _ in 0..repeat_count {
-                    self.move_cursor(AppDirection::PageUp);
-                }
+            // While reading aloud, step back / forward one sentence chunk.
+            Action::TtsPrevSentence => {
+                self.tts_skip(-1)?;
             }
-            KeyCode::Char('l') | KeyCode::Right => {
-                for _ in 0..repeat_count {
-                    self.move_cursor(AppDirection::PageDown);
-                }
-            }
-
-            // Page navigation
-            KeyCode::PageDown | KeyCode::Char(' ') => {
-                for _ in 0..repeat_count {
-                    self.move_cursor(AppDirection::PageDown);
-                }
+            Action::TtsNextSentence => {
+                self.tts_skip(1)?;
             }
-            KeyCode::PageUp => {
-                for _ in 0..repeat_count {
-                    self.move_cursor(AppDirection::PageUp);
-                }
+            Action::PageUp | Action::PageDown => {
+                // Right-to-left books turn forward towards the left; the
+                // dedicated page keys and Space keep their meaning.
+                let swap = self.state.borrow().ui_state.is_rtl
+                    && !matches!(
+                        key.code,
+                        KeyCode::PageUp | KeyCode::PageDown | KeyCode::Char(' ')
+                    );
+                let direction = if (action == Action::PageDown) != swap {
+                    AppDirection::PageDown
+                } else {
+                    AppDirection::PageUp
+                };
+                self.turn_page(direction, repeat_count);
             }
-            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Action::HalfPageUp => {
+                let page = self.page_size();
                 for _ in 0..repeat_count {
-                    self.move_cursor(AppDirection::HalfPageUp);
+                    self.move_cursor(AppDirection::HalfPageUp, page);
                 }
             }
-            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Action::HalfPageDown => {
+                let page = self.page_size();
                 for _ in 0..repeat_count {
-                    self.move_cursor(AppDirection::HalfPageDown);
+                    self.move_cursor(AppDirection::HalfPageDown, page);
                 }
             }
 
             // Chapter navigation
-            KeyCode::Char('L') => {
+            Action::NextChapter => {
                 self.next_chapter();
             }
-            KeyCode::Char('H') => {
+            Action::PrevChapter => {
                 self.previous_chapter();
             }
-            KeyCode::Char('n') => {
-                if key.modifiers.contains(KeyModifiers::CONTROL) {
-                    self.next_chapter();
-                } else {
-                    self.search_next();
-                }
-            }
-            KeyCode::Char('p') => {
-                if key.modifiers.contains(KeyModifiers::CONTROL) {
-                    self.previous_chapter();
-                } else {
-                    self.search_previous();
-                }
+            Action::NextHit => {
+                self.search_next();
             }
-            KeyCode::Char('N') => {
+            Action::PrevHit => {
                 self.search_previous();
             }
 
             // Beginning/End
-            KeyCode::Home => {
+            Action::BookStart => {
                 self.goto_start();
             }
-            KeyCode::End => {
+            Action::BookEnd => {
                 self.goto_end();
             }
-            KeyCode::Char('g') => {
+            Action::BeginReading => {
+                self.goto_landmark("bodymatter");
+            }
+            Action::Cover => {
+                self.goto_landmark("cover");
+            }
+            Action::NextParagraph => {
+                self.move_paragraph(true, repeat_count);
+            }
+            Action::PrevParagraph => {
+                self.move_paragraph(false, repeat_count);
+            }
+            Action::ChapterStart => {
                 self.goto_chapter_start();
             }
-            KeyCode::Char('G') => {
+            Action::ChapterEnd => {
                 self.goto_chapter_end();
             }
+            Action::ScrollCommand => {
+                self.state.borrow_mut().ui_state.pending_z_command = true;
+            }
 
-            KeyCode::Char(_)
-                if key_matches_binding(
-                    &key,
-                    &self
-                        .state
-                        .borrow()
-                        .config
-                        .keymap_user_dict()
-                        .show_highlights,
-                ) =>
-            {
+            Action::Highlights => {
                 self.open_highlights_window()?;
             }
 
+            Action::ClearSearchHighlights => {
+                self.state.borrow_mut().ui_state.search_highlights_hidden = true;
+            }
+
             // Search
-            KeyCode::Char('/') => {
+            Action::Search => {
                 let history = self.db_state.get_search_history().unwrap_or_default();
                 let mut state = self.state.borrow_mut();
                 state.search_data = Some(SearchData::default());
@@ -2629,12 +4366,12 @@ where
             }
 
             // Two-phase flow: first v enters cursor mode, second v starts selection
-            KeyCode::Char('v') => {
+            Action::CursorMode => {
+                let page = self.page_size();
                 let mut state = self.state.borrow_mut();
                 // Place cursor at the first non-empty line on the current page
                 let viewport_start = state.reading_state.row.saturating_sub(1);
                 let total_lines = self.board.total_lines();
-                let page = Self::page_size_for(state.config.settings.show_top_bar);
                 let viewport_end = (viewport_start + page).min(total_lines);
                 let mut start_row = viewport_start.min(total_lines.saturating_sub(1));
                 for row in viewport_start..viewport_end {
@@ -2649,7 +4386,7 @@ where
             }
 
             // Windows
-            KeyCode::Char('q') => {
+            Action::Quit => {
                 let mut state = self.state.borrow_mut();
                 if state.ui_state.active_window != WindowType::Reader {
                     state.ui_state.open_window(WindowType::Reader);
@@ -2657,14 +4394,19 @@ where
                     state.should_quit = true;
                 }
             }
-            KeyCode::Char('?') => {
+            Action::Help => {
                 let mut state = self.state.borrow_mut();
                 state.ui_state.open_window(WindowType::Help);
             }
-            KeyCode::Char('t') => {
-                self.open_toc_window()?;
+            // With a count, `t` jumps straight to that chapter (`3t`).
+            Action::TableOfContents => {
+                if self.state.borrow().count_prefix.is_empty() {
+                    self.open_toc_window()?;
+                } else {
+                    self.goto_chapter_number(repeat_count as usize);
+                }
             }
-            KeyCode::Char('m') => {
+            Action::SetMark => {
                 let mut state = self.state.borrow_mut();
                 state.ui_state.pending_mark_command = Some(PendingMarkCommand::Set);
                 state.ui_state.set_message(
@@ -2672,7 +4414,7 @@ where
                     MessageType::Info,
                 );
             }
-            KeyCode::Char('`') => {
+            Action::JumpToMark => {
                 let mut state = self.state.borrow_mut();
                 state.ui_state.pending_mark_command = Some(PendingMarkCommand::Jump);
                 state.ui_state.set_message(
@@ -2680,64 +4422,139 @@ where
                     MessageType::Info,
                 );
             }
-            KeyCode::Char('B') => {
+            // With a count, `P` jumps to that print page (`112P`).
+            Action::PrintPages => {
+                if self.state.borrow().count_prefix.is_empty() {
+                    self.open_pages_window();
+                } else {
+                    self.goto_print_page(&repeat_count.to_string());
+                }
+            }
+            Action::Bookmarks => {
                 self.open_bookmarks_window()?;
             }
-            KeyCode::Char('u') => {
+            Action::Links => {
                 self.open_links_window()?;
             }
-            KeyCode::Char('o') => {
-                if !key.modifiers.contains(KeyModifiers::CONTROL) {
-                    self.open_images_window()?;
-                }
+            Action::AllImages => {
+                self.open_all_images_window();
             }
-            KeyCode::Char('i') => {
-                if key.modifiers.contains(KeyModifiers::CONTROL) {
-                    self.jump_forward();
-                } else {
-                    self.open_metadata_window()?;
+            Action::Images => {
+                self.open_images_window()?;
+            }
+            Action::Metadata => {
+                self.open_metadata_window()?;
+            }
+            Action::ChapterSource => {
+                self.open_chapter_source_in_editor()?;
+            }
+            Action::ChapterSourceView => {
+                self.open_chapter_source_window();
+            }
+            Action::CapturePage => {
+                self.capture_visible_page();
+            }
+            Action::WriteState => {
+                self.write_state_snapshot()?;
+            }
+            Action::SaveNow => {
+                if self.ebook.is_some() {
+                    self.persist_state()?;
+                    self.state
+                        .borrow_mut()
+                        .ui_state
+                        .set_message("Reading position saved".to_string(), MessageType::Info);
                 }
             }
-            KeyCode::Char('r') => {
+            Action::Library => {
                 self.open_library_window()?;
             }
-            KeyCode::Char('R') => {
+            Action::Statistics => {
                 self.open_statistics_window()?;
             }
-            KeyCode::Char('s') => {
+            Action::Messages => {
+                self.state
+                    .borrow_mut()
+                    .ui_state
+                    .open_window(WindowType::Messages);
+            }
+            Action::Settings => {
                 let mut state = self.state.borrow_mut();
                 state.ui_state.settings_selected_index = 0;
                 state.ui_state.open_window(WindowType::Settings);
             }
-            KeyCode::Char('T') => {
+            Action::ToggleTopBar => {
                 let mut state = self.state.borrow_mut();
                 state.config.settings.show_top_bar = !state.config.settings.show_top_bar;
             }
-            KeyCode::Char('+') => {
+            Action::Slideshow => {
+                self.toggle_slideshow();
+            }
+            Action::ToggleFocusMode => {
+                let mut state = self.state.borrow_mut();
+                state.config.settings.focus_mode = !state.config.settings.focus_mode;
+            }
+            Action::BracketForward | Action::BracketBack => {
+                self.state.borrow_mut().ui_state.pending_bracket_command =
+                    Some(action == Action::BracketForward);
+            }
+            Action::FocusUp => {
+                let mut state = self.state.borrow_mut();
+                state.ui_state.focus_band_top = state
+                    .ui_state
+                    .focus_band_top
+                    .saturating_sub(repeat_count as usize);
+            }
+            Action::FocusDown => {
+                let page_size = self.page_size();
+                let mut state = self.state.borrow_mut();
+                let band = 2 * state.config.settings.focus_lines + 1;
+                let max_top = page_size.saturating_sub(band);
+                state.ui_state.focus_band_top =
+                    (state.ui_state.focus_band_top + repeat_count as usize).min(max_top);
+            }
+            Action::Enlarge => {
                 self.change_textwidth(5)?;
             }
-            KeyCode::Char('=') => {
-                if key.modifiers.contains(KeyModifiers::SHIFT) {
-                    self.change_textwidth(5)?;
-                } else {
-                    self.reset_width()?;
-                }
+            // Some terminals report `+` as Shift+`=`.
+            Action::ResetWidth
+                if key.code == KeyCode::Char('=')
+                    && key.modifiers.contains(KeyModifiers::SHIFT) =>
+            {
+                self.change_textwidth(5)?;
+            }
+            Action::ResetWidth => {
+                self.reset_width()?;
             }
-            KeyCode::Char('-') => {
+            Action::Shrink => {
                 self.change_textwidth(-5)?;
             }
+            Action::ScrollLeft => {
+                let step = HORIZONTAL_SCROLL_STEP * repeat_count as usize;
+                let mut state = self.state.borrow_mut();
+                state.ui_state.horizontal_scroll =
+                    state.ui_state.horizontal_scroll.saturating_sub(step);
+            }
+            Action::ScrollRight => {
+                let step = HORIZONTAL_SCROLL_STEP * repeat_count as usize;
+                let max_scroll = self.max_horizontal_scroll();
+                let mut state = self.state.borrow_mut();
+                state.ui_state.horizontal_scroll =
+                    (state.ui_state.horizontal_scroll + step).min(max_scroll);
+            }
 
             // TTS toggle
-            KeyCode::Char('!') => {
+            Action::TtsToggle => {
                 self.toggle_tts()?;
             }
+            Action::AnnouncePosition => {
+                self.announce_position()?;
+            }
 
             // Color theme cycle
-            KeyCode::Char('c') => {
+            Action::SwitchColor => {
                 self.cycle_color_theme()?;
             }
-
-            _ => {}
         }
 
         Ok(())
@@ -2785,6 +4602,112 @@ where
         }
     }
 
+    /// Complete `]m` / `[m`; other follow-up keys just cancel the prefix.
+    fn handle_pending_bracket_key(&mut self, key: KeyEvent) -> eyre::Result<bool> {
+        let forward = {
+            let mut state = self.state.borrow_mut();
+            if state.ui_state.active_window != WindowType::Reader {
+                return Ok(false);
+            }
+            let Some(forward) = state.ui_state.pending_bracket_command.take() else {
+                return Ok(false);
+            };
+            forward
+        };
+        if key.code == KeyCode::Char('m') {
+            self.step_bookmark(forward)?;
+        }
+        Ok(true)
+    }
+
+    /// Jump to the nearest bookmark after (or before) the current row,
+    /// wrapping around the book's ends.
+    fn step_bookmark(&mut self, forward: bool) -> eyre::Result<()> {
+        let bookmarks = match self.ebook.as_ref() {
+            Some(epub) => self.db_state.get_bookmarks(epub.as_ref())?,
+            None => Vec::new(),
+        };
+        let (row, textwidth) = {
+            let state = self.state.borrow();
+            (state.reading_state.row, state.reading_state.textwidth)
+        };
+        let mut targets: Vec<(usize, String)> = bookmarks
+            .iter()
+            .map(|(name, saved)| (self.restore_row(saved, textwidth), name.clone()))
+            .collect();
+        targets.sort();
+        let rows: Vec<usize> = targets.iter().map(|(row, _)| *row).collect();
+        let Some((index, wrapped)) = step_target(&rows, row, forward) else {
+            self.state
+                .borrow_mut()
+                .ui_state
+                .set_message("No bookmarks".to_string(), MessageType::Info);
+            return Ok(());
+        };
+        self.record_jump_position();
+        let (target, name) = &targets[index];
+        let content_index = self.content_index_for_row(*target);
+        let mut state = self.state.borrow_mut();
+        state.reading_state.row = *target;
+        if let Some(content_index) = content_index {
+            state.reading_state.content_index = content_index;
+        }
+        let wrap_note = if wrapped { " (wrapped)" } else { "" };
+        state.ui_state.set_message(
+            format!(
+                "Bookmark {}/{}: {name}{wrap_note}",
+                index + 1,
+                targets.len()
+            ),
+            MessageType::Info,
+        );
+        Ok(())
+    }
+
+    /// Complete a `z` scroll command: `zz`, `zt` and `zb` put the current
+    /// line at the middle, top or bottom of the viewport. Unknown follow-up
+    /// keys just cancel it.
+    fn handle_pending_z_key(&mut self, key: KeyEvent) -> bool {
+        {
+            let mut state = self.state.borrow_mut();
+            if state.ui_state.active_window != WindowType::Reader
+                || !state.ui_state.pending_z_command
+            {
+                return false;
+            }
+            state.ui_state.pending_z_command = false;
+        }
+        let page = self.page_size();
+        let row = self.state.borrow().reading_state.row;
+        let start = match key.code {
+            KeyCode::Char('z') => row.saturating_sub(page / 2),
+            KeyCode::Char('t') => row,
+            KeyCode::Char('b') => row.saturating_sub(page.saturating_sub(1)),
+            _ => return true,
+        };
+        self.set_viewport_start(row, start);
+        true
+    }
+
+    /// Point the viewport at `start`, clamped to the book and, unless
+    /// `seamless_between_chapters` is on, to the chapter holding `row`.
+    fn set_viewport_start(&mut self, row: usize, start: usize) {
+        let seamless = self
+            .state
+            .borrow()
+            .config
+            .settings
+            .seamless_between_chapters;
+        let mut start = start.min(self.board.total_lines().saturating_sub(1));
+        if !seamless
+            && let Some(index) = self.content_index_for_row(row)
+            && let Some((chapter_start, chapter_end)) = self.chapter_bounds_for_index(index)
+        {
+            start = start.clamp(chapter_start, chapter_end.max(chapter_start));
+        }
+        self.state.borrow_mut().reading_state.row = Self::row_from_start(start);
+    }
+
     fn handle_pending_mark_key(&mut self, key: KeyEvent) -> eyre::Result<bool> {
         let pending = {
             let state = self.state.borrow();
@@ -2883,12 +4806,59 @@ where
         }
     }
 
-    fn set_clipboard_text(&mut self, text: String) -> eyre::Result<bool> {
-        let Some(clipboard) = self.clipboard.as_mut() else {
-            return Ok(false);
+    /// Copy `text` to the system clipboard, falling back to the configured
+    /// `clipboard_command` when there is no clipboard or it fails. Returns
+    /// whether the text was copied; failures are logged, never fatal.
+    fn set_clipboard_text(&mut self, text: String) -> bool {
+        let command = self
+            .state
+            .borrow()
+            .config
+            .settings
+            .clipboard_command
+            .clone()
+            .filter(|command| !command.trim().is_empty());
+        if let Some(clipboard) = self.clipboard.as_mut() {
+            match clipboard.set_text(text.clone()) {
+                Ok(()) => return true,
+                Err(err) => logging::debug(format!("Clipboard copy failed: {}", err)),
+            }
+        }
+        let Some(command) = command else {
+            return false;
         };
-        clipboard.set_text(text)?;
-        Ok(true)
+        match Self::pipe_to_clipboard_command(&command, &text) {
+            Ok(()) => true,
+            Err(err) => {
+                logging::debug(format!("Clipboard command failed: {}", err));
+                false
+            }
+        }
+    }
+
+    /// Run a clipboard command such as `xclip -selection clipboard` with
+    /// `text` on its stdin.
+    fn pipe_to_clipboard_command(command: &str, text: &str) -> eyre::Result<()> {
+        use std::io::Write;
+        let mut parts = Self::split_dictionary_command_template(command)?
+            .into_iter()
+            .map(|(part, _)| part);
+        let program = parts
+            .next()
+            .ok_or_else(|| eyre::eyre!("Clipboard command is empty"))?;
+        let mut child = std::process::Command::new(program)
+            .args(parts)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())?;
+        }
+        if !child.wait()?.success() {
+            return Err(eyre::eyre!("Clipboard command exited with non-zero status"));
+        }
+        Ok(())
     }
 
     /// Handle keys in search mode.
@@ -2926,6 +4896,23 @@ where
                 }
                 self.update_incremental_search();
             }
+            KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.select_search_result_in_adjacent_chapter(true);
+            }
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.select_search_result_in_adjacent_chapter(false);
+            }
+            KeyCode::Tab => {
+                {
+                    let mut state = self.state.borrow_mut();
+                    state.ui_state.search_chapter_only = !state.ui_state.search_chapter_only;
+                }
+                if committed {
+                    self.execute_search();
+                } else {
+                    self.update_incremental_search();
+                }
+            }
             KeyCode::Up if !committed => {
                 self.search_history_older();
             }
@@ -3013,6 +5000,42 @@ where
         Ok(())
     }
 
+    /// Ctrl-n / Ctrl-p in the search window: select the first result of the
+    /// next (or previous) chapter that has any.
+    fn select_search_result_in_adjacent_chapter(&mut self, forward: bool) {
+        let mut state = self.state.borrow_mut();
+        let results = &state.ui_state.search_results;
+        let Some(current) = results.get(state.ui_state.selected_search_result) else {
+            return;
+        };
+        let chapter = current.content_index;
+        let target_chapter = if forward {
+            results
+                .iter()
+                .map(|result| result.content_index)
+                .find(|&index| index > chapter)
+        } else {
+            results
+                .iter()
+                .rev()
+                .map(|result| result.content_index)
+                .find(|&index| index < chapter)
+        };
+        let Some(target) =
+            target_chapter.and_then(|index| results.iter().position(|r| r.content_index == index))
+        else {
+            let direction = if forward { "later" } else { "earlier" };
+            state.ui_state.set_message(
+                format!("No matches in an {direction} chapter"),
+                MessageType::Info,
+            );
+            return;
+        };
+        let line = results[target].first_row();
+        state.ui_state.selected_search_result = target;
+        state.reading_state.row = line;
+    }
+
     /// Up in the search prompt: recall the next-older history entry.
     fn search_history_older(&mut self) {
         {
@@ -3135,6 +5158,79 @@ where
         Ok(())
     }
 
+    fn handle_confirm_resume_keys(&mut self, key: KeyEvent) -> eyre::Result<()> {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                let mut state = self.state.borrow_mut();
+                state.ui_state.pending_resume = None;
+                state.ui_state.open_window(WindowType::Reader);
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc | KeyCode::Char('q') => {
+                let mut state = self.state.borrow_mut();
+                state.ui_state.pending_resume = None;
+                state.reading_state.row = 0;
+                state.ui_state.open_window(WindowType::Reader);
+                drop(state);
+                self.persist_state()?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_confirm_relink_keys(&mut self, key: KeyEvent) -> eyre::Result<()> {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                let pending = self.state.borrow_mut().ui_state.pending_relink.take();
+                if let Some((old_path, new_path)) = pending {
+                    self.db_state.reconcile_filepath(&old_path, &new_path)?;
+                    self.load_ebook(&new_path)?;
+                    let mut state = self.state.borrow_mut();
+                    state.ui_state.open_window(WindowType::Reader);
+                    state.ui_state.set_message(
+                        format!(
+                            "Relinked to {}",
+                            crate::library::abbreviate_home(std::path::Path::new(&new_path))
+                        ),
+                        MessageType::Info,
+                    );
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc | KeyCode::Char('q') => {
+                let mut state = self.state.borrow_mut();
+                state.ui_state.pending_relink = None;
+                state.ui_state.open_window(WindowType::Library);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_confirm_linked_book_keys(&mut self, key: KeyEvent) -> eyre::Result<()> {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                let pending = self.state.borrow_mut().ui_state.pending_linked_book.take();
+                if let Some(path) = pending {
+                    // Before loading, so a resume prompt for the new book can
+                    // take over the screen.
+                    self.state
+                        .borrow_mut()
+                        .ui_state
+                        .open_window(WindowType::Reader);
+                    // `load_ebook` saves the current book's position first.
+                    self.load_ebook(&path)?;
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc | KeyCode::Char('q') => {
+                let mut state = self.state.borrow_mut();
+                state.ui_state.pending_linked_book = None;
+                state.ui_state.open_window(WindowType::Reader);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     /// Handle keys in two phases: cursor mode -> selection mode
     ///
     /// Phase 1 (cursor mode): visual_cursor is Some, visual_anchor is None.
@@ -3182,6 +5278,17 @@ where
             return Ok(());
         }
 
+        // Pending `i`: the next key names the text object.
+        if self.state.borrow().ui_state.pending_visual_text_object {
+            self.state.borrow_mut().ui_state.pending_visual_text_object = false;
+            match key.code {
+                KeyCode::Char('s') => self.select_inner_sentence(),
+                KeyCode::Char('p') => self.select_inner_paragraph(),
+                _ => {}
+            }
+            return Ok(());
+        }
+
         match key.code {
             KeyCode::Esc => {
                 let mut state = self.state.borrow_mut();
@@ -3258,6 +5365,10 @@ where
                     }
                 }
             }
+            KeyCode::Char('K') if !has_anchor => {
+                self.lookup_word_under_cursor()?;
+                self.clear_visual_search_state();
+            }
             KeyCode::Char('C') if !has_anchor => {
                 if let Some(highlight) = self.highlight_at_cursor() {
                     let next_color = HighlightColor::from_name(&highlight.color).next();
@@ -3276,6 +5387,14 @@ where
                 self.yank_selection()?;
                 self.clear_visual_search_state();
             }
+            KeyCode::Char('Y') if has_anchor => {
+                self.yank_selection_as_quote()?;
+                self.clear_visual_search_state();
+            }
+            KeyCode::Char('C') if has_anchor => {
+                self.yank_selection_as_citation()?;
+                self.clear_visual_search_state();
+            }
             KeyCode::Char(_)
                 if has_anchor
                     && key_matches_binding(
@@ -3381,6 +5500,9 @@ where
                 self.state.borrow_mut().ui_state.pending_visual_find =
                     Some((VisualFindDirection::TillBackward, repeat_count));
             }
+            KeyCode::Char('i') => {
+                self.state.borrow_mut().ui_state.pending_visual_text_object = true;
+            }
             _ => {}
         }
         Ok(())
@@ -3394,6 +5516,10 @@ where
     /// popups scroll). A left click in the reader follows the link on the
     /// clicked line, if any.
     fn handle_mouse_event(&mut self, mouse: MouseEvent) -> eyre::Result<()> {
+        // Nothing on screen to click or scroll.
+        if self.terminal_too_small() {
+            return Ok(());
+        }
         let active_window = self.state.borrow().ui_state.active_window.clone();
         let synthesize = |code: KeyCode| KeyEvent::new(code, KeyModifiers::NONE);
         match mouse.kind {
@@ -3427,11 +5553,11 @@ where
     /// carries no per-column link information.
     fn handle_reader_click(&mut self, screen_row: u16) -> eyre::Result<()> {
         // Mirror render_reader_static's vertical layout: a 1-row top bar
-        // plus a 2-row gap when the top bar is shown.
-        let content_top: u16 = if self.state.borrow().config.settings.show_top_bar {
-            3
-        } else {
-            0
+        // plus a 2-row gap when the top bar is shown, then the border.
+        let content_top: u16 = {
+            let settings = &self.state.borrow().config.settings;
+            let top_bar = if settings.show_top_bar { 3 } else { 0 };
+            top_bar + u16::from(settings.content_border)
         };
         if screen_row < content_top {
             return Ok(());
@@ -3584,6 +5710,44 @@ where
         Ok(())
     }
 
+    fn handle_pages_mode_keys(&mut self, key: KeyEvent, repeat_count: u32) -> eyre::Result<()> {
+        let (items, mut index) = {
+            let s = self.state.borrow();
+            let items: Vec<String> = s
+                .ui_state
+                .page_anchors
+                .iter()
+                .map(|anchor| anchor.label.clone())
+                .collect();
+            (items, s.ui_state.pages_selected_index)
+        };
+        if self.handle_list_filter_keys(&key, &items, &mut index) {
+            self.state.borrow_mut().ui_state.pages_selected_index = index;
+            return Ok(());
+        }
+        let list_len = self.state.borrow().ui_state.filtered_list_len(items.len());
+        if self.handle_list_nav(&key, repeat_count, list_len, &mut index) {
+            self.state.borrow_mut().ui_state.pages_selected_index = index;
+        } else if key.code == KeyCode::Enter {
+            let label = {
+                let state = self.state.borrow();
+                state
+                    .ui_state
+                    .selected_list_index(index)
+                    .and_then(|i| state.ui_state.page_anchors.get(i))
+                    .map(|anchor| anchor.label.clone())
+            };
+            if let Some(label) = label {
+                self.goto_print_page(&label);
+                self.state
+                    .borrow_mut()
+                    .ui_state
+                    .open_window(WindowType::Reader);
+            }
+        }
+        Ok(())
+    }
+
     fn handle_bookmarks_mode_keys(&mut self, key: KeyEvent, repeat_count: u32) -> eyre::Result<()> {
         let (items, mut index) = {
             let s = self.state.borrow();
@@ -3647,6 +5811,9 @@ where
                 KeyCode::Char('y') => {
                     self.copy_selected_link()?;
                 }
+                KeyCode::Char('t') => {
+                    self.start_link_tour();
+                }
                 _ => {}
             }
         } else {
@@ -3678,9 +5845,13 @@ where
                 s.ui_state.images_selected_index,
             )
         };
+        let book_wide = self.state.borrow().ui_state.images_book_wide;
         if !self.handle_list_nav(&key, repeat_count, list_len, &mut index) {
             match key.code {
-                KeyCode::Enter => {
+                KeyCode::Enter if book_wide => {
+                    self.jump_to_selected_image();
+                }
+                KeyCode::Enter | KeyCode::Char('v') => {
                     self.open_selected_image()?;
                 }
                 KeyCode::Char('o') => {
@@ -3694,6 +5865,20 @@ where
         Ok(())
     }
 
+    fn handle_messages_mode_keys(&mut self, key: KeyEvent, repeat_count: u32) -> eyre::Result<()> {
+        let (list_len, mut index) = {
+            let s = self.state.borrow();
+            (
+                s.ui_state.message_history.len(),
+                s.ui_state.messages_selected_index,
+            )
+        };
+        if self.handle_list_nav(&key, repeat_count, list_len, &mut index) {
+            self.state.borrow_mut().ui_state.messages_selected_index = index;
+        }
+        Ok(())
+    }
+
     fn handle_image_view_keys(&mut self, key: KeyEvent) -> eyre::Result<()> {
         match key.code {
             KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => {
@@ -3740,12 +5925,40 @@ where
                     self.rebuild_library_entries()?;
                     self.reset_list_filter_after_change();
                 }
+                KeyCode::Char('p') => {
+                    {
+                        let mut state = self.state.borrow_mut();
+                        state.ui_state.library_progress_filter =
+                            state.ui_state.library_progress_filter.next();
+                    }
+                    self.rebuild_library_entries()?;
+                    self.reset_list_filter_after_change();
+                }
                 KeyCode::Char('R') => {
-                    self.spawn_library_scan();
+                    if self
+                        .state
+                        .borrow()
+                        .config
+                        .settings
+                        .library_directories
+                        .is_empty()
+                    {
+                        self.state.borrow_mut().ui_state.set_message(
+                            "No library_directories configured to scan".to_string(),
+                            MessageType::Info,
+                        );
+                    }
+                    self.spawn_library_scan(true);
                 }
                 KeyCode::Char('m') => {
                     self.move_selected_library_book_to_calibre()?;
                 }
+                KeyCode::Char('o') => {
+                    self.reveal_selected_library_item()?;
+                }
+                KeyCode::Char('x') => {
+                    self.toggle_selected_library_finished()?;
+                }
                 KeyCode::Char('O') => {
                     let mut state = self.state.borrow_mut();
                     state.ui_state.opds_catalog_selected_index = 0;
@@ -4196,11 +6409,19 @@ where
                 };
                 if selected == Some(SettingItem::DictionaryClient) {
                     let mut state = self.state.borrow_mut();
-                    state.ui_state.dictionary_command_query =
-                        state.config.settings.dictionary_client.clone();
-                    state
-                        .ui_state
-                        .open_window(WindowType::DictionaryCommandInput);
+                    let settings = &state.config.settings;
+                    let client = settings.dictionary_client.trim();
+                    let index = if client.is_empty() || client == "auto" {
+                        0
+                    } else {
+                        settings
+                            .dictionary_presets
+                            .iter()
+                            .position(|preset| preset.name == client)
+                            .map_or(settings.dictionary_presets.len() + 1, |i| i + 1)
+                    };
+                    state.ui_state.dictionary_preset_selected_index = index;
+                    state.ui_state.open_window(WindowType::DictionaryPresets);
                 } else if matches!(
                     selected,
                     Some(
@@ -4208,6 +6429,7 @@ where
                             | SettingItem::KosyncUsername
                             | SettingItem::KosyncPassword
                             | SettingItem::OpdsDownloadDirectory
+                            | SettingItem::ClipboardCommand
                     )
                 ) {
                     let mut state = self.state.borrow_mut();
@@ -4248,6 +6470,15 @@ where
                                 .clone()
                                 .unwrap_or_default(),
                         ),
+                        SettingItem::ClipboardCommand => (
+                            "Clipboard command",
+                            state
+                                .config
+                                .settings
+                                .clipboard_command
+                                .clone()
+                                .unwrap_or_default(),
+                        ),
                         _ => unreachable!(),
                     };
                     state.ui_state.settings_input_field = Some(field.to_string());
@@ -4258,10 +6489,10 @@ where
                 }
             }
             KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Right => {
-                self.adjust_textwidth(5)?;
+                self.adjust_selected_setting(1)?;
             }
             KeyCode::Char('-') | KeyCode::Left => {
-                self.adjust_textwidth(-5)?;
+                self.adjust_selected_setting(-1)?;
             }
             KeyCode::Char('r') => {
                 self.reset_selected_setting()?;
@@ -4272,10 +6503,10 @@ where
     }
 
     fn handle_help_mode_keys(&mut self, key: KeyEvent, repeat_count: u32) -> eyre::Result<()> {
-        let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
+        let (term_width, term_height) = (self.term_width() as u16, self.term_height());
         let active = self.state.borrow().ui_state.list_filter_active;
         if active {
-            let items = HelpWindow::filterable_lines();
+            let items = HelpWindow::filterable_lines(&self.key_bindings);
             let mut state = self.state.borrow_mut();
             let ui = &mut state.ui_state;
             match key.code {
@@ -4312,15 +6543,16 @@ where
         let max_offset = HelpWindow::max_scroll_offset(
             Rect::new(0, 0, term_width, term_height),
             filter_query.as_deref(),
+            &self.key_bindings,
         );
 
         match key.code {
             KeyCode::Char('/') => {
+                let item_count = HelpWindow::filterable_lines(&self.key_bindings).len();
                 let mut state = self.state.borrow_mut();
                 state.ui_state.list_filter_active = true;
                 state.ui_state.list_filter_query.clear();
-                state.ui_state.list_filter_indices =
-                    Some((0..HelpWindow::filterable_lines().len()).collect());
+                state.ui_state.list_filter_indices = Some((0..item_count).collect());
                 state.ui_state.help_scroll_offset = 0;
             }
             KeyCode::Esc if filter_query.is_some() => {
@@ -4368,7 +6600,20 @@ where
         key: KeyEvent,
         repeat_count: u32,
     ) -> eyre::Result<()> {
-        let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
+        let option_count = {
+            let state = self.state.borrow();
+            state
+                .ui_state
+                .dictionary_disambiguation
+                .as_ref()
+                .filter(|_| !state.ui_state.dictionary_loading)
+                .map_or(0, |d| d.titles.len())
+        };
+        if option_count > 0 {
+            return self.handle_dictionary_option_keys(key, repeat_count, option_count);
+        }
+
+        let (term_width, term_height) = (self.term_width() as u16, self.term_height());
         let max_offset = {
             let state = self.state.borrow();
             DictionaryWindow::max_scroll_offset(
@@ -4413,9 +6658,173 @@ where
                     .dictionary_scroll_offset
                     .saturating_sub((repeat_count as u16).saturating_mul(10));
             }
-            KeyCode::Home => {
-                let mut state = self.state.borrow_mut();
-                state.ui_state.dictionary_scroll_offset = 0;
+            KeyCode::Home => {
+                let mut state = self.state.borrow_mut();
+                state.ui_state.dictionary_scroll_offset = 0;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Pick among the articles of a Wikipedia disambiguation page.
+    fn handle_dictionary_option_keys(
+        &mut self,
+        key: KeyEvent,
+        repeat_count: u32,
+        option_count: usize,
+    ) -> eyre::Result<()> {
+        let last = option_count - 1;
+        let mut state = self.state.borrow_mut();
+        let index = state.ui_state.dictionary_option_index;
+        state.ui_state.dictionary_option_index = match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                state.ui_state.open_window(WindowType::Reader);
+                return Ok(());
+            }
+            KeyCode::Enter => {
+                drop(state);
+                return self.lookup_wikipedia_option();
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                index.saturating_add(repeat_count as usize).min(last)
+            }
+            KeyCode::Char('k') | KeyCode::Up => index.saturating_sub(repeat_count as usize),
+            KeyCode::PageDown => index
+                .saturating_add((repeat_count as usize).saturating_mul(10))
+                .min(last),
+            KeyCode::PageUp => index.saturating_sub((repeat_count as usize).saturating_mul(10)),
+            KeyCode::Home | KeyCode::Char('g') => 0,
+            KeyCode::End | KeyCode::Char('G') => last,
+            _ => index,
+        };
+        Ok(())
+    }
+
+    /// Scroll the chapter source overlay; its own key (`X`) toggles it off.
+    fn handle_chapter_source_keys(&mut self, key: KeyEvent, repeat_count: u32) -> eyre::Result<()> {
+        if self.key_bindings.action_for(&key, false) == Some(Action::ChapterSourceView) {
+            self.state
+                .borrow_mut()
+                .ui_state
+                .open_window(WindowType::Reader);
+            return Ok(());
+        }
+        // The backend's size, so scrolling also works under TestBackend.
+        let area = self
+            .terminal
+            .size()
+            .map(|size| Rect::new(0, 0, size.width, size.height))
+            .unwrap_or_else(|_| Rect::new(0, 0, 80, 24));
+        let max_offset =
+            SourceWindow::max_scroll_offset(area, &self.state.borrow().ui_state.chapter_source);
+
+        let mut state = self.state.borrow_mut();
+        let offset = state.ui_state.chapter_source_scroll_offset;
+        state.ui_state.chapter_source_scroll_offset = match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => {
+                state.ui_state.open_window(WindowType::Reader);
+                return Ok(());
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                offset.saturating_add(repeat_count as u16).min(max_offset)
+            }
+            KeyCode::Char('k') | KeyCode::Up => offset.saturating_sub(repeat_count as u16),
+            KeyCode::PageDown | KeyCode::Char(' ') => offset
+                .saturating_add((repeat_count as u16).saturating_mul(10))
+                .min(max_offset),
+            KeyCode::PageUp => offset.saturating_sub((repeat_count as u16).saturating_mul(10)),
+            KeyCode::Home | KeyCode::Char('g') => 0,
+            KeyCode::End | KeyCode::Char('G') => max_offset,
+            _ => offset,
+        };
+        Ok(())
+    }
+
+    /// Keys for the dictionary presets view opened from Settings. Rows are
+    /// "auto", each preset, then the custom command.
+    fn handle_dictionary_presets_keys(
+        &mut self,
+        key: KeyEvent,
+        repeat_count: u32,
+    ) -> eyre::Result<()> {
+        // Before handle_list_nav, which would consume Esc/q and land on the
+        // Reader; this view backs out to Settings.
+        if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+            self.state
+                .borrow_mut()
+                .ui_state
+                .open_window(WindowType::Settings);
+            return Ok(());
+        }
+        let preset_count = self.state.borrow().config.settings.dictionary_presets.len();
+        let custom_row = preset_count + 1;
+        let mut index = self
+            .state
+            .borrow()
+            .ui_state
+            .dictionary_preset_selected_index
+            .min(custom_row);
+        if self.handle_list_nav(&key, repeat_count, custom_row + 1, &mut index) {
+            self.state
+                .borrow_mut()
+                .ui_state
+                .dictionary_preset_selected_index = index;
+            return Ok(());
+        }
+
+        let mut state = self.state.borrow_mut();
+        match key.code {
+            KeyCode::Enter | KeyCode::Char('e') if index == custom_row => {
+                let client = state.config.settings.dictionary_client.trim().to_string();
+                let is_preset = client == "auto"
+                    || state
+                        .config
+                        .settings
+                        .dictionary_presets
+                        .iter()
+                        .any(|preset| preset.name == client);
+                state.ui_state.dictionary_command_query =
+                    if is_preset { String::new() } else { client };
+                state.ui_state.dictionary_command_preset = None;
+                state
+                    .ui_state
+                    .open_window(WindowType::DictionaryCommandInput);
+            }
+            KeyCode::Enter | KeyCode::Char(' ') if index < custom_row => {
+                state.config.settings.dictionary_client = match index {
+                    0 => "auto".to_string(),
+                    i => state.config.settings.dictionary_presets[i - 1].name.clone(),
+                };
+                state.save_config()?;
+            }
+            KeyCode::Char('e') if index > 0 => {
+                state.ui_state.dictionary_command_query = state.config.settings.dictionary_presets
+                    [index - 1]
+                    .command
+                    .clone();
+                state.ui_state.dictionary_command_preset = Some(index - 1);
+                state
+                    .ui_state
+                    .open_window(WindowType::DictionaryCommandInput);
+            }
+            KeyCode::Char('J') if index > 0 && index < preset_count => {
+                state
+                    .config
+                    .settings
+                    .dictionary_presets
+                    .swap(index - 1, index);
+                state.ui_state.dictionary_preset_selected_index = index + 1;
+                state.save_config()?;
+            }
+            KeyCode::Char('K') if index > 1 && index <= preset_count => {
+                state
+                    .config
+                    .settings
+                    .dictionary_presets
+                    .swap(index - 2, index - 1);
+                state.ui_state.dictionary_preset_selected_index = index - 1;
+                state.save_config()?;
             }
             _ => {}
         }
@@ -4425,20 +6834,27 @@ where
     fn handle_dictionary_command_input_keys(&mut self, key: KeyEvent) -> eyre::Result<()> {
         match key.code {
             KeyCode::Enter => {
-                let query = {
-                    let state = self.state.borrow();
-                    state.ui_state.dictionary_command_query.trim().to_string()
-                };
-                {
-                    let mut state = self.state.borrow_mut();
-                    state.config.settings.dictionary_client = query;
-                    let _ = state.save_config();
-                    state.ui_state.open_window(WindowType::Settings);
+                let mut state = self.state.borrow_mut();
+                let query = state.ui_state.dictionary_command_query.trim().to_string();
+                match state.ui_state.dictionary_command_preset.take() {
+                    // A preset without a command could never run.
+                    Some(_) if query.is_empty() => {}
+                    Some(i) => state.config.settings.dictionary_presets[i].command = query,
+                    None => {
+                        state.config.settings.dictionary_client = if query.is_empty() {
+                            "auto".to_string()
+                        } else {
+                            query
+                        };
+                    }
                 }
+                let _ = state.save_config();
+                state.ui_state.open_window(WindowType::DictionaryPresets);
             }
             KeyCode::Esc => {
                 let mut state = self.state.borrow_mut();
-                state.ui_state.open_window(WindowType::Settings);
+                state.ui_state.dictionary_command_preset = None;
+                state.ui_state.open_window(WindowType::DictionaryPresets);
             }
             KeyCode::Backspace => {
                 let mut state = self.state.borrow_mut();
@@ -4466,6 +6882,7 @@ where
                     Some("OPDS download directory") => {
                         state.config.settings.opds_download_directory = value
                     }
+                    Some("Clipboard command") => state.config.settings.clipboard_command = value,
                     _ => {}
                 }
                 state.save_config()?;
@@ -4793,7 +7210,8 @@ where
         state: &ApplicationState,
         board: &Board,
         content_start_rows: &[usize],
-        library_cover: Option<&mut StatefulProtocol>,
+        key_bindings: &KeyBindings,
+        cover: Option<&mut StatefulProtocol>,
     ) -> Rect {
         let theme = state.theme();
 
@@ -4826,6 +7244,7 @@ where
                 state.ui_state.help_scroll_offset,
                 filter_query,
                 filter_status.as_deref(),
+                key_bindings,
                 &theme,
             );
         } else if state.ui_state.show_toc {
@@ -4906,6 +7325,7 @@ where
                 state.ui_state.library_selected_index,
                 filter.as_deref(),
                 state.ui_state.library_sort_mode,
+                state.ui_state.library_progress_filter,
                 state.ui_state.library_scanning,
                 if state.ui_state.library_cover_visible {
                     state
@@ -4915,7 +7335,7 @@ where
                 } else {
                     None
                 },
-                library_cover,
+                cover,
                 &theme,
             );
         } else if state.ui_state.active_window == WindowType::OpdsCatalogs {
@@ -4954,18 +7374,25 @@ where
                 );
             }
         } else if state.ui_state.show_search {
-            let entries: Vec<String> = state
+            let entries: Vec<SearchEntry> = state
                 .ui_state
                 .search_results
                 .iter()
-                .map(|result| format!("{}: {}", result.first_row() + 1, result.preview))
+                .map(|result| SearchEntry {
+                    line: (result.first_row() + 1).to_string(),
+                    preview: result.preview.clone(),
+                    before: result.context_before.clone(),
+                    after: result.context_after.clone(),
+                })
                 .collect();
             SearchWindow::render(
                 frame,
                 frame.area(),
                 &state.ui_state.search_query,
                 &entries,
+                &state.ui_state.search_history,
                 state.ui_state.selected_search_result,
+                state.ui_state.search_chapter_only,
                 &theme,
             );
         } else if state.ui_state.active_window == WindowType::LinkPreview {
@@ -4979,36 +7406,88 @@ where
                 board,
                 &theme,
             );
+        } else if state.ui_state.show_pages {
+            let filter = state.ui_state.list_filter_status();
+            let filtered_pages: Vec<PageAnchor>;
+            let pages: &[PageAnchor] = match state.ui_state.list_filter_indices.as_ref() {
+                Some(indices) => {
+                    filtered_pages = indices
+                        .iter()
+                        .filter_map(|&i| state.ui_state.page_anchors.get(i).cloned())
+                        .collect();
+                    &filtered_pages
+                }
+                None => &state.ui_state.page_anchors,
+            };
+            PagesWindow::render(
+                frame,
+                frame.area(),
+                pages,
+                state.ui_state.pages_selected_index,
+                filter.as_deref(),
+                &theme,
+            );
+        } else if state.ui_state.show_messages {
+            MessagesWindow::render(
+                frame,
+                frame.area(),
+                &state.ui_state.message_history,
+                state.ui_state.messages_selected_index,
+                &theme,
+            );
         } else if state.ui_state.show_images {
             ImagesWindow::render(
                 frame,
                 frame.area(),
                 &state.ui_state.images_list,
+                state.ui_state.images_book_wide,
                 state.ui_state.images_selected_index,
                 &theme,
             );
-        } else if state.ui_state.show_dictionary {
-            DictionaryWindow::render(
+        } else if state.ui_state.show_chapter_source {
+            SourceWindow::render(
                 frame,
                 frame.area(),
-                &state.ui_state.dictionary_word,
-                &state.ui_state.dictionary_definition,
-                &state.ui_state.dictionary_client_used,
-                state.ui_state.dictionary_scroll_offset,
-                state.ui_state.dictionary_loading,
-                state.ui_state.dictionary_is_wikipedia,
+                &state.ui_state.chapter_source_href,
+                &state.ui_state.chapter_source,
+                state.ui_state.chapter_source_scroll_offset,
                 &theme,
             );
+        } else if state.ui_state.show_dictionary {
+            let view = DictionaryView {
+                word: &state.ui_state.dictionary_word,
+                definition: &state.ui_state.dictionary_definition,
+                client: &state.ui_state.dictionary_client_used,
+                scroll_offset: state.ui_state.dictionary_scroll_offset,
+                loading: state.ui_state.dictionary_loading,
+                is_wikipedia: state.ui_state.dictionary_is_wikipedia,
+                options: state
+                    .ui_state
+                    .dictionary_disambiguation
+                    .as_ref()
+                    .map_or(&[], |d| d.titles.as_slice()),
+                selected_option: state.ui_state.dictionary_option_index,
+            };
+            DictionaryWindow::render(frame, frame.area(), &view, &theme);
         } else if state.ui_state.show_metadata {
             MetadataWindow::render(
                 frame,
                 frame.area(),
                 state.ui_state.metadata.as_ref(),
                 state.ui_state.metadata_filepath.as_deref(),
+                cover,
                 &theme,
             );
         } else if state.ui_state.show_statistics {
             StatisticsWindow::render(frame, frame.area(), &state.ui_state.statistics, &theme);
+        } else if state.ui_state.active_window == WindowType::DictionaryPresets {
+            SettingsWindow::dictionary_presets(
+                frame,
+                frame.area(),
+                &state.config.settings,
+                state.ui_state.dictionary_preset_selected_index,
+                &theme,
+            );
         } else if state.ui_state.active_window == WindowType::DictionaryCommandInput {
             Self::render_dictionary_command_input_static(frame, state, &theme);
         } else if state.ui_state.active_window == WindowType::SettingsTextInput {
@@ -5021,6 +7500,12 @@ where
             Self::render_confirm_delete_highlight_static(frame, state, &theme);
         } else if state.ui_state.active_window == WindowType::ConfirmSyncProgress {
             Self::render_confirm_sync_progress_static(frame, state, &theme);
+        } else if state.ui_state.active_window == WindowType::ConfirmRelink {
+            Self::render_confirm_relink_static(frame, state, &theme);
+        } else if state.ui_state.active_window == WindowType::ConfirmResume {
+            Self::render_confirm_resume_static(frame, state, &theme);
+        } else if state.ui_state.active_window == WindowType::ConfirmOpenLinkedBook {
+            Self::render_confirm_linked_book_static(frame, state, &theme);
         } else if state.ui_state.show_settings {
             let entries = Self::settings_entries(state);
             SettingsWindow::render(
@@ -5138,6 +7623,11 @@ where
             }
             None => format!("{:>4}", "new"),
         };
+        let reading_progress_str = if item.finished {
+            format!("{:>4}", "✓")
+        } else {
+            reading_progress_str
+        };
 
         let filename = {
             let path = &item.filepath;
@@ -5198,7 +7688,13 @@ where
                 SettingItem::ShowLineNumbers => {
                     format!("Show line numbers: {}", settings.show_line_numbers)
                 }
+                SettingItem::LineNumberStyle => {
+                    format!("Line numbers count: {}", settings.line_number_style.label())
+                }
                 SettingItem::MouseSupport => format!("Mouse support: {}", settings.mouse_support),
+                SettingItem::CountPrefix => {
+                    format!("Count prefix (5j): {}", settings.count_prefix_enabled)
+                }
                 SettingItem::PageScrollAnimation => {
                     format!("Page scroll animation: {}", settings.page_scroll_animation)
                 }
@@ -5214,6 +7710,18 @@ where
                         settings.seamless_between_chapters
                     )
                 }
+                SettingItem::ChapterBreakBlankLines => {
+                    format!(
+                        "Chapter break blank lines: {}",
+                        settings.chapter_break_blank_lines
+                    )
+                }
+                SettingItem::ChapterBreakRule => {
+                    format!("Chapter break rule: {}", settings.chapter_break_rule)
+                }
+                SettingItem::ChapterBreakTitle => {
+                    format!("Chapter break title: {}", settings.chapter_break_title)
+                }
                 SettingItem::InlineImages => {
                     format!("Inline images: {}", settings.inline_images.label())
                 }
@@ -5226,6 +7734,79 @@ where
                 SettingItem::JustifyText => {
                     format!("Justify text: {}", settings.justify_text)
                 }
+                SettingItem::FocusMode => {
+                    format!("Reading ruler: {}", settings.focus_mode)
+                }
+                SettingItem::FocusLines => {
+                    format!("Reading ruler lines: ±{}", settings.focus_lines)
+                }
+                SettingItem::BionicReading => {
+                    format!("Bionic reading: {}", settings.bionic_reading)
+                }
+                SettingItem::ResumePrompt => {
+                    format!("Resume prompt: {}", settings.resume_prompt)
+                }
+                SettingItem::SkipFrontMatter => {
+                    format!("Skip front matter: {}", settings.skip_front_matter)
+                }
+                SettingItem::AutoMarkFinished => {
+                    format!("Auto-mark finished: {}", settings.auto_mark_finished)
+                }
+                SettingItem::SlideshowInterval => match settings.slideshow_interval_secs {
+                    0 => "Slideshow interval: off".to_string(),
+                    secs => format!("Slideshow interval: {secs}s"),
+                },
+                SettingItem::SyncSidecar => {
+                    format!("Sync position via sidecar file: {}", settings.sync_sidecar)
+                }
+                SettingItem::AutosaveInterval => match settings.autosave_interval_secs {
+                    0 => "Autosave interval: off".to_string(),
+                    secs => format!("Autosave interval: {secs}s"),
+                },
+                SettingItem::SessionLimitMinutes => match settings.session_limit_minutes {
+                    0 => "Session limit (minutes): off".to_string(),
+                    minutes => format!("Session limit (minutes): {minutes}"),
+                },
+                SettingItem::SessionLimitPages => match settings.session_limit_pages {
+                    0 => "Session limit (pages): off".to_string(),
+                    pages => format!("Session limit (pages): {pages}"),
+                },
+                SettingItem::SessionLimitQuit => {
+                    format!(
+                        "Quit when the session is over: {}",
+                        settings.session_limit_quit
+                    )
+                }
+                SettingItem::SearchPlaceholders => {
+                    format!(
+                        "Search image placeholders: {}",
+                        settings.search_placeholders
+                    )
+                }
+                SettingItem::Hlsearch => {
+                    format!("Keep search highlights while paging: {}", settings.hlsearch)
+                }
+                SettingItem::NormalizeSearch => {
+                    format!(
+                        "Search ignores curly quotes, dashes, ligatures: {}",
+                        settings.normalize_search
+                    )
+                }
+                SettingItem::OpenLinkedBooks => {
+                    format!("Open linked books: {}", settings.open_linked_books)
+                }
+                SettingItem::AggregateDictionaries => {
+                    format!(
+                        "Aggregate dictionaries: {}",
+                        settings.aggregate_dictionaries
+                    )
+                }
+                SettingItem::AsciiWikipedia => {
+                    format!(
+                        "Wikipedia for ASCII text: {}",
+                        settings.ascii_wikipedia.code()
+                    )
+                }
                 SettingItem::DictionaryClient => {
                     let client = if settings.dictionary_client.trim().is_empty() {
                         "auto"
@@ -5238,12 +7819,51 @@ where
                         format!("Dictionary client: {client}")
                     }
                 }
+                SettingItem::DefaultTtsEngine => format!(
+                    "Default TTS engine: {}",
+                    settings.preferred_tts_engine.as_deref().unwrap_or("purr")
+                ),
                 SettingItem::TtsEngine => {
-                    let engine = settings.preferred_tts_engine.as_deref().unwrap_or("purr");
-                    format!("TTS engine: {engine}")
+                    let engine = state.effective_tts_engine().unwrap_or("purr");
+                    if state.reading_state.tts_engine.is_some() {
+                        format!("TTS engine: {engine} (book)")
+                    } else {
+                        format!("TTS engine: {engine}")
+                    }
+                }
+                SettingItem::TtsRate => match settings.tts_rate {
+                    Some(rate) => format!("TTS rate (espeak/say): {rate} wpm"),
+                    None => "TTS rate (espeak/say): engine default".to_string(),
+                },
+                SettingItem::TtsReadImages => {
+                    format!("TTS reads image descriptions: {}", settings.tts_read_images)
+                }
+                SettingItem::TtsAnnounceChapters => {
+                    format!("TTS announces chapters: {}", settings.tts_announce_chapters)
+                }
+                SettingItem::TtsStopAtChapter => {
+                    format!("TTS stops at chapter end: {}", settings.tts_stop_at_chapter)
+                }
+                SettingItem::PlayNarration => {
+                    format!("Play recorded narration: {}", settings.play_narration)
                 }
                 SettingItem::Width => format!("Text width: {}", state.reading_state.textwidth),
                 SettingItem::ShowTopBar => format!("Show top bar: {}", settings.show_top_bar),
+                SettingItem::ContentBorder => {
+                    format!("Border around text: {}", settings.content_border)
+                }
+                SettingItem::ShowChapterTitle => {
+                    format!("Chapter title in top bar: {}", settings.show_chapter_title)
+                }
+                SettingItem::ShowEta => {
+                    format!("Reading pace and ETA in top bar: {}", settings.show_eta)
+                }
+                SettingItem::SetTerminalTitle => {
+                    format!("Terminal title shows book: {}", settings.set_terminal_title)
+                }
+                SettingItem::PreferEpubPageNumbers => {
+                    format!("EPUB page numbers: {}", settings.prefer_epub_page_numbers)
+                }
                 SettingItem::ColorTheme => {
                     let suffix = if state.book_color_theme.is_some() {
                         " (book)"
@@ -5273,6 +7893,10 @@ where
                     }
                 ),
                 SettingItem::KosyncPullNow => "Pull KOReader progress now".to_string(),
+                SettingItem::ClipboardCommand => format!(
+                    "Clipboard fallback command: {}",
+                    settings.clipboard_command.as_deref().unwrap_or("not set")
+                ),
                 SettingItem::OpdsDownloadDirectory => format!(
                     "Download directory: {}",
                     settings
@@ -5307,12 +7931,17 @@ where
         } else {
             None
         };
-        let title = state
+        let book_title = state
             .ui_state
             .metadata
             .as_ref()
             .and_then(|meta| meta.title.as_deref())
             .unwrap_or("repy");
+        // The chapter leads so that truncation eats the book title first.
+        let title = match state.ui_state.chapter_title.as_deref() {
+            Some(chapter) if chapter != book_title => format!("{chapter} · {book_title}"),
+            _ => book_title.to_string(),
+        };
 
         let show_top_bar = state.config.settings.show_top_bar;
         let top_bar_height = if show_top_bar { 1 } else { 0 };
@@ -5330,25 +7959,48 @@ where
             ])
             .split(frame_area);
 
-        // Main content area, centered: the wrap width recomputed with the
-        // same formula the parse paths use, plus the gutter columns (the
-        // line-number margin "9999 " and the highlight marker), so justified
-        // lines exactly fill the text area instead of being clipped.
+        // Main content area, centered between the configured margins: the
+        // wrap width recomputed with the same formula the parse paths use,
+        // plus the gutter columns (the line-number margin "9999 " and the
+        // highlight marker), so justified lines exactly fill the text area
+        // instead of being clipped.
         let gutter_width = reader_gutter_width(
             state.config.settings.show_line_numbers,
             !state.ui_state.highlights.is_empty(),
         );
-        let available_width = chunks[2].width as usize;
+        let settings = &state.config.settings;
+        // The content border takes a row and a column on each side.
+        let text_region = if settings.content_border {
+            Block::default().borders(Borders::ALL).inner(chunks[2])
+        } else {
+            chunks[2]
+        };
+        let left_margin = settings.left_margin.min(text_region.width as usize);
+        let available_width =
+            (text_region.width as usize).saturating_sub(left_margin + settings.right_margin);
         let wrap_width =
             compute_wrap_width(available_width, state.reading_state.textwidth, gutter_width);
         let content_width = (wrap_width + gutter_width).min(available_width) as u16;
-        let left_pad = (chunks[2].width.saturating_sub(content_width)) / 2;
+        let left_pad =
+            left_margin as u16 + (available_width as u16).saturating_sub(content_width) / 2;
         let content_area = Rect {
-            x: chunks[2].x + left_pad,
-            y: chunks[2].y,
+            x: text_region.x + left_pad,
+            y: text_region.y,
             width: content_width,
-            height: chunks[2].height,
+            height: text_region.height,
         };
+        if settings.content_border && !content_area.is_empty() {
+            let frame_rect = Rect {
+                x: content_area.x - 1,
+                y: content_area.y - 1,
+                width: content_area.width + 2,
+                height: content_area.height + 2,
+            };
+            let border = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.muted_fg));
+            frame.render_widget(border, frame_rect);
+        }
 
         // Link handling: keep main text untouched; show a subtle header hint only when the page has
         // links. Pressing `u` opens a list; Enter jumps for internal anchors when possible.
@@ -5369,9 +8021,15 @@ where
         } else {
             None
         };
-        let page_text = board
-            .current_page_label(state.reading_state.row)
-            .map(|label| format!("p.{}", label));
+        // Books without a page-list fall back to screen pages either way.
+        let page_text = if state.config.settings.prefer_epub_page_numbers && board.has_print_pages()
+        {
+            board
+                .current_page_label(state.reading_state.row)
+                .map(|label| format!("p.{}", label))
+        } else {
+            board.screen_page_label(visible_start, content_area.height as usize)
+        };
         let progress_text = match (page_text, percent_text) {
             (Some(page), Some(pct)) => Some(format!("{} {}", page, pct)),
             (Some(page), None) => Some(page),
@@ -5384,6 +8042,21 @@ where
             .estimated_chapter_minutes_left
             .filter(|minutes| *minutes > 0)
             .map(|minutes| format!("~{} left", Self::format_minutes_compact(minutes)));
+        let eta_hint = if state.config.settings.show_eta {
+            let stats = &state.ui_state.statistics;
+            stats
+                .session_words_per_minute
+                .zip(stats.session_book_minutes_left)
+                .map(|(wpm, minutes)| {
+                    format!(
+                        "{:.0}wpm ~{} to end",
+                        wpm,
+                        Self::format_minutes_compact(minutes)
+                    )
+                })
+        } else {
+            None
+        };
         let search_hint = if state.ui_state.search_results.is_empty() {
             None
         } else {
@@ -5393,11 +8066,28 @@ where
                 state.ui_state.search_results.len()
             ))
         };
+        let tts_hint = state
+            .ui_state
+            .tts_progress
+            .map(|(current, total)| format!("TTS {current}/{total}"));
+        let session_hint = match Self::session_limit_left(state) {
+            (_, Some(pages)) if pages <= SESSION_COUNTDOWN_PAGES => Some(format!(
+                "session: {pages} page{} left",
+                if pages == 1 { "" } else { "s" }
+            )),
+            (Some(left), _) if left <= SESSION_COUNTDOWN => {
+                Some(format!("session: {}m left", left.as_secs().div_ceil(60)))
+            }
+            _ => None,
+        };
         let right_parts: Vec<String> = [
             mode_hint,
+            session_hint,
+            tts_hint,
             search_hint,
             link_hint,
             time_left_hint,
+            eta_hint,
             progress_text,
         ]
         .into_iter()
@@ -5410,11 +8100,49 @@ where
         };
         if show_top_bar {
             let header_line =
-                Self::build_header_line(title, right_text.as_deref(), chunks[0].width);
+                Self::build_header_line(&title, right_text.as_deref(), chunks[0].width);
             let header = Paragraph::new(Line::from(header_line));
             frame.render_widget(header, chunks[0]);
         }
 
+        // A thin bar under the text tracks how far the TTS run has got.
+        if let Some((current, total)) = state.ui_state.tts_progress
+            && chunks[3].height > 0
+            && total > 0
+        {
+            let width = content_area.width as usize;
+            let filled = (width * current / total).min(width);
+            let bar = Line::from(vec![
+                Span::styled("━".repeat(filled), Style::default().fg(theme.info_fg)),
+                Span::styled(
+                    "─".repeat(width - filled),
+                    Style::default().fg(theme.muted_fg),
+                ),
+            ]);
+            let bar_area = Rect {
+                x: content_area.x,
+                y: chunks[3].y,
+                width: content_area.width,
+                height: 1,
+            };
+            frame.render_widget(Paragraph::new(bar), bar_area);
+        }
+
+        // Show a count being typed (`25` of `25j`) in the bottom-right
+        // corner, like Vim's showcmd, so a stray digit is not a surprise.
+        if !state.count_prefix.is_empty() && chunks[3].height > 0 {
+            let count_area = Rect {
+                y: chunks[3].y + chunks[3].height - 1,
+                height: 1,
+                width: chunks[3].width.saturating_sub(1),
+                ..chunks[3]
+            };
+            let count = Paragraph::new(state.count_prefix.as_str())
+                .style(Style::default().fg(theme.info_fg))
+                .alignment(ratatui::layout::Alignment::Right);
+            frame.render_widget(count, count_area);
+        }
+
         board.render(frame, content_area, state, Some(content_start_rows), theme);
         content_area
     }
@@ -5537,10 +8265,18 @@ where
             3,
         );
 
+        let title = match state
+            .ui_state
+            .dictionary_command_preset
+            .and_then(|i| state.config.settings.dictionary_presets.get(i))
+        {
+            Some(preset) => format!("Command for {} (%q for query)", preset.name),
+            None => "Dictionary Command Template (%q for query)".to_string(),
+        };
         let input = Paragraph::new(Line::from(state.ui_state.dictionary_command_query.as_str()))
             .block(
                 Block::default()
-                    .title("Dictionary Command Template (%q for query)")
+                    .title(title)
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(theme.info_fg)),
             );
@@ -5754,6 +8490,122 @@ where
         );
     }
 
+    fn render_confirm_linked_book_static(
+        frame: &mut Frame,
+        state: &ApplicationState,
+        theme: &Theme,
+    ) {
+        let Some(path) = state.ui_state.pending_linked_book.as_ref() else {
+            return;
+        };
+        let area = frame.area();
+        let width = (area.width * 2 / 3).max(44).min(area.width);
+        let height = 7u16.min(area.height);
+        let popup_area = Rect::new(
+            area.x + area.width.saturating_sub(width) / 2,
+            area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        );
+        let lines = vec![
+            Line::from(""),
+            Line::from("  This link points to another book:"),
+            Line::from(format!(
+                "  {}",
+                crate::library::abbreviate_home(std::path::Path::new(path))
+            )),
+            Line::from(""),
+            Line::from("  Open it? (y/N)"),
+        ];
+        let block = Block::default()
+            .title("Open Linked Book")
+            .borders(Borders::ALL)
+            .style(theme.base_style());
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false })
+                .style(theme.base_style()),
+            popup_area,
+        );
+    }
+
+    fn render_confirm_resume_static(frame: &mut Frame, state: &ApplicationState, theme: &Theme) {
+        let Some((progress, last_read)) = state.ui_state.pending_resume.as_ref() else {
+            return;
+        };
+        let area = frame.area();
+        let width = (area.width * 2 / 3).max(44).min(area.width);
+        let height = 6u16.min(area.height);
+        let popup_area = Rect::new(
+            area.x + area.width.saturating_sub(width) / 2,
+            area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        );
+        let lines = vec![
+            Line::from(""),
+            Line::from(format!(
+                "  Resume at {:.0}% (last read {})? (Y/n)",
+                progress * 100.0,
+                format_time_ago(Utc::now() - *last_read)
+            )),
+            Line::from(""),
+            Line::from("  n starts from the beginning"),
+        ];
+        let block = Block::default()
+            .title("Resume Reading")
+            .borders(Borders::ALL)
+            .style(theme.base_style());
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false })
+                .style(theme.base_style()),
+            popup_area,
+        );
+    }
+
+    fn render_confirm_relink_static(frame: &mut Frame, state: &ApplicationState, theme: &Theme) {
+        let Some((_, new_path)) = state.ui_state.pending_relink.as_ref() else {
+            return;
+        };
+        let area = frame.area();
+        let width = (area.width * 2 / 3).max(44).min(area.width);
+        let height = 9u16.min(area.height);
+        let popup_area = Rect::new(
+            area.x + area.width.saturating_sub(width) / 2,
+            area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        );
+        let lines = vec![
+            Line::from(""),
+            Line::from("  The book has moved. A file with the same content was found at:"),
+            Line::from(format!(
+                "  {}",
+                crate::library::abbreviate_home(std::path::Path::new(new_path))
+            )),
+            Line::from(""),
+            Line::from("  Relink the library entry and open it? (y/N)"),
+        ];
+        let block = Block::default()
+            .title("Relink Book")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.warning_fg))
+            .style(theme.base_style());
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false })
+                .style(theme.base_style()),
+            popup_area,
+        );
+    }
+
     fn render_link_preview_static(
         frame: &mut Frame,
         state: &ApplicationState,
@@ -5815,6 +8667,57 @@ where
         Ok(())
     }
 
+    fn open_pages_window(&mut self) {
+        let current_row = self.state.borrow().reading_state.row;
+        let lines = self.board.lines().unwrap_or_default();
+        let anchors: Vec<PageAnchor> = self
+            .board
+            .page_anchors()
+            .into_iter()
+            .map(|(row, label)| PageAnchor {
+                label: label.to_string(),
+                row,
+                preview: lines
+                    .get(row)
+                    .map(|line| line.trim().to_string())
+                    .unwrap_or_default(),
+            })
+            .collect();
+        let selected_index = anchors
+            .iter()
+            .rposition(|anchor| anchor.row <= current_row)
+            .unwrap_or(0);
+
+        let mut state = self.state.borrow_mut();
+        state.ui_state.page_anchors = anchors;
+        state.ui_state.pages_selected_index = selected_index;
+        state.ui_state.open_window(WindowType::Pages);
+    }
+
+    /// Jump to the start of EPUB print page `label`.
+    fn goto_print_page(&mut self, label: &str) {
+        let anchors = self.board.page_anchors();
+        let target = anchors
+            .iter()
+            .find(|(_, page)| page.eq_ignore_ascii_case(label))
+            .map(|&(row, _)| row);
+        let Some(row) = target else {
+            let message = if anchors.is_empty() {
+                "This book has no print page numbers".to_string()
+            } else {
+                format!("No print page {label}")
+            };
+            self.state
+                .borrow_mut()
+                .ui_state
+                .set_message(message, MessageType::Warning);
+            return;
+        };
+        self.record_jump_position();
+        self.state.borrow_mut().reading_state.row = Self::row_from_start(row);
+        self.sync_reading_content_index();
+    }
+
     fn open_bookmarks_window(&mut self) -> eyre::Result<()> {
         let bookmarks = if let Some(epub) = self.ebook.as_ref() {
             self.db_state.get_bookmarks(epub.as_ref())?
@@ -5862,7 +8765,7 @@ where
                     ),
                 )
             };
-            let wrap_width = compute_wrap_width(self.term_width(), textwidth, gutter_width);
+            let wrap_width = compute_wrap_width(self.reading_area_width(), textwidth, gutter_width);
             if self.current_text_width != Some(wrap_width) {
                 // The rebuild refreshes highlight ranges itself.
                 return self.rebuild_text_structure_with_textwidth(textwidth);
@@ -6081,7 +8984,12 @@ where
         if let Some(_lines) = self.board.lines() {
             for i in start..end {
                 if let Some(src) = self.board.image_src(i) {
-                    images.push((i, src));
+                    images.push(ImageEntry {
+                        row: i,
+                        src,
+                        alt: self.board.image_alt(i).map(str::to_string),
+                        chapter: None,
+                    });
                 }
             }
         }
@@ -6090,20 +8998,73 @@ where
         if images.is_empty() {
             state
                 .ui_state
-                .set_message("No images on this page".to_string(), MessageType::Info);
-            return Ok(());
+                .set_message("No images on this page".to_string(), MessageType::Info);
+            return Ok(());
+        }
+        state.ui_state.images_list = images;
+        state.ui_state.images_book_wide = false;
+        state.ui_state.images_selected_index = 0;
+        state.ui_state.open_window(WindowType::Images);
+        Ok(())
+    }
+
+    /// List every image in the book with the chapter it appears in.
+    fn open_all_images_window(&mut self) {
+        if self.book_images.is_empty() {
+            self.state
+                .borrow_mut()
+                .ui_state
+                .set_message("This book has no images".to_string(), MessageType::Info);
+            return;
         }
+        let images = self
+            .book_images
+            .iter()
+            .map(|(row, src)| ImageEntry {
+                row: *row,
+                src: src.clone(),
+                alt: self.board.image_alt(*row).map(str::to_string),
+                chapter: self.toc_label_for_row(*row),
+            })
+            .collect();
+        let current_row = self.state.borrow().reading_state.row;
+        let selected = self
+            .book_images
+            .iter()
+            .rposition(|&(row, _)| row < current_row)
+            .unwrap_or(0);
+        let mut state = self.state.borrow_mut();
         state.ui_state.images_list = images;
-        state.ui_state.images_selected_index = 0;
+        state.ui_state.images_book_wide = true;
+        state.ui_state.images_selected_index = selected;
         state.ui_state.open_window(WindowType::Images);
-        Ok(())
+    }
+
+    /// Jump to the selected entry of the book-wide images list.
+    fn jump_to_selected_image(&mut self) {
+        let Some(row) = ({
+            let state = self.state.borrow();
+            state
+                .ui_state
+                .images_list
+                .get(state.ui_state.images_selected_index)
+                .map(|image| image.row)
+        }) else {
+            return;
+        };
+        self.record_jump_position();
+        let mut state = self.state.borrow_mut();
+        state.reading_state.row = Self::row_from_start(row);
+        state.ui_state.open_window(WindowType::Reader);
+        drop(state);
+        self.sync_reading_content_index();
     }
 
     fn open_library_window(&mut self) -> eyre::Result<()> {
         // Populate immediately from history plus the cached scan results,
         // then refresh the cache in the background.
         self.rebuild_library_entries()?;
-        self.spawn_library_scan();
+        self.spawn_library_scan(false);
         let mut state = self.state.borrow_mut();
         state.ui_state.library_selected_index = 0;
         state.ui_state.library_cover_visible = false;
@@ -6126,7 +9087,11 @@ where
         let scanned = self.db_state.get_scanned_library_files()?;
         let mut state = self.state.borrow_mut();
         let sort_mode = state.ui_state.library_sort_mode;
-        state.ui_state.library_items = Self::merge_library_entries(history, scanned, sort_mode);
+        let progress_filter = state.ui_state.library_progress_filter;
+        state.ui_state.library_items = Self::merge_library_entries(history, scanned, sort_mode)
+            .into_iter()
+            .filter(|entry| progress_filter.matches(entry))
+            .collect();
         state.ui_state.library_selected_index = selected_key
             .and_then(|key| {
                 state
@@ -6173,6 +9138,7 @@ where
                 history_filepath: Some(item.filepath.clone()),
                 last_read: Some(item.last_read),
                 reading_progress: item.reading_progress,
+                finished: item.finished,
                 on_disk,
             });
         }
@@ -6221,6 +9187,7 @@ where
                     history_filepath: None,
                     last_read: None,
                     reading_progress: None,
+                    finished: false,
                     on_disk: true,
                 }),
             }
@@ -6276,11 +9243,13 @@ where
 
     /// Kick off a background scan of the configured library directories,
     /// following the TTS worker-thread pattern. The worker opens its own
-    /// SQLite connection and signals completion over a channel polled in the
-    /// main event loop. No-op when a scan is already running or no
-    /// directories are configured.
-    fn spawn_library_scan(&mut self) {
+    /// SQLite connection and sends the paths it could not read over a channel
+    /// polled in the main event loop; a `manual` scan (the library's `R`)
+    /// reports them. No-op when a scan is already running or no directories
+    /// are configured.
+    fn spawn_library_scan(&mut self, manual: bool) {
         if self.library_scan_rx.is_some() {
+            self.library_scan_is_manual |= manual;
             return;
         }
         let dirs = self
@@ -6295,22 +9264,134 @@ where
         }
         let (tx, rx) = std::sync::mpsc::channel();
         std::thread::spawn(move || {
+            let mut skipped = Vec::new();
             match State::new() {
-                Ok(state) => {
-                    if let Err(err) = crate::library::scan_library_directories(&dirs, &state) {
-                        logging::debug(format!("Library scan failed: {}", err));
-                    }
-                }
+                Ok(state) => match crate::library::scan_library_directories(&dirs, &state) {
+                    Ok(scan) => skipped = scan.skipped,
+                    Err(err) => logging::debug(format!("Library scan failed: {}", err)),
+                },
                 Err(err) => {
                     logging::debug(format!("Library scan could not open database: {}", err));
                 }
             }
-            let _ = tx.send(());
+            let _ = tx.send(skipped);
         });
         self.library_scan_rx = Some(rx);
+        self.library_scan_is_manual = manual;
         self.state.borrow_mut().ui_state.library_scanning = true;
     }
 
+    /// Tell the user which library paths a rescan could not read.
+    fn report_skipped_library_paths(&self, skipped: &[std::path::PathBuf]) {
+        let Some(first) = skipped.first() else {
+            return;
+        };
+        let first = crate::library::abbreviate_home(first);
+        let message = match skipped.len() {
+            1 => format!("Rescan skipped unreadable {first}"),
+            n => format!("Rescan skipped {n} unreadable paths, e.g. {first}"),
+        };
+        self.state
+            .borrow_mut()
+            .ui_state
+            .set_message(message, MessageType::Warning);
+    }
+
+    /// Archive path and raw bytes of the chapter under the reading position,
+    /// straight from the book file (EPUB content documents only).
+    fn chapter_source(&mut self) -> Option<(String, Vec<u8>)> {
+        let row = self.state.borrow().reading_state.row;
+        let content_index = self.content_index_for_row(row)?;
+        let ebook = self.ebook.as_mut()?;
+        let href = ebook.spine_href(content_index)?;
+        let (_, bytes) = ebook.get_resource(&href).ok()?;
+        Some((href, bytes))
+    }
+
+    /// Show the current chapter's markup, pretty-printed, in an overlay.
+    fn open_chapter_source_window(&mut self) {
+        let Some((href, bytes)) = self.chapter_source() else {
+            self.state.borrow_mut().ui_state.set_message(
+                "No raw source for this chapter".to_string(),
+                MessageType::Warning,
+            );
+            return;
+        };
+        let mut state = self.state.borrow_mut();
+        state.ui_state.chapter_source =
+            SourceWindow::pretty_print(&String::from_utf8_lossy(&bytes));
+        state.ui_state.chapter_source_href = href;
+        state.ui_state.open_window(WindowType::ChapterSource);
+    }
+
+    /// Open a read-only copy of the current chapter's source in `$VISUAL` or
+    /// `$EDITOR` (default `vi`), suspending the TUI while the editor runs.
+    fn open_chapter_source_in_editor(&mut self) -> eyre::Result<()> {
+        let Some((href, bytes)) = self.chapter_source() else {
+            self.state.borrow_mut().ui_state.set_message(
+                "No raw source for this chapter".to_string(),
+                MessageType::Warning,
+            );
+            return Ok(());
+        };
+        let filename = std::path::Path::new(&href)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("chapter.xhtml");
+        // A fresh, exclusively created file (keeping the chapter's name as a
+        // suffix so editors pick the right syntax), marked read-only before
+        // the editor sees it and removed once the editor exits.
+        let mut temp_file = tempfile::Builder::new()
+            .prefix("repy_src_")
+            .suffix(&format!("_{filename}"))
+            .tempfile()?;
+        io::Write::write_all(&mut temp_file, &bytes)?;
+        io::Write::flush(&mut temp_file)?;
+        let mut permissions = temp_file.as_file().metadata()?.permissions();
+        permissions.set_readonly(true);
+        temp_file.as_file().set_permissions(permissions)?;
+
+        let editor = std::env::var("VISUAL")
+            .or_else(|_| std::env::var("EDITOR"))
+            .unwrap_or_else(|_| "vi".to_string());
+        let mut words = editor.split_whitespace();
+        let program = words.next().unwrap_or("vi");
+
+        if self.alternate_screen {
+            crossterm::execute!(io::stdout(), crossterm::terminal::LeaveAlternateScreen)?;
+        }
+        crossterm::execute!(
+            io::stdout(),
+            crossterm::event::DisableMouseCapture,
+            crossterm::event::DisableBracketedPaste
+        )?;
+        crossterm::terminal::disable_raw_mode()?;
+        let status = std::process::Command::new(program)
+            .args(words)
+            .arg(temp_file.path())
+            .status();
+        if let Err(err) = temp_file.close() {
+            logging::warn(format!("Could not remove chapter source copy: {err}"));
+        }
+        crossterm::terminal::enable_raw_mode()?;
+        if self.alternate_screen {
+            crossterm::execute!(io::stdout(), crossterm::terminal::EnterAlternateScreen)?;
+        }
+        crossterm::execute!(io::stdout(), crossterm::event::EnableBracketedPaste)?;
+        if self.state.borrow().config.settings.mouse_support {
+            crossterm::execute!(io::stdout(), crossterm::event::EnableMouseCapture)?;
+        }
+        self.terminal.clear()?;
+
+        if !status.is_ok_and(|status| status.success()) {
+            self.state.borrow_mut().ui_state.set_message(
+                format!("Failed to run editor: {editor}"),
+                MessageType::Error,
+            );
+        }
+        Ok(())
+    }
+
     fn open_metadata_window(&mut self) -> eyre::Result<()> {
         let metadata = self.ebook.as_ref().map(|epub| epub.get_meta().clone());
         let filepath = self.ebook.as_ref().map(|epub| epub.path().to_string());
@@ -6318,6 +9399,7 @@ where
         state.ui_state.metadata = metadata;
         state.ui_state.metadata_filepath = filepath;
         state.ui_state.open_window(WindowType::Metadata);
+        self.metadata_cover = None;
         Ok(())
     }
 
@@ -6367,18 +9449,52 @@ where
     }
 
     // Navigation methods
-    fn move_cursor(&mut self, direction: AppDirection) {
-        let (seamless, show_top_bar) = {
+    /// Page up/down `repeat_count` times. With `page_scroll_animation` on,
+    /// a turn that stays within one chapter is queued for the run loop to
+    /// animate; chapter hops and multi-page jumps still land instantly.
+    fn turn_page(&mut self, direction: AppDirection, repeat_count: u32) {
+        let from = self.state.borrow().reading_state.row;
+        let page = self.page_size();
+        let mut turned = 0;
+        for _ in 0..repeat_count {
+            let before = self.state.borrow().reading_state.row;
+            self.move_cursor(direction.clone(), page);
+            if self.state.borrow().reading_state.row != before {
+                turned += 1;
+            }
+        }
+        let (to, animate) = {
             let state = self.state.borrow();
             (
-                state.config.settings.seamless_between_chapters,
-                state.config.settings.show_top_bar,
+                state.reading_state.row,
+                state.config.settings.page_scroll_animation,
             )
         };
+        if direction == AppDirection::PageDown {
+            self.state.borrow_mut().ui_state.session_page_turns += turned;
+        }
+        self.page_animation = None;
+        if animate
+            && from != to
+            && from.abs_diff(to) <= page
+            && self.content_index_for_row(from) == self.content_index_for_row(to)
+        {
+            self.page_animation = Some((from, to));
+        }
+    }
+
+    /// Move the reading position one step in `direction`, with pages of
+    /// `page` rows.
+    fn move_cursor(&mut self, direction: AppDirection, page: usize) {
+        let seamless = self
+            .state
+            .borrow()
+            .config
+            .settings
+            .seamless_between_chapters;
         let mut state = self.state.borrow_mut();
         let total_lines = self.board.total_lines();
         let current_row = state.reading_state.row;
-        let page = Self::page_size_for(show_top_bar);
 
         match direction {
             AppDirection::Up => {
@@ -6660,7 +9776,7 @@ where
         pos = next;
 
         while let Some(ch) = self.char_at_visual_pos(pos) {
-            if !Self::is_word_char(ch) {
+            if !Board::is_word_char(ch) {
                 break;
             }
             let Some(next) = self.next_visual_pos(pos) else {
@@ -6671,7 +9787,7 @@ where
         }
 
         while let Some(ch) = self.char_at_visual_pos(pos) {
-            if Self::is_word_char(ch) {
+            if Board::is_word_char(ch) {
                 self.set_visual_cursor_and_scroll(pos);
                 return;
             }
@@ -6692,7 +9808,7 @@ where
         pos = prev;
 
         while let Some(ch) = self.char_at_visual_pos(pos) {
-            if Self::is_word_char(ch) {
+            if Board::is_word_char(ch) {
                 break;
             }
             let Some(prev) = self.prev_visual_pos(pos) else {
@@ -6705,13 +9821,16 @@ where
             let Some(ch) = self.char_at_visual_pos(prev) else {
                 break;
             };
-            if !Self::is_word_char(ch) {
+            if !Board::is_word_char(ch) {
                 break;
             }
             pos = prev;
         }
 
-        if self.char_at_visual_pos(pos).is_some_and(Self::is_word_char) {
+        if self
+            .char_at_visual_pos(pos)
+            .is_some_and(Board::is_word_char)
+        {
             self.set_visual_cursor_and_scroll(pos);
         }
     }
@@ -6729,7 +9848,7 @@ where
 
         // Step 2: skip non-word characters (whitespace, punctuation)
         while let Some(ch) = self.char_at_visual_pos(pos) {
-            if Self::is_word_char(ch) {
+            if Board::is_word_char(ch) {
                 break;
             }
             let Some(next) = self.next_visual_pos(pos) else {
@@ -6743,7 +9862,7 @@ where
             let Some(ch) = self.char_at_visual_pos(next) else {
                 break;
             };
-            if !Self::is_word_char(ch) {
+            if !Board::is_word_char(ch) {
                 break;
             }
             pos = next;
@@ -6752,6 +9871,90 @@ where
         self.set_visual_cursor_and_scroll(pos);
     }
 
+    /// Paragraph rows (`start..end`) containing `row`, as used for TTS.
+    fn paragraph_range_at(&self, row: usize) -> Option<(usize, usize)> {
+        self.paragraph_ranges()
+            .into_iter()
+            .find(|&(start, end)| (start..end).contains(&row))
+    }
+
+    /// `ip`: select the paragraph under the cursor, first to last character.
+    fn select_inner_paragraph(&mut self) {
+        let Some((row, _)) = self.current_visual_cursor() else {
+            return;
+        };
+        let Some((start, end)) = self.paragraph_range_at(row) else {
+            return;
+        };
+        let Some(last_row) = (start..end)
+            .rev()
+            .find(|&r| self.board.line_char_count(r) > 0)
+        else {
+            return;
+        };
+        let last_col = self.board.line_char_count(last_row) - 1;
+        self.state.borrow_mut().ui_state.visual_anchor = Some((start, 0));
+        self.set_visual_cursor_and_scroll((last_row, last_col));
+    }
+
+    /// `is`: select the sentence under the cursor, using the same sentence
+    /// boundaries as the TTS chunker within the cursor's paragraph.
+    fn select_inner_sentence(&mut self) {
+        let Some((row, col)) = self.current_visual_cursor() else {
+            return;
+        };
+        let Some((start, end)) = self.paragraph_range_at(row) else {
+            return;
+        };
+
+        // Flatten the paragraph's rows, joined by one space, remembering
+        // where each character sits on screen.
+        let mut chars: Vec<char> = Vec::new();
+        let mut positions: Vec<Option<(usize, usize)>> = Vec::new();
+        let mut cursor_index = 0;
+        for r in start..end {
+            if r > start {
+                chars.push(' ');
+                positions.push(None);
+            }
+            for (c, ch) in self.board.get_line(r).unwrap_or("").chars().enumerate() {
+                if (r, c) == (row, col) {
+                    cursor_index = chars.len();
+                }
+                chars.push(ch);
+                positions.push(Some((r, c)));
+            }
+        }
+
+        let boundaries: Vec<usize> = (0..chars.len())
+            .filter_map(|i| Self::sentence_end_after(&chars, i))
+            .collect();
+        let mut first = boundaries
+            .iter()
+            .copied()
+            .rfind(|&b| b <= cursor_index)
+            .unwrap_or(0);
+        while first < chars.len() && chars[first].is_whitespace() {
+            first += 1;
+        }
+        let mut last = boundaries
+            .iter()
+            .copied()
+            .find(|&b| b > cursor_index)
+            .unwrap_or(chars.len());
+        while last > first && chars[last - 1].is_whitespace() {
+            last -= 1;
+        }
+        if first >= last {
+            return;
+        }
+        let (Some(anchor), Some(cursor)) = (positions[first], positions[last - 1]) else {
+            return;
+        };
+        self.state.borrow_mut().ui_state.visual_anchor = Some(anchor);
+        self.set_visual_cursor_and_scroll(cursor);
+    }
+
     fn move_visual_cursor_line_end(&mut self) {
         let Some((row, _)) = self.current_visual_cursor() else {
             return;
@@ -6918,18 +10121,34 @@ where
         state.ui_state.visual_cursor
     }
 
+    /// Move the cursor, scrolling so it keeps `scrolloff` lines of context
+    /// above and below it (fewer near the ends of the book).
     fn set_visual_cursor_and_scroll(&mut self, pos: (usize, usize)) {
         let (row, col) = pos;
         let page_size = self.page_size();
+        let total_lines = self.board.total_lines();
         let mut state = self.state.borrow_mut();
         state.ui_state.visual_cursor = Some((row, col));
 
+        let scrolloff = state
+            .config
+            .settings
+            .scrolloff
+            .min(page_size.saturating_sub(1) / 2);
         let viewport_start = state.reading_state.row.saturating_sub(1);
         let viewport_end = viewport_start.saturating_add(page_size);
-        if row < viewport_start {
-            state.reading_state.row = Self::row_from_start(row);
-        } else if row >= viewport_end {
-            let new_start = row.saturating_sub(page_size.saturating_sub(1));
+        if row < viewport_start + scrolloff {
+            let new_start = row.saturating_sub(scrolloff);
+            if new_start < viewport_start {
+                state.reading_state.row = Self::row_from_start(new_start);
+            }
+        } else if row + scrolloff >= viewport_end {
+            // Never scroll past the last page just to show context lines.
+            let last_start = total_lines.saturating_sub(page_size).max(viewport_start);
+            let new_start = (row + scrolloff + 1)
+                .saturating_sub(page_size)
+                .min(last_start)
+                .max(row.saturating_sub(page_size.saturating_sub(1)));
             state.reading_state.row = Self::row_from_start(new_start);
         }
     }
@@ -6977,10 +10196,6 @@ where
         self.board.get_line(row)?.chars().nth(col)
     }
 
-    fn is_word_char(ch: char) -> bool {
-        ch.is_alphanumeric() || ch == '_'
-    }
-
     fn next_chapter(&mut self) {
         let rows = self.chapter_rows();
         if rows.is_empty() {
@@ -7015,6 +10230,86 @@ where
         state.reading_state.row = 0;
     }
 
+    /// Jump to `fraction` (0.0–1.0) of the book's text.
+    fn goto_fraction(&mut self, fraction: f64) {
+        self.record_jump_position();
+        let row = self.board.row_for_fraction(fraction);
+        self.state.borrow_mut().reading_state.row = row;
+    }
+
+    /// Start row of the book's first landmark of `kind`.
+    fn landmark_start(&self, kind: &str) -> Option<usize> {
+        self.ebook
+            .as_ref()?
+            .landmarks()
+            .iter()
+            .filter(|landmark| landmark.kind == kind)
+            .find_map(|landmark| {
+                self.effective_toc_row(landmark.content_index, landmark.section.as_deref())
+            })
+    }
+
+    /// Where the book proper begins: the `bodymatter` landmark, else the
+    /// first TOC entry.
+    fn body_matter_start(&self) -> Option<usize> {
+        self.landmark_start("bodymatter").or_else(|| {
+            self.ebook.as_ref()?.toc_entries().iter().find_map(|entry| {
+                self.effective_toc_row(entry.content_index, entry.section.as_deref())
+            })
+        })
+    }
+
+    /// Jump to the book's first landmark of `kind` (`bodymatter`, `cover`),
+    /// or to the first content file when the book declares none.
+    fn goto_landmark(&mut self, kind: &str) {
+        let start = self
+            .landmark_start(kind)
+            .or_else(|| self.content_start_rows.first().copied())
+            .unwrap_or(0);
+        self.record_jump_position();
+        self.state.borrow_mut().reading_state.row = Self::row_from_start(start);
+        self.sync_reading_content_index();
+    }
+
+    /// Move the reading position to the next (or previous) paragraph start,
+    /// `count` times; stays put when there is no paragraph in that direction.
+    fn move_paragraph(&mut self, forward: bool, count: u32) {
+        let starts: Vec<usize> = self
+            .paragraph_ranges()
+            .into_iter()
+            .map(|(start, _)| start)
+            .collect();
+        let mut row = self.state.borrow().reading_state.row;
+        for _ in 0..count {
+            let target = if forward {
+                starts.iter().copied().find(|&start| start > row)
+            } else {
+                starts.iter().rev().copied().find(|&start| start < row)
+            };
+            match target {
+                Some(target) => row = target,
+                None => break,
+            }
+        }
+        self.state.borrow_mut().reading_state.row = row;
+    }
+
+    /// Jump to the `number`th chapter (1-based), clamped to the chapter count.
+    /// Books without a usable TOC number their spine documents instead.
+    fn goto_chapter_number(&mut self, number: usize) {
+        let mut rows = self.chapter_rows();
+        if rows.is_empty() {
+            rows = self.content_start_rows.clone();
+        }
+        if rows.is_empty() {
+            return;
+        }
+        let index = number.saturating_sub(1).min(rows.len() - 1);
+        self.record_jump_position();
+        let mut state = self.state.borrow_mut();
+        state.reading_state.row = rows[index];
+    }
+
     fn goto_chapter_start(&mut self) {
         let rows = self.chapter_rows();
         if rows.is_empty() {
@@ -7101,33 +10396,18 @@ where
         last_content_row.unwrap_or_else(|| next_chapter_start.saturating_sub(1))
     }
 
-    /// Pure page-size calculation; callers that already hold a borrow on `state`
-    /// should call this directly to avoid a RefCell double-borrow panic.
-    fn page_size_for(show_top_bar: bool) -> usize {
-        match crossterm::terminal::size() {
-            Ok((_cols, rows)) => {
-                let chrome: u16 = if show_top_bar {
-                    1 + 2 + 2 // top_bar + top_gap + bottom_gap
-                } else {
-                    2 // bottom_gap only
-                };
-                rows.saturating_sub(chrome) as usize
-            }
-            Err(_) => 0,
+    /// Terminal height in rows, preferring the backend's size (also
+    /// correct under TestBackend) and falling back to querying the terminal.
+    fn term_height(&self) -> u16 {
+        match self.terminal.size() {
+            Ok(size) => size.height,
+            Err(_) => crossterm::terminal::size().map_or(0, |(_, rows)| rows),
         }
     }
 
+    /// Text rows on the current page; see [`page_rows`].
     fn page_size(&self) -> usize {
-        let show_top_bar = self.state.borrow().config.settings.show_top_bar;
-        // Prefer the backend's size (also correct under TestBackend);
-        // fall back to querying the terminal directly.
-        match self.terminal.size() {
-            Ok(size) => {
-                let chrome: u16 = if show_top_bar { 1 + 2 + 2 } else { 2 };
-                size.height.saturating_sub(chrome) as usize
-            }
-            Err(_) => Self::page_size_for(show_top_bar),
-        }
+        page_rows(self.term_height(), &self.state.borrow().config.settings)
     }
 
     /// Terminal width in columns, preferring the backend's size (also
@@ -7142,6 +10422,31 @@ where
         }
     }
 
+    /// Terminal columns left for the reading area once the configured
+    /// `left_margin`/`right_margin` are set aside.
+    fn reading_area_width(&self) -> usize {
+        let margins = {
+            let settings = &self.state.borrow().config.settings;
+            let border = if settings.content_border { 2 } else { 0 };
+            settings.left_margin + settings.right_margin + border
+        };
+        self.term_width().saturating_sub(margins)
+    }
+
+    /// How far the widest line on the current page overflows the reading
+    /// area, i.e. the furthest `<` / `>` can usefully scroll.
+    fn max_horizontal_scroll(&self) -> usize {
+        let page_size = self.page_size();
+        let area_width = self.reading_area_width();
+        let state = self.state.borrow();
+        self.board.max_horizontal_scroll(
+            &state,
+            Some(&self.content_start_rows),
+            area_width,
+            page_size,
+        )
+    }
+
     /// Row cap for inline image blocks, or `None` when the setting keeps
     /// one-line placeholders.
     fn inline_image_max_rows(&self) -> Option<usize> {
@@ -7151,22 +10456,196 @@ where
         }
     }
 
-    fn typography_options(&self) -> TypographyOptions {
-        let settings = &self.state.borrow().config.settings;
-        TypographyOptions {
-            paragraph_style: settings.paragraph_style,
-            line_spacing: settings.line_spacing,
-            justify: settings.justify_text,
-        }
+    fn typography_options(&self) -> TypographyOptions {
+        let settings = &self.state.borrow().config.settings;
+        TypographyOptions {
+            paragraph_style: settings.paragraph_style,
+            line_spacing: settings.line_spacing,
+            justify: settings.justify_text,
+        }
+    }
+
+    fn chapter_break_options(&self) -> ChapterBreakOptions {
+        let settings = &self.state.borrow().config.settings;
+        ChapterBreakOptions {
+            blank_lines: settings.chapter_break_blank_lines,
+            title_banner: settings.chapter_break_title,
+        }
+    }
+
+    fn chapter_break_page_height(&self) -> Option<usize> {
+        let state = self.state.borrow();
+        if state.config.settings.seamless_between_chapters {
+            None
+        } else {
+            Some(self.page_size())
+        }
+    }
+
+    /// Start or pause the `a` slideshow, which turns a page every
+    /// `slideshow_interval_secs` until the end of the book.
+    fn toggle_slideshow(&mut self) {
+        let mut state = self.state.borrow_mut();
+        let secs = state.config.settings.slideshow_interval_secs;
+        let (message, message_type) = if secs == 0 {
+            (
+                "Set a slideshow interval in Settings first".to_string(),
+                MessageType::Warning,
+            )
+        } else if state.ui_state.slideshow_next.take().is_some() {
+            ("Slideshow paused".to_string(), MessageType::Info)
+        } else {
+            state.ui_state.slideshow_next = Some(Instant::now() + Duration::from_secs(secs));
+            (
+                format!("Slideshow: every {secs}s (+/- to adjust, a to pause)"),
+                MessageType::Info,
+            )
+        };
+        state.ui_state.set_message(message, message_type);
+    }
+
+    fn adjust_slideshow_interval(&mut self, delta: i64) {
+        let mut state = self.state.borrow_mut();
+        let secs = state
+            .config
+            .settings
+            .slideshow_interval_secs
+            .saturating_add_signed(delta)
+            .max(1);
+        state.config.settings.slideshow_interval_secs = secs;
+        state.ui_state.slideshow_next = Some(Instant::now() + Duration::from_secs(secs));
+        state
+            .ui_state
+            .set_message(format!("Slideshow: every {secs}s"), MessageType::Info);
+    }
+
+    /// Turn the page when the slideshow is due; stop at the last page.
+    fn tick_slideshow(&mut self) -> eyre::Result<()> {
+        let due = self
+            .state
+            .borrow()
+            .ui_state
+            .slideshow_next
+            .is_some_and(|next| Instant::now() >= next);
+        if !due {
+            return Ok(());
+        }
+        let previous_row = self.state.borrow().reading_state.row;
+        self.turn_page(AppDirection::PageDown, 1);
+        self.record_reading_activity(previous_row)?;
+        let mut state = self.state.borrow_mut();
+        if state.reading_state.row <= previous_row {
+            state.ui_state.slideshow_next = None;
+            state
+                .ui_state
+                .set_message("Slideshow finished".to_string(), MessageType::Info);
+        } else {
+            let secs = state.config.settings.slideshow_interval_secs;
+            state.ui_state.slideshow_next = Some(Instant::now() + Duration::from_secs(secs));
+        }
+        Ok(())
+    }
+
+    /// Append the visible page to the `capture_file` setting.
+    fn capture_visible_page(&mut self) {
+        let Some(path) = self.state.borrow().config.settings.capture_file.clone() else {
+            self.state.borrow_mut().ui_state.set_message(
+                "Set capture_file to capture pages".to_string(),
+                MessageType::Warning,
+            );
+            return;
+        };
+        let (start, end) = self.visible_line_range();
+        let lines: Vec<&str> = (start..end)
+            .filter_map(|row| self.board.get_line(row))
+            .filter(|line| *line != CHAPTER_BREAK_MARKER)
+            .collect();
+        let row = self.state.borrow().reading_state.row;
+        let chapter = self.toc_label_for_row(row);
+        let percent = (self.board.content_fraction(row) * 100.0).round() as u32;
+        let capture = format_page_capture(chapter.as_deref(), percent, &lines);
+
+        let path = crate::library::expand_tilde(&path);
+        let result = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|file| {
+                let mut writer = io::BufWriter::new(file);
+                io::Write::write_all(&mut writer, capture.as_bytes())?;
+                io::Write::flush(&mut writer)
+            });
+        let (message, message_type) = match result {
+            Ok(()) => (
+                format!("Page captured to {}", path.display()),
+                MessageType::Info,
+            ),
+            Err(err) => (format!("Capture failed: {err}"), MessageType::Error),
+        };
+        self.state
+            .borrow_mut()
+            .ui_state
+            .set_message(message, message_type);
+    }
+
+    /// Where the reader is, for status bars: the reading state plus chapter,
+    /// percent, and the text on screen. `None` before a book is open.
+    pub fn reading_snapshot(&self) -> Option<ReadingSnapshot> {
+        let ebook = self.ebook.as_ref()?;
+        let meta = ebook.get_meta();
+        let (start, end) = self.visible_line_range();
+        let visible_text = (start..end)
+            .filter_map(|row| self.board.get_line(row))
+            .filter(|line| *line != CHAPTER_BREAK_MARKER)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let reading_state = self.state.borrow().reading_state.clone();
+        let row = reading_state.row;
+        Some(ReadingSnapshot {
+            file: ebook.path().to_string(),
+            title: meta.title.clone(),
+            author: meta.creator.clone(),
+            chapter: self.toc_label_for_row(row),
+            percent: (self.board.content_fraction(row) * 100.0).round() as u32,
+            visible_text: visible_text.trim_end().to_string(),
+            reading_state,
+        })
     }
 
-    fn chapter_break_page_height(&self) -> Option<usize> {
-        let state = self.state.borrow();
-        if state.config.settings.seamless_between_chapters {
-            None
-        } else {
-            Some(self.page_size())
-        }
+    /// `J`: write [`Self::reading_snapshot`] to `state_file`, replacing its
+    /// contents, or to `reading-state.json` in the config directory when it
+    /// is unset. Nothing else changes, not even the saved position.
+    fn write_state_snapshot(&mut self) -> eyre::Result<()> {
+        let Some(snapshot) = self.reading_snapshot() else {
+            return Ok(());
+        };
+        let json = serde_json::to_string_pretty(&snapshot)?;
+        let configured = self.state.borrow().config.settings.state_file.clone();
+        let path = match configured {
+            Some(path) => Ok(crate::library::expand_tilde(&path)),
+            None => {
+                crate::config::get_app_data_prefix().map(|prefix| prefix.join(DEFAULT_STATE_FILE))
+            }
+        };
+        let written = path.and_then(|path| {
+            std::fs::write(&path, format!("{json}\n"))?;
+            Ok(path)
+        });
+        let (message, message_type) = match written {
+            Ok(path) => (
+                format!("Reading state written to {}", path.display()),
+                MessageType::Info,
+            ),
+            Err(err) => (
+                format!("Writing reading state failed: {err}"),
+                MessageType::Error,
+            ),
+        };
+        self.state
+            .borrow_mut()
+            .ui_state
+            .set_message(message, message_type);
+        Ok(())
     }
 
     fn visible_line_range(&self) -> (usize, usize) {
@@ -7521,7 +11000,7 @@ where
             return;
         }
 
-        let regex = match Regex::new(&query) {
+        let regex = match self.compile_search_query(&query) {
             Ok(regex) => regex,
             Err(err) => {
                 let mut state = self.state.borrow_mut();
@@ -7565,33 +11044,58 @@ where
         }
     }
 
+    /// Compile the search `query`, folded like the text when
+    /// `normalize_search` is on so that `don't` finds `don’t`.
+    fn compile_search_query(&self, query: &str) -> Result<Regex, regex::Error> {
+        if self.state.borrow().config.settings.normalize_search
+            && let Some((folded, _)) = fold_search_text(query)
+        {
+            return Regex::new(&folded);
+        }
+        Regex::new(query)
+    }
+
     /// Scan canonical chapter source for `regex`, returning one result per
     /// source hit and character-column highlight ranges for every touched row.
+    /// With `normalize_search` on, the regex runs over the folded text and
+    /// hits are mapped back onto the original characters.
     fn scan_search_matches(&self, regex: &Regex) -> Vec<SearchResult> {
         let mut results = Vec::new();
+        let scope = self.search_scope_bounds();
+        let search_placeholders = self.state.borrow().config.settings.search_placeholders;
+        let normalize = self.state.borrow().config.settings.normalize_search;
         for (content_index, chapter) in self.chapter_text_structures.iter().enumerate() {
             let Some(&chapter_start) = self.content_start_rows.get(content_index) else {
                 continue;
             };
+            if scope.is_some_and(|(start, end)| chapter_start < start || chapter_start > end) {
+                continue;
+            }
             let source_map = &chapter.source_map;
+            let folded = normalize
+                .then(|| fold_search_text(&source_map.source_text))
+                .flatten();
+            let (haystack, origin) = match &folded {
+                Some((text, origin)) => (text.as_str(), Some(origin)),
+                None => (source_map.source_text.as_str(), None),
+            };
             let mut byte_cursor = 0;
             let mut char_cursor = 0;
-            for mat in regex.find_iter(&source_map.source_text) {
-                char_cursor += source_map.source_text[byte_cursor..mat.start()]
-                    .chars()
-                    .count();
-                let source_start = char_cursor;
-                let source_end = source_start
-                    + source_map.source_text[mat.start()..mat.end()]
-                        .chars()
-                        .count();
+            for mat in regex.find_iter(haystack) {
+                char_cursor += haystack[byte_cursor..mat.start()].chars().count();
+                let match_start = char_cursor;
+                let match_end = match_start + haystack[mat.start()..mat.end()].chars().count();
                 byte_cursor = mat.end();
-                char_cursor = source_end;
+                char_cursor = match_end;
                 // `regex::find_iter` advances after empty matches, but empty
                 // source ranges cannot produce a useful rendered highlight.
                 if mat.is_empty() {
                     continue;
                 }
+                let (source_start, source_end) = match origin {
+                    Some(origin) => (origin[match_start], origin[match_end - 1] + 1),
+                    None => (match_start, match_end),
+                };
                 let first_row = source_map.row_for_offset(source_start);
                 let last_row = source_map.row_for_offset(source_end - 1);
                 let mut per_row = Vec::new();
@@ -7627,11 +11131,24 @@ where
                     per_row.push((chapter_start + local_row, range.0, range.1));
                 }
 
+                // Placeholder rows are not prose; keep only hits on text
+                // unless the reader opted in.
+                if !search_placeholders {
+                    per_row.retain(|&(row, _, _)| {
+                        self.board
+                            .get_line(row)
+                            .is_some_and(|line| self.is_text_row(row, line))
+                    });
+                }
                 if per_row.is_empty() {
                     continue;
                 }
                 results.push(SearchResult {
                     preview: Self::search_preview(source_map, source_start, source_end),
+                    context_before: first_row
+                        .checked_sub(1)
+                        .and_then(|row| Self::search_context_line(chapter, row)),
+                    context_after: Self::search_context_line(chapter, last_row + 1),
                     content_index,
                     source_start,
                     source_end,
@@ -7642,6 +11159,18 @@ where
         results
     }
 
+    /// Chapter-local `row` as a context line, or `None` past the chapter's
+    /// ends, on a blank row, or on the chapter-break marker.
+    fn search_context_line(chapter: &TextStructure, row: usize) -> Option<String> {
+        let line = chapter.text_lines.get(row)?;
+        if line == crate::models::CHAPTER_BREAK_MARKER {
+            return None;
+        }
+        // Collapse justification gaps, as the preview does.
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        (!line.is_empty()).then_some(line)
+    }
+
     fn search_preview(source_map: &SourceMap, start: usize, end: usize) -> String {
         let chars: Vec<char> = source_map.source_text.chars().collect();
         let mut preview_start = start.min(chars.len());
@@ -7668,6 +11197,16 @@ where
             .join(" ")
     }
 
+    /// Row bounds of the chapter a chapter-scoped search is restricted to.
+    fn search_scope_bounds(&self) -> Option<(usize, usize)> {
+        let state = self.state.borrow();
+        if !state.ui_state.search_chapter_only {
+            return None;
+        }
+        let index = self.content_index_for_row(state.ui_state.search_origin_row)?;
+        self.chapter_bounds_for_index(index)
+    }
+
     /// Re-run the search as the query is typed. Invalid (possibly partial)
     /// regexes clear the matches without an error message; an empty query
     /// restores the pre-search view.
@@ -7684,7 +11223,7 @@ where
             return;
         }
 
-        let Ok(regex) = Regex::new(&query) else {
+        let Ok(regex) = self.compile_search_query(&query) else {
             let mut state = self.state.borrow_mut();
             state.ui_state.clear_search_results();
             return;
@@ -7762,6 +11301,7 @@ where
                 .unwrap_or(0)
         };
         state.ui_state.selected_search_result = next;
+        state.ui_state.search_highlights_hidden = false;
         let total = state.ui_state.search_results.len();
         state
             .ui_state
@@ -7804,6 +11344,7 @@ where
                 .unwrap_or(len - 1)
         };
         state.ui_state.selected_search_result = prev;
+        state.ui_state.search_highlights_hidden = false;
         state
             .ui_state
             .set_message(format!("Match {}/{}", prev + 1, len), MessageType::Info);
@@ -8160,6 +11701,44 @@ where
         Ok(())
     }
 
+    /// `x` in the library window: flip the selected book's finished flag.
+    /// Only books with reading history have a library row to carry it.
+    fn toggle_selected_library_finished(&mut self) -> eyre::Result<()> {
+        let mut state = self.state.borrow_mut();
+        let Some(entry) = state
+            .ui_state
+            .selected_list_index(state.ui_state.library_selected_index)
+            .and_then(|i| state.ui_state.library_items.get_mut(i))
+        else {
+            return Ok(());
+        };
+        let Some(path) = entry.history_filepath.clone() else {
+            state.ui_state.set_message(
+                "Open the book once before marking it finished".to_string(),
+                MessageType::Info,
+            );
+            return Ok(());
+        };
+        entry.finished = !entry.finished;
+        let finished = entry.finished;
+        self.db_state.set_library_finished(&path, finished)?;
+        state.ui_state.set_message(
+            if finished {
+                "Marked as finished"
+            } else {
+                "Marked as not finished"
+            }
+            .to_string(),
+            MessageType::Info,
+        );
+        // The book may no longer belong in a progress-filtered view.
+        if state.ui_state.library_progress_filter != LibraryProgressFilter::All {
+            drop(state);
+            self.rebuild_library_entries()?;
+        }
+        Ok(())
+    }
+
     /// Filepath of the library entry under the cursor (filter-aware).
     fn selected_library_path(&self) -> Option<String> {
         let state = self.state.borrow();
@@ -8170,6 +11749,48 @@ where
             .map(|item| item.filepath.clone())
     }
 
+    /// `o` in the library window: show the folder holding the selected book
+    /// in the system file manager. Without an opener, the folder path is
+    /// copied instead, mirroring how external links degrade.
+    fn reveal_selected_library_item(&mut self) -> eyre::Result<()> {
+        let Some(path) = self.selected_library_path() else {
+            return Ok(());
+        };
+        let Some(folder) = std::path::Path::new(&path)
+            .parent()
+            .filter(|dir| dir.is_dir())
+            .map(|dir| dir.to_string_lossy().into_owned())
+        else {
+            self.state.borrow_mut().ui_state.set_message(
+                "Containing folder no longer exists".to_string(),
+                MessageType::Warning,
+            );
+            return Ok(());
+        };
+
+        match self.open_folder(&folder) {
+            Ok(true) => {
+                self.state
+                    .borrow_mut()
+                    .ui_state
+                    .set_message("Opened containing folder".to_string(), MessageType::Info);
+            }
+            Ok(false) | Err(_) => {
+                let copied = self.set_clipboard_text(folder);
+                let message = if copied {
+                    "No file manager available; folder path copied"
+                } else {
+                    "No file manager available; clipboard unavailable"
+                };
+                self.state
+                    .borrow_mut()
+                    .ui_state
+                    .set_message(message.to_string(), MessageType::Warning);
+            }
+        }
+        Ok(())
+    }
+
     /// `m` in the library window: import the selected book into Calibre in
     /// the background. [`Self::poll_calibre_import`] finishes the move.
     fn move_selected_library_book_to_calibre(&mut self) -> eyre::Result<()> {
@@ -8274,7 +11895,7 @@ where
                     crate::library::abbreviate_home(std::path::Path::new(&new_path))
                 );
             }
-            self.spawn_library_scan();
+            self.spawn_library_scan(false);
             self.rebuild_library_entries()?;
         }
         self.state
@@ -8458,6 +12079,30 @@ where
         }
     }
 
+    /// Decodes the open book's cover for the Metadata window. Runs after
+    /// [`Self::poll_library_cover`] so the first frame showing the cover gets
+    /// its follow-up redraw. Without a graphics protocol nothing is loaded.
+    fn poll_metadata_cover(&mut self) {
+        if !self.state.borrow().ui_state.show_metadata {
+            self.metadata_cover = None;
+            return;
+        }
+        if self.metadata_cover.is_some() {
+            return;
+        }
+        let protocol = if self.graphics.is_available() {
+            self.ebook
+                .as_mut()
+                .and_then(|book| book.get_cover())
+                .and_then(|(_mime, bytes)| image::load_from_memory(&bytes).ok())
+                .and_then(|img| self.graphics.new_protocol(img))
+        } else {
+            None
+        };
+        self.library_cover_redraw_pending = protocol.is_some();
+        self.metadata_cover = Some(protocol);
+    }
+
     fn open_selected_library_item(&mut self) -> eyre::Result<()> {
         let filepath = self.selected_library_path();
         if let Some(path) = filepath {
@@ -8471,6 +12116,10 @@ where
                 self.load_ebook(&path)?;
                 let mut state = self.state.borrow_mut();
                 state.ui_state.open_window(WindowType::Reader);
+            } else if let Some(moved_to) = self.find_moved_book(&path) {
+                let mut state = self.state.borrow_mut();
+                state.ui_state.pending_relink = Some((path, moved_to));
+                state.ui_state.open_window(WindowType::ConfirmRelink);
             } else {
                 let mut state = self.state.borrow_mut();
                 state.ui_state.set_message(
@@ -8482,22 +12131,43 @@ where
         Ok(())
     }
 
+    /// Look for a missing book under the other paths the state knows about
+    /// (scanned library directories and history), matching by the content
+    /// hash recorded when it was last opened.
+    fn find_moved_book(&self, missing_path: &str) -> Option<String> {
+        let content_hash = self
+            .db_state
+            .library_content_hash(missing_path)
+            .ok()
+            .flatten()?;
+        let candidates = self
+            .db_state
+            .relink_candidate_paths(missing_path)
+            .unwrap_or_default();
+        candidates.into_iter().find(|candidate| {
+            std::path::Path::new(candidate).is_file()
+                && sync::document_id(candidate).is_ok_and(|hash| hash == content_hash)
+        })
+    }
+
     /// Extract the selected image's source path, MIME type, and raw bytes,
     /// reporting extraction failures as a status message.
     fn selected_image_data(&mut self) -> Option<(String, String, Vec<u8>)> {
-        let src = {
+        let (row, src) = {
             let state = self.state.borrow();
             state
                 .ui_state
                 .images_list
                 .get(state.ui_state.images_selected_index)
-                .map(|(_, src)| src.clone())
+                .map(|image| (image.row, image.src.clone()))
         }?;
+        // Resolve relative to the chapter the image is in, which the
+        // book-wide list does not share with the current position.
+        let content_index = self
+            .content_index_for_row(row)
+            .unwrap_or_else(|| self.state.borrow().reading_state.content_index);
         let epub = self.ebook.as_mut()?;
-
-        // Resolve relative path
-        let current_index = self.state.borrow().reading_state.content_index;
-        let base_path = epub.spine_href(current_index);
+        let base_path = epub.spine_href(content_index);
         let resolved_path = if let Some(base) = base_path {
             Self::resolve_relative_href(&src, Some(&base)).unwrap_or(src.clone())
         } else {
@@ -8631,6 +12301,10 @@ where
                 // terminal is too narrow to absorb it in the margins.
                 rebuild_chapter_breaks = true;
             }
+            SettingItem::LineNumberStyle => {
+                state.config.settings.line_number_style =
+                    state.config.settings.line_number_style.next();
+            }
             SettingItem::MouseSupport => {
                 state.config.settings.mouse_support = !state.config.settings.mouse_support;
                 // Apply immediately so the toggle works without a restart.
@@ -8640,6 +12314,11 @@ where
                     crossterm::execute!(io::stdout(), crossterm::event::DisableMouseCapture)?;
                 }
             }
+            SettingItem::CountPrefix => {
+                state.config.settings.count_prefix_enabled =
+                    !state.config.settings.count_prefix_enabled;
+                state.count_prefix.clear();
+            }
             SettingItem::PageScrollAnimation => {
                 state.config.settings.page_scroll_animation =
                     !state.config.settings.page_scroll_animation;
@@ -8653,6 +12332,21 @@ where
                     !state.config.settings.seamless_between_chapters;
                 rebuild_chapter_breaks = true;
             }
+            SettingItem::ChapterBreakBlankLines => {
+                state.config.settings.chapter_break_blank_lines =
+                    (state.config.settings.chapter_break_blank_lines + 1) % 4;
+                rebuild_chapter_breaks = true;
+            }
+            SettingItem::ChapterBreakRule => {
+                // Drawn by the board; the row layout is unchanged.
+                state.config.settings.chapter_break_rule =
+                    !state.config.settings.chapter_break_rule;
+            }
+            SettingItem::ChapterBreakTitle => {
+                state.config.settings.chapter_break_title =
+                    !state.config.settings.chapter_break_title;
+                rebuild_chapter_breaks = true;
+            }
             SettingItem::InlineImages => {
                 state.config.settings.inline_images = state.config.settings.inline_images.next();
                 // The rebuild notices the inline-image mismatch and
@@ -8672,6 +12366,84 @@ where
                 state.config.settings.justify_text = !state.config.settings.justify_text;
                 rebuild_chapter_breaks = true;
             }
+            SettingItem::FocusMode => {
+                state.config.settings.focus_mode = !state.config.settings.focus_mode;
+            }
+            SettingItem::FocusLines => {
+                state.config.settings.focus_lines = (state.config.settings.focus_lines + 1) % 5;
+            }
+            SettingItem::BionicReading => {
+                state.config.settings.bionic_reading = !state.config.settings.bionic_reading;
+            }
+            SettingItem::ResumePrompt => {
+                state.config.settings.resume_prompt = !state.config.settings.resume_prompt;
+            }
+            SettingItem::SkipFrontMatter => {
+                state.config.settings.skip_front_matter = !state.config.settings.skip_front_matter;
+            }
+            SettingItem::AutoMarkFinished => {
+                state.config.settings.auto_mark_finished =
+                    !state.config.settings.auto_mark_finished;
+            }
+            SettingItem::SlideshowInterval => {
+                let current = state.config.settings.slideshow_interval_secs;
+                state.config.settings.slideshow_interval_secs = SLIDESHOW_INTERVALS
+                    .into_iter()
+                    .find(|&secs| secs > current)
+                    .unwrap_or(0);
+                if state.config.settings.slideshow_interval_secs == 0 {
+                    state.ui_state.slideshow_next = None;
+                }
+            }
+            SettingItem::SyncSidecar => {
+                state.config.settings.sync_sidecar = !state.config.settings.sync_sidecar;
+            }
+            SettingItem::AutosaveInterval => {
+                let current = state.config.settings.autosave_interval_secs;
+                state.config.settings.autosave_interval_secs = AUTOSAVE_INTERVALS
+                    .into_iter()
+                    .find(|&secs| secs > current)
+                    .unwrap_or(0);
+            }
+            SettingItem::SessionLimitMinutes => {
+                let current = state.config.settings.session_limit_minutes;
+                state.config.settings.session_limit_minutes = SESSION_LIMIT_MINUTES
+                    .into_iter()
+                    .find(|&minutes| minutes > current)
+                    .unwrap_or(0);
+            }
+            SettingItem::SessionLimitPages => {
+                let current = state.config.settings.session_limit_pages;
+                state.config.settings.session_limit_pages = SESSION_LIMIT_PAGES
+                    .into_iter()
+                    .find(|&pages| pages > current)
+                    .unwrap_or(0);
+            }
+            SettingItem::SessionLimitQuit => {
+                state.config.settings.session_limit_quit =
+                    !state.config.settings.session_limit_quit;
+            }
+            SettingItem::SearchPlaceholders => {
+                state.config.settings.search_placeholders =
+                    !state.config.settings.search_placeholders;
+            }
+            SettingItem::Hlsearch => {
+                state.config.settings.hlsearch = !state.config.settings.hlsearch;
+            }
+            SettingItem::NormalizeSearch => {
+                state.config.settings.normalize_search = !state.config.settings.normalize_search;
+            }
+            SettingItem::OpenLinkedBooks => {
+                state.config.settings.open_linked_books = !state.config.settings.open_linked_books;
+            }
+            SettingItem::AggregateDictionaries => {
+                state.config.settings.aggregate_dictionaries =
+                    !state.config.settings.aggregate_dictionaries;
+            }
+            SettingItem::AsciiWikipedia => {
+                state.config.settings.ascii_wikipedia =
+                    state.config.settings.ascii_wikipedia.next();
+            }
             SettingItem::DictionaryClient => {
                 let current = if state.config.settings.dictionary_client.trim().is_empty() {
                     "auto"
@@ -8679,26 +12451,45 @@ where
                     state.config.settings.dictionary_client.trim()
                 };
                 let options: Vec<&str> = std::iter::once("auto")
-                    .chain(DICT_PRESET_LIST.iter().copied())
+                    .chain(
+                        state
+                            .config
+                            .settings
+                            .dictionary_presets
+                            .iter()
+                            .map(|preset| preset.name.as_str()),
+                    )
                     .collect();
                 let current_index = options.iter().position(|v| *v == current).unwrap_or(0);
                 let next_index = (current_index + 1) % options.len();
                 state.config.settings.dictionary_client = options[next_index].to_string();
             }
-            SettingItem::TtsEngine => {
+            SettingItem::DefaultTtsEngine => {
                 let current = state
                     .config
                     .settings
                     .preferred_tts_engine
                     .as_deref()
-                    .unwrap_or("")
-                    .to_string();
-                let current_ref = if current.is_empty() { "purr" } else { &current };
-                use crate::settings::TTS_PRESET_LIST;
-                let options: Vec<&str> = TTS_PRESET_LIST.to_vec();
-                let current_index = options.iter().position(|v| *v == current_ref).unwrap_or(0);
-                let next_index = (current_index + 1) % options.len();
-                state.config.settings.preferred_tts_engine = Some(options[next_index].to_string());
+                    .unwrap_or("purr");
+                state.config.settings.preferred_tts_engine = Some(next_tts_preset(current));
+            }
+            SettingItem::TtsEngine => {
+                let next = next_tts_preset(state.effective_tts_engine().unwrap_or("purr"));
+                // With a book open the choice is remembered for that book
+                // only; landing on the global engine drops the book's choice.
+                if self.ebook.is_some() {
+                    let global = state
+                        .config
+                        .settings
+                        .preferred_tts_engine
+                        .as_deref()
+                        .unwrap_or("purr");
+                    state.reading_state.tts_engine = (next != global).then_some(next);
+                    drop(state);
+                    self.persist_state()?;
+                    return Ok(());
+                }
+                state.config.settings.preferred_tts_engine = Some(next);
             }
             SettingItem::Width => {
                 let textwidth = state.config.settings.width.unwrap_or(DEFAULT_TEXT_WIDTH);
@@ -8710,6 +12501,26 @@ where
             SettingItem::ShowTopBar => {
                 state.config.settings.show_top_bar = !state.config.settings.show_top_bar;
             }
+            SettingItem::ContentBorder => {
+                state.config.settings.content_border = !state.config.settings.content_border;
+                // The border's two columns can narrow the wrap width.
+                rebuild_chapter_breaks = true;
+            }
+            SettingItem::ShowChapterTitle => {
+                state.config.settings.show_chapter_title =
+                    !state.config.settings.show_chapter_title;
+            }
+            SettingItem::ShowEta => {
+                state.config.settings.show_eta = !state.config.settings.show_eta;
+            }
+            SettingItem::SetTerminalTitle => {
+                state.config.settings.set_terminal_title =
+                    !state.config.settings.set_terminal_title;
+            }
+            SettingItem::PreferEpubPageNumbers => {
+                state.config.settings.prefer_epub_page_numbers =
+                    !state.config.settings.prefer_epub_page_numbers;
+            }
             SettingItem::ColorTheme => {
                 drop(state);
                 self.cycle_color_theme()?;
@@ -8718,7 +12529,23 @@ where
             SettingItem::KosyncServer
             | SettingItem::KosyncUsername
             | SettingItem::KosyncPassword
-            | SettingItem::OpdsDownloadDirectory => return Ok(()),
+            | SettingItem::OpdsDownloadDirectory
+            | SettingItem::ClipboardCommand
+            | SettingItem::TtsRate => return Ok(()),
+            SettingItem::TtsReadImages => {
+                state.config.settings.tts_read_images = !state.config.settings.tts_read_images;
+            }
+            SettingItem::TtsAnnounceChapters => {
+                state.config.settings.tts_announce_chapters =
+                    !state.config.settings.tts_announce_chapters;
+            }
+            SettingItem::TtsStopAtChapter => {
+                state.config.settings.tts_stop_at_chapter =
+                    !state.config.settings.tts_stop_at_chapter;
+            }
+            SettingItem::PlayNarration => {
+                state.config.settings.play_narration = !state.config.settings.play_narration;
+            }
             SettingItem::OpdsAddToCalibre => {
                 state.config.settings.opds_add_to_calibre =
                     !state.config.settings.opds_add_to_calibre;
@@ -8768,17 +12595,27 @@ where
         self.persist_state()
     }
 
-    fn adjust_textwidth(&mut self, delta: i32) -> eyre::Result<()> {
+    /// Step the selected numeric setting up or down: text width by 5
+    /// columns, TTS rate by 10 words per minute.
+    fn adjust_selected_setting(&mut self, direction: i32) -> eyre::Result<()> {
         let selected = {
             let state = self.state.borrow();
             SettingItem::all()
                 .get(state.ui_state.settings_selected_index)
                 .copied()
         };
-        if selected != Some(SettingItem::Width) {
-            return Ok(());
+        match selected {
+            Some(SettingItem::Width) => self.change_textwidth(5 * direction),
+            Some(SettingItem::TtsRate) => {
+                let mut state = self.state.borrow_mut();
+                let current = state.config.settings.tts_rate.unwrap_or(DEFAULT_TTS_RATE) as i32;
+                let rate = (current + 10 * direction).clamp(MIN_TTS_RATE, MAX_TTS_RATE) as u32;
+                state.config.settings.tts_rate = Some(rate);
+                state.save_config()?;
+                Ok(())
+            }
+            _ => Ok(()),
         }
-        self.change_textwidth(delta)
     }
 
     fn reset_selected_setting(&mut self) -> eyre::Result<()> {
@@ -8857,6 +12694,44 @@ where
                         .set_message("Justify text reset to false".to_string(), MessageType::Info);
                 }
             }
+            Some(SettingItem::DefaultTtsEngine) => {
+                let mut state = self.state.borrow_mut();
+                state.config.settings.preferred_tts_engine =
+                    Settings::default().preferred_tts_engine;
+                if state.save_config()? {
+                    let engine = state.config.settings.preferred_tts_engine.clone();
+                    state.ui_state.set_message(
+                        format!(
+                            "Default TTS engine reset to {}",
+                            engine.as_deref().unwrap_or("purr")
+                        ),
+                        MessageType::Info,
+                    );
+                }
+            }
+            Some(SettingItem::TtsEngine) => {
+                let had_override = self.state.borrow().reading_state.tts_engine.is_some();
+                if had_override {
+                    self.state.borrow_mut().reading_state.tts_engine = None;
+                    self.persist_state()?;
+                    let mut state = self.state.borrow_mut();
+                    let engine = state.effective_tts_engine().unwrap_or("purr").to_string();
+                    state.ui_state.set_message(
+                        format!("TTS engine reset to global ({engine})"),
+                        MessageType::Info,
+                    );
+                }
+            }
+            Some(SettingItem::TtsRate) => {
+                let mut state = self.state.borrow_mut();
+                state.config.settings.tts_rate = None;
+                if state.save_config()? {
+                    state.ui_state.set_message(
+                        "TTS rate reset to engine default".to_string(),
+                        MessageType::Info,
+                    );
+                }
+            }
             Some(SettingItem::ColorTheme) => {
                 let saved = self.set_effective_color_theme(None)?;
                 let theme_name = self.state.borrow().effective_color_theme().name();
@@ -8887,6 +12762,11 @@ where
                 state.config.settings.opds_download_directory = None;
                 state.save_config()?;
             }
+            Some(SettingItem::ClipboardCommand) => {
+                let mut state = self.state.borrow_mut();
+                state.config.settings.clipboard_command = None;
+                state.save_config()?;
+            }
             _ => {}
         }
         Ok(())
@@ -8910,12 +12790,13 @@ where
                 !state.ui_state.highlights.is_empty(),
             )
         };
-        let text_width = compute_wrap_width(self.term_width(), textwidth, gutter_width);
+        let text_width = compute_wrap_width(self.reading_area_width(), textwidth, gutter_width);
 
         // Collect page_height and inline options before any mutable borrows
         let page_height = self.chapter_break_page_height();
         let inline_image_rows = self.inline_image_max_rows();
         let typography = self.typography_options();
+        let chapter_break = self.chapter_break_options();
 
         let epub = match self.ebook.as_mut() {
             Some(epub) => epub,
@@ -8925,7 +12806,8 @@ where
         // Check if we need to rebuild or if width is the same
         let needs_rebuild = self.current_text_width != Some(text_width);
 
-        let typography_changed = typography != self.current_typography;
+        let typography_changed =
+            typography != self.current_typography || chapter_break != self.current_chapter_break;
         if inline_image_rows != self.current_inline_image_rows || typography_changed {
             // The inline-image layout changed: every chapter's rows are
             // stale, so re-parse the whole book.
@@ -8935,10 +12817,13 @@ where
                 page_height,
                 inline_image_rows,
                 typography,
+                chapter_break,
             )?;
             self.current_text_width = Some(text_width);
             self.current_inline_image_rows = inline_image_rows;
             self.current_typography = typography;
+            self.current_chapter_break = chapter_break;
+            self.chapter_wrap_cache.clear();
         } else if needs_rebuild {
             // Only re-parse the current chapter for performance
             let total_chapters = epub.contents().len();
@@ -8952,27 +12837,62 @@ where
                     0
                 };
 
-                // Parse only the current chapter with new width
-                let mut parsed_chapter = renderer::parse_chapter_with_typography(
-                    epub.as_mut(),
-                    current_chapter_idx,
-                    text_width,
-                    starting_line,
-                    inline_image_rows,
-                    typography,
-                )?;
-
-                // Add chapter break if needed
-                if let Some(ph) = page_height
-                    && current_chapter_idx + 1 < total_chapters
-                {
-                    let total_lines = starting_line + parsed_chapter.text_lines.len();
-                    let break_lines = build_chapter_break(ph, total_lines);
-                    parsed_chapter.text_lines.extend(break_lines);
-                }
+                let cached = self.chapter_wrap_cache.iter().position(|entry| {
+                    entry.chapter == current_chapter_idx
+                        && entry.text_width == text_width
+                        && entry.starting_line == starting_line
+                        && entry.page_height == page_height
+                });
+                let parsed_chapter = match cached {
+                    Some(i) => self.chapter_wrap_cache.remove(i).structure,
+                    None => {
+                        // Parse only the current chapter with new width
+                        let mut parsed_chapter = renderer::parse_chapter_with_typography(
+                            epub.as_mut(),
+                            current_chapter_idx,
+                            text_width,
+                            starting_line,
+                            inline_image_rows,
+                            typography,
+                        )?;
+
+                        // Add chapter break if needed
+                        if let Some(ph) = page_height
+                            && current_chapter_idx + 1 < total_chapters
+                        {
+                            let total_lines = starting_line + parsed_chapter.text_lines.len();
+                            let next_title =
+                                renderer::next_chapter_title(epub.as_ref(), current_chapter_idx);
+                            let break_lines = build_chapter_break(
+                                ph,
+                                total_lines,
+                                self.current_chapter_break,
+                                next_title.as_deref(),
+                            );
+                            parsed_chapter.text_lines.extend(break_lines);
+                        }
+                        parsed_chapter
+                    }
+                };
 
-                // Update the cached structure for this chapter
-                self.chapter_text_structures[current_chapter_idx] = parsed_chapter;
+                // Update the cached structure for this chapter, keeping the
+                // old layout in case the width comes back.
+                let previous = std::mem::replace(
+                    &mut self.chapter_text_structures[current_chapter_idx],
+                    parsed_chapter,
+                );
+                if let Some(previous_width) = self.current_text_width {
+                    if self.chapter_wrap_cache.len() >= CHAPTER_WRAP_CACHE_LIMIT {
+                        self.chapter_wrap_cache.remove(0);
+                    }
+                    self.chapter_wrap_cache.push(CachedChapterWrap {
+                        chapter: current_chapter_idx,
+                        text_width: previous_width,
+                        starting_line,
+                        page_height,
+                        structure: previous,
+                    });
+                }
                 self.current_text_width = Some(text_width);
             }
         }
@@ -8990,6 +12910,9 @@ where
             combined_text_structure
                 .image_maps
                 .extend(ts.image_maps.clone());
+            combined_text_structure
+                .image_alts
+                .extend(ts.image_alts.clone());
             combined_text_structure
                 .section_rows
                 .extend(ts.section_rows.clone());
@@ -9010,6 +12933,7 @@ where
                 .typography_spacing_rows
                 .extend(ts.typography_spacing_rows.iter().copied());
         }
+        self.book_images = sorted_images(&combined_text_structure.image_maps);
         self.board.update_text_structure(combined_text_structure);
         self.content_start_rows = content_start_rows;
         self.refresh_highlight_ranges()?;
@@ -9041,7 +12965,86 @@ where
         Ok(())
     }
 
-    fn yank_selection(&mut self) -> eyre::Result<()> {
+    fn yank_selection(&mut self) -> eyre::Result<()> {
+        let (anchor, cursor) = {
+            let state = self.state.borrow();
+            match (state.ui_state.visual_anchor, state.ui_state.visual_cursor) {
+                (Some(anchor), Some(cursor)) => (anchor, cursor),
+                _ => return Ok(()),
+            }
+        };
+
+        let selected_text = self.get_selected_source_text(anchor, cursor);
+        if !selected_text.is_empty() {
+            let template = self.state.borrow().config.settings.yank_template.clone();
+            let text = if template.is_empty() || template == "%s" {
+                selected_text
+            } else {
+                let row = anchor.min(cursor).0;
+                let chapter = self.toc_label_for_row(row);
+                let meta = self.ebook.as_ref().map(|book| book.get_meta());
+                let percent = (self.board.content_fraction(row) * 100.0).round() as u32;
+                render_yank_template(
+                    &template,
+                    &selected_text,
+                    meta.and_then(|meta| meta.title.as_deref()),
+                    meta.and_then(|meta| meta.creator.as_deref()),
+                    chapter.as_deref(),
+                    percent,
+                )
+            };
+            let copied = self.set_clipboard_text(text);
+            let ui_state = &mut self.state.borrow_mut().ui_state;
+            if copied {
+                ui_state.set_message("Text copied to clipboard".to_string(), MessageType::Info);
+            } else {
+                ui_state.set_message("Clipboard unavailable".to_string(), MessageType::Warning);
+            }
+        }
+        self.state
+            .borrow_mut()
+            .ui_state
+            .open_window(WindowType::Reader);
+        Ok(())
+    }
+
+    /// Copy the selection as a Markdown blockquote attributed to the book
+    /// title and the chapter the selection starts in.
+    fn yank_selection_as_quote(&mut self) -> eyre::Result<()> {
+        let (anchor, cursor) = {
+            let state = self.state.borrow();
+            match (state.ui_state.visual_anchor, state.ui_state.visual_cursor) {
+                (Some(anchor), Some(cursor)) => (anchor, cursor),
+                _ => return Ok(()),
+            }
+        };
+
+        let selected_text = self.get_selected_source_text(anchor, cursor);
+        if !selected_text.trim().is_empty() {
+            let chapter = self.toc_label_for_row(anchor.min(cursor).0);
+            let title = self
+                .ebook
+                .as_ref()
+                .and_then(|book| book.get_meta().title.clone());
+            let quote = format_markdown_quote(&selected_text, title.as_deref(), chapter.as_deref());
+            let copied = self.set_clipboard_text(quote);
+            let ui_state = &mut self.state.borrow_mut().ui_state;
+            if copied {
+                ui_state.set_message("Quote copied to clipboard".to_string(), MessageType::Info);
+            } else {
+                ui_state.set_message("Clipboard unavailable".to_string(), MessageType::Warning);
+            }
+        }
+        self.state
+            .borrow_mut()
+            .ui_state
+            .open_window(WindowType::Reader);
+        Ok(())
+    }
+
+    /// Copy the selection with a citation naming the print pages it spans,
+    /// or the percentage read when the EPUB has no page-list.
+    fn yank_selection_as_citation(&mut self) -> eyre::Result<()> {
         let (anchor, cursor) = {
             let state = self.state.borrow();
             match (state.ui_state.visual_anchor, state.ui_state.visual_cursor) {
@@ -9051,11 +13054,28 @@ where
         };
 
         let selected_text = self.get_selected_source_text(anchor, cursor);
-        if !selected_text.is_empty() {
-            let copied = self.set_clipboard_text(selected_text)?;
+        if !selected_text.trim().is_empty() {
+            let (start_row, end_row) = (anchor.min(cursor).0, anchor.max(cursor).0);
+            let location = match (
+                self.board.current_page_label(start_row),
+                self.board.current_page_label(end_row),
+            ) {
+                (Some(first), Some(last)) => {
+                    CitationLocation::Pages(first.to_string(), last.to_string())
+                }
+                _ => CitationLocation::Percent(
+                    (self.board.content_fraction(start_row) * 100.0).round() as u32,
+                ),
+            };
+            let meta = self.ebook.as_ref().map(|book| book.get_meta());
+            let citation = format_citation(&selected_text, meta, &location);
+            let copied = self.set_clipboard_text(citation);
             let ui_state = &mut self.state.borrow_mut().ui_state;
             if copied {
-                ui_state.set_message("Text copied to clipboard".to_string(), MessageType::Info);
+                ui_state.set_message(
+                    "Citation copied to clipboard".to_string(),
+                    MessageType::Info,
+                );
             } else {
                 ui_state.set_message("Clipboard unavailable".to_string(), MessageType::Warning);
             }
@@ -9067,6 +13087,18 @@ where
         Ok(())
     }
 
+    /// Label of the last TOC entry starting at or before `row`.
+    fn toc_label_for_row(&self, row: usize) -> Option<String> {
+        let toc_entries = self.ebook.as_ref()?.toc_entries();
+        (0..toc_entries.len())
+            .filter(|&i| {
+                self.toc_activation_row(toc_entries, i)
+                    .is_some_and(|start| start <= row)
+            })
+            .last()
+            .map(|i| toc_entries[i].label.clone())
+    }
+
     fn create_highlight_from_selection(&mut self, edit_comment: bool) -> eyre::Result<()> {
         let (anchor, cursor, book_identity) = {
             let state = self.state.borrow();
@@ -9166,6 +13198,26 @@ where
         Ok(())
     }
 
+    /// Dictionary lookup of the word under the cursor, without selecting it.
+    fn lookup_word_under_cursor(&mut self) -> eyre::Result<()> {
+        let Some((row, col)) = self.current_visual_cursor() else {
+            return Ok(());
+        };
+        let Some((_, start, end)) = self.board.word_at(row, col) else {
+            self.state
+                .borrow_mut()
+                .ui_state
+                .set_message("No word under cursor".to_string(), MessageType::Info);
+            return Ok(());
+        };
+        {
+            let mut state = self.state.borrow_mut();
+            state.ui_state.visual_anchor = Some((row, start));
+            state.ui_state.visual_cursor = Some((row, end - 1));
+        }
+        self.dictionary_lookup()
+    }
+
     fn dictionary_lookup(&mut self) -> eyre::Result<()> {
         let (anchor, cursor) = {
             let state = self.state.borrow();
@@ -9176,7 +13228,7 @@ where
         };
 
         let selected_text = self.get_selected_source_text(anchor, cursor);
-        let word = selected_text.trim().to_string();
+        let word = clean_lookup_query(&selected_text);
         if word.is_empty() {
             self.state
                 .borrow_mut()
@@ -9185,9 +13237,14 @@ where
             return Ok(());
         }
 
-        let dictionary_client = {
-            let state = self.state.borrow();
-            state.config.settings.dictionary_client.trim().to_string()
+        let (clients_to_try, aggregate) = {
+            let settings = &self.state.borrow().config.settings;
+            let client = settings.dictionary_client.trim();
+            let auto = client.is_empty() || client == "auto";
+            (
+                settings.dictionary_commands(),
+                settings.aggregate_dictionaries && auto,
+            )
         };
 
         let (tx, rx) = std::sync::mpsc::channel();
@@ -9200,6 +13257,7 @@ where
             state.ui_state.dictionary_loading = true;
             state.ui_state.dictionary_scroll_offset = 0;
             state.ui_state.dictionary_is_wikipedia = false;
+            state.ui_state.dictionary_disambiguation = None;
             state.ui_state.visual_anchor = None;
             state.ui_state.visual_cursor = None;
             state.ui_state.open_window(WindowType::Dictionary);
@@ -9210,30 +13268,28 @@ where
             let start_total = Instant::now();
             let total_timeout = Duration::from_secs(10);
 
-            let clients_to_try: Vec<String> =
-                if dictionary_client.is_empty() || dictionary_client == "auto" {
-                    DICT_PRESET_LIST.iter().map(|c| (*c).to_string()).collect()
-                } else {
-                    vec![dictionary_client]
-                };
-
             let mut any_command_ran = false;
             let mut last_stderr: Option<String> = None;
             let mut definition: Option<String> = None;
             let mut successful_client: String = String::new();
+            let mut outputs: Vec<(String, String)> = Vec::new();
 
-            for client in clients_to_try {
+            for (client, template) in clients_to_try {
                 let remaining = total_timeout.saturating_sub(start_total.elapsed());
                 if remaining.is_zero() {
                     break;
                 }
 
-                match Self::run_dictionary_client(&client, &word_clone, remaining) {
+                match Self::run_dictionary_client(&template, &word_clone, remaining) {
                     Ok(out) => {
                         any_command_ran = true;
                         let stdout_text = String::from_utf8_lossy(&out.stdout).trim().to_string();
                         let stderr_text = String::from_utf8_lossy(&out.stderr).trim().to_string();
                         if !stdout_text.is_empty() {
+                            if aggregate {
+                                outputs.push((client, stdout_text));
+                                continue;
+                            }
                             definition = Some(stdout_text);
                             successful_client = client;
                             break;
@@ -9248,6 +13304,15 @@ where
                 }
             }
 
+            if !outputs.is_empty() {
+                successful_client = outputs
+                    .iter()
+                    .map(|(client, _)| client.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                definition = Some(Self::join_dictionary_outputs(&outputs));
+            }
+
             let result_definition = if let Some(text) = definition {
                 Ok(text)
             } else if start_total.elapsed() >= total_timeout {
@@ -9266,6 +13331,7 @@ where
                 word: word_clone,
                 definition: result_definition,
                 client: successful_client,
+                disambiguation: None,
             });
         });
 
@@ -9282,7 +13348,7 @@ where
         };
 
         let selected_text = self.board.get_selected_text_range(anchor, cursor);
-        let query = selected_text.trim().to_string();
+        let query = clean_lookup_query(&selected_text);
         if query.is_empty() {
             self.state
                 .borrow_mut()
@@ -9301,34 +13367,84 @@ where
             state.ui_state.dictionary_loading = true;
             state.ui_state.dictionary_scroll_offset = 0;
             state.ui_state.dictionary_is_wikipedia = true;
+            state.ui_state.dictionary_disambiguation = None;
             state.ui_state.visual_anchor = None;
             state.ui_state.visual_cursor = None;
             state.ui_state.open_window(WindowType::Dictionary);
         }
 
+        let ascii_wikipedia = self.state.borrow().config.settings.ascii_wikipedia;
         std::thread::spawn(move || {
             let total_timeout = Duration::from_secs(10);
-            let language = Self::detect_wikipedia_language(&query);
-            let result_definition =
-                match Self::wikipedia_lookup_summary(&query, &language, total_timeout) {
-                    Ok(result) => Ok(format!("Wikipedia: {}\n\n{}", result.url, result.summary)),
-                    Err(err) => {
-                        let message = err.to_string();
-                        if message.contains("timed out") {
-                            Err(format!(
-                                "Wikipedia query timed out after {}s",
-                                total_timeout.as_secs()
-                            ))
-                        } else {
-                            Err(format!("Wikipedia lookup failed.\n\n{}", message))
-                        }
-                    }
-                };
+            let language = Self::detect_wikipedia_language(&query, ascii_wikipedia);
+            let fallback_language = Self::alternate_english_wikipedia(&language);
+            let (result_definition, disambiguation) = match Self::wikipedia_lookup_summary(
+                &query,
+                &language,
+                fallback_language,
+                total_timeout,
+            ) {
+                Ok(WikipediaLookup::Summary(result)) => (
+                    Ok(format!("Wikipedia: {}\n\n{}", result.url, result.summary)),
+                    None,
+                ),
+                Ok(WikipediaLookup::Disambiguation(disambiguation)) => (
+                    Ok(format!("'{}' may refer to:", query)),
+                    Some(disambiguation),
+                ),
+                Err(err) => (
+                    Err(Self::wikipedia_error_message(&err, total_timeout)),
+                    None,
+                ),
+            };
 
             let _ = tx.send(DictionaryResult {
                 word: query,
                 definition: result_definition,
                 client: "Wikipedia".to_string(),
+                disambiguation,
+            });
+        });
+
+        Ok(())
+    }
+
+    /// Look up the highlighted disambiguation option in the same language.
+    fn lookup_wikipedia_option(&mut self) -> eyre::Result<()> {
+        let (language, title) = {
+            let mut state = self.state.borrow_mut();
+            let index = state.ui_state.dictionary_option_index;
+            let Some(disambiguation) = state.ui_state.dictionary_disambiguation.take() else {
+                return Ok(());
+            };
+            let Some(title) = disambiguation.titles.get(index).cloned() else {
+                return Ok(());
+            };
+            state.ui_state.dictionary_word = title.clone();
+            state.ui_state.dictionary_definition = String::new();
+            state.ui_state.dictionary_loading = true;
+            state.ui_state.dictionary_scroll_offset = 0;
+            (disambiguation.language, title)
+        };
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.dictionary_res_rx = Some(rx);
+
+        std::thread::spawn(move || {
+            let total_timeout = Duration::from_secs(10);
+            let result = Self::wikipedia_client(&language, total_timeout)
+                .and_then(|client| Self::fetch_wikipedia_summary(&client, &language, &title));
+            let result_definition = match result {
+                Ok(Some(result)) => Ok(format!("Wikipedia: {}\n\n{}", result.url, result.summary)),
+                Ok(None) => Err(format!("No Wikipedia summary found for '{}'", title)),
+                Err(err) => Err(Self::wikipedia_error_message(&err, total_timeout)),
+            };
+
+            let _ = tx.send(DictionaryResult {
+                word: title,
+                definition: result_definition,
+                client: "Wikipedia".to_string(),
+                disambiguation: None,
             });
         });
 
@@ -9376,7 +13492,7 @@ where
                     .set_message("Opened search in browser".to_string(), MessageType::Info);
             }
             Ok(false) | Err(_) => {
-                let copied = self.set_clipboard_text(url)?;
+                let copied = self.set_clipboard_text(url);
                 let mut state = self.state.borrow_mut();
                 state.ui_state.visual_anchor = None;
                 state.ui_state.visual_cursor = None;
@@ -9405,7 +13521,7 @@ where
                 .map(|link| link.url.clone())
         };
         if let Some(url) = url {
-            let copied = self.set_clipboard_text(url)?;
+            let copied = self.set_clipboard_text(url);
             let ui_state = &mut self.state.borrow_mut().ui_state;
             if copied {
                 ui_state.set_message("Link copied to clipboard".to_string(), MessageType::Info);
@@ -9449,6 +13565,17 @@ where
             return Ok(());
         }
 
+        if self.state.borrow().config.settings.open_linked_books
+            && let Some(path) = self.linked_book_path(&link.url)
+        {
+            let mut state = self.state.borrow_mut();
+            state.ui_state.pending_linked_book = Some(path);
+            state
+                .ui_state
+                .open_window(WindowType::ConfirmOpenLinkedBook);
+            return Ok(());
+        }
+
         if Self::is_external_link(&link.url) {
             match self.open_external_link(&link.url) {
                 Ok(true) => {
@@ -9458,7 +13585,7 @@ where
                     return Ok(());
                 }
                 Ok(false) | Err(_) => {
-                    let copied = self.set_clipboard_text(link.url)?;
+                    let copied = self.set_clipboard_text(link.url);
                     let ui_state = &mut self.state.borrow_mut().ui_state;
                     let message = if copied {
                         "Failed to open; link copied"
@@ -9472,7 +13599,7 @@ where
             }
         }
 
-        let copied = self.set_clipboard_text(link.url)?;
+        let copied = self.set_clipboard_text(link.url);
         let ui_state = &mut self.state.borrow_mut().ui_state;
         if copied {
             ui_state.set_message("Link copied to clipboard".to_string(), MessageType::Info);
@@ -9483,6 +13610,91 @@ where
         Ok(())
     }
 
+    /// Start touring the internal-link targets listed in the links window,
+    /// in page order, from the first one.
+    fn start_link_tour(&mut self) {
+        let mut targets: Vec<usize> = Vec::new();
+        for row in self
+            .state
+            .borrow()
+            .ui_state
+            .links
+            .iter()
+            .filter_map(|link| link.target_row)
+        {
+            if !targets.contains(&row) {
+                targets.push(row);
+            }
+        }
+        if targets.is_empty() {
+            self.state.borrow_mut().ui_state.set_message(
+                "No internal links on this page".to_string(),
+                MessageType::Info,
+            );
+            return;
+        }
+
+        self.record_jump_position();
+        let origin = {
+            let row = self.state.borrow().reading_state.row;
+            self.position_state_for_row(row)
+        };
+        self.state.borrow_mut().ui_state.link_tour = Some(LinkTour {
+            targets,
+            index: 0,
+            origin,
+        });
+        self.show_link_tour_stop();
+    }
+
+    /// Move to the tour's next note, or back to the start after the last.
+    fn advance_link_tour(&mut self) {
+        let more = {
+            let mut state = self.state.borrow_mut();
+            let Some(tour) = state.ui_state.link_tour.as_mut() else {
+                return;
+            };
+            tour.index += 1;
+            tour.index < tour.targets.len()
+        };
+        if more {
+            self.show_link_tour_stop();
+        } else {
+            self.finish_link_tour();
+        }
+    }
+
+    fn show_link_tour_stop(&mut self) {
+        let mut state = self.state.borrow_mut();
+        let Some((row, index, total)) = state
+            .ui_state
+            .link_tour
+            .as_ref()
+            .map(|tour| (tour.targets[tour.index], tour.index, tour.targets.len()))
+        else {
+            return;
+        };
+        state.reading_state.row = row;
+        state.ui_state.open_window(WindowType::Reader);
+        state.ui_state.set_message(
+            format!("Note {}/{}: Enter next, Esc return", index + 1, total),
+            MessageType::Info,
+        );
+        drop(state);
+        self.sync_reading_content_index();
+    }
+
+    /// End the tour and return to the page it started from.
+    fn finish_link_tour(&mut self) {
+        let Some(tour) = self.state.borrow_mut().ui_state.link_tour.take() else {
+            return;
+        };
+        let current_textwidth = self.state.borrow().reading_state.textwidth;
+        let row = self.restore_row(&tour.origin, current_textwidth);
+        self.state.borrow_mut().reading_state.row = row;
+        self.sync_reading_content_index();
+    }
+
     fn confirm_link_preview_jump(&mut self) {
         let target_row = {
             let mut state = self.state.borrow_mut();
@@ -9607,6 +13819,26 @@ where
         None
     }
 
+    /// The ebook file a link such as `vol2.epub#ch3` names, resolved against
+    /// the current book's directory, when it exists there.
+    fn linked_book_path(&self, href: &str) -> Option<String> {
+        let href = href.trim();
+        if Self::is_external_link(href) {
+            return None;
+        }
+        let file = href.split_once('#').map_or(href, |(file, _)| file);
+        let extension = std::path::Path::new(file)
+            .extension()?
+            .to_str()?
+            .to_ascii_lowercase();
+        if !LINKED_BOOK_EXTENSIONS.contains(&extension.as_str()) {
+            return None;
+        }
+        let current = std::path::Path::new(self.ebook.as_ref()?.path());
+        let path = current.parent()?.join(file);
+        path.is_file().then(|| path.to_string_lossy().into_owned())
+    }
+
     fn is_external_link(href: &str) -> bool {
         let href = href.to_ascii_lowercase();
         href.starts_with("http://")
@@ -9616,6 +13848,28 @@ where
             || href.starts_with("ftp://")
     }
 
+    /// Open a directory with the platform's default file manager. Output is
+    /// discarded so the opener cannot scribble over the TUI.
+    fn open_folder(&self, folder: &str) -> eyre::Result<bool> {
+        let opener = if cfg!(target_os = "macos") {
+            "open"
+        } else if cfg!(windows) {
+            "explorer"
+        } else {
+            "xdg-open"
+        };
+        let status = std::process::Command::new(opener)
+            .arg(folder)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status();
+        match status {
+            Ok(status) => Ok(status.success()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     fn open_external_link(&self, url: &str) -> eyre::Result<bool> {
         // Use a system opener to keep link handling out of the TUI.
         let status = std::process::Command::new("xdg-open").arg(url).status();
@@ -9627,18 +13881,26 @@ where
 
     // ── TTS (Text-to-Speech) ───────────────────────────────────────
 
-    /// Collect text chunks for TTS with precise per-line underline ranges.
-    fn build_tts_chunks(&self) -> Vec<TtsChunk> {
+    /// Whether board row `i` is prose rather than a blank row, the
+    /// chapter-break marker, or an image placeholder.
+    fn is_text_row(&self, i: usize, line: &str) -> bool {
+        !line.is_empty()
+            && line != CHAPTER_BREAK_MARKER
+            && !line.starts_with("[Image:")
+            && !line.starts_with("[[Image:")
+            && self.board.image_src(i).is_none()
+    }
+
+    /// Text paragraphs as `[start, end)` row ranges: runs of content lines
+    /// between blank lines, chapter breaks, and image placeholders.
+    fn paragraph_ranges(&self) -> Vec<(usize, usize)> {
         let Some(lines) = self.board.lines() else {
             return Vec::new();
         };
-
-        // First pass: collect raw paragraphs as (start, end) line ranges.
         let mut raw_paragraphs: Vec<(usize, usize)> = Vec::new();
         let mut start: Option<usize> = None;
         for (i, line) in lines.iter().enumerate() {
-            let is_text =
-                !line.is_empty() && line != CHAPTER_BREAK_MARKER && !line.starts_with("[Image:");
+            let is_text = self.is_text_row(i, line);
             // A blank spacing row keeps a wrapped paragraph together, but a
             // paragraph must never begin on one (spacing rows also pad
             // paragraph gaps under double line spacing).
@@ -9655,11 +13917,70 @@ where
         if let Some(s) = start {
             raw_paragraphs.push((s, lines.len()));
         }
+        raw_paragraphs
+    }
+
+    /// Underline column ranges for the chapter source offsets `source`,
+    /// kept within the chapter-local `rows`, and the first local row the
+    /// range touches.
+    fn tts_underline(
+        lines: &[String],
+        chapter_start: usize,
+        source_map: &SourceMap,
+        source: std::ops::Range<usize>,
+        rows: std::ops::Range<usize>,
+    ) -> (usize, HashMap<usize, (usize, usize)>) {
+        let mut underline = HashMap::new();
+        let first_local_row = source_map
+            .row_for_offset(source.start)
+            .clamp(rows.start, rows.end.saturating_sub(1));
+        let last_local_row = source_map
+            .row_for_offset(source.end - 1)
+            .clamp(first_local_row, rows.end.saturating_sub(1));
+
+        for local_row in first_local_row..=last_local_row {
+            let Some(&(row_start, row_end)) = source_map.row_spans.get(local_row) else {
+                continue;
+            };
+            let overlap_start = source.start.max(row_start as usize);
+            let overlap_end = source.end.min(row_end as usize);
+            if overlap_start >= overlap_end {
+                continue;
+            }
+            let global_row = chapter_start + local_row;
+            let Some(rendered_row) = lines.get(global_row) else {
+                continue;
+            };
+            let col_start = source_map.col_at(
+                local_row,
+                rendered_row,
+                overlap_start,
+                SourceOffsetBias::Start,
+            );
+            let col_end = source_map.col_at(
+                local_row,
+                rendered_row,
+                overlap_end - 1,
+                SourceOffsetBias::End,
+            );
+
+            if col_start < col_end {
+                underline.insert(global_row, (col_start, col_end));
+            }
+        }
+        (first_local_row, underline)
+    }
+
+    /// Collect text chunks for TTS with precise per-line underline ranges.
+    fn build_tts_chunks(&self) -> Vec<TtsChunk> {
+        let Some(lines) = self.board.lines() else {
+            return Vec::new();
+        };
 
-        // Second pass: split each paragraph into sentence-boundary chunks
-        // and compute per-line underline character ranges.
+        // Split each paragraph into sentence-boundary chunks and compute
+        // per-line underline character ranges.
         let mut chunks = Vec::new();
-        for (para_start, para_end) in raw_paragraphs {
+        for (para_start, para_end) in self.paragraph_ranges() {
             let Some(content_index) = self.content_index_for_row(para_start) else {
                 continue;
             };
@@ -9715,53 +14036,69 @@ where
                     continue;
                 }
 
-                let mut underline = HashMap::new();
-                let first_local_row = source_map
-                    .row_for_offset(chunk_start)
-                    .clamp(local_para_start, local_para_end.saturating_sub(1));
-                let last_local_row = source_map
-                    .row_for_offset(chunk_end - 1)
-                    .clamp(first_local_row, local_para_end.saturating_sub(1));
-
-                for local_row in first_local_row..=last_local_row {
-                    let Some(&(row_start, row_end)) = source_map.row_spans.get(local_row) else {
-                        continue;
-                    };
-                    let overlap_start = chunk_start.max(row_start as usize);
-                    let overlap_end = chunk_end.min(row_end as usize);
-                    if overlap_start >= overlap_end {
-                        continue;
-                    }
-                    let global_row = chapter_start + local_row;
-                    let Some(rendered_row) = lines.get(global_row) else {
-                        continue;
-                    };
-                    let col_start = source_map.col_at(
-                        local_row,
-                        rendered_row,
-                        overlap_start,
-                        SourceOffsetBias::Start,
-                    );
-                    let col_end = source_map.col_at(
-                        local_row,
-                        rendered_row,
-                        overlap_end - 1,
-                        SourceOffsetBias::End,
-                    );
-
-                    if col_start < col_end {
-                        underline.insert(global_row, (col_start, col_end));
-                    }
-                }
-
+                let (first_local_row, underline) = Self::tts_underline(
+                    lines,
+                    chapter_start,
+                    source_map,
+                    chunk_start..chunk_end,
+                    local_para_start..local_para_end,
+                );
                 chunks.push(TtsChunk {
                     text: chunk_text,
                     first_line: chapter_start + first_local_row,
                     underline,
+                    clip: None,
                 });
             }
         }
-        chunks
+
+        let (read_images, announce_chapters) = {
+            let settings = &self.state.borrow().config.settings;
+            (settings.tts_read_images, settings.tts_announce_chapters)
+        };
+        // Images are read by their description; ones without alt text are
+        // skipped like other non-prose rows.
+        if read_images {
+            for (row, _) in &self.book_images {
+                let Some(alt) = self.board.image_alt(*row) else {
+                    continue;
+                };
+                let width = lines.get(*row).map_or(0, |line| line.chars().count());
+                chunks.push(TtsChunk {
+                    text: format!("Image: {alt}"),
+                    first_line: *row,
+                    underline: HashMap::from([(*row, (0, width))]),
+                    clip: None,
+                });
+            }
+        }
+        // Announcements go first so the stable sort keeps them ahead of the
+        // heading text on the same row.
+        let mut announcements = Vec::new();
+        if announce_chapters && let Some(ebook) = self.ebook.as_ref() {
+            let toc_entries = ebook.toc_entries();
+            let mut announced_rows = HashSet::new();
+            for (index, entry) in toc_entries.iter().enumerate() {
+                let label = entry.label.trim();
+                if label.is_empty() {
+                    continue;
+                }
+                let Some(row) = self.toc_activation_row(toc_entries, index) else {
+                    continue;
+                };
+                if announced_rows.insert(row) {
+                    announcements.push(TtsChunk {
+                        text: format!("Chapter: {label}"),
+                        first_line: row,
+                        underline: HashMap::new(),
+                        clip: None,
+                    });
+                }
+            }
+        }
+        announcements.append(&mut chunks);
+        announcements.sort_by_key(|chunk| chunk.first_line);
+        announcements
     }
 
     /// Skip trailing closers and inline footnote markers after terminal punctuation.
@@ -9889,7 +14226,92 @@ where
                 chunk_start += 1;
             }
         }
-
+
+        chunks
+    }
+
+    /// Chunks for every narrated phrase of chapters with a media overlay.
+    fn build_narration_chunks(&mut self) -> Vec<TtsChunk> {
+        let Some(ebook) = self.ebook.as_mut() else {
+            return Vec::new();
+        };
+        let overlays: Vec<(usize, Vec<NarrationClip>)> = (0..ebook.contents().len())
+            .map(|index| (index, ebook.media_overlay(index)))
+            .filter(|(_, clips)| !clips.is_empty())
+            .collect();
+        self.narration_chunks(&overlays)
+    }
+
+    /// One chunk per clip, spanning the text from its element to the next
+    /// clip's element or the end of the paragraph, whichever comes first.
+    /// Clips whose element is missing from the text are skipped.
+    fn narration_chunks(&self, overlays: &[(usize, Vec<NarrationClip>)]) -> Vec<TtsChunk> {
+        let Some(lines) = self.board.lines() else {
+            return Vec::new();
+        };
+        let paragraphs = self.paragraph_ranges();
+        let mut chunks = Vec::new();
+        for (content_index, clips) in overlays {
+            let (Some(chapter), Some(&chapter_start)) = (
+                self.chapter_text_structures.get(*content_index),
+                self.content_start_rows.get(*content_index),
+            ) else {
+                continue;
+            };
+            let source_map = &chapter.source_map;
+            let starts: Vec<Option<usize>> = clips
+                .iter()
+                .map(|clip| chapter.section_offsets.get(&clip.fragment).copied())
+                .collect();
+            for (index, clip) in clips.iter().enumerate() {
+                let Some(start) = starts[index] else {
+                    continue;
+                };
+                let local_row = source_map.row_for_offset(start);
+                let Some(&(para_start, para_end)) = paragraphs
+                    .iter()
+                    .find(|(first, last)| (*first..*last).contains(&(chapter_start + local_row)))
+                else {
+                    continue;
+                };
+                let rows = para_start - chapter_start
+                    ..(para_end - chapter_start).min(source_map.row_spans.len());
+                if rows.is_empty() {
+                    continue;
+                }
+                let paragraph_end = source_map.row_spans[rows.clone()]
+                    .iter()
+                    .map(|&(_, end)| end as usize)
+                    .max()
+                    .unwrap_or(start);
+                let next_start = starts[index + 1..]
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .find(|&next| next > start)
+                    .unwrap_or(usize::MAX);
+                let span: String = source_map
+                    .source_text
+                    .chars()
+                    .skip(start)
+                    .take(paragraph_end.min(next_start).saturating_sub(start))
+                    .collect();
+                let text = span.trim();
+                if text.is_empty() {
+                    continue;
+                }
+                let start = start + (span.chars().count() - span.trim_start().chars().count());
+                let end = start + text.chars().count();
+                let (first_local_row, underline) =
+                    Self::tts_underline(lines, chapter_start, source_map, start..end, rows);
+                chunks.push(TtsChunk {
+                    text: text.to_string(),
+                    first_line: chapter_start + first_local_row,
+                    underline,
+                    clip: Some(clip.clone()),
+                });
+            }
+        }
         chunks
     }
 
@@ -10088,6 +14510,10 @@ where
                         playback_index = index.min(total_chunks);
                         next_to_convert = next_to_convert.max(playback_index);
                     }
+                    TtsWorkerCommand::Seek(index) => {
+                        playback_index = index.min(total_chunks);
+                        next_to_convert = next_to_convert.min(playback_index);
+                    }
                     TtsWorkerCommand::Stop => return,
                 }
             }
@@ -10123,6 +14549,10 @@ where
                     playback_index = index.min(total_chunks);
                     next_to_convert = next_to_convert.max(playback_index);
                 }
+                Ok(TtsWorkerCommand::Seek(index)) => {
+                    playback_index = index.min(total_chunks);
+                    next_to_convert = next_to_convert.min(playback_index);
+                }
                 Ok(TtsWorkerCommand::Stop) => return,
                 Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
                 Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => return,
@@ -10203,16 +14633,78 @@ where
             self.stop_tts();
             return Ok(());
         }
+        if self.state.borrow().config.settings.play_narration && self.start_narration()? {
+            return Ok(());
+        }
+        let Some(engine) = self.tts_ready_engine() else {
+            return Ok(());
+        };
+
+        let chunks = self.build_tts_chunks();
+        let current_row = self.state.borrow().reading_state.row.saturating_sub(1);
+        self.tts_chunks = chunks;
+        let idx = match self.find_chunk_at(current_row) {
+            Some(i) => i,
+            None => {
+                self.tts_chunks.clear();
+                let mut state = self.state.borrow_mut();
+                state
+                    .ui_state
+                    .set_message("No text found to read".to_string(), MessageType::Error);
+                return Ok(());
+            }
+        };
+        self.tts_start(engine, idx)
+    }
+
+    /// Play the book's recorded narration from the current position when
+    /// this chapter has a media overlay. Returns `false` to fall back to
+    /// synthetic speech.
+    fn start_narration(&mut self) -> eyre::Result<bool> {
+        let chunks = self.build_narration_chunks();
+        if chunks.is_empty() {
+            return Ok(false);
+        }
+        let current_row = self.state.borrow().reading_state.row.saturating_sub(1);
+        self.tts_chunks = chunks;
+        let idx = self.find_chunk_at(current_row).filter(|&idx| {
+            self.content_index_for_row(self.tts_chunks[idx].first_line)
+                == self.content_index_for_row(current_row)
+        });
+        let Some(idx) = idx else {
+            self.tts_chunks.clear();
+            return Ok(false);
+        };
+        let has_player = self
+            .state
+            .borrow()
+            .config
+            .settings
+            .narration_player
+            .is_some();
+        if !has_player && self.tts_detect_player().is_none() {
+            self.tts_chunks.clear();
+            self.state.borrow_mut().ui_state.set_message(
+                "Narration: no audio player found; install mpv or ffplay".to_string(),
+                MessageType::Error,
+            );
+            return Ok(true);
+        }
+        self.tts_ready_audio.clear();
+        self.tts_current_engine.clear();
+        self.tts_temp_dir = Some(Self::tts_create_temp_dir()?);
+        self.tts_chunk_index = idx;
+        self.tts_speak_current()?;
+        Ok(true)
+    }
 
-        // Check if the TTS engine is available
+    /// The configured TTS engine, once its program (and, for file-based
+    /// engines, an audio player) is known to be installed. Reports what is
+    /// missing as a status message otherwise.
+    fn tts_ready_engine(&mut self) -> Option<String> {
         let engine = {
             let state = self.state.borrow();
-            state
-                .config
-                .settings
-                .preferred_tts_engine
-                .clone()
-                .unwrap_or_default()
+            state.effective_tts_engine().unwrap_or_default().to_string()
         };
         let program = if engine == "edge-tts" {
             "edge-tts"
@@ -10235,7 +14727,7 @@ where
                     format!("TTS failed: command '{}' not found", program)
                 };
                 state.ui_state.set_message(msg, MessageType::Error);
-                return Ok(());
+                return None;
             }
         }
 
@@ -10246,24 +14738,16 @@ where
                 "TTS: no audio player found; install mpv or ffplay".to_string(),
                 MessageType::Error,
             );
-            return Ok(());
+            return None;
         }
+        Some(engine)
+    }
 
-        self.tts_chunks = self.build_tts_chunks();
+    /// Start reading `self.tts_chunks` from chunk `idx` with `engine`.
+    fn tts_start(&mut self, engine: String, idx: usize) -> eyre::Result<()> {
         self.tts_ready_audio.clear();
         self.tts_current_engine = engine.clone();
         self.tts_temp_dir = None;
-        let current_row = self.state.borrow().reading_state.row.saturating_sub(1);
-        let idx = match self.find_chunk_at(current_row) {
-            Some(i) => i,
-            None => {
-                let mut state = self.state.borrow_mut();
-                state
-                    .ui_state
-                    .set_message("No text found to read".to_string(), MessageType::Error);
-                return Ok(());
-            }
-        };
         self.tts_chunk_index = idx;
         if Self::is_file_based_engine(&engine) {
             self.tts_temp_dir = Some(Self::tts_create_temp_dir()?);
@@ -10277,6 +14761,37 @@ where
         Ok(())
     }
 
+    /// Speak where the reader is: chapter, percent read, and the first line
+    /// on screen. A one-chunk run that stops by itself; it interrupts any
+    /// reading in progress.
+    fn announce_position(&mut self) -> eyre::Result<()> {
+        if self.state.borrow().ui_state.tts_active {
+            self.stop_tts();
+        }
+        let Some(engine) = self.tts_ready_engine() else {
+            return Ok(());
+        };
+        let row = self.state.borrow().reading_state.row;
+        let (start, end) = self.visible_line_range();
+        let top_line = (start..end).find(|&r| {
+            self.board
+                .get_line(r)
+                .is_some_and(|line| !line.trim().is_empty() && line != CHAPTER_BREAK_MARKER)
+        });
+        let text = position_announcement(
+            self.toc_label_for_row(row).as_deref(),
+            (self.board.content_fraction(row) * 100.0).round() as u32,
+            top_line.and_then(|r| self.board.get_line(r)),
+        );
+        self.tts_chunks = vec![TtsChunk {
+            text,
+            first_line: top_line.unwrap_or(start),
+            underline: HashMap::new(),
+            clip: None,
+        }];
+        self.tts_start(engine, 0)
+    }
+
     /// Speak the current chunk.
     fn tts_speak_current(&mut self) -> eyre::Result<()> {
         let chunk = match self.tts_chunks.get(self.tts_chunk_index) {
@@ -10291,23 +14806,16 @@ where
         let first_line = chunk.first_line;
         let last_line = chunk.underline.keys().max().copied().unwrap_or(first_line);
         let underline = chunk.underline.clone();
+        let clip = chunk.clip.clone();
 
         // Update UI state: mark active, set underline ranges, scroll
+        let page_height = self.page_size().max(1);
         {
             let mut state = self.state.borrow_mut();
             state.ui_state.tts_active = true;
             state.ui_state.tts_underline_ranges = underline;
+            state.ui_state.tts_progress = Some((self.tts_chunk_index + 1, self.tts_chunks.len()));
 
-            let term_rows = match crossterm::terminal::size() {
-                Ok((_, rows)) => rows as usize,
-                Err(_) => 24,
-            };
-            let chrome = if state.config.settings.show_top_bar {
-                1 + 2 + 2
-            } else {
-                2
-            };
-            let page_height = term_rows.saturating_sub(chrome).max(1);
             state.reading_state.row = Self::tts_target_row_for_chunk(
                 state.reading_state.row,
                 first_line,
@@ -10321,6 +14829,10 @@ where
         // Redraw before starting synthesis
         self.draw()?;
 
+        if let Some(clip) = clip {
+            return self.tts_play_clip(&clip);
+        }
+
         let engine = self.tts_current_engine.clone();
 
         // --- File-based engines: background conversion queue → play via mpv/ffplay ---
@@ -10331,20 +14843,20 @@ where
             return Ok(());
         }
 
-        // --- Inline engines (custom {}-only command) ---
-        let (program, args) = if engine.contains("{}") {
-            let expanded = engine.replace("{}", &text);
-            let parts: Vec<&str> = expanded.split_whitespace().collect();
-            if parts.is_empty() {
-                self.stop_tts();
-                return Ok(());
-            }
-            (
-                parts[0].to_string(),
-                parts[1..].iter().map(|s| s.to_string()).collect(),
+        // --- Inline engines (custom {} command, or a bare program) ---
+        let command = {
+            let state = self.state.borrow();
+            let settings = &state.config.settings;
+            tts_inline_command(
+                &engine,
+                &text,
+                settings.tts_rate,
+                settings.tts_voice.as_deref(),
             )
-        } else {
-            (engine, vec![text])
+        };
+        let Some((program, args)) = command else {
+            self.stop_tts();
+            return Ok(());
         };
 
         // Spawn TTS process in its own process group so we can kill all its children.
@@ -10408,12 +14920,20 @@ where
         self.tts_current_audio_path = Some(audio_path.clone());
         self.tts_set_converting(false);
 
+        let mut cmd = std::process::Command::new(player.program());
+        cmd.args(player.args(&audio_path));
+        self.tts_spawn_player(cmd, "TTS player failed");
+        Ok(())
+    }
+
+    /// Run `cmd` in its own process group as the chunk being played,
+    /// signalling `tts_done_rx` when it exits. Stops TTS with a
+    /// `failure`-prefixed message if it cannot start.
+    fn tts_spawn_player(&mut self, mut cmd: std::process::Command, failure: &str) {
         let (tx, rx) = std::sync::mpsc::channel::<()>();
         self.tts_done_rx = Some(rx);
 
-        let mut cmd = std::process::Command::new(player.program());
-        cmd.args(player.args(&audio_path))
-            .stdout(std::process::Stdio::null())
+        cmd.stdout(std::process::Stdio::null())
             .stderr(std::process::Stdio::null());
 
         #[cfg(unix)]
@@ -10443,13 +14963,79 @@ where
                 let mut state = self.state.borrow_mut();
                 state
                     .ui_state
-                    .set_message(format!("TTS player failed: {err}"), MessageType::Error);
-                return Ok(());
+                    .set_message(format!("{failure}: {err}"), MessageType::Error);
             }
         }
+    }
+
+    /// Play one clip of recorded narration with `narration_player`, or
+    /// mpv/ffplay when none is configured.
+    fn tts_play_clip(&mut self, clip: &NarrationClip) -> eyre::Result<()> {
+        let audio = match self.narration_audio_file(&clip.audio) {
+            Ok(audio) => audio,
+            Err(err) => {
+                self.stop_tts();
+                self.state
+                    .borrow_mut()
+                    .ui_state
+                    .set_message(format!("Narration failed: {err}"), MessageType::Error);
+                return Ok(());
+            }
+        };
+        let template = self.state.borrow().config.settings.narration_player.clone();
+        let cmd = if let Some(template) = template {
+            let Some((program, args)) = narration_player_command(&template, &audio, clip) else {
+                self.stop_tts();
+                self.state.borrow_mut().ui_state.set_message(
+                    "Narration failed: empty narration_player".to_string(),
+                    MessageType::Error,
+                );
+                return Ok(());
+            };
+            let mut cmd = std::process::Command::new(program);
+            cmd.args(args);
+            cmd
+        } else {
+            let Some(player) = self.tts_detect_player() else {
+                self.stop_tts();
+                return Ok(());
+            };
+            let mut cmd = std::process::Command::new(player.program());
+            cmd.args(player.clip_args(&audio, clip.clip_begin, clip.clip_end));
+            cmd
+        };
+        self.tts_spawn_player(cmd, "Narration player failed");
         Ok(())
     }
 
+    /// Temp file holding the narration audio at archive path `href`,
+    /// extracted from the book on first use.
+    fn narration_audio_file(&mut self, href: &str) -> eyre::Result<std::path::PathBuf> {
+        if let Some(path) = self.narration_audio.get(href) {
+            return Ok(path.clone());
+        }
+        let dir = self
+            .tts_temp_dir
+            .clone()
+            .ok_or_else(|| eyre::eyre!("missing TTS temp dir"))?;
+        let ebook = self
+            .ebook
+            .as_mut()
+            .ok_or_else(|| eyre::eyre!("no book open"))?;
+        let (_, bytes) = ebook.get_resource(href)?;
+        let extension = std::path::Path::new(href)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("audio");
+        let path = dir.join(format!(
+            "repy_narration_{}.{extension}",
+            self.narration_audio.len()
+        ));
+        std::fs::write(&path, bytes)?;
+        self.narration_audio.insert(href.to_string(), path.clone());
+        Ok(path)
+    }
+
     /// Advance to the next chunk after the current one finishes.
     fn tts_advance_paragraph(&mut self) -> eyre::Result<()> {
         // Clean up the temp file for the chunk that just finished playing.
@@ -10465,12 +15051,157 @@ where
                 .set_message("TTS finished".to_string(), MessageType::Info);
             return Ok(());
         }
+        if self.tts_crossed_chapter() {
+            // Park the view on the next chapter so `!` picks up from there.
+            let next_line = self.tts_chunks[self.tts_chunk_index].first_line;
+            self.stop_tts();
+            let mut state = self.state.borrow_mut();
+            state.reading_state.row = Self::row_from_start(next_line);
+            state.ui_state.set_message(
+                "End of chapter — press ! to continue".to_string(),
+                MessageType::Info,
+            );
+            return Ok(());
+        }
         self.tts_notify_worker();
         self.tts_speak_current()
     }
 
-    /// Stop TTS playback — kill the entire process group.
-    fn stop_tts(&mut self) {
+    /// With `tts_stop_at_chapter` on, whether the chunk about to be read
+    /// starts a different chapter than the one just finished.
+    fn tts_crossed_chapter(&self) -> bool {
+        if !self.state.borrow().config.settings.tts_stop_at_chapter {
+            return false;
+        }
+        let Some(previous) = self
+            .tts_chunk_index
+            .checked_sub(1)
+            .and_then(|index| self.tts_chunks.get(index))
+        else {
+            return false;
+        };
+        let next = &self.tts_chunks[self.tts_chunk_index];
+        self.content_index_for_row(previous.first_line)
+            != self.content_index_for_row(next.first_line)
+    }
+
+    /// Skip `delta` chunks (sentences) forward or back while TTS is active,
+    /// clamped to the first and last chunk. The target chunk is read from
+    /// its beginning, so skipping by zero replays the current one.
+    fn tts_skip(&mut self, delta: isize) -> eyre::Result<()> {
+        if self.tts_chunks.is_empty() {
+            return Ok(());
+        }
+        let target = self
+            .tts_chunk_index
+            .saturating_add_signed(delta)
+            .min(self.tts_chunks.len() - 1);
+        self.tts_stop_playback();
+        if target <= self.tts_chunk_index {
+            // Audio converted past a backwards jump would be stale once the
+            // worker starts over at `target`; drop it.
+            let stale: Vec<usize> = self
+                .tts_ready_audio
+                .keys()
+                .copied()
+                .filter(|&index| index >= target)
+                .collect();
+            for index in stale {
+                if let Some(path) = self.tts_ready_audio.remove(&index) {
+                    let _ = std::fs::remove_file(path);
+                }
+            }
+            if let Some(tx) = &self.tts_worker_tx {
+                let _ = tx.send(TtsWorkerCommand::Seek(target));
+            }
+        }
+        self.tts_chunk_index = target;
+        self.tts_speak_current()
+    }
+
+    /// Rebuild the TTS chunks after the text was re-wrapped (terminal
+    /// resize) so underlines and scrolling follow the new line numbers. The
+    /// chunk being spoken keeps playing; reading continues from its
+    /// counterpart in the new layout.
+    fn tts_relayout_chunks(&mut self) {
+        if !self.state.borrow().ui_state.tts_active || self.tts_chunks.is_empty() {
+            return;
+        }
+        let old_texts: Vec<String> = self.tts_chunks.iter().map(|c| c.text.clone()).collect();
+        let narrating = self.tts_chunks.iter().any(|chunk| chunk.clip.is_some());
+        let new_chunks = if narrating {
+            self.build_narration_chunks()
+        } else {
+            self.build_tts_chunks()
+        };
+        if new_chunks.is_empty() {
+            return;
+        }
+        let index = Self::tts_remap_chunk_index(&old_texts, self.tts_chunk_index, &new_chunks);
+        let texts_changed = old_texts.len() != new_chunks.len()
+            || old_texts
+                .iter()
+                .zip(&new_chunks)
+                .any(|(old, new)| *old != new.text);
+        self.tts_chunks = new_chunks;
+        self.tts_chunk_index = index;
+
+        // Prefetched audio is keyed by chunk index; when the chunking itself
+        // changed, start the converter over against the new chunks.
+        if texts_changed && let Some(tx) = self.tts_worker_tx.take() {
+            let _ = tx.send(TtsWorkerCommand::Stop);
+            self.tts_worker_rx = None;
+            for (_, path) in self.tts_ready_audio.drain() {
+                let _ = std::fs::remove_file(&path);
+            }
+            if let Some(temp_dir) = self.tts_temp_dir.clone() {
+                // The chunk on air is already converted; resume after it.
+                let start = if self.tts_current_audio_path.is_some() {
+                    index + 1
+                } else {
+                    index
+                };
+                let engine = self.tts_current_engine.clone();
+                self.tts_spawn_worker(engine, temp_dir, start);
+            }
+        }
+
+        let chunk = &self.tts_chunks[index];
+        let mut state = self.state.borrow_mut();
+        state.ui_state.tts_underline_ranges = chunk.underline.clone();
+        state.ui_state.tts_progress = Some((index + 1, self.tts_chunks.len()));
+    }
+
+    /// Index in `new_chunks` of the chunk that carries the text of
+    /// `old_texts[old_index]`: an exact match nearest the old position,
+    /// else the nearest chunk sharing its opening words, else the same
+    /// relative position.
+    fn tts_remap_chunk_index(
+        old_texts: &[String],
+        old_index: usize,
+        new_chunks: &[TtsChunk],
+    ) -> usize {
+        let last = new_chunks.len().saturating_sub(1);
+        let Some(old_text) = old_texts.get(old_index) else {
+            return old_index.min(last);
+        };
+        let nearest = |matches: &dyn Fn(&TtsChunk) -> bool| {
+            new_chunks
+                .iter()
+                .enumerate()
+                .filter(|(_, chunk)| matches(chunk))
+                .min_by_key(|(i, _)| i.abs_diff(old_index))
+                .map(|(i, _)| i)
+        };
+        let prefix: String = old_text.chars().take(24).collect();
+        nearest(&|chunk| chunk.text == *old_text)
+            .or_else(|| nearest(&|chunk| !prefix.is_empty() && chunk.text.starts_with(&prefix)))
+            .unwrap_or_else(|| (old_index * new_chunks.len() / old_texts.len().max(1)).min(last))
+    }
+
+    /// Kill the process speaking the current chunk and discard its audio,
+    /// leaving the rest of the TTS session intact.
+    fn tts_stop_playback(&mut self) {
         #[allow(unused_variables)]
         if let Some(pid) = self.tts_kill_pid.take() {
             #[cfg(unix)]
@@ -10488,19 +15219,25 @@ where
             let _ = child.kill();
         }
         self.tts_done_rx = None;
+        if let Some(path) = self.tts_current_audio_path.take() {
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+
+    /// Stop TTS playback — kill the entire process group.
+    fn stop_tts(&mut self) {
+        self.tts_stop_playback();
 
         if let Some(tx) = self.tts_worker_tx.take() {
             let _ = tx.send(TtsWorkerCommand::Stop);
         }
         self.tts_worker_rx = None;
 
-        // Delete temp audio files.
-        if let Some(path) = self.tts_current_audio_path.take() {
-            let _ = std::fs::remove_file(&path);
-        }
+        // Delete the remaining temp audio files.
         for (_, path) in self.tts_ready_audio.drain() {
             let _ = std::fs::remove_file(&path);
         }
+        self.narration_audio.clear();
         if let Some(dir) = self.tts_temp_dir.take() {
             let _ = std::fs::remove_dir_all(dir);
         }
@@ -10512,6 +15249,7 @@ where
         state.ui_state.tts_active = false;
         state.ui_state.tts_converting = false;
         state.ui_state.tts_underline_ranges.clear();
+        state.ui_state.tts_progress = None;
     }
 
     /// Check if a program exists in the PATH.
@@ -10546,20 +15284,25 @@ where
 #[cfg(test)]
 mod tests {
     use super::{
-        Reader, SearchResult, TtsChunk, TypographyOptions, WikipediaSearchResponse,
-        WikipediaSummaryResponse,
+        ChapterBreakOptions, CitationLocation, EdgeTtsPlayer, Reader, SearchResult, TtsChunk,
+        TypographyOptions, WikipediaLookup, WikipediaSearchResponse, WikipediaSummaryResponse,
+        clean_lookup_query, fold_search_text, format_citation, format_markdown_quote,
+        format_page_capture, format_time_ago, narration_player_command, page_rows,
+        position_announcement, render_hook_arg, render_yank_template, rolling_words_per_minute,
+        sorted_images, step_target, terminal_title_text, tts_inline_command,
     };
     use crate::config::Config;
     use crate::css::StyledClasses;
     use crate::models::{
-        LibraryItem, LibrarySortMode, ScannedBook, SourceOffsetBias, TextStructure, TocEntry,
+        LibraryItem, LibraryProgressFilter, LibrarySortMode, NarrationClip, ScannedBook,
+        SourceOffsetBias, TextStructure, TocEntry,
     };
     use crate::parser::parse_html_with_styles_and_typography;
     use crate::settings::{CfgDefaultKeymaps, LineSpacing, ParagraphStyle, Settings};
     use crate::state::State;
     use crate::ui::board::Board;
+    use crate::ui::keymap::KeyBindings;
     use crate::ui::reader::{ApplicationState, MessageType};
-    use arboard::Clipboard;
     use ratatui::Terminal;
     use ratatui::backend::CrosstermBackend;
     use std::cell::RefCell;
@@ -10579,6 +15322,8 @@ mod tests {
         let config =
             Config::with_settings(Settings::default(), CfgDefaultKeymaps::default()).unwrap();
         let state = State::new_for_test();
+        let key_bindings =
+            KeyBindings::with_preset(config.keymap_user_dict(), config.keymap_preset);
         let app_state = Rc::new(RefCell::new(ApplicationState::new(config)));
 
         let mut board = Board::new();
@@ -10591,17 +15336,22 @@ mod tests {
         Reader {
             state: app_state,
             terminal: Terminal::new(CrosstermBackend::new(std::io::stdout())).unwrap(),
+            key_bindings,
             db_state: state,
             board,
-            clipboard: Clipboard::new().ok(),
+            clipboard: None,
             ebook: None,
             content_start_rows: Vec::new(),
             chapter_text_structures: Vec::new(),
+            book_images: Vec::new(),
             current_text_width: None,
             current_inline_image_rows: None,
             current_typography: TypographyOptions::default(),
+            current_chapter_break: ChapterBreakOptions::default(),
+            chapter_wrap_cache: Vec::new(),
             dictionary_res_rx: None,
             library_scan_rx: None,
+            library_scan_is_manual: false,
             opds_rx: None,
             opds_request_id: 0,
             opds_catalog_index: None,
@@ -10616,11 +15366,13 @@ mod tests {
             tts_audio_player: None,
             tts_current_audio_path: None,
             tts_ready_audio: HashMap::new(),
+            narration_audio: HashMap::new(),
             tts_worker_tx: None,
             tts_worker_rx: None,
             tts_current_engine: String::new(),
             tts_temp_dir: None,
             reading_session: None,
+            last_saved: None,
             cached_statistics: None,
             graphics: crate::ui::graphics::Graphics::disabled(),
             image_view: None,
@@ -10629,8 +15381,15 @@ mod tests {
             library_covers: HashMap::new(),
             library_cover_pending: None,
             library_cover_redraw_pending: false,
+            metadata_cover: None,
             kosync_pull_rx: None,
             kosync_pull_is_manual: false,
+            page_animation: None,
+            alternate_screen: true,
+            startup_book: None,
+            terminal_active: false,
+            rpc: None,
+            terminal_title: None,
         }
     }
 
@@ -10651,6 +15410,19 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn page_rows_leave_out_the_reader_chrome() {
+        let settings = Settings::default();
+        assert_eq!(page_rows(24, &settings), 19);
+        let bordered = Settings {
+            content_border: true,
+            show_top_bar: false,
+            ..Settings::default()
+        };
+        assert_eq!(page_rows(24, &bordered), 20);
+        assert_eq!(page_rows(3, &settings), 0);
+    }
+
     fn reader_with_source_chapters(chapters: Vec<TextStructure>) -> Reader {
         let mut content_start_rows = Vec::with_capacity(chapters.len());
         let mut text_lines = Vec::new();
@@ -10840,7 +15612,12 @@ mod tests {
         )
         .unwrap();
         let (start, _) = rendered_word_position(&first, "ending.");
-        let break_lines = crate::renderer::build_chapter_break(8, first.text_lines.len());
+        let break_lines = crate::renderer::build_chapter_break(
+            8,
+            first.text_lines.len(),
+            ChapterBreakOptions::default(),
+            None,
+        );
         first.text_lines.extend(break_lines);
         let second = parse_html_with_styles_and_typography(
             "<p>Second chapter opening.</p>",
@@ -10920,12 +15697,58 @@ mod tests {
         assert!(!results[0].preview.contains("gamma\ndelta"));
     }
 
+    #[test]
+    fn source_search_skips_image_placeholders_unless_enabled() {
+        let chapter = parse_html_with_styles_and_typography(
+            r#"<p>An Image of the sea.</p><p><img src="sea.jpg"/></p>"#,
+            Some(40),
+            None,
+            0,
+            &StyledClasses::default(),
+            None,
+            TypographyOptions::default(),
+        )
+        .unwrap();
+        let reader = reader_with_source_chapters(vec![chapter]);
+        let regex = regex::Regex::new("Image").unwrap();
+
+        let results = reader.scan_search_matches(&regex);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].preview, "An Image of the sea.");
+
+        reader
+            .state
+            .borrow_mut()
+            .config
+            .settings
+            .search_placeholders = true;
+        assert_eq!(reader.scan_search_matches(&regex).len(), 2);
+    }
+
+    #[test]
+    fn source_search_results_carry_the_rows_around_the_hit() {
+        let reader = reader_with_source_chapters(vec![source_selection_fixture()]);
+        let results = reader.scan_search_matches(&regex::Regex::new("epsilon").unwrap());
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].context_before.as_deref(),
+            Some("Alpha beta gamma")
+        );
+        assert_eq!(
+            results[0].context_after.as_deref(),
+            Some("The characteris-")
+        );
+    }
+
     #[test]
     fn search_navigation_counts_multi_row_hits_once() {
         let mut reader = make_test_reader((0..12).map(|n| format!("row {n}")).collect());
         let hits = vec![
             SearchResult {
                 preview: "three row hit".into(),
+                context_before: None,
+                context_after: None,
                 content_index: 0,
                 source_start: 0,
                 source_end: 10,
@@ -10933,6 +15756,8 @@ mod tests {
             },
             SearchResult {
                 preview: "next hit".into(),
+                context_before: None,
+                context_after: None,
                 content_index: 0,
                 source_start: 20,
                 source_end: 24,
@@ -10994,6 +15819,8 @@ mod tests {
         let mut reader = make_test_reader((0..12).map(|n| format!("row {n}")).collect());
         let hit = |row| SearchResult {
             preview: format!("hit {row}"),
+            context_before: None,
+            context_after: None,
             content_index: 0,
             source_start: row,
             source_end: row + 1,
@@ -11048,17 +15875,119 @@ mod tests {
     }
 
     #[test]
-    fn source_search_uses_character_columns_for_non_ascii_text() {
-        let chapter = tts_fixture("<p>甲乙 café 丙</p>", 80, TypographyOptions::default());
-        let line = chapter.text_lines[0].clone();
-        let reader = reader_with_source_chapters(vec![chapter]);
-        let results = reader.scan_search_matches(&regex::Regex::new("café").unwrap());
-        let expected_start = line.chars().position(|ch| ch == 'c').unwrap();
-        assert_eq!(results.len(), 1);
+    fn source_search_uses_character_columns_for_non_ascii_text() {
+        let chapter = tts_fixture("<p>甲乙 café 丙</p>", 80, TypographyOptions::default());
+        let line = chapter.text_lines[0].clone();
+        let reader = reader_with_source_chapters(vec![chapter]);
+        let results = reader.scan_search_matches(&regex::Regex::new("café").unwrap());
+        let expected_start = line.chars().position(|ch| ch == 'c').unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].per_row,
+            vec![(0, expected_start, expected_start + 4)]
+        );
+    }
+
+    #[test]
+    fn execute_search_stores_character_columns_for_multibyte_lines() {
+        let chapter = tts_fixture(
+            "<p>Ünïcode 日本語 naïve text</p>",
+            80,
+            TypographyOptions::default(),
+        );
+        let line = chapter.text_lines[0].clone();
+        let mut reader = reader_with_source_chapters(vec![chapter]);
+        reader.state.borrow_mut().ui_state.search_query = "naïve".to_string();
+        reader.execute_search();
+
+        let byte_start = line.find("naïve").unwrap();
+        let char_start = line[..byte_start].chars().count();
+        assert_ne!(byte_start, char_start);
+        let state = reader.state.borrow();
+        assert_eq!(
+            state.ui_state.search_matches.get(&0),
+            Some(&vec![(char_start, char_start + 5)])
+        );
+    }
+
+    #[test]
+    fn lookup_queries_are_composed_cleaned_and_trimmed() {
+        assert_eq!(clean_lookup_query("word,"), "word");
+        assert_eq!(clean_lookup_query("  \u{201C}Hello!\u{201D} "), "Hello");
+        assert_eq!(clean_lookup_query("(e.g."), "e.g");
+        // A combining acute accent composes into a single `é`.
+        assert_eq!(clean_lookup_query("cafe\u{0301}."), "caf\u{00E9}");
+        assert_eq!(
+            clean_lookup_query("in\u{00AD}com\u{200B}plete"),
+            "incomplete"
+        );
+        assert_eq!(clean_lookup_query("New\n  York"), "New York");
+        assert_eq!(clean_lookup_query("C++"), "C++");
+        assert_eq!(
+            clean_lookup_query("\u{3002}\u{6F22}\u{5B57}\u{3001}"),
+            "\u{6F22}\u{5B57}"
+        );
+        assert_eq!(clean_lookup_query("..."), "");
+    }
+
+    #[test]
+    fn single_word_lookups_lose_surrounding_punctuation_but_phrases_do_not() {
+        assert_eq!(clean_lookup_query("\"Hello,\""), "Hello");
+        assert_eq!(clean_lookup_query("(foo)"), "foo");
+        assert_eq!(clean_lookup_query("co-op"), "co-op");
+        assert_eq!(clean_lookup_query("'don't'"), "don't");
+        assert_eq!(
+            clean_lookup_query("\u{2018}rock\u{2019}n\u{2019}roll\u{2019}"),
+            "rock\u{2019}n\u{2019}roll"
+        );
+        assert_eq!(
+            clean_lookup_query("\"New York,\" he said."),
+            "\"New York,\" he said."
+        );
+    }
+
+    #[test]
+    fn fold_search_text_maps_folded_characters_back_to_the_source() {
+        assert_eq!(fold_search_text("plain text - 'quoted'"), None);
+        let (folded, origin) = fold_search_text("a\u{FB01}n\u{2019}t\u{2014}").unwrap();
+        assert_eq!(folded, "afin't-");
+        assert_eq!(origin, vec![0, 1, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn normalize_search_matches_typographic_text_with_plain_queries() {
+        let chapter = tts_fixture(
+            "<p>I don\u{2019}t mind the \u{201C}\u{FB01}ne\u{201D} print.</p>",
+            80,
+            TypographyOptions::default(),
+        );
+        let line: Vec<char> = chapter.text_lines[0].chars().collect();
+        let mut reader = reader_with_source_chapters(vec![chapter]);
+        let column = |needle: char| line.iter().position(|&c| c == needle).unwrap();
+
+        reader.state.borrow_mut().ui_state.search_query = "don't".to_string();
+        reader.execute_search();
         assert_eq!(
-            results[0].per_row,
-            vec![(0, expected_start, expected_start + 4)]
+            reader.state.borrow().ui_state.search_matches.get(&0),
+            Some(&vec![(column('d'), column('t') + 1)])
+        );
+
+        reader.state.borrow_mut().ui_state.search_query = "\"fine\"".to_string();
+        reader.execute_search();
+        assert_eq!(
+            reader.state.borrow().ui_state.search_matches.get(&0),
+            Some(&vec![(column('\u{201C}'), column('\u{201D}') + 1)])
+        );
+        // The results list previews the book's own characters.
+        assert!(
+            reader.state.borrow().ui_state.search_results[0]
+                .preview
+                .contains("\u{201C}\u{FB01}ne\u{201D}")
         );
+
+        reader.state.borrow_mut().config.settings.normalize_search = false;
+        reader.execute_search();
+        assert!(reader.state.borrow().ui_state.search_results.is_empty());
     }
 
     fn read_request_line(stream: TcpStream) -> (TcpStream, String) {
@@ -11166,6 +16095,187 @@ mod tests {
         assert_eq!(reader.toc_activation_row(&toc_entries, 0), Some(1));
     }
 
+    #[test]
+    fn format_time_ago_uses_coarse_units() {
+        assert_eq!(format_time_ago(chrono::TimeDelta::hours(30)), "1 day ago");
+        assert_eq!(format_time_ago(chrono::TimeDelta::days(3)), "3 days ago");
+        assert_eq!(format_time_ago(chrono::TimeDelta::days(20)), "2 weeks ago");
+        assert_eq!(format_time_ago(chrono::TimeDelta::days(95)), "3 months ago");
+        assert_eq!(format_time_ago(chrono::TimeDelta::days(800)), "2 years ago");
+    }
+
+    #[test]
+    fn markdown_quote_prefixes_lines_and_attributes_source() {
+        assert_eq!(
+            format_markdown_quote(
+                "First line\nsecond line\n\nNext paragraph\n",
+                Some("Meditations"),
+                Some("Book II")
+            ),
+            "> First line\n> second line\n>\n> Next paragraph\n\n— Meditations, Book II"
+        );
+        assert_eq!(format_markdown_quote("Alone", None, None), "> Alone");
+    }
+
+    #[test]
+    fn citation_names_author_year_title_and_pages() {
+        let meta = crate::models::BookMetadata {
+            title: Some("Meditations".to_string()),
+            creator: Some("Marcus Aurelius".to_string()),
+            publisher: Some("Penguin".to_string()),
+            date: Some("2004-06-01".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            format_citation(
+                "Waste no more time\narguing",
+                Some(&meta),
+                &CitationLocation::Pages("41".to_string(), "41".to_string()),
+            ),
+            "\u{201c}Waste no more time arguing\u{201d}\n\nMarcus Aurelius (2004). Meditations, Penguin, p. 41."
+        );
+        assert!(
+            format_citation(
+                "x",
+                Some(&meta),
+                &CitationLocation::Pages("41".to_string(), "42".to_string())
+            )
+            .ends_with("pp. 41–42.")
+        );
+        assert_eq!(
+            format_citation("x", None, &CitationLocation::Percent(37)),
+            "\u{201c}x\u{201d}\n\n(n.d.). 37%."
+        );
+    }
+
+    #[test]
+    fn page_capture_has_header_and_drops_trailing_blank_lines() {
+        assert_eq!(
+            format_page_capture(Some("Book II"), 42, &["First line  ", "", "Second", "", ""]),
+            "## Book II (42%)\n\nFirst line\n\nSecond\n\n"
+        );
+        assert_eq!(format_page_capture(None, 7, &["Only"]), "## 7%\n\nOnly\n\n");
+    }
+
+    #[test]
+    fn step_target_picks_the_neighbouring_row_and_wraps() {
+        let rows = [10, 40, 90];
+        assert_eq!(step_target(&rows, 10, true), Some((1, false)));
+        assert_eq!(step_target(&rows, 95, true), Some((0, true)));
+        assert_eq!(step_target(&rows, 40, false), Some((0, false)));
+        assert_eq!(step_target(&rows, 5, false), Some((2, true)));
+        assert_eq!(step_target(&[], 5, true), None);
+    }
+
+    #[test]
+    fn position_announcement_names_chapter_percent_and_line() {
+        assert_eq!(
+            position_announcement(Some("Book II"), 42, Some("  From my grandfather Verus ")),
+            "Book II, 42 percent. From my grandfather Verus"
+        );
+        assert_eq!(position_announcement(None, 0, None), "0 percent.");
+        assert_eq!(position_announcement(Some(" "), 5, Some("")), "5 percent.");
+    }
+
+    #[test]
+    fn rolling_words_per_minute_needs_enough_reading_time() {
+        use std::collections::VecDeque;
+        use std::time::{Duration, Instant};
+
+        let start = Instant::now();
+        let samples = VecDeque::from([
+            (start, 0),
+            (start + Duration::from_secs(60), 200),
+            (start + Duration::from_secs(120), 500),
+        ]);
+        let wpm = rolling_words_per_minute(&samples, start + Duration::from_secs(120))
+            .expect("two minutes of reading");
+        assert!((wpm - 250.0).abs() < 1e-9);
+
+        // Too short a span, or no words at all, gives no pace yet.
+        assert_eq!(
+            rolling_words_per_minute(&samples, start + Duration::from_secs(10)),
+            None
+        );
+        let idle = VecDeque::from([(start, 40), (start + Duration::from_secs(90), 40)]);
+        assert_eq!(
+            rolling_words_per_minute(&idle, start + Duration::from_secs(90)),
+            None
+        );
+    }
+
+    #[test]
+    fn yank_template_expands_fields_and_keeps_unknown_ones() {
+        assert_eq!(
+            render_yank_template(
+                "\"%s\" — %a, %t (%c, %p) 100%% %x",
+                "Be brief.",
+                Some("Meditations"),
+                Some("Marcus Aurelius"),
+                Some(" Book II "),
+                42,
+            ),
+            "\"Be brief.\" — Marcus Aurelius, Meditations (Book II, 42%) 100% %x"
+        );
+        assert_eq!(render_yank_template("%s", "x", None, None, None, 0), "x");
+        assert_eq!(
+            render_yank_template("%t|%a|%c%", "x", None, None, None, 0),
+            "||%"
+        );
+    }
+
+    #[test]
+    fn hook_args_expand_file_percent_and_title() {
+        let render = |arg: &str| render_hook_arg(arg, "/books/a b.epub", 37, "Walden");
+        assert_eq!(render("%f"), "/books/a b.epub");
+        assert_eq!(render("--progress=%p"), "--progress=37");
+        assert_eq!(render("%t: %p%%"), "Walden: 37%");
+        assert_eq!(render("%x %"), "%x %");
+    }
+
+    #[test]
+    fn terminal_title_names_book_and_chapter_without_control_characters() {
+        assert_eq!(
+            terminal_title_text(" Walden ", Some("Economy")),
+            "Walden — Economy"
+        );
+        assert_eq!(terminal_title_text("Walden", Some("  ")), "Walden");
+        assert_eq!(terminal_title_text("Walden", None), "Walden");
+        assert_eq!(
+            terminal_title_text("Evil\x07\x1b]0;x", Some("One\ntwo")),
+            "Evil]0;x — Onetwo"
+        );
+    }
+
+    #[test]
+    fn tts_inline_command_adds_rate_and_voice_for_espeak_and_say() {
+        let args = |engine: &str| {
+            tts_inline_command(engine, "Hello there", Some(200), Some("en-us")).unwrap()
+        };
+        assert_eq!(
+            args("espeak-ng"),
+            (
+                "espeak-ng".to_string(),
+                vec!["-s", "200", "-v", "en-us", "Hello there"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect()
+            )
+        );
+        assert_eq!(args("/usr/bin/say").1[..2], ["-r", "200"]);
+        assert_eq!(args("festival").1, vec!["Hello there"]);
+        // A template is the user's full command line; nothing is added.
+        assert_eq!(
+            args("espeak -s 120 {}").1,
+            vec!["-s", "120", "Hello", "there"]
+        );
+        assert_eq!(
+            tts_inline_command("say", "Hi", None, None).unwrap().1,
+            vec!["Hi"]
+        );
+        assert!(tts_inline_command("{}", "", None, None).is_none());
+    }
+
     #[test]
     fn resolve_relative_href_joins_base_dir() {
         let resolved = TestReader::resolve_relative_href(
@@ -11190,6 +16300,29 @@ mod tests {
         assert_eq!(resolved, Some("Text/chapter007.xhtml".to_string()));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn clipboard_command_receives_text_on_stdin() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("clip.txt");
+        let command = format!("sh -c 'cat > \"{}\"'", out.display());
+        TestReader::pipe_to_clipboard_command(&command, "copied text").unwrap();
+        assert_eq!(std::fs::read_to_string(&out).unwrap(), "copied text");
+        assert!(TestReader::pipe_to_clipboard_command("false", "x").is_err());
+    }
+
+    #[test]
+    fn join_dictionary_outputs_adds_a_header_per_client() {
+        let outputs = vec![
+            ("sdcv".to_string(), "apple: a fruit".to_string()),
+            ("dict".to_string(), "apple\n  n 1: fruit".to_string()),
+        ];
+        assert_eq!(
+            TestReader::join_dictionary_outputs(&outputs),
+            "== sdcv ==\n\napple: a fruit\n\n== dict ==\n\napple\n  n 1: fruit"
+        );
+    }
+
     #[test]
     fn build_dictionary_command_replaces_placeholder() {
         let (program, args) =
@@ -11322,9 +16455,13 @@ mod tests {
             write_json_response(&mut stream, "200 OK", body);
         });
 
-        let result = TestReader::wikipedia_lookup_summary("Rust", &base, Duration::from_secs(2))
-            .expect("direct lookup should succeed");
+        let result =
+            TestReader::wikipedia_lookup_summary("Rust", &base, None, Duration::from_secs(2))
+                .expect("direct lookup should succeed");
         server.join().unwrap();
+        let WikipediaLookup::Summary(result) = result else {
+            panic!("expected a summary");
+        };
 
         assert_eq!(result.url, "https://simple.wikipedia.org/wiki/Rust");
         assert!(result.summary.contains("systems programming language"));
@@ -11386,9 +16523,12 @@ mod tests {
         });
 
         let result =
-            TestReader::wikipedia_lookup_summary("NoSuchTerm", &base, Duration::from_secs(2))
+            TestReader::wikipedia_lookup_summary("NoSuchTerm", &base, None, Duration::from_secs(2))
                 .expect("fallback lookup should succeed");
         server.join().unwrap();
+        let WikipediaLookup::Summary(result) = result else {
+            panic!("expected a summary");
+        };
 
         assert_eq!(
             result.url,
@@ -11397,6 +16537,126 @@ mod tests {
         assert!(result.summary.contains("focused on safety"));
     }
 
+    #[test]
+    fn wikipedia_lookup_summary_lists_disambiguation_options() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let (mut stream, request_line) = read_request_line(stream);
+            assert!(request_line.contains("titles=Mercury"));
+            let disambiguation = r#"{
+              "query": {
+                "pages": {
+                  "99": {
+                    "title": "Mercury",
+                    "extract": "Mercury may refer to:",
+                    "pageprops": {
+                      "disambiguation": ""
+                    }
+                  }
+                }
+              }
+            }"#;
+            write_json_response(&mut stream, "200 OK", disambiguation);
+
+            let (stream, _) = listener.accept().unwrap();
+            let (mut stream, request_line) = read_request_line(stream);
+            assert!(request_line.contains("prop=links"));
+            assert!(request_line.contains("plnamespace=0"));
+            assert!(request_line.contains("titles=Mercury"));
+            let links = r#"{
+              "query": {
+                "pages": {
+                  "99": {
+                    "title": "Mercury",
+                    "links": [
+                      { "ns": 0, "title": "Mercury (element)" },
+                      { "ns": 0, "title": "Mercury (planet)" }
+                    ]
+                  }
+                }
+              }
+            }"#;
+            write_json_response(&mut stream, "200 OK", links);
+        });
+
+        let result =
+            TestReader::wikipedia_lookup_summary("Mercury", &base, None, Duration::from_secs(2))
+                .expect("disambiguation lookup should succeed");
+        server.join().unwrap();
+
+        let WikipediaLookup::Disambiguation(disambiguation) = result else {
+            panic!("expected disambiguation options");
+        };
+        assert_eq!(disambiguation.language, base);
+        assert_eq!(
+            disambiguation.titles,
+            vec!["Mercury (element)", "Mercury (planet)"]
+        );
+    }
+
+    #[test]
+    fn wikipedia_lookup_summary_retries_on_the_fallback_language() {
+        let primary = TcpListener::bind("127.0.0.1:0").unwrap();
+        let fallback = TcpListener::bind("127.0.0.1:0").unwrap();
+        let primary_base = format!("http://{}", primary.local_addr().unwrap());
+        let fallback_base = format!("http://{}", fallback.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (stream, _) = primary.accept().unwrap();
+            let (mut stream, request_line) = read_request_line(stream);
+            assert!(request_line.contains("titles=Borrowck"));
+            let miss = r#"{
+              "query": {
+                "pages": {
+                  "-1": {
+                    "title": "Borrowck",
+                    "missing": ""
+                  }
+                }
+              }
+            }"#;
+            write_json_response(&mut stream, "200 OK", miss);
+
+            let (stream, _) = fallback.accept().unwrap();
+            let (mut stream, request_line) = read_request_line(stream);
+            assert!(request_line.contains("titles=Borrowck"));
+            let hit = r#"{
+              "query": {
+                "pages": {
+                  "789": {
+                    "title": "Borrowck",
+                    "extract": "The borrow checker enforces ownership rules.",
+                    "fullurl": "https://en.wikipedia.org/wiki/Borrowck"
+                  }
+                }
+              }
+            }"#;
+            write_json_response(&mut stream, "200 OK", hit);
+        });
+
+        let result = TestReader::wikipedia_lookup_summary(
+            "Borrowck",
+            &primary_base,
+            Some(&fallback_base),
+            Duration::from_secs(2),
+        )
+        .expect("fallback language lookup should succeed");
+        server.join().unwrap();
+        let WikipediaLookup::Summary(result) = result else {
+            panic!("expected a summary");
+        };
+
+        assert_eq!(result.url, "https://en.wikipedia.org/wiki/Borrowck");
+        assert_eq!(
+            TestReader::alternate_english_wikipedia("simple"),
+            Some("en")
+        );
+        assert_eq!(TestReader::alternate_english_wikipedia("de"), None);
+    }
+
     #[test]
     fn tts_detection_hint_on_missing_program() {
         let mut reader = make_test_reader(vec!["Some text to read for TTS test.".to_string()]);
@@ -11449,6 +16709,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn tts_reads_image_descriptions_in_reading_order() {
+        let chapter = tts_fixture(
+            r#"<p>Before the figure.</p><p><img src="barn.png" alt="A red barn at dusk"></p><p><img src="logo.png"></p><p>After the figure.</p>"#,
+            40,
+            TypographyOptions::default(),
+        );
+        let mut reader = reader_with_source_chapters(vec![chapter.clone()]);
+        reader.book_images = sorted_images(&chapter.image_maps);
+        reader.board.update_text_structure(chapter);
+        let texts: Vec<String> = reader
+            .build_tts_chunks()
+            .into_iter()
+            .map(|chunk| chunk.text)
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Before the figure.",
+                "Image: A red barn at dusk",
+                "After the figure."
+            ]
+        );
+    }
+
     #[test]
     fn tts_chunks_use_clean_source_with_justified_indented_text() {
         let chapter = tts_fixture(
@@ -11475,6 +16760,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn narration_players_seek_to_the_clip() {
+        let path = std::path::Path::new("/tmp/ch1.mp3");
+        assert_eq!(
+            EdgeTtsPlayer::Mpv.clip_args(path, 1.5, Some(4.0)),
+            [
+                "--really-quiet",
+                "--no-video",
+                "--start=1.500",
+                "--end=4.000",
+                "/tmp/ch1.mp3"
+            ]
+        );
+        assert_eq!(
+            EdgeTtsPlayer::Ffplay.clip_args(path, 1.5, None),
+            [
+                "-nodisp",
+                "-autoexit",
+                "-loglevel",
+                "quiet",
+                "-ss",
+                "1.500",
+                "/tmp/ch1.mp3"
+            ]
+        );
+    }
+
+    #[test]
+    fn narration_player_template_keeps_a_spaced_path_whole() {
+        let clip = NarrationClip {
+            fragment: "s1".to_string(),
+            audio: "audio/ch1.mp3".to_string(),
+            clip_begin: 1.5,
+            clip_end: None,
+        };
+        let audio = std::path::Path::new("/tmp/My Books/ch1.mp3");
+        assert_eq!(
+            narration_player_command("mpv --start={start} --end={end} {file}", audio, &clip),
+            Some((
+                "mpv".to_string(),
+                vec![
+                    "--start=1.500".to_string(),
+                    "--end=86401.500".to_string(),
+                    "/tmp/My Books/ch1.mp3".to_string(),
+                ]
+            ))
+        );
+        assert_eq!(narration_player_command("  ", audio, &clip), None);
+    }
+
+    #[test]
+    fn narration_chunks_underline_each_clip_up_to_the_next_one() {
+        let chapter = tts_fixture(
+            r#"<p><span id="s1">The cat sat on the mat.</span> <span id="s2">It purred.</span></p><p id="s3">The end.</p>"#,
+            40,
+            TypographyOptions::default(),
+        );
+        let reader = reader_with_source_chapters(vec![chapter]);
+        let clip = |fragment: &str, begin: f64| NarrationClip {
+            fragment: fragment.to_string(),
+            audio: "audio/ch1.mp3".to_string(),
+            clip_begin: begin,
+            clip_end: Some(begin + 1.0),
+        };
+        let clips = vec![
+            clip("s1", 0.0),
+            clip("missing", 1.0),
+            clip("s2", 2.0),
+            clip("s3", 3.0),
+        ];
+        let chunks = reader.narration_chunks(&[(0, clips)]);
+        let texts: Vec<&str> = chunks.iter().map(|chunk| chunk.text.as_str()).collect();
+        assert_eq!(texts, ["The cat sat on the mat.", "It purred.", "The end."]);
+        let begins: Vec<f64> = chunks
+            .iter()
+            .map(|chunk| chunk.clip.as_ref().unwrap().clip_begin)
+            .collect();
+        assert_eq!(begins, [0.0, 2.0, 3.0]);
+        assert_eq!(chunks[0].underline.get(&0), Some(&(0, 23)));
+        assert_eq!(chunks[2].first_line, 2);
+    }
+
     #[test]
     fn tts_chunk_projects_an_unbroken_word_across_a_wrap_hyphen() {
         let hyphen = tts_fixture(
@@ -11548,6 +16915,85 @@ mod tests {
         assert_eq!(target_row, 11);
     }
 
+    #[test]
+    fn tts_remap_chunk_index_follows_the_spoken_text() {
+        let chunk = |text: &str| TtsChunk {
+            text: text.to_string(),
+            first_line: 0,
+            underline: HashMap::new(),
+            clip: None,
+        };
+        let old: Vec<String> = [
+            "Intro.",
+            "Again.",
+            "Middle sentence here.",
+            "Again.",
+            "End.",
+        ]
+        .iter()
+        .map(|t| t.to_string())
+        .collect();
+        let new = vec![
+            chunk("Intro."),
+            chunk("Again."),
+            chunk("Middle sentence here."),
+            chunk("Again."),
+            chunk("End."),
+        ];
+        // Duplicate text resolves to the occurrence nearest the old index.
+        assert_eq!(TestReader::tts_remap_chunk_index(&old, 3, &new), 3);
+        assert_eq!(TestReader::tts_remap_chunk_index(&old, 1, &new), 1);
+
+        // A chunk split differently is found by its opening words.
+        let split = vec![
+            chunk("Intro. Again."),
+            chunk("Middle sentence here. And more."),
+            chunk("Then again. End."),
+        ];
+        assert_eq!(TestReader::tts_remap_chunk_index(&old, 2, &split), 1);
+        // No textual match falls back to the same relative position.
+        assert_eq!(TestReader::tts_remap_chunk_index(&old, 4, &split), 2);
+    }
+
+    #[test]
+    fn tts_stop_at_chapter_pauses_before_the_next_chapter() {
+        let mut reader = make_test_reader(
+            ["One.", "Two.", "", "Three.", "Four."]
+                .map(String::from)
+                .to_vec(),
+        );
+        reader.content_start_rows = vec![0, 3];
+        let chunk = |line: usize| TtsChunk {
+            text: format!("line {line}"),
+            first_line: line,
+            underline: HashMap::from([(line, (0, 4))]),
+            clip: None,
+        };
+        reader.tts_chunks = vec![chunk(0), chunk(1), chunk(3), chunk(4)];
+        reader.tts_chunk_index = 1;
+        reader
+            .state
+            .borrow_mut()
+            .config
+            .settings
+            .tts_stop_at_chapter = true;
+        reader.state.borrow_mut().ui_state.tts_active = true;
+
+        reader.tts_advance_paragraph().unwrap();
+
+        let state = reader.state.borrow();
+        assert!(!state.ui_state.tts_active);
+        assert_eq!(state.reading_state.row, 4);
+        assert_eq!(
+            state.ui_state.message.as_deref(),
+            Some("End of chapter — press ! to continue")
+        );
+        drop(state);
+        // `!` resumes with the first chunk of the next chapter.
+        reader.tts_chunks = vec![chunk(0), chunk(1), chunk(3), chunk(4)];
+        assert_eq!(reader.find_chunk_at(3), Some(2));
+    }
+
     #[test]
     fn find_chunk_at_uses_visible_top_line_without_skipping_footnotes() {
         let mut reader = make_test_reader(vec![
@@ -11561,12 +17007,14 @@ mod tests {
                 text: "[1] Mahaparinibbana Sutta, Digha Nikaya 16.".to_string(),
                 first_line: 0,
                 underline: HashMap::from([(0, (0, 43))]),
+                clip: None,
             },
             TtsChunk {
                 text: "[2] See chap. 3, n. 1, on [here], regarding the use of the word \"Right.\""
                     .to_string(),
                 first_line: 2,
                 underline: HashMap::from([(2, (0, 73))]),
+                clip: None,
             },
         ];
 
@@ -11612,6 +17060,7 @@ mod tests {
             title: Some(title.to_string()),
             author: Some(format!("{} Author", title)),
             reading_progress: Some(progress),
+            finished: false,
         }
     }
 
@@ -11754,6 +17203,34 @@ mod tests {
         // Progress descending; books without progress sort last.
         assert_eq!(titles, vec!["Mango", "Zebra", "Apple"]);
     }
+
+    #[test]
+    fn test_library_progress_filter_buckets_entries() {
+        let mut finished = history_item("/h/done.epub", "Done", 1, 0.4);
+        finished.finished = true;
+        let history = vec![
+            history_item("/h/started.epub", "Started", 5, 0.2),
+            history_item("/h/opened.epub", "Opened", 10, 0.0),
+            finished,
+        ];
+        let scanned = vec![scanned_book("/d/new.epub", "New")];
+        let entries = TestReader::merge_library_entries(history, scanned, LibrarySortMode::Title);
+        let titles = |filter: LibraryProgressFilter| -> Vec<String> {
+            entries
+                .iter()
+                .filter(|e| filter.matches(e))
+                .map(|e| e.title.clone().unwrap())
+                .collect()
+        };
+        assert_eq!(titles(LibraryProgressFilter::All).len(), 4);
+        assert_eq!(titles(LibraryProgressFilter::Unread), vec!["New", "Opened"]);
+        assert_eq!(titles(LibraryProgressFilter::InProgress), vec!["Started"]);
+        assert_eq!(titles(LibraryProgressFilter::Finished), vec!["Done"]);
+        assert_eq!(
+            LibraryProgressFilter::Finished.next(),
+            LibraryProgressFilter::All
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1,14 +1,19 @@
 //! Integration-style snapshot tests that drive `Reader<TestBackend>` through
 //! synthetic key events and snapshot the rendered 80x24 screen with insta.
 
-use super::{READING_JUMP_MIN_THRESHOLD_ROWS, Reader, SearchResult, SettingItem};
+use super::{
+    CHAPTER_WRAP_CACHE_LIMIT, READING_IDLE_TIMEOUT, READING_JUMP_MIN_THRESHOLD_ROWS, Reader,
+    SearchResult, SettingItem,
+};
 use crate::config::Config;
-use crate::models::ReadingState;
-use crate::settings::{CfgDefaultKeymaps, Settings};
+use crate::models::{ReadingState, WindowType};
+use crate::settings::{CfgDefaultKeymaps, DEFAULT_TEXT_WIDTH, Settings};
 use crate::state::State;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::backend::TestBackend;
+use std::collections::VecDeque;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 fn test_reader() -> Reader<TestBackend> {
     test_reader_with_settings(Settings::default())
@@ -16,8 +21,13 @@ fn test_reader() -> Reader<TestBackend> {
 
 fn test_reader_with_settings(settings: Settings) -> Reader<TestBackend> {
     let config = Config::with_settings(settings, CfgDefaultKeymaps::default()).unwrap();
-    let mut reader = Reader::with_backend(config, TestBackend::new(80, 24), State::new_for_test())
-        .expect("failed to construct test reader");
+    let mut reader = Reader::with_backend(
+        config,
+        TestBackend::new(80, 24),
+        State::new_for_test(),
+        None,
+    )
+    .expect("failed to construct test reader");
 
     let fixture_path = format!("{}/tests/fixtures/small.epub", env!("CARGO_MANIFEST_DIR"));
     reader
@@ -63,8 +73,13 @@ fn invalid_config_starts_with_warning_and_blocks_settings_save() {
         path.display()
     );
     let config = Config::fallback(path, error);
-    let mut reader = Reader::with_backend(config, TestBackend::new(80, 24), State::new_for_test())
-        .expect("failed to construct fallback reader");
+    let mut reader = Reader::with_backend(
+        config,
+        TestBackend::new(80, 24),
+        State::new_for_test(),
+        None,
+    )
+    .expect("failed to construct fallback reader");
     let fixture_path = format!("{}/tests/fixtures/small.epub", env!("CARGO_MANIFEST_DIR"));
     reader
         .load_ebook(&fixture_path)
@@ -97,6 +112,267 @@ fn scroll_down() {
     insta::assert_snapshot!(reader.terminal.backend());
 }
 
+#[test]
+fn rtl_book_right_aligns_and_turns_forward_with_h() {
+    let mut reader = test_reader();
+    reader.state.borrow_mut().ui_state.is_rtl = true;
+    for _ in 0..3 {
+        press_char(&mut reader, 'h');
+    }
+    assert!(reader.state.borrow().reading_state.row > 0);
+    insta::assert_snapshot!(reader.terminal.backend());
+}
+
+#[test]
+fn yank_without_clipboard_reports_unavailable() {
+    let mut reader = test_reader();
+    reader.clipboard = None;
+    for key in ['v', 'v', 'l', 'l', 'y'] {
+        press_char(&mut reader, key);
+    }
+    let state = reader.state.borrow();
+    assert_eq!(
+        state.ui_state.message.as_deref(),
+        Some("Clipboard unavailable")
+    );
+}
+
+#[test]
+fn digits_jump_to_percent_when_count_prefix_is_off() {
+    let mut reader = test_reader_with_settings(Settings {
+        count_prefix_enabled: false,
+        ..Settings::default()
+    });
+    press_char(&mut reader, '5');
+    let half = reader.board.row_for_fraction(0.5);
+    assert!(half > 0);
+    assert_eq!(reader.state.borrow().reading_state.row, half);
+    assert!(reader.state.borrow().count_prefix.is_empty());
+
+    press_char(&mut reader, '0');
+    assert_eq!(reader.state.borrow().reading_state.row, 0);
+    // The jump is recorded, so Ctrl+o returns to the 50% mark.
+    reader
+        .handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(reader.state.borrow().reading_state.row, half);
+}
+
+#[test]
+fn pending_count_prefix_shows_in_the_bottom_right() {
+    let mut reader = test_reader();
+    type_str(&mut reader, "25");
+    insta::assert_snapshot!(reader.terminal.backend());
+
+    // Esc drops the count along with the indicator.
+    press(&mut reader, KeyCode::Esc);
+    assert!(reader.state.borrow().count_prefix.is_empty());
+    let screen = format!("{}", reader.terminal.backend());
+    assert!(!screen.lines().last().unwrap().contains("25"));
+}
+
+#[test]
+fn esc_cancels_a_pending_count_without_leaving_cursor_mode() {
+    let mut reader = test_reader();
+    press_char(&mut reader, 'v');
+    type_str(&mut reader, "12");
+    press(&mut reader, KeyCode::Esc);
+    assert!(reader.state.borrow().count_prefix.is_empty());
+    assert_eq!(
+        reader.state.borrow().ui_state.active_window,
+        super::WindowType::Visual
+    );
+
+    // With nothing pending, Esc leaves cursor mode as before.
+    press(&mut reader, KeyCode::Esc);
+    assert_eq!(
+        reader.state.borrow().ui_state.active_window,
+        super::WindowType::Reader
+    );
+}
+
+#[test]
+fn count_prefix_t_jumps_to_chapter_number() {
+    let mut reader = test_reader();
+    let rows = reader.chapter_rows();
+    // The fixture has no TOC, so chapters are its spine documents.
+    let rows = if rows.is_empty() {
+        reader.content_start_rows.clone()
+    } else {
+        rows
+    };
+    type_str(&mut reader, "3t");
+    assert_eq!(reader.state.borrow().reading_state.row, rows[2]);
+    assert_eq!(
+        reader.state.borrow().ui_state.active_window,
+        super::WindowType::Reader
+    );
+    type_str(&mut reader, "999t");
+    assert_eq!(
+        reader.state.borrow().reading_state.row,
+        *rows.last().unwrap()
+    );
+}
+
+#[test]
+fn braces_move_between_paragraph_starts() {
+    let mut reader = test_reader();
+    let starts: Vec<usize> = reader
+        .paragraph_ranges()
+        .into_iter()
+        .map(|(start, _)| start)
+        .filter(|&start| start > 0)
+        .collect();
+    assert!(starts.len() >= 3);
+    press_char(&mut reader, '}');
+    assert_eq!(reader.state.borrow().reading_state.row, starts[0]);
+    type_str(&mut reader, "2}");
+    assert_eq!(reader.state.borrow().reading_state.row, starts[2]);
+    press_char(&mut reader, '{');
+    assert_eq!(reader.state.borrow().reading_state.row, starts[1]);
+}
+
+#[test]
+fn zz_centers_the_current_line_within_its_chapter() {
+    let mut reader = test_reader();
+    let page = reader.page_size();
+    let (chapter_start, _) = reader
+        .content_start_rows
+        .clone()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, _)| reader.chapter_bounds_for_index(index))
+        .filter(|&(start, _)| start > 0)
+        .max_by_key(|&(start, end)| end - start)
+        .unwrap();
+
+    let row = chapter_start + page;
+    reader.state.borrow_mut().reading_state.row = row;
+    type_str(&mut reader, "zz");
+    assert_eq!(
+        reader.state.borrow().reading_state.row,
+        Reader::<TestBackend>::row_from_start(row - page / 2)
+    );
+
+    // Near the chapter start the viewport stops at the chapter's first line.
+    reader.state.borrow_mut().reading_state.row = chapter_start + 2;
+    type_str(&mut reader, "zz");
+    assert_eq!(reader.state.borrow().reading_state.row, chapter_start + 1);
+    assert!(!reader.state.borrow().ui_state.pending_z_command);
+}
+
+#[test]
+fn zt_and_zb_put_the_current_line_at_the_viewport_edges() {
+    let mut reader = test_reader();
+    let page = reader.page_size();
+    let (chapter_start, _) = reader
+        .content_start_rows
+        .clone()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, _)| reader.chapter_bounds_for_index(index))
+        .max_by_key(|&(start, end)| end - start)
+        .unwrap();
+    let row = chapter_start + page;
+
+    reader.state.borrow_mut().reading_state.row = row;
+    type_str(&mut reader, "zt");
+    assert_eq!(
+        reader.state.borrow().reading_state.row,
+        Reader::<TestBackend>::row_from_start(row)
+    );
+
+    reader.state.borrow_mut().reading_state.row = row;
+    type_str(&mut reader, "zb");
+    assert_eq!(
+        reader.state.borrow().reading_state.row,
+        Reader::<TestBackend>::row_from_start(row + 1 - page)
+    );
+
+    // Any other key cancels the pending `z` without scrolling.
+    reader.state.borrow_mut().reading_state.row = row;
+    type_str(&mut reader, "zq");
+    assert_eq!(reader.state.borrow().reading_state.row, row);
+    assert!(!reader.state.borrow().should_quit);
+}
+
+#[cfg(unix)]
+#[test]
+fn tts_sentence_keys_step_between_chunks() {
+    let mut reader = test_reader();
+    reader.tts_chunks = reader.build_tts_chunks();
+    assert!(reader.tts_chunks.len() >= 2);
+    // `true` acts as an inline engine that "speaks" instantly.
+    reader.tts_current_engine = "true".to_string();
+    reader.tts_chunk_index = 1;
+    reader.state.borrow_mut().ui_state.tts_active = true;
+
+    press_char(&mut reader, ',');
+    assert_eq!(reader.tts_chunk_index, 0);
+    press(&mut reader, KeyCode::Left);
+    assert_eq!(reader.tts_chunk_index, 0);
+    press_char(&mut reader, '.');
+    press(&mut reader, KeyCode::Right);
+    assert_eq!(reader.tts_chunk_index, 2.min(reader.tts_chunks.len() - 1));
+    assert!(reader.state.borrow().ui_state.tts_active);
+    assert_eq!(
+        reader.state.borrow().ui_state.tts_progress,
+        Some((reader.tts_chunk_index + 1, reader.tts_chunks.len()))
+    );
+    reader.stop_tts();
+    assert_eq!(reader.state.borrow().ui_state.tts_progress, None);
+}
+
+#[test]
+fn resize_during_tts_keeps_the_spoken_chunk() {
+    let mut reader = test_reader();
+    reader.tts_chunks = reader.build_tts_chunks();
+    // The last chunk of a chapter in the middle of the book, read from
+    // there: a resize re-wraps the chapter being read.
+    let middle = reader.tts_chunks[reader.tts_chunks.len() / 2].first_line;
+    let chapter = reader.content_index_for_row(middle).unwrap();
+    let chapter_end = reader
+        .content_start_rows
+        .get(chapter + 1)
+        .copied()
+        .unwrap_or(usize::MAX);
+    let index = reader
+        .tts_chunks
+        .iter()
+        .rposition(|chunk| chunk.first_line < chapter_end)
+        .unwrap();
+    reader.state.borrow_mut().reading_state.row = middle;
+    let spoken = reader.tts_chunks[index].text.clone();
+    let old_first_line = reader.tts_chunks[index].first_line;
+    reader.tts_chunk_index = index;
+    reader.state.borrow_mut().ui_state.tts_active = true;
+
+    reader.terminal.backend_mut().resize(40, 24);
+    let textwidth = reader.state.borrow().reading_state.textwidth;
+    reader
+        .rebuild_text_structure_with_textwidth(textwidth)
+        .unwrap();
+    reader.tts_relayout_chunks();
+
+    let chunk = &reader.tts_chunks[reader.tts_chunk_index];
+    assert_eq!(chunk.text, spoken);
+    assert!(chunk.first_line > old_first_line);
+    let state = reader.state.borrow();
+    assert_eq!(state.ui_state.tts_underline_ranges, chunk.underline);
+    assert_eq!(
+        state.ui_state.tts_progress,
+        Some((reader.tts_chunk_index + 1, reader.tts_chunks.len()))
+    );
+}
+
+#[test]
+fn tts_progress_indicator() {
+    let mut reader = test_reader();
+    reader.state.borrow_mut().ui_state.tts_progress = Some((3, 12));
+    reader.draw().unwrap();
+    insta::assert_snapshot!(reader.terminal.backend());
+}
+
 #[test]
 fn help_window() {
     let mut reader = test_reader();
@@ -171,6 +447,8 @@ fn multi_row_search_hit_counter_and_layout() {
         .ui_state
         .replace_search_results(vec![SearchResult {
             preview: "multi-row fixture hit".into(),
+            context_before: None,
+            context_after: None,
             content_index: 0,
             source_start: 0,
             source_end: 12,
@@ -300,6 +578,371 @@ fn reading_stats_dedup_and_jump_detection() {
     assert_eq!(session.max_counted_row, current_row);
 }
 
+#[test]
+fn show_eta_puts_session_pace_and_time_to_finish_in_the_top_bar() {
+    let mut reader = test_reader_with_settings(Settings {
+        show_eta: true,
+        ..Settings::default()
+    });
+    for _ in 0..3 {
+        press_recorded(&mut reader, KeyCode::Char('j'));
+    }
+    let words = reader
+        .reading_session
+        .as_ref()
+        .expect("session active")
+        .words;
+    assert!(words > 0, "the first rows must hold some words");
+
+    // Pretend those rows took two minutes to read.
+    let session = reader.reading_session.as_mut().expect("session active");
+    let started = Instant::now() - Duration::from_secs(120);
+    session.pace_samples = VecDeque::from([(started, 0), (Instant::now(), words)]);
+    reader
+        .refresh_statistics_snapshot()
+        .expect("refreshing statistics failed");
+
+    let stats = reader.state.borrow().ui_state.statistics.clone();
+    let wpm = stats.session_words_per_minute.expect("session pace");
+    assert!((wpm - words as f64 / 2.0).abs() < 1.0);
+    assert!(stats.session_book_minutes_left.is_some());
+
+    let header = |reader: &Reader<TestBackend>| {
+        format!("{}", reader.terminal.backend())
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string()
+    };
+    reader.draw().expect("failed to draw");
+    assert!(header(&reader).contains("wpm ~"), "{}", header(&reader));
+
+    // Off by default.
+    reader.state.borrow_mut().config.settings.show_eta = false;
+    reader.draw().expect("failed to draw");
+    assert!(!header(&reader).contains("wpm"), "{}", header(&reader));
+}
+
+#[test]
+fn sync_sidecar_carries_the_position_between_databases() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let book = dir.path().join("small.epub");
+    std::fs::copy(
+        format!("{}/tests/fixtures/small.epub", env!("CARGO_MANIFEST_DIR")),
+        &book,
+    )
+    .expect("copy fixture");
+    let book = book.to_str().expect("utf-8 path");
+    let machine = || {
+        let settings = Settings {
+            sync_sidecar: true,
+            ..Settings::default()
+        };
+        let config = Config::with_settings(settings, CfgDefaultKeymaps::default()).unwrap();
+        let mut reader = Reader::with_backend(
+            config,
+            TestBackend::new(80, 24),
+            State::new_for_test(),
+            None,
+        )
+        .expect("failed to construct test reader");
+        reader.load_ebook(book).expect("failed to load book");
+        reader
+    };
+
+    // One machine reads a few pages and saves its position.
+    let mut first = machine();
+    for _ in 0..3 {
+        press_char(&mut first, 'l');
+    }
+    first.persist_state().expect("persist failed");
+    let first_row = first.state.borrow().reading_state.row;
+    assert!(first_row > 0);
+    assert!(crate::sidecar::sidecar_path(book).exists());
+
+    // The other one, with its own database, resumes there.
+    let mut second = machine();
+    assert_eq!(second.state.borrow().reading_state.row, first_row);
+
+    // A sidecar older than the local position is ignored.
+    press_char(&mut second, 'l');
+    second.persist_state().expect("persist failed");
+    let second_row = second.state.borrow().reading_state.row;
+    let book_id = second
+        .state
+        .borrow()
+        .ui_state
+        .book_identity
+        .as_ref()
+        .map(|identity| identity.book_id.clone())
+        .expect("book identity");
+    let mut stale = crate::sidecar::read(book, &book_id)
+        .expect("read sidecar")
+        .expect("sidecar present");
+    stale.row = 0;
+    stale.source_offset = None;
+    stale.updated_at = chrono::DateTime::UNIX_EPOCH;
+    crate::sidecar::write(book, &stale).expect("write sidecar");
+    second.load_ebook(book).expect("failed to reload book");
+    assert_eq!(second.state.borrow().reading_state.row, second_row);
+}
+
+#[test]
+fn sync_sidecar_written_by_the_same_reader_is_not_newer() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let book = dir.path().join("small.epub");
+    std::fs::copy(
+        format!("{}/tests/fixtures/small.epub", env!("CARGO_MANIFEST_DIR")),
+        &book,
+    )
+    .expect("copy fixture");
+    let book = book.to_str().expect("utf-8 path");
+    let settings = Settings {
+        sync_sidecar: true,
+        ..Settings::default()
+    };
+    let config = Config::with_settings(settings, CfgDefaultKeymaps::default()).unwrap();
+    let mut reader = Reader::with_backend(
+        config,
+        TestBackend::new(80, 24),
+        State::new_for_test(),
+        None,
+    )
+    .expect("failed to construct test reader");
+    reader.load_ebook(book).expect("failed to load book");
+    for _ in 0..3 {
+        press_char(&mut reader, 'l');
+    }
+    reader.persist_state().expect("persist failed");
+    let row = reader.state.borrow().reading_state.row;
+    let book_id = reader
+        .state
+        .borrow()
+        .ui_state
+        .book_identity
+        .as_ref()
+        .map(|identity| identity.book_id.clone())
+        .expect("book identity");
+
+    reader.state.borrow_mut().ui_state.clear_message();
+    reader.load_ebook(book).expect("failed to reload book");
+    assert_eq!(reader.state.borrow().reading_state.row, row);
+    assert_ne!(
+        reader.state.borrow().ui_state.message.as_deref(),
+        Some("Resumed from the position in the sidecar file")
+    );
+
+    // Written a moment after the library row, at another spot: still within
+    // the whole-second slack, so the local position wins.
+    let mut sidecar = crate::sidecar::read(book, &book_id)
+        .expect("read sidecar")
+        .expect("sidecar present");
+    sidecar.row = 0;
+    sidecar.source_offset = None;
+    sidecar.updated_at = chrono::Utc::now() + chrono::Duration::milliseconds(900);
+    crate::sidecar::write(book, &sidecar).expect("write sidecar");
+    reader.persist_state().expect("persist failed");
+    reader.load_ebook(book).expect("failed to reload book");
+    assert_eq!(reader.state.borrow().reading_state.row, row);
+}
+
+#[test]
+fn autosave_persists_a_changed_position_after_the_interval() {
+    let mut reader = test_reader();
+    let saved_row = |reader: &Reader<TestBackend>| {
+        let book = reader.ebook.as_deref().expect("book loaded");
+        reader
+            .db_state
+            .get_last_reading_state(book)
+            .expect("query failed")
+            .expect("reading state saved on open")
+            .row
+    };
+    let opened_row = saved_row(&reader);
+    press_char(&mut reader, 'l');
+    let row = reader.state.borrow().reading_state.row;
+    assert_ne!(row, opened_row);
+
+    // Not due yet: the book was saved when it was opened.
+    reader.tick_autosave().expect("autosave failed");
+    assert_eq!(saved_row(&reader), opened_row);
+
+    let (_, last_row) = reader.last_saved.expect("saved on open");
+    reader.last_saved = Some((Instant::now() - Duration::from_secs(61), last_row));
+    reader.tick_autosave().expect("autosave failed");
+    assert_eq!(saved_row(&reader), row);
+
+    // Ctrl+s saves at once.
+    press_char(&mut reader, 'l');
+    reader
+        .handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL))
+        .expect("key handling failed");
+    assert_eq!(saved_row(&reader), reader.state.borrow().reading_state.row);
+}
+
+#[test]
+fn session_limit_counts_down_then_saves_and_stops() {
+    let mut reader = test_reader_with_settings(Settings {
+        session_limit_pages: 3,
+        ..Settings::default()
+    });
+    let header = |reader: &Reader<TestBackend>| {
+        format!("{}", reader.terminal.backend())
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string()
+    };
+    press(&mut reader, KeyCode::PageDown);
+    reader.tick_session_limit().expect("session tick failed");
+    reader.draw().expect("failed to draw");
+    assert!(
+        header(&reader).contains("session: 2 pages left"),
+        "{}",
+        header(&reader)
+    );
+    assert!(!reader.state.borrow().ui_state.session_over);
+
+    press(&mut reader, KeyCode::PageDown);
+    press(&mut reader, KeyCode::PageDown);
+    reader.tick_session_limit().expect("session tick failed");
+    let row = reader.state.borrow().reading_state.row;
+    let book = reader.ebook.as_deref().expect("book loaded");
+    let saved = reader
+        .db_state
+        .get_last_reading_state(book)
+        .expect("query failed")
+        .expect("reading state saved");
+    assert_eq!(saved.row, row);
+    {
+        let state = reader.state.borrow();
+        assert!(state.ui_state.session_over);
+        assert!(!state.should_quit);
+        assert!(
+            state
+                .ui_state
+                .message
+                .as_deref()
+                .is_some_and(|m| m.starts_with("Session over"))
+        );
+    }
+
+    // A timed session with `session_limit_quit` quits once time is up.
+    let mut reader = test_reader_with_settings(Settings {
+        session_limit_minutes: 10,
+        session_limit_quit: true,
+        ..Settings::default()
+    });
+    reader.state.borrow_mut().ui_state.session_started =
+        Some(Instant::now() - Duration::from_secs(8 * 60));
+    reader.draw().expect("failed to draw");
+    assert!(
+        header(&reader).contains("session: 2m left"),
+        "{}",
+        header(&reader)
+    );
+    reader.state.borrow_mut().ui_state.session_started =
+        Some(Instant::now() - Duration::from_secs(10 * 60));
+    reader.tick_session_limit().expect("session tick failed");
+    assert!(reader.state.borrow().should_quit);
+}
+
+#[test]
+fn session_limit_restarts_after_an_idle_break_or_a_new_book() {
+    let mut reader = test_reader_with_settings(Settings {
+        session_limit_pages: 2,
+        ..Settings::default()
+    });
+    press(&mut reader, KeyCode::PageDown);
+    press(&mut reader, KeyCode::PageDown);
+    reader.tick_session_limit().expect("session tick failed");
+    assert!(reader.state.borrow().ui_state.session_over);
+
+    // An idle break ends the session; the next turn starts a fresh count.
+    reader
+        .reading_session
+        .as_mut()
+        .expect("reading session open")
+        .last_activity = Instant::now() - READING_IDLE_TIMEOUT;
+    reader
+        .close_idle_reading_session()
+        .expect("closing the idle session failed");
+    reader.state.borrow_mut().ui_state.clear_message();
+    // Like the run loop, record the turn as reading activity.
+    let previous_row = reader.state.borrow().reading_state.row;
+    press(&mut reader, KeyCode::PageDown);
+    reader
+        .record_reading_activity(previous_row)
+        .expect("recording activity failed");
+    {
+        let state = reader.state.borrow();
+        assert!(!state.ui_state.session_over);
+        assert_eq!(state.ui_state.session_page_turns, 1);
+        assert!(state.ui_state.session_started.is_some());
+    }
+
+    // Opening a book starts over too.
+    press(&mut reader, KeyCode::PageDown);
+    reader.tick_session_limit().expect("session tick failed");
+    assert!(reader.state.borrow().ui_state.session_over);
+    let fixture_path = format!("{}/tests/fixtures/small.epub", env!("CARGO_MANIFEST_DIR"));
+    reader
+        .load_ebook(&fixture_path)
+        .expect("failed to reload fixture epub");
+    reader.state.borrow_mut().ui_state.clear_message();
+    {
+        let state = reader.state.borrow();
+        assert!(!state.ui_state.session_over);
+        assert_eq!(state.ui_state.session_page_turns, 0);
+    }
+
+    // Turns past the end of the book don't count.
+    press(&mut reader, KeyCode::End);
+    loop {
+        let row = reader.state.borrow().reading_state.row;
+        press(&mut reader, KeyCode::PageDown);
+        if reader.state.borrow().reading_state.row == row {
+            break;
+        }
+    }
+    let turns = reader.state.borrow().ui_state.session_page_turns;
+    press(&mut reader, KeyCode::PageDown);
+    assert_eq!(reader.state.borrow().ui_state.session_page_turns, turns);
+}
+
+#[test]
+fn tiny_terminal_shows_a_notice_and_recovers_on_resize() {
+    let mut reader = test_reader();
+    let row = reader.state.borrow().reading_state.row;
+    reader.terminal.backend_mut().resize(40, 4);
+    reader.draw().expect("failed to draw");
+    insta::assert_snapshot!(reader.terminal.backend());
+
+    // Reading keys do nothing while there is no page to show.
+    press(&mut reader, KeyCode::PageDown);
+    press_char(&mut reader, 'j');
+    assert_eq!(reader.state.borrow().reading_state.row, row);
+
+    reader.terminal.backend_mut().resize(80, 24);
+    reader.draw().expect("failed to draw");
+    let screen = format!("{}", reader.terminal.backend());
+    assert!(!screen.contains("Terminal too small"));
+    press(&mut reader, KeyCode::PageDown);
+    assert!(reader.state.borrow().reading_state.row > row);
+}
+
+#[test]
+fn content_border_frames_the_text() {
+    let plain_page = test_reader().page_size();
+    let mut reader = test_reader_with_settings(Settings {
+        content_border: true,
+        ..Settings::default()
+    });
+    assert_eq!(reader.page_size(), plain_page - 2);
+    reader.draw().expect("failed to draw");
+    insta::assert_snapshot!(reader.terminal.backend());
+}
+
 #[test]
 fn settings_window_sections() {
     let mut reader = test_reader();
@@ -365,37 +1008,511 @@ fn typography_settings_reparse_the_full_book() {
 }
 
 #[test]
-fn line_numbers() {
+fn dictionary_presets_view_reorders_edits_and_picks_default() {
     let mut reader = test_reader();
-    // There is no single "toggle line numbers" key; it lives in the
-    // Settings window as the first item (index 0), toggled with Enter.
     press_char(&mut reader, 's');
+    let client_index = SettingItem::all()
+        .iter()
+        .position(|item| *item == SettingItem::DictionaryClient)
+        .unwrap();
+    for _ in 0..client_index {
+        press_char(&mut reader, 'j');
+    }
     press(&mut reader, KeyCode::Enter);
-    press_char(&mut reader, 'q');
     insta::assert_snapshot!(reader.terminal.backend());
-}
 
-/// The last-read timestamp embeds the current time, and the history entry's
-/// filepath embeds the repo checkout path; redact both.
-fn library_snapshot_filters() -> Vec<(&'static str, &'static str)> {
-    vec![
-        (r"\d{2}:\d{2}(AM|PM) \w{3} \d{2}", "[time]"),
-        (r"\([^)]*small\.epub\)?", "([path]/small.epub)"),
-    ]
+    // Move "sdcv" to the top, point its command at another dictionary, and
+    // make it the default.
+    press_char(&mut reader, 'j');
+    press_char(&mut reader, 'j');
+    press_char(&mut reader, 'K');
+    press_char(&mut reader, 'e');
+    for _ in 0.."%q".len() {
+        press(&mut reader, KeyCode::Backspace);
+    }
+    type_str(&mut reader, "-u Webster %q");
+    press(&mut reader, KeyCode::Enter);
+    press(&mut reader, KeyCode::Enter);
+    {
+        let settings = &reader.state.borrow().config.settings;
+        let names: Vec<&str> = settings
+            .dictionary_presets
+            .iter()
+            .map(|preset| preset.name.as_str())
+            .collect();
+        assert_eq!(names, ["sdcv", "dict", "wkdict"]);
+        assert_eq!(
+            settings.dictionary_presets[0].command,
+            "sdcv -n -u Webster %q"
+        );
+        assert_eq!(settings.dictionary_client, "sdcv");
+    }
+
+    // The custom row takes a template of its own; `q` backs out to Settings.
+    for _ in 0..4 {
+        press_char(&mut reader, 'j');
+    }
+    press(&mut reader, KeyCode::Enter);
+    type_str(&mut reader, "mydict %q");
+    press(&mut reader, KeyCode::Enter);
+    press_char(&mut reader, 'q');
+    let state = reader.state.borrow();
+    assert_eq!(state.config.settings.dictionary_client, "mydict %q");
+    assert_eq!(state.ui_state.active_window, WindowType::Settings);
 }
 
 #[test]
-fn library_window() {
+fn tts_engine_setting_is_remembered_per_book() {
     let mut reader = test_reader();
-    // A book the scanner found on disk but that was never opened.
-    reader
-        .db_state
-        .upsert_library_file(
-            "/scanned/example.epub",
-            1,
-            Some("Scanned Book"),
-            Some("Some Author"),
-        )
+    press_char(&mut reader, 's');
+    let engine_index = SettingItem::all()
+        .iter()
+        .position(|item| *item == SettingItem::TtsEngine)
+        .unwrap();
+    for _ in 0..engine_index {
+        press_char(&mut reader, 'j');
+    }
+    press(&mut reader, KeyCode::Enter);
+    {
+        let state = reader.state.borrow();
+        assert_eq!(state.reading_state.tts_engine.as_deref(), Some("edge-tts"));
+        assert_eq!(
+            state.config.settings.preferred_tts_engine.as_deref(),
+            Some("purr")
+        );
+        assert_eq!(state.effective_tts_engine(), Some("edge-tts"));
+    }
+    let stored = reader
+        .db_state
+        .get_last_reading_state(reader.ebook.as_deref().unwrap())
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.tts_engine.as_deref(), Some("edge-tts"));
+
+    press_char(&mut reader, 'r');
+    let state = reader.state.borrow();
+    assert_eq!(state.reading_state.tts_engine, None);
+    assert_eq!(state.effective_tts_engine(), Some("purr"));
+}
+
+#[test]
+fn tts_engine_cycle_returns_to_global_and_global_is_editable_while_reading() {
+    let mut reader = test_reader();
+    press_char(&mut reader, 's');
+    let engine_index = SettingItem::all()
+        .iter()
+        .position(|item| *item == SettingItem::TtsEngine)
+        .unwrap();
+    for _ in 0..engine_index {
+        press_char(&mut reader, 'j');
+    }
+    // purr (global) → edge-tts → trans → purr, which is the global engine
+    // again and so clears the book's choice.
+    press(&mut reader, KeyCode::Enter);
+    press(&mut reader, KeyCode::Enter);
+    assert_eq!(
+        reader.state.borrow().reading_state.tts_engine.as_deref(),
+        Some("trans")
+    );
+    press(&mut reader, KeyCode::Enter);
+    assert_eq!(reader.state.borrow().reading_state.tts_engine, None);
+
+    // The row above changes the global engine even with a book open.
+    press_char(&mut reader, 'k');
+    press(&mut reader, KeyCode::Enter);
+    {
+        let state = reader.state.borrow();
+        assert_eq!(state.reading_state.tts_engine, None);
+        assert_eq!(
+            state.config.settings.preferred_tts_engine.as_deref(),
+            Some("edge-tts")
+        );
+        assert_eq!(state.effective_tts_engine(), Some("edge-tts"));
+    }
+    press_char(&mut reader, 'r');
+    assert_eq!(
+        reader
+            .state
+            .borrow()
+            .config
+            .settings
+            .preferred_tts_engine
+            .as_deref(),
+        Some("purr")
+    );
+}
+
+#[test]
+fn tts_rate_setting_steps_from_engine_default() {
+    let mut reader = test_reader();
+    press_char(&mut reader, 's');
+    let rate_index = SettingItem::all()
+        .iter()
+        .position(|item| *item == SettingItem::TtsRate)
+        .unwrap();
+    for _ in 0..rate_index {
+        press_char(&mut reader, 'j');
+    }
+    press_char(&mut reader, '+');
+    assert_eq!(reader.state.borrow().config.settings.tts_rate, Some(185));
+    press_char(&mut reader, '-');
+    press_char(&mut reader, '-');
+    assert_eq!(reader.state.borrow().config.settings.tts_rate, Some(165));
+    press_char(&mut reader, 'r');
+    assert_eq!(reader.state.borrow().config.settings.tts_rate, None);
+}
+
+#[test]
+fn messages_window_lists_recent_messages_newest_first() {
+    use chrono::TimeZone;
+
+    let mut reader = test_reader();
+    {
+        let mut state = reader.state.borrow_mut();
+        let ui = &mut state.ui_state;
+        ui.set_message("Theme: Sepia".into(), super::MessageType::Info);
+        ui.set_message(
+            "Dictionary lookup failed: command 'sdcv' not found".into(),
+            super::MessageType::Error,
+        );
+        // Back-to-back repeats collapse into one entry.
+        ui.set_message("Theme: Dark".into(), super::MessageType::Info);
+        ui.set_message("Theme: Dark".into(), super::MessageType::Info);
+        ui.clear_message();
+        for (minute, entry) in ui.message_history.iter_mut().enumerate() {
+            entry.time = chrono::Local
+                .with_ymd_and_hms(2024, 5, 1, 9, minute as u32, 0)
+                .unwrap();
+        }
+    }
+    press_char(&mut reader, 'E');
+    press_char(&mut reader, 'j');
+    insta::assert_snapshot!(reader.terminal.backend());
+
+    {
+        let mut state = reader.state.borrow_mut();
+        for i in 0..60 {
+            state
+                .ui_state
+                .set_message(format!("message {i}"), super::MessageType::Info);
+        }
+        assert_eq!(state.ui_state.message_history.len(), 50);
+        assert_eq!(state.ui_state.message_history[0].text, "message 10");
+    }
+    press_char(&mut reader, 'q');
+    assert!(!reader.state.borrow().ui_state.show_messages);
+}
+
+#[test]
+fn top_bar_leads_with_current_chapter_title() {
+    let mut reader = test_reader_with_settings(Settings {
+        show_chapter_title: true,
+        ..Settings::default()
+    });
+    // small.epub has no TOC, so the book title is shown as before.
+    let header = |reader: &Reader<TestBackend>| {
+        format!("{}", reader.terminal.backend())
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim_matches('"')
+            .to_string()
+    };
+    assert!(header(&reader).contains("Accessible EPUB 3"));
+    assert!(!header(&reader).contains(" · "));
+
+    let other = format!(
+        "{}/tests/fixtures/meditations.epub",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    reader.load_ebook(&other).unwrap();
+    let toc = reader.ebook.as_ref().unwrap().toc_entries().clone();
+    let rows: Vec<Option<usize>> = (0..toc.len())
+        .map(|i| reader.toc_activation_row(&toc, i))
+        .collect();
+    // A later entry that starts on a row of its own.
+    let (index, row) = (1..toc.len())
+        .find_map(|i| {
+            let row = rows[i]?;
+            let after_previous = rows[..i].iter().flatten().all(|&r| r < row);
+            let before_next = rows[i + 1..].iter().flatten().all(|&r| r > row);
+            (after_previous && before_next).then_some((i, row))
+        })
+        .expect("meditations should have a later TOC entry");
+    reader.state.borrow_mut().reading_state.row = row + 1;
+    reader.draw().unwrap();
+    let label: String = toc[index].label.chars().take(10).collect();
+    let line = header(&reader);
+    assert!(line.trim_start().starts_with(&label), "{line}");
+}
+
+#[test]
+fn books_without_a_page_list_count_screen_pages() {
+    let reader = test_reader();
+    assert!(!reader.board.has_print_pages());
+    let total = reader.board.total_lines().div_ceil(reader.page_size());
+    let screen = format!("{}", reader.terminal.backend());
+    let header = screen.lines().next().unwrap_or_default();
+    assert!(header.contains(&format!("p.1/{total}")), "{header}");
+}
+
+#[test]
+fn screen_page_numbers_replace_epub_pages_when_preferred_off() {
+    let mut reader = test_reader_with_settings(Settings {
+        prefer_epub_page_numbers: false,
+        ..Settings::default()
+    });
+    let header = |reader: &Reader<TestBackend>| {
+        format!("{}", reader.terminal.backend())
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string()
+    };
+    let page = reader.page_size();
+    let total = reader.board.total_lines().div_ceil(page);
+    assert!(header(&reader).contains(&format!("p.1/{total}")));
+
+    press(&mut reader, KeyCode::PageDown);
+    assert!(header(&reader).contains(&format!("p.2/{total}")));
+}
+
+#[test]
+fn begin_reading_without_landmarks_falls_back_to_the_first_file() {
+    let mut reader = test_reader();
+    press(&mut reader, KeyCode::End);
+    let end_row = reader.state.borrow().reading_state.row;
+    assert!(end_row > 0);
+
+    press_char(&mut reader, 'b');
+    assert_eq!(reader.state.borrow().reading_state.row, 0);
+    // The jump is recorded, so Ctrl+o returns to where reading left off.
+    reader
+        .handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL))
+        .expect("key handling failed");
+    assert_eq!(reader.state.borrow().reading_state.row, end_row);
+}
+
+#[test]
+fn loading_screen_names_the_book_while_it_opens() {
+    let mut reader = test_reader();
+    let fixture_path = format!(
+        "{}/tests/fixtures/meditations.epub",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    // `run` sets this once the terminal is set up; before that a load
+    // draws nothing.
+    reader.terminal_active = true;
+    reader
+        .load_ebook(&fixture_path)
+        .expect("failed to load fixture epub");
+    insta::assert_snapshot!(reader.terminal.backend());
+}
+
+#[test]
+fn cover_key_reaches_the_title_page_after_skipped_front_matter() {
+    let mut reader = test_reader_with_settings(Settings {
+        skip_front_matter: true,
+        ..Settings::default()
+    });
+    let fixture_path = format!(
+        "{}/tests/fixtures/meditations.epub",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    reader
+        .load_ebook(&fixture_path)
+        .expect("failed to load fixture epub");
+    let opened_row = reader.state.borrow().reading_state.row;
+    assert!(opened_row > 0);
+    let opened = reader.position_state_for_row(opened_row);
+
+    // No cover landmark: `C` falls back to the first content file.
+    press_char(&mut reader, 'C');
+    assert_eq!(reader.state.borrow().reading_state.row, 0);
+    // Jumps are kept in source coordinates, so Ctrl+o lands on the same
+    // source position the book opened at.
+    reader
+        .handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL))
+        .expect("key handling failed");
+    let back_row = reader.state.borrow().reading_state.row;
+    let back = reader.position_state_for_row(back_row);
+    assert_eq!(
+        (back.content_index, back.source_offset),
+        (opened.content_index, opened.source_offset)
+    );
+}
+
+#[test]
+fn skip_front_matter_only_applies_to_a_first_open() {
+    let mut reader = test_reader_with_settings(Settings {
+        skip_front_matter: true,
+        ..Settings::default()
+    });
+    let fixture_path = format!(
+        "{}/tests/fixtures/meditations.epub",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    reader
+        .load_ebook(&fixture_path)
+        .expect("failed to load fixture epub");
+    // meditations.epub has no bodymatter landmark, so its first TOC entry
+    // is used.
+    let first_chapter = reader.chapter_rows()[0];
+    assert!(first_chapter > 0);
+    assert_eq!(
+        reader.state.borrow().reading_state.row,
+        Reader::<TestBackend>::row_from_start(first_chapter)
+    );
+
+    // A saved position wins when the book is opened again.
+    press(&mut reader, KeyCode::End);
+    let saved_row = reader.state.borrow().reading_state.row;
+    reader
+        .load_ebook(&fixture_path)
+        .expect("failed to reload fixture epub");
+    assert_eq!(reader.state.borrow().reading_state.row, saved_row);
+}
+
+#[test]
+fn print_page_jump_warns_without_a_page_list() {
+    let mut reader = test_reader();
+    let row = reader.state.borrow().reading_state.row;
+    for key in ['1', '2', 'P'] {
+        press_char(&mut reader, key);
+    }
+    {
+        let state = reader.state.borrow();
+        assert_eq!(
+            state.ui_state.message.as_deref(),
+            Some("This book has no print page numbers")
+        );
+        assert_eq!(state.reading_state.row, row);
+    }
+
+    // The warning is sticky: one key dismisses it before `P` opens the list.
+    press(&mut reader, KeyCode::Esc);
+    press_char(&mut reader, 'P');
+    assert!(reader.state.borrow().ui_state.show_pages);
+    let screen = format!("{}", reader.terminal.backend());
+    assert!(screen.contains("Print Pages"), "{screen}");
+}
+
+#[test]
+fn margins_shift_the_reading_area() {
+    let reader = test_reader_with_settings(Settings {
+        left_margin: 20,
+        right_margin: 10,
+        ..Settings::default()
+    });
+    // 50 columns remain; the minimum 5-column padding per side leaves a
+    // 40-column text area starting at column 25.
+    let screen = format!("{}", reader.terminal.backend());
+    let body: Vec<Vec<char>> = screen
+        .lines()
+        .skip(3)
+        .take(19)
+        .map(|line| line.trim_matches('"').chars().collect())
+        .collect();
+    assert!(
+        body.iter()
+            .any(|line| line.iter().any(|c| !c.is_whitespace()))
+    );
+    for line in &body {
+        assert!(line[..25].iter().all(|c| c.is_whitespace()), "{line:?}");
+        assert!(line[65..].iter().all(|c| c.is_whitespace()), "{line:?}");
+    }
+}
+
+#[test]
+fn scrolloff_keeps_context_around_the_cursor() {
+    let mut reader = test_reader_with_settings(Settings {
+        scrolloff: 3,
+        ..Settings::default()
+    });
+    let page = reader.page_size();
+    // Lines visible (above, below) the cursor.
+    let context = |reader: &Reader<TestBackend>| {
+        let state = reader.state.borrow();
+        let (cursor_row, _) = state.ui_state.visual_cursor.expect("cursor mode");
+        let viewport_start = state.reading_state.row.saturating_sub(1);
+        (
+            cursor_row - viewport_start,
+            viewport_start + page - 1 - cursor_row,
+        )
+    };
+
+    press_char(&mut reader, 'v');
+    type_str(&mut reader, &format!("{}j", page + 5));
+    assert_eq!(context(&reader).1, 3);
+
+    type_str(&mut reader, &format!("{}k", page));
+    assert_eq!(context(&reader).0, 3);
+}
+
+#[test]
+fn chapter_source_reads_the_current_content_document() {
+    let mut reader = test_reader();
+    let (href, bytes) = reader.chapter_source().expect("epub chapter source");
+    assert!(href.ends_with(".xhtml"), "{href}");
+    assert!(String::from_utf8_lossy(&bytes).contains("<html"));
+
+    press(&mut reader, KeyCode::End);
+    let (last_href, _) = reader.chapter_source().expect("epub chapter source");
+    assert_ne!(last_href, href);
+}
+
+#[test]
+fn chapter_source_overlay_toggles_with_x() {
+    let mut reader = test_reader();
+    // Skip the short cover and ad pages to a chapter long enough to scroll.
+    type_str(&mut reader, "5t");
+    press_char(&mut reader, 'X');
+    let screen = format!("{}", reader.terminal.backend());
+    assert!(screen.contains("Source: "), "{screen}");
+    assert!(screen.contains("<html"), "{screen}");
+
+    press_char(&mut reader, 'j');
+    assert_eq!(
+        reader.state.borrow().ui_state.chapter_source_scroll_offset,
+        1
+    );
+
+    press_char(&mut reader, 'X');
+    assert!(!reader.state.borrow().ui_state.show_chapter_source);
+}
+
+#[test]
+fn line_numbers() {
+    let mut reader = test_reader();
+    // There is no single "toggle line numbers" key; it lives in the
+    // Settings window as the first item (index 0), toggled with Enter.
+    press_char(&mut reader, 's');
+    press(&mut reader, KeyCode::Enter);
+    press_char(&mut reader, 'q');
+    insta::assert_snapshot!(reader.terminal.backend());
+}
+
+/// The last-read timestamp embeds the current time, and the history entry's
+/// filepath embeds the repo checkout path; redact both.
+fn library_snapshot_filters() -> Vec<(&'static str, &'static str)> {
+    vec![
+        (r"\d{2}:\d{2}(AM|PM) \w{3} \d{2}", "[time]"),
+        (r"\([^)]*small\.epub\)?", "([path]/small.epub)"),
+    ]
+}
+
+#[test]
+fn library_window() {
+    let mut reader = test_reader();
+    // A book the scanner found on disk but that was never opened.
+    reader
+        .db_state
+        .upsert_library_file(
+            "/scanned/example.epub",
+            1,
+            Some("Scanned Book"),
+            Some("Some Author"),
+        )
         .unwrap();
     press_char(&mut reader, 'r');
     insta::with_settings!({filters => library_snapshot_filters()}, {
@@ -513,6 +1630,7 @@ fn legacy_position_without_source_offset_uses_restore_ladder() {
         row: usize::MAX,
         rel_pctg: Some(0.5),
         section: None,
+        tts_engine: None,
     };
 
     assert_eq!(
@@ -562,6 +1680,41 @@ fn legacy_position_without_source_offset_uses_restore_ladder() {
     );
 }
 
+#[test]
+fn width_toggle_reuses_the_cached_chapter_layout() {
+    let mut reader = test_reader_with_settings(Settings {
+        width: Some(50),
+        ..Settings::default()
+    });
+    let chapter = reader.state.borrow().reading_state.content_index;
+    let original = reader.chapter_text_structures[chapter].text_lines.clone();
+    let original_width = reader.current_text_width.expect("laid out");
+
+    press_char(&mut reader, '+');
+    assert_ne!(reader.current_text_width, Some(original_width));
+    assert_eq!(reader.chapter_wrap_cache.len(), 1);
+    assert_eq!(reader.chapter_wrap_cache[0].text_width, original_width);
+
+    // Back at the old width the cached layout is swapped in, and the wider
+    // one takes its place in the cache.
+    press_char(&mut reader, '-');
+    assert_eq!(reader.current_text_width, Some(original_width));
+    assert_eq!(reader.chapter_text_structures[chapter].text_lines, original);
+    assert_eq!(reader.chapter_wrap_cache.len(), 1);
+    assert_ne!(reader.chapter_wrap_cache[0].text_width, original_width);
+
+    // Widening from the narrowest width lays out more new widths than the
+    // cache holds; only the most recent are kept.
+    let mut reader = test_reader_with_settings(Settings {
+        width: Some(20),
+        ..Settings::default()
+    });
+    for _ in 0..2 * CHAPTER_WRAP_CACHE_LIMIT {
+        press_char(&mut reader, '+');
+    }
+    assert_eq!(reader.chapter_wrap_cache.len(), CHAPTER_WRAP_CACHE_LIMIT);
+}
+
 #[test]
 fn width_change_preserves_first_visible_sentence() {
     let mut settings = Settings {
@@ -1151,3 +2304,772 @@ fn cover_reloads_after_move_without_keypress() {
         "cover should be visible without any keypress:\n{screen}"
     );
 }
+
+/// Paging within a chapter queues a scroll animation for the run loop;
+/// with the setting off the page still turns but nothing is queued.
+#[test]
+fn page_turn_queues_animation_only_when_enabled() {
+    let mut reader = test_reader();
+    // Start of the third content document, which is longer than a page.
+    let from = reader.content_start_rows[2] + 1;
+    reader.state.borrow_mut().reading_state.row = from;
+    reader.turn_page(crate::models::Direction::PageDown, 1);
+    let to = reader.state.borrow().reading_state.row;
+    assert_ne!(from, to);
+    assert_eq!(reader.page_animation, Some((from, to)));
+
+    let settings = Settings {
+        page_scroll_animation: false,
+        ..Settings::default()
+    };
+    let mut reader = test_reader_with_settings(settings);
+    press_char(&mut reader, 'l');
+    assert_ne!(reader.state.borrow().reading_state.row, 0);
+    assert_eq!(reader.page_animation, None);
+}
+
+/// A history entry whose file was moved is matched by content hash against
+/// the scanned library files, and confirming the prompt relinks it.
+#[test]
+fn moved_book_is_found_by_content_hash_and_relinked() {
+    let mut reader = test_reader();
+    let dir = tempfile::tempdir().unwrap();
+    let original = dir.path().join("original.epub");
+    let fixture = format!("{}/tests/fixtures/small.epub", env!("CARGO_MANIFEST_DIR"));
+    std::fs::copy(&fixture, &original).unwrap();
+    reader
+        .load_ebook(&original.to_string_lossy())
+        .expect("load copied book");
+    let old_path = reader.ebook.as_ref().unwrap().path().to_string();
+
+    let moved_dir = dir.path().join("shelf");
+    std::fs::create_dir(&moved_dir).unwrap();
+    let moved = moved_dir.join("renamed.epub");
+    std::fs::rename(&original, &moved).unwrap();
+    let moved = std::fs::canonicalize(&moved)
+        .unwrap()
+        .to_string_lossy()
+        .into_owned();
+    reader
+        .db_state
+        .upsert_library_file(&moved, 0, None, None)
+        .unwrap();
+
+    assert_eq!(reader.find_moved_book(&old_path), Some(moved.clone()));
+
+    reader.state.borrow_mut().ui_state.pending_relink = Some((old_path.clone(), moved.clone()));
+    reader
+        .state
+        .borrow_mut()
+        .ui_state
+        .open_window(crate::models::WindowType::ConfirmRelink);
+    press_char(&mut reader, 'y');
+    assert_eq!(reader.ebook.as_ref().unwrap().path(), moved);
+    let history = reader.db_state.get_from_history().unwrap();
+    assert!(history.iter().any(|item| item.filepath == moved));
+    assert!(history.iter().all(|item| item.filepath != old_path));
+}
+
+#[test]
+fn link_to_a_neighbouring_ebook_offers_to_open_it() {
+    let mut reader = test_reader();
+    let dir = tempfile::tempdir().unwrap();
+    let fixtures = format!("{}/tests/fixtures", env!("CARGO_MANIFEST_DIR"));
+    let volume_one = dir.path().join("volume1.epub");
+    let volume_two = dir.path().join("volume2.epub");
+    std::fs::copy(format!("{fixtures}/small.epub"), &volume_one).unwrap();
+    std::fs::copy(format!("{fixtures}/meditations.epub"), &volume_two).unwrap();
+    reader
+        .load_ebook(&volume_one.to_string_lossy())
+        .expect("load first volume");
+    let link = |url: &str| crate::models::LinkEntry {
+        row: 0,
+        source_offset: None,
+        label: "next volume".to_string(),
+        url: url.to_string(),
+        target_row: None,
+    };
+
+    // A missing file keeps the copy-to-clipboard fallback.
+    reader.follow_link_entry(link("volume3.epub")).unwrap();
+    assert_eq!(reader.state.borrow().ui_state.pending_linked_book, None);
+    reader.state.borrow_mut().ui_state.clear_message();
+
+    reader
+        .follow_link_entry(link("volume2.epub#part1"))
+        .unwrap();
+    assert_eq!(
+        reader.state.borrow().ui_state.active_window,
+        crate::models::WindowType::ConfirmOpenLinkedBook
+    );
+    press_char(&mut reader, 'y');
+    assert!(
+        reader
+            .ebook
+            .as_ref()
+            .unwrap()
+            .path()
+            .ends_with("volume2.epub")
+    );
+    assert_eq!(
+        reader.state.borrow().ui_state.active_window,
+        crate::models::WindowType::Reader
+    );
+    let history = reader.db_state.get_from_history().unwrap();
+    assert!(
+        history
+            .iter()
+            .any(|item| item.filepath.ends_with("volume1.epub"))
+    );
+}
+
+#[test]
+fn bracket_m_steps_through_bookmarks_and_wraps() {
+    let mut reader = test_reader();
+    let rows = [reader.content_start_rows[2], reader.content_start_rows[4]];
+    for (name, row) in ["early", "late"].iter().zip(rows) {
+        let mut saved = reader.state.borrow().reading_state.clone();
+        saved.row = row;
+        saved.source_offset = None;
+        reader
+            .db_state
+            .insert_bookmark(reader.ebook.as_deref().unwrap(), name, &saved)
+            .unwrap();
+    }
+
+    type_str(&mut reader, "]m");
+    assert_eq!(reader.state.borrow().reading_state.row, rows[0]);
+    type_str(&mut reader, "]m");
+    assert_eq!(reader.state.borrow().reading_state.row, rows[1]);
+    type_str(&mut reader, "]m");
+    assert_eq!(reader.state.borrow().reading_state.row, rows[0]);
+    assert_eq!(
+        reader.state.borrow().ui_state.message.as_deref(),
+        Some("Bookmark 1/2: early (wrapped)")
+    );
+    type_str(&mut reader, "[m");
+    assert_eq!(reader.state.borrow().reading_state.row, rows[1]);
+
+    // The reading ruler has keys of its own, so `]m` still works with it on.
+    press_char(&mut reader, 'F');
+    let band_top = reader.state.borrow().ui_state.focus_band_top;
+    type_str(&mut reader, "]m");
+    let state = reader.state.borrow();
+    assert_eq!(state.reading_state.row, rows[0]);
+    assert_eq!(state.ui_state.focus_band_top, band_top);
+    assert_eq!(state.ui_state.pending_mark_command, None);
+}
+
+#[test]
+fn resume_prompt_declined_starts_from_the_beginning() {
+    let mut reader = test_reader_with_settings(Settings {
+        resume_prompt: true,
+        ..Settings::default()
+    });
+    // The fixture was opened just now, so no prompt is due yet.
+    assert_eq!(reader.state.borrow().ui_state.pending_resume, None);
+
+    reader.state.borrow_mut().reading_state.row = reader.content_start_rows[3];
+    let reading_state = reader.state.borrow().reading_state.clone();
+    let path = reader.ebook.as_ref().unwrap().path().to_string();
+    assert_eq!(reader.resume_prompt_for(&path, &reading_state), None);
+
+    let last_read = chrono::Utc::now() - chrono::Duration::days(3);
+    {
+        let mut state = reader.state.borrow_mut();
+        state.ui_state.pending_resume = Some((0.47, last_read));
+        state
+            .ui_state
+            .open_window(crate::models::WindowType::ConfirmResume);
+    }
+    reader.draw().unwrap();
+    let screen = format!("{}", reader.terminal.backend());
+    assert!(screen.contains("Resume at 47% (last read 3 days ago)?"));
+
+    press_char(&mut reader, 'n');
+    let state = reader.state.borrow();
+    assert_eq!(
+        state.ui_state.active_window,
+        crate::models::WindowType::Reader
+    );
+    assert_eq!(state.ui_state.pending_resume, None);
+    assert_eq!(state.reading_state.row, 0);
+}
+
+#[test]
+fn library_finished_flag_toggles_and_auto_marks() {
+    let mut reader = test_reader();
+    press_char(&mut reader, 'r');
+    press_char(&mut reader, 'x');
+    assert!(reader.state.borrow().ui_state.library_items[0].finished);
+    let screen = format!("{}", reader.terminal.backend());
+    assert!(screen.contains("   ✓ "));
+    press_char(&mut reader, 'x');
+    assert!(!reader.db_state.get_from_history().unwrap()[0].finished);
+
+    press(&mut reader, KeyCode::Esc);
+    reader.state.borrow_mut().config.settings.auto_mark_finished = true;
+    reader.persist_state().unwrap();
+    assert!(!reader.db_state.get_from_history().unwrap()[0].finished);
+    let last_row = reader.board.total_lines() - 1;
+    reader.state.borrow_mut().reading_state.row = last_row;
+    reader.persist_state().unwrap();
+    assert!(reader.db_state.get_from_history().unwrap()[0].finished);
+}
+
+#[test]
+fn library_progress_filter_cycles_and_shows_in_title() {
+    let mut reader = test_reader();
+    reader.state.borrow_mut().reading_state.row = reader.content_start_rows[3];
+    reader.persist_state().unwrap();
+    reader
+        .db_state
+        .upsert_library_file("/scanned/unread.epub", 1, Some("Unread Book"), None)
+        .unwrap();
+    press_char(&mut reader, 'r');
+    assert_eq!(reader.state.borrow().ui_state.library_items.len(), 2);
+
+    press_char(&mut reader, 'p');
+    let screen = format!("{}", reader.terminal.backend());
+    assert!(screen.contains("Library — by recent · unread"));
+    let titles = |reader: &Reader<TestBackend>| -> Vec<Option<String>> {
+        let state = reader.state.borrow();
+        state
+            .ui_state
+            .library_items
+            .iter()
+            .map(|entry| entry.title.clone())
+            .collect()
+    };
+    assert_eq!(titles(&reader), vec![Some("Unread Book".to_string())]);
+
+    press_char(&mut reader, 'p');
+    assert_eq!(titles(&reader), vec![Some("Accessible EPUB 3".to_string())]);
+    assert_eq!(reader.state.borrow().ui_state.library_selected_index, 0);
+
+    press_char(&mut reader, 'p');
+    assert!(titles(&reader).is_empty());
+    press_char(&mut reader, 'p');
+    assert_eq!(titles(&reader).len(), 2);
+}
+
+#[test]
+fn wikipedia_disambiguation_options_are_selectable() {
+    let mut reader = test_reader();
+    {
+        let mut state = reader.state.borrow_mut();
+        state.ui_state.dictionary_word = "Mercury".to_string();
+        state.ui_state.dictionary_definition = "'Mercury' may refer to:".to_string();
+        state.ui_state.dictionary_is_wikipedia = true;
+        state.ui_state.dictionary_disambiguation = Some(super::WikipediaDisambiguation {
+            // Nothing listens here, so the follow-up lookup fails fast.
+            language: "http://127.0.0.1:9".to_string(),
+            titles: vec![
+                "Mercury (element)".to_string(),
+                "Mercury (mythology)".to_string(),
+                "Mercury (planet)".to_string(),
+            ],
+        });
+        state
+            .ui_state
+            .open_window(crate::models::WindowType::Dictionary);
+    }
+    press_char(&mut reader, 'j');
+    press_char(&mut reader, 'j');
+    insta::assert_snapshot!(reader.terminal.backend());
+
+    press_char(&mut reader, 'k');
+    press(&mut reader, KeyCode::Enter);
+    let state = reader.state.borrow();
+    assert_eq!(state.ui_state.dictionary_word, "Mercury (mythology)");
+    assert!(state.ui_state.dictionary_loading);
+    assert!(state.ui_state.dictionary_disambiguation.is_none());
+    assert!(state.ui_state.show_dictionary);
+}
+
+#[test]
+fn empty_search_lists_recent_searches() {
+    let mut reader = test_reader();
+    for query in ["accessib\\w+", "EPUB 3"] {
+        reader.db_state.add_search_history(query).unwrap();
+    }
+    press_char(&mut reader, '/');
+    insta::assert_snapshot!(reader.terminal.backend());
+
+    press(&mut reader, KeyCode::Up);
+    assert_eq!(reader.state.borrow().ui_state.search_query, "EPUB 3");
+}
+
+#[test]
+fn tab_restricts_search_to_the_current_chapter() {
+    let mut reader = test_reader();
+    type_str(&mut reader, "5t");
+    let chapter = reader
+        .content_index_for_row(reader.state.borrow().reading_state.row)
+        .unwrap();
+    press_char(&mut reader, '/');
+    type_str(&mut reader, "the");
+    let book_hits = reader.state.borrow().ui_state.search_results.len();
+
+    press(&mut reader, KeyCode::Tab);
+    insta::assert_snapshot!(reader.terminal.backend());
+    {
+        let state = reader.state.borrow();
+        let results = &state.ui_state.search_results;
+        assert!(!results.is_empty());
+        assert!(results.len() < book_hits);
+        assert!(results.iter().all(|result| result.content_index == chapter));
+    }
+
+    press(&mut reader, KeyCode::Tab);
+    assert_eq!(
+        reader.state.borrow().ui_state.search_results.len(),
+        book_hits
+    );
+}
+
+#[test]
+fn ctrl_n_and_ctrl_p_step_search_results_by_chapter() {
+    let mut reader = test_reader();
+    press_char(&mut reader, '/');
+    type_str(&mut reader, "the");
+    press(&mut reader, KeyCode::Enter);
+    let selected = |reader: &Reader<TestBackend>| {
+        let state = reader.state.borrow();
+        let index = state.ui_state.selected_search_result;
+        let chapter = state.ui_state.search_results[index].content_index;
+        let first_in_chapter = state
+            .ui_state
+            .search_results
+            .iter()
+            .position(|result| result.content_index == chapter)
+            .unwrap();
+        (index, chapter, first_in_chapter)
+    };
+    let (_, start_chapter, _) = selected(&reader);
+
+    let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+    reader.handle_key_event(ctrl('n')).unwrap();
+    let (index, next_chapter, first_in_chapter) = selected(&reader);
+    assert!(next_chapter > start_chapter);
+    assert_eq!(index, first_in_chapter);
+    let state = reader.state.borrow();
+    assert_eq!(
+        state.reading_state.row,
+        state.ui_state.search_results[index].first_row()
+    );
+    drop(state);
+
+    reader.handle_key_event(ctrl('p')).unwrap();
+    let (index, chapter, first_in_chapter) = selected(&reader);
+    assert_eq!(chapter, start_chapter);
+    assert_eq!(index, first_in_chapter);
+}
+
+#[test]
+fn link_tour_visits_each_note_then_returns() {
+    use crate::models::LinkEntry;
+    use crate::ui::reader::WindowType;
+
+    let mut reader = test_reader();
+    let total = reader.board.total_lines();
+    assert!(total > 30, "fixture too short for a tour");
+    let link = |target_row: Option<usize>| LinkEntry {
+        row: 2,
+        source_offset: None,
+        label: "1".to_string(),
+        url: "#note".to_string(),
+        target_row,
+    };
+    {
+        let mut state = reader.state.borrow_mut();
+        state.reading_state.row = 2;
+        state.ui_state.links = vec![link(Some(20)), link(None), link(Some(20)), link(Some(30))];
+        state.ui_state.links_selected_index = 0;
+        state.ui_state.open_window(WindowType::Links);
+    }
+
+    press_char(&mut reader, 't');
+    assert_eq!(reader.state.borrow().reading_state.row, 20);
+    assert_eq!(
+        reader.state.borrow().ui_state.active_window,
+        WindowType::Reader
+    );
+    press(&mut reader, KeyCode::Enter);
+    assert_eq!(reader.state.borrow().reading_state.row, 30);
+    press(&mut reader, KeyCode::Enter);
+    let state = reader.state.borrow();
+    assert_eq!(state.reading_state.row, 2);
+    assert!(state.ui_state.link_tour.is_none());
+}
+
+#[test]
+fn chapter_break_rule_and_next_title_banner() {
+    use crate::models::CHAPTER_BREAK_MARKER;
+
+    let mut settings = Settings::default();
+    settings.chapter_break_blank_lines = 2;
+    settings.chapter_break_rule = true;
+    settings.chapter_break_title = true;
+    let mut reader = test_reader_with_settings(settings);
+    let fixture = format!(
+        "{}/tests/fixtures/meditations.epub",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    reader.load_ebook(&fixture).unwrap();
+    reader.state.borrow_mut().ui_state.clear_message();
+    let lines = reader.board.lines().unwrap();
+    // Breaks before chapters without a TOC entry get no banner.
+    let marker_row = lines
+        .windows(2)
+        .position(|pair| pair[0] == CHAPTER_BREAK_MARKER && !pair[1].is_empty())
+        .expect("fixture has a chapter break with a banner");
+    assert_eq!(lines[marker_row - 1], "");
+    assert_eq!(lines[marker_row - 2], "");
+
+    reader.state.borrow_mut().reading_state.row = marker_row.saturating_sub(3);
+    reader.draw().unwrap();
+    insta::assert_snapshot!(reader.terminal.backend());
+}
+
+#[test]
+fn reading_ruler_dims_lines_outside_the_focus_band() {
+    use ratatui::style::Modifier;
+
+    let mut reader = test_reader();
+    reader.state.borrow_mut().reading_state.row = 700;
+    reader.draw().unwrap();
+    let dim_rows = |reader: &Reader<TestBackend>| -> Vec<(u16, bool)> {
+        let buffer = reader.terminal.backend().buffer();
+        (0..buffer.area.height)
+            .filter_map(|y| {
+                let cells: Vec<_> = (0..buffer.area.width)
+                    .map(|x| &buffer[(x, y)])
+                    .filter(|cell| !cell.symbol().trim().is_empty())
+                    .collect();
+                (!cells.is_empty()).then(|| {
+                    (
+                        y,
+                        cells
+                            .iter()
+                            .all(|cell| cell.modifier.contains(Modifier::DIM)),
+                    )
+                })
+            })
+            .collect()
+    };
+    assert!(dim_rows(&reader).iter().all(|(_, dim)| !dim));
+
+    // The band covers `2 * focus_lines + 1` rows from the top of the page;
+    // the header row is not part of the board.
+    let bright = |reader: &Reader<TestBackend>| -> Vec<u16> {
+        dim_rows(reader)
+            .into_iter()
+            .filter(|&(y, dim)| y > 0 && !dim)
+            .map(|(y, _)| y)
+            .collect()
+    };
+    press_char(&mut reader, 'F');
+    assert_eq!(bright(&reader), vec![3, 4, 5]);
+    press_char(&mut reader, ')');
+    assert_eq!(bright(&reader), vec![4, 5, 6]);
+    press_char(&mut reader, 'F');
+    assert!(dim_rows(&reader).iter().all(|(_, dim)| !dim));
+}
+
+#[test]
+fn adjusted_width_survives_reload_in_auto_and_fixed_width_modes() {
+    let fixture_path = format!("{}/tests/fixtures/small.epub", env!("CARGO_MANIFEST_DIR"));
+    for configured in [None, Some(70)] {
+        let mut settings = Settings::default();
+        settings.width = configured;
+        let mut reader = test_reader_with_settings(settings);
+        let initial = reader.state.borrow().reading_state.textwidth;
+        assert_eq!(initial, configured.unwrap_or(DEFAULT_TEXT_WIDTH));
+
+        press_char(&mut reader, '-');
+        press_char(&mut reader, '-');
+        let adjusted = reader.state.borrow().reading_state.textwidth;
+        assert_eq!(adjusted, initial - 10);
+
+        // Reopening the book restores the adjusted width, not the config.
+        reader.load_ebook(&fixture_path).unwrap();
+        assert_eq!(
+            reader.state.borrow().reading_state.textwidth,
+            adjusted,
+            "width {configured:?}"
+        );
+
+        // `=` goes back to the configured width and persists that too.
+        press_char(&mut reader, '=');
+        reader.load_ebook(&fixture_path).unwrap();
+        assert_eq!(reader.state.borrow().reading_state.textwidth, initial);
+    }
+}
+
+#[test]
+fn k_in_cursor_mode_looks_up_the_word_under_the_cursor() {
+    use crate::ui::reader::WindowType;
+
+    let mut reader = test_reader();
+    reader.state.borrow_mut().reading_state.row = 700;
+    reader.draw().unwrap();
+    press_char(&mut reader, 'v');
+    let (row, col, word) = (700..800)
+        .find_map(|row| {
+            let line = reader.board.get_line(row)?;
+            let col = line.chars().position(|ch| ch.is_alphabetic())? + 1;
+            let (word, _, _) = reader.board.word_at(row, col)?;
+            Some((row, col, word))
+        })
+        .expect("a word near the top of the page");
+    reader.state.borrow_mut().ui_state.visual_cursor = Some((row, col));
+
+    press_char(&mut reader, 'K');
+    let state = reader.state.borrow();
+    assert_eq!(state.ui_state.active_window, WindowType::Dictionary);
+    assert_eq!(state.ui_state.dictionary_word, word);
+    assert!(state.ui_state.visual_anchor.is_none());
+}
+
+#[test]
+fn is_and_ip_select_the_sentence_and_paragraph_under_the_cursor() {
+    use crate::models::TextStructure;
+
+    let mut reader = test_reader();
+    reader.board.update_text_structure(TextStructure {
+        text_lines: [
+            "First one, Mr. Smith. Second",
+            "sentence spans rows! Third",
+            "",
+            "Next paragraph.",
+        ]
+        .iter()
+        .map(|line| line.to_string())
+        .collect(),
+        ..Default::default()
+    });
+    reader.content_start_rows = vec![0];
+    reader.state.borrow_mut().reading_state.row = 0;
+    press_char(&mut reader, 'v');
+    let selection = |reader: &Reader<TestBackend>| {
+        let state = reader.state.borrow();
+        (state.ui_state.visual_anchor, state.ui_state.visual_cursor)
+    };
+
+    reader.state.borrow_mut().ui_state.visual_cursor = Some((1, 3));
+    type_str(&mut reader, "is");
+    assert_eq!(selection(&reader), (Some((0, 22)), Some((1, 19))));
+
+    // The abbreviation does not end the first sentence.
+    reader.state.borrow_mut().ui_state.visual_cursor = Some((0, 16));
+    type_str(&mut reader, "is");
+    assert_eq!(selection(&reader), (Some((0, 0)), Some((0, 20))));
+
+    reader.state.borrow_mut().ui_state.visual_cursor = Some((1, 24));
+    type_str(&mut reader, "ip");
+    assert_eq!(selection(&reader), (Some((0, 0)), Some((1, 25))));
+}
+
+/// `W` appends the visible page under a header, and a second capture
+/// accumulates after the first instead of replacing it.
+#[test]
+fn capture_page_appends_visible_lines_to_the_capture_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("captures.md");
+    let settings = Settings {
+        capture_file: Some(path.to_string_lossy().into_owned()),
+        ..Settings::default()
+    };
+    let mut reader = test_reader_with_settings(settings);
+    let row = reader.content_start_rows[2] + 1;
+    reader.state.borrow_mut().reading_state.row = row;
+    let first_line = reader
+        .board
+        .get_line(row - 1)
+        .unwrap()
+        .trim_end()
+        .to_string();
+
+    press_char(&mut reader, 'W');
+    press_char(&mut reader, 'W');
+
+    let captured = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(captured.matches("## ").count(), 2, "{captured}");
+    assert!(captured.starts_with("## "), "{captured}");
+    assert!(captured.contains(&first_line), "{captured}");
+}
+
+/// `a` only starts a slideshow once an interval is set; a due slideshow
+/// turns one page, `+` lengthens the interval, and the last page stops it.
+#[test]
+fn slideshow_turns_pages_until_the_end_of_the_book() {
+    let mut reader = test_reader();
+    press_char(&mut reader, 'a');
+    assert!(reader.state.borrow().ui_state.slideshow_next.is_none());
+
+    let settings = Settings {
+        slideshow_interval_secs: 5,
+        ..Settings::default()
+    };
+    let mut reader = test_reader_with_settings(settings);
+    press_char(&mut reader, 'a');
+    assert!(reader.state.borrow().ui_state.slideshow_next.is_some());
+
+    press_char(&mut reader, '+');
+    assert_eq!(
+        reader
+            .state
+            .borrow()
+            .config
+            .settings
+            .slideshow_interval_secs,
+        6
+    );
+
+    reader.state.borrow_mut().ui_state.slideshow_next = Some(std::time::Instant::now());
+    reader.tick_slideshow().unwrap();
+    assert_ne!(reader.state.borrow().reading_state.row, 0);
+    assert!(reader.state.borrow().ui_state.slideshow_next.is_some());
+
+    let mut pages = 0;
+    while reader.state.borrow().ui_state.slideshow_next.is_some() {
+        reader.state.borrow_mut().ui_state.slideshow_next = Some(std::time::Instant::now());
+        reader.tick_slideshow().unwrap();
+        pages += 1;
+        assert!(pages < 1000, "slideshow never reached the end");
+    }
+    let end_row = reader.state.borrow().reading_state.row;
+    assert!(end_row + reader.page_size() >= reader.board.total_lines());
+
+    press_char(&mut reader, 'a');
+    press_char(&mut reader, 'a');
+    assert!(reader.state.borrow().ui_state.slideshow_next.is_none());
+}
+
+/// `O` lists every image in the book in reading order, and Enter jumps to
+/// the selected one from anywhere.
+#[test]
+fn all_images_window_lists_the_whole_book_and_jumps_to_an_image() {
+    let mut reader = test_reader();
+    let images = reader.book_images.clone();
+    assert!(images.len() >= 2, "{images:?}");
+    assert!(images.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+    press_char(&mut reader, 'O');
+    {
+        let state = reader.state.borrow();
+        assert_eq!(state.ui_state.active_window, super::WindowType::Images);
+        assert!(state.ui_state.images_book_wide);
+        let listed: Vec<(usize, String)> = state
+            .ui_state
+            .images_list
+            .iter()
+            .map(|image| (image.row, image.src.clone()))
+            .collect();
+        assert_eq!(listed, images);
+    }
+
+    // Images from other chapters still resolve against their own chapter.
+    let last = images.len() - 1;
+    reader.state.borrow_mut().ui_state.images_selected_index = last;
+    assert!(reader.selected_image_data().is_some());
+
+    press(&mut reader, KeyCode::Enter);
+    let state = reader.state.borrow();
+    assert_eq!(state.ui_state.active_window, super::WindowType::Reader);
+    assert_eq!(state.reading_state.row, images[last].0 + 1);
+}
+
+/// Image descriptions are read by default and can be turned off; chapter
+/// announcements are opt-in and precede the chapter's own text.
+#[test]
+fn tts_image_and_chapter_chunks_follow_their_settings() {
+    let texts = |reader: &Reader<TestBackend>| -> Vec<String> {
+        reader
+            .build_tts_chunks()
+            .into_iter()
+            .map(|chunk| chunk.text)
+            .collect()
+    };
+
+    let mut reader = test_reader();
+    assert!(texts(&reader).contains(&"Image: First Edition".to_string()));
+    reader.state.borrow_mut().config.settings.tts_read_images = false;
+    assert!(!texts(&reader).iter().any(|text| text.starts_with("Image:")));
+
+    let meditations = format!(
+        "{}/tests/fixtures/meditations.epub",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    reader.load_ebook(&meditations).unwrap();
+    assert!(
+        !texts(&reader)
+            .iter()
+            .any(|text| text.starts_with("Chapter:"))
+    );
+
+    reader
+        .state
+        .borrow_mut()
+        .config
+        .settings
+        .tts_announce_chapters = true;
+    let chunks = reader.build_tts_chunks();
+    let toc = reader.ebook.as_ref().unwrap().toc_entries().clone();
+    let first = toc.iter().position(|e| !e.label.trim().is_empty()).unwrap();
+    let row = reader.toc_activation_row(&toc, first).unwrap();
+    let announced = chunks
+        .iter()
+        .position(|chunk| chunk.text == format!("Chapter: {}", toc[first].label.trim()))
+        .expect("first chapter announced");
+    assert_eq!(chunks[announced].first_line, row);
+    assert!(chunks[..announced].iter().all(|c| c.first_line < row));
+}
+
+#[test]
+fn search_highlights_follow_hlsearch_and_ctrl_l() {
+    use crate::theme::{ColorTheme, Theme};
+
+    let theme = Theme::for_color_theme(ColorTheme::Default);
+    let highlighted_cells = |reader: &mut Reader<TestBackend>| {
+        reader.state.borrow_mut().ui_state.clear_message();
+        reader.draw().unwrap();
+        let buffer = reader.terminal.backend().buffer();
+        buffer
+            .content()
+            .iter()
+            .filter(|cell| cell.bg == theme.search_bg || cell.bg == theme.search_current_bg)
+            .count()
+    };
+    // Jump to the first hit, then scroll well past it into dense prose.
+    let search_then_read_on = |reader: &mut Reader<TestBackend>| {
+        press_char(reader, '/');
+        type_str(reader, "the");
+        press(reader, KeyCode::Enter);
+        press(reader, KeyCode::Enter);
+        assert!(highlighted_cells(reader) > 0);
+        reader.state.borrow_mut().reading_state.row = 700;
+    };
+
+    let mut reader = test_reader();
+    search_then_read_on(&mut reader);
+    assert!(highlighted_cells(&mut reader) > 0, "hlsearch keeps matches");
+
+    let ctrl_l = KeyEvent::new(KeyCode::Char('l'), KeyModifiers::CONTROL);
+    reader.handle_key_event(ctrl_l).unwrap();
+    assert_eq!(highlighted_cells(&mut reader), 0);
+    press_char(&mut reader, 'n');
+    assert!(highlighted_cells(&mut reader) > 0, "n shows them again");
+
+    let mut reader = test_reader_with_settings(Settings {
+        hlsearch: false,
+        ..Settings::default()
+    });
+    search_then_read_on(&mut reader);
+    let state = reader.state.borrow();
+    let hit = &state.ui_state.search_results[state.ui_state.selected_search_result];
+    assert!(hit.first_row() < state.reading_state.row);
+    drop(state);
+    assert_eq!(highlighted_cells(&mut reader), 0);
+}
@@ -0,0 +1,218 @@
+//! Terminal setup that survives the unexpected: a guard that puts the
+//! terminal back (raw mode off, main screen, cursor shown) when the reader
+//! unwinds from an error or a panic, and termination-signal handling so that
+//! a closed terminal window or a `kill` still saves the reading position.
+//!
+//! Signal handlers only set a flag; the event loop notices it through
+//! [`poll_event`] and quits the normal way, running the usual cleanup.
+
+use std::io;
+use std::sync::Once;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use ratatui::backend::{Backend, ClearType, WindowSize};
+use ratatui::buffer::Cell;
+use ratatui::layout::{Position, Size};
+
+/// How often a blocked [`poll_event`] wakes up to check for a termination
+/// signal; crossterm retries its poll on `EINTR`, so a signal alone does not
+/// wake it.
+const SIGNAL_CHECK_INTERVAL: Duration = Duration::from_millis(250);
+
+static TERMINATION_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Whether a [`TerminalGuard`] is armed; the panic hook restores the
+/// terminal only then, so it does nothing after a clean exit.
+static GUARD_ARMED: AtomicBool = AtomicBool::new(false);
+static GUARD_ALTERNATE_SCREEN: AtomicBool = AtomicBool::new(false);
+static PANIC_HOOK: Once = Once::new();
+
+/// Restores the terminal on drop unless [`TerminalGuard::disarm`] was called
+/// after a normal cleanup.
+pub struct TerminalGuard {
+    alternate_screen: bool,
+    armed: bool,
+}
+
+impl TerminalGuard {
+    /// Arm the guard and make panics restore the terminal before their
+    /// message is printed, so it lands on the main screen. The panic hook is
+    /// installed once and stays inert while no guard is armed.
+    pub fn new(alternate_screen: bool) -> Self {
+        PANIC_HOOK.call_once(|| {
+            let previous_hook = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                if GUARD_ARMED.swap(false, Ordering::SeqCst) {
+                    restore(GUARD_ALTERNATE_SCREEN.load(Ordering::SeqCst));
+                }
+                previous_hook(info);
+            }));
+        });
+        GUARD_ALTERNATE_SCREEN.store(alternate_screen, Ordering::SeqCst);
+        GUARD_ARMED.store(true, Ordering::SeqCst);
+        Self {
+            alternate_screen,
+            armed: true,
+        }
+    }
+
+    pub fn disarm(&mut self) {
+        self.armed = false;
+        GUARD_ARMED.store(false, Ordering::SeqCst);
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        if self.armed {
+            self.disarm();
+            restore(self.alternate_screen);
+        }
+    }
+}
+
+/// Best-effort terminal reset; errors are ignored since the terminal may
+/// already be gone.
+fn restore(alternate_screen: bool) {
+    let _ = crossterm::terminal::disable_raw_mode();
+    if alternate_screen {
+        let _ = crossterm::execute!(io::stdout(), crossterm::terminal::LeaveAlternateScreen);
+    }
+    let _ = crossterm::execute!(
+        io::stdout(),
+        crossterm::event::DisableMouseCapture,
+        crossterm::event::DisableBracketedPaste,
+        crossterm::cursor::Show
+    );
+}
+
+/// Signal handler behind [`install_termination_handler`].
+#[cfg(unix)]
+extern "C" fn request_termination(_signal: libc::c_int) {
+    TERMINATION_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+/// Turn SIGTERM, SIGHUP, and SIGINT into a quit request. In raw mode Ctrl-C
+/// arrives as a key, so SIGINT only comes from outside (`kill -INT`).
+#[cfg(unix)]
+pub fn install_termination_handler() {
+    let handler = request_termination as extern "C" fn(libc::c_int) as libc::sighandler_t;
+    for signal in [libc::SIGTERM, libc::SIGHUP, libc::SIGINT] {
+        // SAFETY: the handler only stores to an atomic, which is
+        // async-signal-safe.
+        unsafe {
+            libc::signal(signal, handler);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+pub fn install_termination_handler() {}
+
+pub fn termination_requested() -> bool {
+    TERMINATION_REQUESTED.load(Ordering::SeqCst)
+}
+
+/// `crossterm::event::poll` that gives up early, returning `false`, once a
+/// termination signal has arrived.
+pub fn poll_event(timeout: Duration) -> io::Result<bool> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        // Checked first: once the terminal is hung up, polling it may fail.
+        if termination_requested() {
+            return Ok(false);
+        }
+        let slice = deadline
+            .saturating_duration_since(Instant::now())
+            .min(SIGNAL_CHECK_INTERVAL);
+        if crossterm::event::poll(slice)? {
+            return Ok(true);
+        }
+        if Instant::now() >= deadline {
+            return Ok(false);
+        }
+    }
+}
+
+/// A backend of a fixed size that draws nowhere, for laying out a reader
+/// without a terminal, e.g. `--print-state` computing the visible page.
+pub struct HeadlessBackend {
+    size: Size,
+    cursor: Position,
+}
+
+impl HeadlessBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            size: Size::new(width, height),
+            cursor: Position::ORIGIN,
+        }
+    }
+}
+
+impl Backend for HeadlessBackend {
+    type Error = io::Error;
+
+    fn draw<'a, I>(&mut self, _content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        Ok(self.cursor)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.cursor = position.into();
+        Ok(())
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn clear_region(&mut self, _clear_type: ClearType) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.size)
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        Ok(WindowSize {
+            columns_rows: self.size,
+            pixels: Size::default(),
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_termination_signal_requests_quit() {
+        // Call the handler directly rather than installing it process-wide
+        // and raising a real signal inside the shared test binary.
+        assert!(!termination_requested());
+        request_termination(libc::SIGTERM);
+        assert!(termination_requested());
+        TERMINATION_REQUESTED.store(false, Ordering::SeqCst);
+    }
+}
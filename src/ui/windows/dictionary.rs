@@ -1,6 +1,8 @@
 use ratatui::{
     Frame,
     layout::Rect,
+    style::Style,
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 use textwrap::{Options, WordSplitter};
@@ -9,6 +11,21 @@ use crate::theme::Theme;
 
 pub struct DictionaryWindow;
 
+/// What the dictionary popup shows: the looked-up text and where it came
+/// from, plus the scroll, loading, and disambiguation state.
+pub struct DictionaryView<'a> {
+    pub word: &'a str,
+    pub definition: &'a str,
+    /// Dictionary program that answered; empty when unknown.
+    pub client: &'a str,
+    pub scroll_offset: u16,
+    pub loading: bool,
+    pub is_wikipedia: bool,
+    /// Wikipedia disambiguation titles to pick from, if any.
+    pub options: &'a [String],
+    pub selected_option: usize,
+}
+
 /// A logical paragraph: its leading indent (in columns) and the joined text content.
 struct LogicalParagraph {
     indent: usize,
@@ -32,17 +49,17 @@ impl DictionaryWindow {
             .min(u16::MAX as usize) as u16
     }
 
-    pub fn render(
-        frame: &mut Frame,
-        area: Rect,
-        word: &str,
-        definition: &str,
-        client: &str,
-        scroll_offset: u16,
-        loading: bool,
-        is_wikipedia: bool,
-        theme: &Theme,
-    ) {
+    pub fn render(frame: &mut Frame, area: Rect, view: &DictionaryView, theme: &Theme) {
+        let DictionaryView {
+            word,
+            definition,
+            client,
+            scroll_offset,
+            loading,
+            is_wikipedia,
+            options,
+            selected_option,
+        } = *view;
         let popup_area = super::centered_popup_area(area, 70, 80);
         frame.render_widget(Clear, popup_area);
 
@@ -92,6 +109,19 @@ impl DictionaryWindow {
         let inner_width = popup_area.width.saturating_sub(2) as usize;
         let reflowed = Self::reflow(definition, inner_width);
 
+        if !options.is_empty() {
+            Self::render_options(
+                frame,
+                popup_area,
+                block,
+                &reflowed,
+                options,
+                selected_option,
+                theme,
+            );
+            return;
+        }
+
         let paragraph = Paragraph::new(reflowed)
             .style(theme.base_style())
             .block(block)
@@ -101,6 +131,48 @@ impl DictionaryWindow {
         frame.render_widget(paragraph, popup_area);
     }
 
+    /// The definition text as a header, followed by a selectable list.
+    fn render_options(
+        frame: &mut Frame,
+        popup_area: Rect,
+        block: Block,
+        header: &str,
+        options: &[String],
+        selected_option: usize,
+        theme: &Theme,
+    ) {
+        let mut lines: Vec<Line> = header.lines().map(Line::from).collect();
+        lines.push(Line::from(""));
+        let first_option = lines.len();
+        lines.extend(options.iter().enumerate().map(|(i, option)| {
+            let style = if i == selected_option {
+                Style::default()
+                    .bg(theme.highlight_bg)
+                    .fg(theme.highlight_fg)
+            } else {
+                Style::default()
+            };
+            Line::from(Span::styled(format!(" {option}"), style))
+        }));
+
+        // Keep the selection in view, centered when the list is long
+        let inner_height = popup_area.height.saturating_sub(2) as usize;
+        let selected_line = first_option + selected_option;
+        let scroll_offset = if lines.len() <= inner_height {
+            0
+        } else {
+            let target_top = selected_line.saturating_sub(inner_height / 2);
+            target_top.min(lines.len().saturating_sub(inner_height))
+        };
+
+        let paragraph = Paragraph::new(lines)
+            .style(theme.base_style())
+            .block(block.title_bottom(" Enter: look up "))
+            .scroll((scroll_offset as u16, 0));
+
+        frame.render_widget(paragraph, popup_area);
+    }
+
     /// Count leading whitespace columns (treating each char as 1 column).
     fn indent_of(line: &str) -> usize {
         line.len() - line.trim_start().len()
@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::theme::Theme;
+use crate::ui::keymap::{Action, KeyBindings};
 
 use super::fuzzy_filter_indices;
 
@@ -18,120 +19,217 @@ fn is_section_header(line: &str) -> bool {
 
 pub struct HelpWindow;
 
-const HELP_TEXT: &[&str] = &[
-    " Navigation:",
-    "   k / Up            Line Up",
-    "   j / Down          Line Down",
-    "   h / Left          Page Up",
-    "   l / Right / Space Page Down",
-    "   Ctrl+u            Half Page Up",
-    "   Ctrl+d            Half Page Down",
-    "   L                 Next Chapter",
-    "   H                 Prev Chapter",
-    "   g                 Chapter Start",
-    "   G                 Chapter End",
-    "   Home              Book Start",
-    "   End               Book End",
-    " Jump History:",
-    "   Ctrl+o            Jump Back",
-    "   Ctrl+i/Tab        Jump Forward",
-    "   m<c>              Set Mark <c> (a-z, A-Z, 0-9)",
-    "   `<c>              Jump To Mark <c>",
-    " Search:",
-    "   /                 Start Search (matches update as you type)",
-    "   Up / Down         Recall search history while typing",
-    "   Enter             Confirm query; Enter again jumps & closes",
-    "   n                 Next Hit",
-    "   p / N             Previous Hit",
-    " Annotations:",
-    "   A                 Highlights List",
-    "   Enter             Jump to Highlight",
-    "   e                 Edit Highlight Comment",
-    "   d                 Delete Highlight",
-    " Display:",
-    "   + / -             Increase/Decrease Width",
-    "   =                 Reset Width",
-    "   T                 Toggle Top Bar",
-    "   c                 Cycle Color Theme",
-    " Windows & Tools:",
-    "   t                 Table Of Contents",
-    "   B                 Bookmarks",
-    "   e                 Edit Bookmark Label",
-    "   u                 Links on Page (Enter previews internal links)",
-    "   o                 Images on Page (Enter shows in-terminal, o external)",
-    "   i                 Metadata",
-    "   r                 Library (history + scanned directories)",
-    "   R                 Reading Statistics",
-    "   s                 Settings",
-    "   /                 Fuzzy-filter list and Help windows",
-    "                     (Esc clears; Enter applies)",
-    " Library Window:",
-    "   Enter             Open book",
-    "   c                 Toggle selected book details and cover",
-    "   f                 Cycle available formats",
-    "   R                 Refresh library directories",
-    "   O                 Browse OPDS catalogs (from Library)",
-    "   m                 Move book to Calibre (via calibredb)",
-    "   d                 Remove from history",
-    "   s                 Cycle sort (recent/title/author/series/progress)",
-    " OPDS Catalog:",
-    "   Enter             Open folder / download book",
-    "   /                 Search catalog",
-    "   [ / ]             Previous / next feed page",
-    "   f                 Cycle download format",
-    "   c                 Toggle book details",
-    "   h or Backspace    Go back one level",
-    "   q                 Return to Library",
-    " Text-to-Speech:",
-    "   !                 Toggle TTS (Read Aloud)",
-    " Cursor Mode:",
-    "   hjkl, w/b/e       Move cursor (prefix with count, e.g. 5j)",
-    "   ^ / $             Start (non-blank) / end of line",
-    "   [ / ]             Previous / next paragraph",
-    "   f<c> / F<c>       Jump to next/prev <c> on current line",
-    "   t<c> / T<c>       Jump just before/after next/prev <c> (line-local)",
-    "   /                 Search visible screen (smartcase, spans wraps)",
-    "   n / N             Next / Previous match",
-    "   Enter             Edit comment of highlight under cursor",
-    "   d                 Delete highlight under cursor",
-    "   C                 Cycle color of highlight under cursor",
-    " Selection Mode:",
-    "   hjkl, w/b/e       Extend selection (prefix with count)",
-    "   ^ / $             Extend to start / end of line",
-    "   [ / ]             Extend by paragraph",
-    "   f<c> / F<c>       Extend to next/prev <c> on current line",
-    "   t<c> / T<c>       Extend till just before/after next/prev <c>",
-    "   /                 Search visible screen (extends selection)",
-    "   n / N             Next / Previous match",
-    "   y                 Yank selection",
-    "   a                 Highlight selection",
-    "   c                 Highlight and comment",
-    "   d                 Dictionary Lookup",
-    "   p                 Wikipedia Summary",
-    "   s                 Search with Ecosia",
-    "   q                 Quit / Close Window",
+/// One line of the Help window. Normal-mode entries name an [`Action`] and
+/// take their keys from the live [`KeyBindings`], so remaps are listed as
+/// they are dispatched.
+enum HelpEntry {
+    Section(&'static str),
+    Bound(Action),
+    /// The action's keys between a prefix and a suffix (a count or the
+    /// follow-up key), with its own description.
+    Keyed(&'static str, Action, &'static str, &'static str),
+    /// Keys handled outside normal mode: another window or a sub-mode.
+    Text(&'static str, &'static str),
+}
+
+use HelpEntry::{Bound, Keyed, Section, Text};
+
+const HELP_ENTRIES: &[HelpEntry] = &[
+    Section(" Navigation:"),
+    Bound(Action::LineUp),
+    Bound(Action::LineDown),
+    Bound(Action::PageUp),
+    Bound(Action::PageDown),
+    Bound(Action::HalfPageUp),
+    Bound(Action::HalfPageDown),
+    Bound(Action::NextChapter),
+    Bound(Action::PrevChapter),
+    Bound(Action::ChapterStart),
+    Bound(Action::ChapterEnd),
+    Keyed("{n}", Action::TableOfContents, "", "Go to Chapter n"),
+    Keyed("{n}", Action::PrintPages, "", "Go to Print Page n"),
+    Bound(Action::PrevParagraph),
+    Bound(Action::NextParagraph),
+    Bound(Action::BookStart),
+    Bound(Action::BookEnd),
+    Text("0-9", "Go to n×10% (when count prefixes are off)"),
+    Bound(Action::BeginReading),
+    Bound(Action::Cover),
+    Keyed("", Action::ScrollCommand, "z", "Center Current Line"),
+    Keyed("", Action::ScrollCommand, "t", "Current Line to Top"),
+    Keyed("", Action::ScrollCommand, "b", "Current Line to Bottom"),
+    Section(" Jump History:"),
+    Bound(Action::JumpBack),
+    Bound(Action::JumpForward),
+    Keyed("", Action::SetMark, "<c>", Action::SetMark.description()),
+    Keyed(
+        "",
+        Action::JumpToMark,
+        "<c>",
+        Action::JumpToMark.description(),
+    ),
+    Section(" Search:"),
+    Bound(Action::Search),
+    Text("Up / Down", "Recall search history while typing"),
+    Text("Tab", "Toggle chapter / book search scope"),
+    Text("Ctrl+n / Ctrl+p", "First result in next / previous chapter"),
+    Text("Enter", "Confirm query; Enter again jumps & closes"),
+    Bound(Action::NextHit),
+    Bound(Action::PrevHit),
+    Bound(Action::ClearSearchHighlights),
+    Section(" Annotations:"),
+    Bound(Action::Highlights),
+    Text("Enter", "Jump to Highlight"),
+    Text("e", "Edit Highlight Comment"),
+    Text("d", "Delete Highlight"),
+    Section(" Display:"),
+    Bound(Action::Enlarge),
+    Bound(Action::Shrink),
+    Bound(Action::ResetWidth),
+    Bound(Action::ScrollLeft),
+    Bound(Action::ScrollRight),
+    Bound(Action::ToggleTopBar),
+    Bound(Action::Slideshow),
+    Text("+ / -", "Slideshow Interval (while running)"),
+    Bound(Action::ToggleFocusMode),
+    Bound(Action::FocusUp),
+    Bound(Action::FocusDown),
+    Bound(Action::SwitchColor),
+    Section(" Windows & Tools:"),
+    Bound(Action::TableOfContents),
+    Bound(Action::PrintPages),
+    Bound(Action::Bookmarks),
+    Keyed("", Action::BracketForward, "m", "Next Bookmark"),
+    Keyed("", Action::BracketBack, "m", "Previous Bookmark"),
+    Text("e", "Edit Bookmark Label"),
+    Bound(Action::Links),
+    Text("t", "Tour Internal Links (Enter next, Esc return)"),
+    Bound(Action::Images),
+    Bound(Action::AllImages),
+    Bound(Action::Metadata),
+    Bound(Action::ChapterSource),
+    Bound(Action::ChapterSourceView),
+    Bound(Action::CapturePage),
+    Bound(Action::WriteState),
+    Bound(Action::SaveNow),
+    Bound(Action::Library),
+    Bound(Action::Statistics),
+    Bound(Action::Messages),
+    Bound(Action::Settings),
+    Bound(Action::CursorMode),
+    Bound(Action::Help),
+    Text("/", "Fuzzy-filter list and Help windows"),
+    Text("", "(Esc clears; Enter applies)"),
+    Bound(Action::Quit),
+    Section(" Library Window:"),
+    Text("Enter", "Open book"),
+    Text("c", "Toggle selected book details and cover"),
+    Text("f", "Cycle available formats"),
+    Text("R", "Refresh library directories"),
+    Text("O", "Browse OPDS catalogs (from Library)"),
+    Text("o", "Open containing folder in file manager"),
+    Text("x", "Toggle finished mark"),
+    Text("p", "Cycle status filter (unread/in progress/finished)"),
+    Text("m", "Move book to Calibre (via calibredb)"),
+    Text("d", "Remove from history"),
+    Text("s", "Cycle sort (recent/title/author/series/progress)"),
+    Section(" OPDS Catalog:"),
+    Text("Enter", "Open folder / download book"),
+    Text("/", "Search catalog"),
+    Text("[ / ]", "Previous / next feed page"),
+    Text("f", "Cycle download format"),
+    Text("c", "Toggle book details"),
+    Text("h or Backspace", "Go back one level"),
+    Text("q", "Return to Library"),
+    Section(" Text-to-Speech:"),
+    Bound(Action::TtsToggle),
+    Bound(Action::AnnouncePosition),
+    Bound(Action::TtsPrevSentence),
+    Bound(Action::TtsNextSentence),
+    Text("Esc", "Stop reading aloud"),
+    Section(" Cursor Mode:"),
+    Text("hjkl, w/b/e", "Move cursor (prefix with count, e.g. 5j)"),
+    Text("^ / $", "Start (non-blank) / end of line"),
+    Text("[ / ]", "Previous / next paragraph"),
+    Text("f<c> / F<c>", "Jump to next/prev <c> on current line"),
+    Text(
+        "t<c> / T<c>",
+        "Jump just before/after next/prev <c> (line-local)",
+    ),
+    Text("/", "Search visible screen (smartcase, spans wraps)"),
+    Text("n / N", "Next / Previous match"),
+    Text("Enter", "Edit comment of highlight under cursor"),
+    Text("d", "Delete highlight under cursor"),
+    Text("C", "Cycle color of highlight under cursor"),
+    Text("K", "Dictionary lookup of word under cursor"),
+    Text("is / ip", "Select inner sentence / paragraph"),
+    Section(" Selection Mode:"),
+    Text("hjkl, w/b/e", "Extend selection (prefix with count)"),
+    Text("^ / $", "Extend to start / end of line"),
+    Text("[ / ]", "Extend by paragraph"),
+    Text("f<c> / F<c>", "Extend to next/prev <c> on current line"),
+    Text("t<c> / T<c>", "Extend till just before/after next/prev <c>"),
+    Text("is / ip", "Reselect as inner sentence / paragraph"),
+    Text("/", "Search visible screen (extends selection)"),
+    Text("n / N", "Next / Previous match"),
+    Text("y", "Yank selection"),
+    Text("Y", "Yank as Markdown quote"),
+    Text("C", "Yank with citation (print page or %)"),
+    Text("a", "Highlight selection"),
+    Text("c", "Highlight and comment"),
+    Text("d", "Dictionary Lookup"),
+    Text("p", "Wikipedia Summary"),
+    Text("s", "Search with Ecosia"),
+    Text("q", "Quit / Close Window"),
 ];
 
+fn item_line(keys: &str, description: &str) -> String {
+    format!("   {keys:<17} {description}")
+}
+
+fn keys_label(bindings: &KeyBindings, prefix: &str, action: Action, suffix: &str) -> String {
+    bindings
+        .keys(action)
+        .iter()
+        .map(|key| format!("{prefix}{}{suffix}", key.label()))
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// The full help text for `bindings`.
+fn help_lines(bindings: &KeyBindings) -> Vec<String> {
+    HELP_ENTRIES
+        .iter()
+        .map(|entry| match *entry {
+            Section(header) => header.to_string(),
+            Bound(action) => item_line(&keys_label(bindings, "", action, ""), action.description()),
+            Keyed(prefix, action, suffix, description) => {
+                item_line(&keys_label(bindings, prefix, action, suffix), description)
+            }
+            Text(keys, description) => item_line(keys, description),
+        })
+        .collect()
+}
+
 impl HelpWindow {
-    pub fn get_total_lines() -> usize {
-        HELP_TEXT.len()
+    pub fn get_total_lines(bindings: &KeyBindings) -> usize {
+        help_lines(bindings).len()
     }
 
-    pub fn filterable_lines() -> Vec<String> {
-        HELP_TEXT
-            .iter()
+    pub fn filterable_lines(bindings: &KeyBindings) -> Vec<String> {
+        help_lines(bindings)
+            .into_iter()
             .filter(|line| !is_section_header(line))
-            .map(|line| (*line).to_string())
             .collect()
     }
 
-    fn filtered_text(query: Option<&str>) -> Vec<&'static str> {
+    fn filtered_text(lines: &[String], query: Option<&str>) -> Vec<String> {
         let Some(query) = query else {
-            return HELP_TEXT.to_vec();
+            return lines.to_vec();
         };
-        let items: Vec<&str> = HELP_TEXT
+        let items: Vec<&str> = lines
             .iter()
-            .copied()
+            .map(String::as_str)
             .filter(|line| !is_section_header(line))
             .collect();
         let matches = fuzzy_filter_indices(query, &items);
@@ -143,20 +241,20 @@ impl HelpWindow {
         let mut result = Vec::new();
         let mut item_index = 0;
         let mut section_start = 0;
-        while section_start < HELP_TEXT.len() {
-            let section_end = HELP_TEXT[section_start + 1..]
+        while section_start < lines.len() {
+            let section_end = lines[section_start + 1..]
                 .iter()
                 .position(|line| is_section_header(line))
-                .map_or(HELP_TEXT.len(), |offset| section_start + 1 + offset);
+                .map_or(lines.len(), |offset| section_start + 1 + offset);
             let section_item_count = section_end - section_start - 1;
             if matched[item_index..item_index + section_item_count]
                 .iter()
                 .any(|matched| *matched)
             {
-                result.push(HELP_TEXT[section_start]);
-                for (offset, line) in HELP_TEXT[section_start + 1..section_end].iter().enumerate() {
+                result.push(lines[section_start].clone());
+                for (offset, line) in lines[section_start + 1..section_end].iter().enumerate() {
                     if matched[item_index + offset] {
-                        result.push(line);
+                        result.push(line.clone());
                     }
                 }
             }
@@ -166,8 +264,8 @@ impl HelpWindow {
         result
     }
 
-    pub fn max_scroll_offset(area: Rect, filter: Option<&str>) -> u16 {
-        let content_len = Self::filtered_text(filter).len();
+    pub fn max_scroll_offset(area: Rect, filter: Option<&str>, bindings: &KeyBindings) -> u16 {
+        let content_len = Self::filtered_text(&help_lines(bindings), filter).len();
         let height = (content_len as u16 + 2).min(area.height);
         let inner_height = height.saturating_sub(2) as usize;
         content_len
@@ -181,17 +279,19 @@ impl HelpWindow {
         scroll_offset: u16,
         filter_query: Option<&str>,
         filter_status: Option<&str>,
+        bindings: &KeyBindings,
         theme: &Theme,
     ) {
         let header_style = theme
             .base_style()
             .fg(theme.info_fg)
             .add_modifier(Modifier::BOLD);
-        let help_content: Vec<Line> = Self::filtered_text(filter_query)
-            .iter()
-            .map(|&s| {
+        let help_content: Vec<Line> = Self::filtered_text(&help_lines(bindings), filter_query)
+            .into_iter()
+            .map(|s| {
+                let header = is_section_header(&s);
                 let line = Line::from(s);
-                if is_section_header(s) {
+                if header {
                     line.style(header_style)
                 } else {
                     line
@@ -232,16 +332,23 @@ impl HelpWindow {
 mod tests {
     use super::*;
 
+    fn default_lines() -> Vec<String> {
+        help_lines(&KeyBindings::default())
+    }
+
     #[test]
     fn max_scroll_offset_zero_when_help_fits() {
-        let area = Rect::new(0, 0, 120, 100);
-        assert_eq!(HelpWindow::max_scroll_offset(area, None), 0);
+        let area = Rect::new(0, 0, 120, 160);
+        assert_eq!(
+            HelpWindow::max_scroll_offset(area, None, &KeyBindings::default()),
+            0
+        );
     }
 
     #[test]
     fn max_scroll_offset_positive_when_help_overflows() {
         let area = Rect::new(0, 0, 120, 10);
-        assert!(HelpWindow::max_scroll_offset(area, None) > 0);
+        assert!(HelpWindow::max_scroll_offset(area, None, &KeyBindings::default()) > 0);
     }
 
     #[test]
@@ -252,25 +359,37 @@ mod tests {
         assert!(!is_section_header(""));
         // Every non-blank help line is either a header or an indented item.
         assert!(
-            HELP_TEXT.iter().any(|line| is_section_header(line)),
+            default_lines().iter().any(|line| is_section_header(line)),
             "help text should contain section headers"
         );
     }
 
     #[test]
     fn filtering_keeps_matching_lines_and_their_headers() {
-        let lines = HelpWindow::filtered_text(Some("bookmark"));
-        assert!(lines.contains(&" Windows & Tools:"));
-        assert!(lines.contains(&"   B                 Bookmarks"));
-        assert!(!lines.contains(&" Navigation:"));
-        assert!(!lines.contains(&"   k / Up            Line Up"));
+        let lines = HelpWindow::filtered_text(&default_lines(), Some("bookmark"));
+        assert!(lines.iter().any(|line| line == " Windows & Tools:"));
+        assert!(
+            lines
+                .iter()
+                .any(|line| line == "   B                 Bookmarks")
+        );
+        assert!(!lines.iter().any(|line| line == " Navigation:"));
+        assert!(
+            !lines
+                .iter()
+                .any(|line| line == "   k / Up            Line Up")
+        );
     }
 
     #[test]
     fn filtering_drops_empty_sections() {
-        let lines = HelpWindow::filtered_text(Some("wikipedia"));
-        assert!(lines.contains(&" Selection Mode:"));
-        assert!(lines.contains(&"   p                 Wikipedia Summary"));
+        let lines = HelpWindow::filtered_text(&default_lines(), Some("wikipedia"));
+        assert!(lines.iter().any(|line| line == " Selection Mode:"));
+        assert!(
+            lines
+                .iter()
+                .any(|line| line == "   p                 Wikipedia Summary")
+        );
         assert_eq!(
             lines.iter().filter(|line| is_section_header(line)).count(),
             1
@@ -280,7 +399,36 @@ mod tests {
     #[test]
     fn filtered_scroll_bounds_use_visible_content() {
         let area = Rect::new(0, 0, 80, 10);
-        assert!(HelpWindow::max_scroll_offset(area, None) > 0);
-        assert_eq!(HelpWindow::max_scroll_offset(area, Some("bookmark")), 0);
+        let bindings = KeyBindings::default();
+        assert!(HelpWindow::max_scroll_offset(area, None, &bindings) > 0);
+        assert_eq!(
+            HelpWindow::max_scroll_offset(area, Some("bookmark"), &bindings),
+            0
+        );
+    }
+
+    #[test]
+    fn every_bound_action_is_listed() {
+        for action in KeyBindings::default().actions() {
+            assert!(
+                HELP_ENTRIES.iter().any(|entry| matches!(
+                    *entry,
+                    Bound(listed) | Keyed(_, listed, _, _) if listed == action
+                )),
+                "{action:?} missing from help"
+            );
+        }
+    }
+
+    #[test]
+    fn help_lists_remapped_keys() {
+        let keymap = crate::settings::CfgDefaultKeymaps {
+            scroll_down: "J".to_string(),
+            mark_position: "M".to_string(),
+            ..Default::default()
+        };
+        let lines = help_lines(&KeyBindings::new(&keymap));
+        assert!(lines.contains(&"   J / Down          Line Down".to_string()));
+        assert!(lines.contains(&"   M<c>              Set Mark <c> (a-z, A-Z, 0-9)".to_string()));
     }
 }
@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::theme::Theme;
+use crate::ui::reader::ImageEntry;
 
 pub struct ImagesWindow;
 
@@ -14,7 +15,8 @@ impl ImagesWindow {
     pub fn render(
         frame: &mut Frame,
         area: Rect,
-        images: &[(usize, String)],
+        images: &[ImageEntry],
+        book_wide: bool,
         selected_index: usize,
         theme: &Theme,
     ) {
@@ -24,19 +26,32 @@ impl ImagesWindow {
 
         let items: Vec<ListItem> = images
             .iter()
-            .map(|(line, src)| {
-                let filename = std::path::Path::new(src)
+            .map(|image| {
+                let filename = std::path::Path::new(&image.src)
                     .file_name()
                     .and_then(|n| n.to_str())
-                    .unwrap_or(src);
-                ListItem::new(Line::from(format!("Line {}: {}", line + 1, filename)))
+                    .unwrap_or(&image.src);
+                let mut text = match &image.chapter {
+                    Some(chapter) => format!("{chapter}: {filename}"),
+                    None => format!("Line {}: {}", image.row + 1, filename),
+                };
+                if let Some(alt) = &image.alt {
+                    text.push_str(" — ");
+                    text.push_str(alt);
+                }
+                ListItem::new(Line::from(text))
             })
             .collect();
+        let title = if book_wide {
+            "All Images (Enter jump, v view, o external viewer)"
+        } else {
+            "Images on Page (Enter view, o external viewer)"
+        };
 
         let list = List::new(items)
             .block(
                 Block::default()
-                    .title("Images on Page (Enter view, o external viewer)")
+                    .title(title)
                     .borders(Borders::ALL)
                     .style(theme.base_style()),
             )
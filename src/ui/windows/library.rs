@@ -7,7 +7,7 @@ use ratatui::{
 };
 use ratatui_image::{Resize, StatefulImage, protocol::StatefulProtocol};
 
-use crate::models::{LibraryEntry, LibrarySortMode};
+use crate::models::{LibraryEntry, LibraryProgressFilter, LibrarySortMode};
 use crate::theme::Theme;
 
 /// Minimum popup width before metadata is placed beside the book list.
@@ -24,6 +24,7 @@ impl LibraryWindow {
         selected_index: usize,
         filter: Option<&str>,
         sort_mode: LibrarySortMode,
+        progress_filter: LibraryProgressFilter,
         scanning: bool,
         details: Option<&LibraryEntry>,
         cover: Option<&mut StatefulProtocol>,
@@ -38,11 +39,13 @@ impl LibraryWindow {
 
         frame.render_widget(Clear, popup_area);
 
-        let title = if scanning {
-            format!("Library — by {} (scanning…)", sort_mode.label())
-        } else {
-            format!("Library — by {}", sort_mode.label())
-        };
+        let mut title = format!("Library — by {}", sort_mode.label());
+        if progress_filter != LibraryProgressFilter::All {
+            title.push_str(&format!(" · {}", progress_filter.label()));
+        }
+        if scanning {
+            title.push_str(" (scanning…)");
+        }
 
         let make_block = || {
             let mut block = Block::default()
@@ -61,6 +64,8 @@ impl LibraryWindow {
         if entries.is_empty() {
             let message = if filter.is_some() {
                 "No matches"
+            } else if progress_filter != LibraryProgressFilter::All {
+                "No books with this status — press p to change the filter"
             } else if scanning {
                 "Scanning library directories…"
             } else {
@@ -175,7 +175,7 @@ impl LinksWindow {
             list_area.width - 4,
             1,
         );
-        let status_line = Paragraph::new("Enter: follow  y: copy  q: close")
+        let status_line = Paragraph::new("Enter: follow  t: tour  y: copy  q: close")
             .style(Style::default().fg(theme.warning_fg));
         frame.render_widget(status_line, status_area);
 
@@ -0,0 +1,103 @@
+use std::collections::VecDeque;
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Padding, Paragraph, Wrap},
+};
+
+use crate::theme::Theme;
+use crate::ui::reader::{LoggedMessage, MessageType};
+
+pub struct MessagesWindow;
+
+impl MessagesWindow {
+    /// Recent status messages, newest first, with the selected one shown in
+    /// full below the list.
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        messages: &VecDeque<LoggedMessage>,
+        selected_index: usize,
+        theme: &Theme,
+    ) {
+        let popup_area = super::centered_popup_area(area, 70, 70);
+
+        frame.render_widget(Clear, popup_area);
+
+        if messages.is_empty() {
+            let block = Block::default()
+                .title("Messages")
+                .borders(Borders::ALL)
+                .padding(Padding::horizontal(1))
+                .style(theme.base_style());
+            let paragraph = Paragraph::new("No messages yet")
+                .style(theme.base_style().fg(theme.muted_fg))
+                .block(block);
+            frame.render_widget(paragraph, popup_area);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(5)])
+            .split(popup_area);
+
+        let items: Vec<ListItem> = messages
+            .iter()
+            .rev()
+            .map(|entry| {
+                let (label, color) = match entry.message_type {
+                    MessageType::Info => ("info ", theme.info_fg),
+                    MessageType::Warning => ("warn ", theme.warning_fg),
+                    MessageType::Error => ("error", theme.error_fg),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{} ", entry.time.format("%H:%M:%S")),
+                        Style::default().fg(theme.muted_fg),
+                    ),
+                    Span::styled(format!("{label} "), Style::default().fg(color)),
+                    Span::raw(entry.text.lines().next().unwrap_or_default().to_string()),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(format!("Messages ({})", messages.len()))
+                    .borders(Borders::ALL)
+                    .style(theme.base_style()),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(theme.highlight_bg)
+                    .fg(theme.highlight_fg)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        let mut state = ListState::default();
+        state.select(Some(selected_index));
+        frame.render_stateful_widget(list, chunks[0], &mut state);
+
+        let detail = messages
+            .len()
+            .checked_sub(selected_index + 1)
+            .and_then(|index| messages.get(index))
+            .map(|entry| entry.text.as_str())
+            .unwrap_or_default();
+        let paragraph = Paragraph::new(detail)
+            .wrap(Wrap { trim: false })
+            .style(theme.base_style())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .padding(Padding::horizontal(1))
+                    .style(theme.base_style()),
+            );
+        frame.render_widget(paragraph, chunks[1]);
+    }
+}
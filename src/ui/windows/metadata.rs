@@ -2,11 +2,12 @@ use crate::models::BookMetadata;
 use crate::theme::Theme;
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Rect, Size},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
 };
+use ratatui_image::{Resize, StatefulImage, protocol::StatefulProtocol};
 
 pub struct MetadataWindow;
 
@@ -16,6 +17,7 @@ impl MetadataWindow {
         area: Rect,
         metadata: Option<&BookMetadata>,
         filepath: Option<&str>,
+        cover: Option<&mut StatefulProtocol>,
         theme: &Theme,
     ) {
         let popup_area = super::centered_popup_area(area, 60, 80);
@@ -74,14 +76,35 @@ impl MetadataWindow {
                 )),
             ];
 
-            let paragraph = Paragraph::new(content).style(theme.base_style()).block(
-                Block::default()
-                    .title("Metadata")
-                    .borders(Borders::ALL)
-                    .style(theme.base_style()),
-            );
+            let block = Block::default()
+                .title("Metadata")
+                .borders(Borders::ALL)
+                .style(theme.base_style());
+            let inner = block.inner(popup_area);
+            frame.render_widget(block, popup_area);
 
-            frame.render_widget(paragraph, popup_area);
+            // The cover thumbnail takes at most a third of the popup width,
+            // to the left of the text.
+            let mut text_area = inner;
+            if let Some(protocol) = cover {
+                let bounds = Size::new(inner.width / 3, inner.height);
+                let fitted = protocol.size_for(Resize::Fit(None), bounds);
+                let cover_area = Rect::new(
+                    inner.x,
+                    inner.y,
+                    fitted.width.min(bounds.width),
+                    fitted.height.min(bounds.height),
+                );
+                if !cover_area.is_empty() {
+                    frame.render_stateful_widget(StatefulImage::default(), cover_area, protocol);
+                    let offset = cover_area.width + 1;
+                    text_area.x += offset;
+                    text_area.width = text_area.width.saturating_sub(offset);
+                }
+            }
+
+            let paragraph = Paragraph::new(content).style(theme.base_style());
+            frame.render_widget(paragraph, text_area);
         } else {
             let content = vec![
                 Line::from("No metadata available"),
@@ -4,10 +4,13 @@ pub mod help;
 pub mod images;
 pub mod library;
 pub mod links;
+pub mod messages;
 pub mod metadata;
 pub mod opds;
+pub mod pages;
 pub mod search;
 pub mod settings;
+pub mod source;
 pub mod statistics;
 pub mod toc;
 
@@ -0,0 +1,98 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::theme::Theme;
+use crate::ui::reader::PageAnchor;
+
+pub struct PagesWindow;
+
+impl PagesWindow {
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        pages: &[PageAnchor],
+        selected_index: usize,
+        filter: Option<&str>,
+        theme: &Theme,
+    ) {
+        let popup_area = super::centered_popup_area(area, 60, 70);
+
+        frame.render_widget(Clear, popup_area);
+
+        let make_block = || {
+            let mut block = Block::default()
+                .title("Print Pages")
+                .borders(Borders::ALL)
+                .style(theme.base_style());
+            if let Some(filter) = filter {
+                block = block.title_bottom(Span::styled(
+                    filter.to_string(),
+                    Style::default().fg(theme.warning_fg),
+                ));
+            }
+            block
+        };
+
+        if pages.is_empty() {
+            let message = if filter.is_some() {
+                "No matches"
+            } else {
+                "This book has no print page numbers"
+            };
+            let mut empty_text = vec![Line::from(message)];
+            if filter.is_none() {
+                empty_text.push(Line::from(""));
+                empty_text.push(Line::from(Span::styled(
+                    "Press any key to close",
+                    Style::default().add_modifier(Modifier::ITALIC),
+                )));
+            }
+
+            let paragraph = Paragraph::new(empty_text)
+                .style(theme.base_style().fg(theme.muted_fg))
+                .block(make_block());
+
+            frame.render_widget(paragraph, popup_area);
+            return;
+        }
+
+        let lines: Vec<Line> = pages
+            .iter()
+            .enumerate()
+            .map(|(i, page)| {
+                let style = if i == selected_index {
+                    Style::default()
+                        .bg(theme.highlight_bg)
+                        .fg(theme.highlight_fg)
+                } else {
+                    Style::default()
+                };
+                Line::from(Span::styled(
+                    format!(" p.{:<6} {}", page.label, page.preview),
+                    style,
+                ))
+            })
+            .collect();
+
+        // Keep the selection in view, centered when the list is long
+        let inner_height = popup_area.height.saturating_sub(2) as usize;
+        let scroll_offset = if lines.len() <= inner_height {
+            0
+        } else {
+            let target_top = selected_index.saturating_sub(inner_height / 2);
+            target_top.min(lines.len().saturating_sub(inner_height))
+        };
+
+        let paragraph = Paragraph::new(lines)
+            .style(theme.base_style())
+            .block(make_block())
+            .scroll((scroll_offset as u16, 0));
+
+        frame.render_widget(paragraph, popup_area);
+    }
+}
@@ -8,15 +8,30 @@ use ratatui::{
 
 use crate::theme::Theme;
 
+/// Rows each result takes in the list: context above, the hit, context below.
+const ENTRY_HEIGHT: usize = 3;
+
+/// One search hit as listed: its line number and preview, between the
+/// rendered rows around it.
+pub struct SearchEntry {
+    pub line: String,
+    pub preview: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
 pub struct SearchWindow;
 
 impl SearchWindow {
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         frame: &mut Frame,
         area: Rect,
         query: &str,
-        results: &[String],
+        results: &[SearchEntry],
+        history: &[String],
         selected_index: usize,
+        chapter_only: bool,
         theme: &Theme,
     ) {
         let popup_area = super::centered_popup_area(area, 60, 70);
@@ -27,6 +42,10 @@ impl SearchWindow {
             .block(
                 Block::default()
                     .title("Search")
+                    .title(
+                        Line::from(if chapter_only { " chapter " } else { " book " })
+                            .right_aligned(),
+                    )
                     .borders(Borders::ALL)
                     .style(theme.base_style()),
             )
@@ -45,6 +64,24 @@ impl SearchWindow {
             popup_area.height - 3,
         );
 
+        // An empty query lists recent searches; Up/Down fill them in.
+        if query.is_empty() && !history.is_empty() {
+            let visible = list_area.height.saturating_sub(2) as usize;
+            let items: Vec<ListItem> = history
+                .iter()
+                .take(visible)
+                .map(|entry| ListItem::new(Line::from(format!("/{entry}"))))
+                .collect();
+            let list = List::new(items).block(
+                Block::default()
+                    .title(" Recent searches (Up / Down) ")
+                    .borders(Borders::ALL)
+                    .style(theme.base_style()),
+            );
+            frame.render_widget(list, list_area);
+            return;
+        }
+
         if results.is_empty() {
             let empty = Paragraph::new("No matches yet")
                 .style(theme.base_style().fg(theme.muted_fg))
@@ -58,7 +95,7 @@ impl SearchWindow {
         }
 
         // Keep the selected result visible by windowing the list around it.
-        let visible = list_area.height.saturating_sub(2) as usize;
+        let visible = list_area.height.saturating_sub(2) as usize / ENTRY_HEIGHT;
         let offset = if visible == 0 {
             0
         } else {
@@ -80,7 +117,20 @@ impl SearchWindow {
                 } else {
                     Style::default()
                 };
-                ListItem::new(Line::from(entry.clone())).style(style)
+                // Context rows line up under the preview, past the "12: ".
+                let indent = " ".repeat(entry.line.len() + 2);
+                let context = |text: &Option<String>| {
+                    Line::styled(
+                        format!("{indent}{}", text.as_deref().unwrap_or_default()),
+                        Style::default().fg(theme.muted_fg),
+                    )
+                };
+                ListItem::new(vec![
+                    context(&entry.before),
+                    Line::from(format!("{}: {}", entry.line, entry.preview)),
+                    context(&entry.after),
+                ])
+                .style(style)
             })
             .collect();
 
@@ -6,6 +6,7 @@ use ratatui::{
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 
+use crate::settings::Settings;
 use crate::theme::Theme;
 
 pub struct SettingsWindow;
@@ -99,4 +100,77 @@ impl SettingsWindow {
         frame.render_stateful_widget(list, rows[0], &mut state);
         frame.render_widget(footer, rows[1]);
     }
+
+    /// Render the dictionary presets view: "auto", each preset with its
+    /// command, then the custom command. `*` marks `dictionary_client`.
+    pub fn dictionary_presets(
+        frame: &mut Frame,
+        area: Rect,
+        settings: &Settings,
+        selected_index: usize,
+        theme: &Theme,
+    ) {
+        let popup_area = Rect::new(
+            area.x + area.width / 6,
+            area.y + area.height / 8,
+            area.width * 2 / 3,
+            area.height * 3 / 4,
+        );
+
+        frame.render_widget(Clear, popup_area);
+        let block = Block::default()
+            .title("Dictionary Presets")
+            .borders(Borders::ALL)
+            .style(theme.base_style());
+        let inner = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+        let footer = Paragraph::new("Enter: default  e: edit command  J/K: move  q: back")
+            .style(theme.base_style().fg(theme.muted_fg));
+
+        let client = settings.dictionary_client.trim();
+        let client = if client.is_empty() { "auto" } else { client };
+        let presets = &settings.dictionary_presets;
+        let is_custom = client != "auto" && !presets.iter().any(|p| p.name == client);
+        let name_width = presets
+            .iter()
+            .map(|p| p.name.chars().count())
+            .chain([6])
+            .max()
+            .unwrap_or(6);
+
+        let mut entries = vec![(client == "auto", "auto", "try each preset in order")];
+        entries.extend(
+            presets
+                .iter()
+                .map(|p| (p.name == client, p.name.as_str(), p.command.as_str())),
+        );
+        entries.push((
+            is_custom,
+            "custom",
+            if is_custom { client } else { "(none)" },
+        ));
+        let items: Vec<ListItem> = entries
+            .into_iter()
+            .map(|(default, name, command)| {
+                let marker = if default { '*' } else { ' ' };
+                ListItem::new(Line::from(format!(
+                    " {marker} {name:<name_width$}  {command}"
+                )))
+                .style(theme.base_style())
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(theme.highlight_bg)
+                .fg(theme.highlight_fg),
+        );
+        let mut state = ListState::default().with_selected(Some(selected_index));
+        frame.render_stateful_widget(list, rows[0], &mut state);
+        frame.render_widget(footer, rows[1]);
+    }
 }
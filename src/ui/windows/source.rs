@@ -0,0 +1,148 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::theme::Theme;
+
+pub struct SourceWindow;
+
+/// Elements that never take a closing tag, so they don't open a level.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+impl SourceWindow {
+    /// Re-indent markup with one tag or text run per line, nested two
+    /// spaces per element level.
+    pub fn pretty_print(source: &str) -> String {
+        let mut out = String::new();
+        let mut depth = 0usize;
+        let mut rest = source;
+        while !rest.is_empty() {
+            let (token, tail) = if rest.starts_with("<!--") {
+                let end = rest.find("-->").map_or(rest.len(), |i| i + 3);
+                rest.split_at(end)
+            } else if rest.starts_with('<') {
+                let end = rest.find('>').map_or(rest.len(), |i| i + 1);
+                rest.split_at(end)
+            } else {
+                rest.split_at(rest.find('<').unwrap_or(rest.len()))
+            };
+            rest = tail;
+
+            let token = token.trim();
+            if token.is_empty() {
+                continue;
+            }
+            let closing = token.starts_with("</");
+            if closing {
+                depth = depth.saturating_sub(1);
+            }
+            out.push_str(&"  ".repeat(depth));
+            out.push_str(&token.split_whitespace().collect::<Vec<_>>().join(" "));
+            out.push('\n');
+            if !closing && Self::opens_level(token) {
+                depth += 1;
+            }
+        }
+        out
+    }
+
+    fn opens_level(token: &str) -> bool {
+        if !token.starts_with('<') || token.starts_with("<!") || token.starts_with("<?") {
+            return false;
+        }
+        if token.ends_with("/>") {
+            return false;
+        }
+        let name: String = token[1..]
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == ':' || *c == '-')
+            .collect();
+        !VOID_ELEMENTS.contains(&name.to_ascii_lowercase().as_str())
+    }
+
+    /// Source lines soft-wrapped to the popup's inner width.
+    fn wrapped_lines(source: &str, width: usize) -> Vec<String> {
+        let width = width.max(1);
+        source
+            .lines()
+            .flat_map(|line| {
+                if line.is_empty() {
+                    vec![String::new()]
+                } else {
+                    textwrap::wrap(line, width)
+                        .into_iter()
+                        .map(|part| part.into_owned())
+                        .collect()
+                }
+            })
+            .collect()
+    }
+
+    pub fn max_scroll_offset(area: Rect, source: &str) -> u16 {
+        let popup_area = super::centered_popup_area(area, 90, 90);
+        let inner_width = popup_area.width.saturating_sub(2) as usize;
+        let inner_height = popup_area.height.saturating_sub(2) as usize;
+        Self::wrapped_lines(source, inner_width)
+            .len()
+            .saturating_sub(inner_height)
+            .min(u16::MAX as usize) as u16
+    }
+
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        title: &str,
+        source: &str,
+        scroll_offset: u16,
+        theme: &Theme,
+    ) {
+        let popup_area = super::centered_popup_area(area, 90, 90);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(format!("Source: {title}"))
+            .borders(Borders::ALL)
+            .style(theme.base_style());
+
+        let inner_width = popup_area.width.saturating_sub(2) as usize;
+        let text = Self::wrapped_lines(source, inner_width).join("\n");
+        let paragraph = Paragraph::new(text)
+            .style(theme.base_style())
+            .block(block)
+            .scroll((scroll_offset, 0));
+
+        frame.render_widget(paragraph, popup_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pretty_print_indents_nested_elements() {
+        let source = r##"<?xml version="1.0"?><html><body><p class="x">Hello <a href="#n1">note</a></p><br/><img src="a.png"></body></html>"##;
+        assert_eq!(
+            SourceWindow::pretty_print(source),
+            r##"<?xml version="1.0"?>
+<html>
+  <body>
+    <p class="x">
+      Hello
+      <a href="#n1">
+        note
+      </a>
+    </p>
+    <br/>
+    <img src="a.png">
+  </body>
+</html>
+"##
+        );
+    }
+}
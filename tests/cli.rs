@@ -43,6 +43,56 @@ fn test_ebook_arg() {
         .stdout(predicates::str::contains("ebook: [\"my_book.epub\"]"));
 }
 
+#[test]
+fn test_import_epy_flag() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("repy"));
+    cmd.env("REPY_CLI_ECHO", "1");
+    cmd.arg("--import-epy").arg("states.db");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("import_epy: Some(\"states.db\")"));
+}
+
+#[test]
+fn test_print_state_flag() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("repy"));
+    cmd.env("REPY_CLI_ECHO", "1");
+    cmd.arg("--print-state");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("print_state: true"));
+}
+
+#[test]
+fn test_rpc_flag() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("repy"));
+    cmd.env("REPY_CLI_ECHO", "1");
+    cmd.arg("--rpc").arg("my_book.epub");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("rpc: true"));
+}
+
+#[test]
+fn test_no_altscreen_flag() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("repy"));
+    cmd.env("REPY_CLI_ECHO", "1");
+    cmd.arg("--no-altscreen").arg("my_book.epub");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("no_altscreen: true"));
+}
+
+#[test]
+fn test_keymap_preset_flag() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("repy"));
+    cmd.env("REPY_CLI_ECHO", "1");
+    cmd.arg("--keymap").arg("arrows-only").arg("my_book.epub");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("keymap: ArrowsOnly"));
+}
+
 #[test]
 fn test_bash_completions() {
     let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("repy"));
@@ -110,6 +160,18 @@ fn test_dump_fixture_epub() {
         .stdout(predicates::str::is_empty().not());
 }
 
+#[test]
+fn test_pager_pipes_rendered_text_to_pager_command() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("repy"));
+    cmd.env("XDG_CONFIG_HOME", dir.path());
+    cmd.env("PAGER", "cat");
+    cmd.arg("--pager").arg("tests/fixtures/small.epub");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("Accessible EPUB 3"));
+}
+
 #[test]
 fn test_dump_uses_defaults_when_config_is_invalid() {
     let dir = tempfile::tempdir().unwrap();
@@ -253,3 +315,35 @@ fn test_history_number_out_of_range_fails() {
         .failure()
         .stderr(predicates::str::contains("out of range"));
 }
+
+#[test]
+fn test_export_annotations_json() {
+    let dir = tempfile::tempdir().unwrap();
+    let book_path = std::fs::canonicalize("tests/fixtures/small.epub").unwrap();
+    let book = repy::formats::open(&book_path.to_string_lossy()).unwrap();
+    let state = repy::state::State::new_at(dir.path().join("repy").join("states.db")).unwrap();
+    let position = repy::models::ReadingState {
+        content_index: 2,
+        row: 40,
+        ..Default::default()
+    };
+    state
+        .set_last_reading_state(book.as_ref(), &position)
+        .unwrap();
+    state
+        .insert_bookmark(book.as_ref(), "Chapter two", &position)
+        .unwrap();
+    drop(state);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("repy"));
+    cmd.env("XDG_CONFIG_HOME", dir.path())
+        .arg("--export-annotations")
+        .arg("tests/fixtures/small.epub");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let value: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(value["schema_version"], 1);
+    assert_eq!(value["book"]["title"], "Accessible EPUB 3");
+    assert_eq!(value["bookmarks"][0]["name"], "Chapter two");
+    assert_eq!(value["bookmarks"][0]["content_index"], 2);
+    assert!(value["highlights"].as_array().unwrap().is_empty());
+}
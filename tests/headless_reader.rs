@@ -0,0 +1,265 @@
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::backend::TestBackend;
+    use repy::config::Config;
+    use repy::models::WindowType;
+    use repy::settings::{CfgDefaultKeymaps, Settings};
+    use repy::state::State;
+    use repy::ui::reader::Reader;
+
+    /// Text rows on an 80x24 screen: the top bar and padding take five.
+    const PAGE_ROWS: usize = 19;
+
+    fn headless_reader(book: &str) -> Reader<TestBackend> {
+        let config = Config::with_settings(Settings::default(), CfgDefaultKeymaps::default())
+            .expect("test config");
+        let state = State::in_memory().expect("in-memory state");
+        let mut reader = Reader::with_backend(config, TestBackend::new(80, 24), state, None)
+            .expect("headless reader");
+        reader.load_ebook(book).expect("load book");
+        reader
+    }
+
+    /// A three-chapter book written as a folder of HTML files, one short
+    /// numbered paragraph per row, so every page is easy to recognise.
+    fn generated_book() -> (tempfile::TempDir, Reader<TestBackend>) {
+        let dir = tempfile::tempdir().expect("temp dir");
+        for chapter in 1..=3 {
+            let body: String = (1..=30)
+                .map(|n| format!("<p>Chapter {chapter} line {n}.</p>"))
+                .collect();
+            std::fs::write(
+                dir.path().join(format!("ch{chapter}.html")),
+                format!(
+                    "<html><head><title>Chapter {chapter}</title></head><body>{body}</body></html>"
+                ),
+            )
+            .expect("write chapter");
+        }
+        let reader = headless_reader(dir.path().to_str().expect("utf-8 path"));
+        (dir, reader)
+    }
+
+    fn press(reader: &mut Reader<TestBackend>, code: KeyCode) {
+        reader
+            .handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+            .expect("key handling failed");
+    }
+
+    fn type_str(reader: &mut Reader<TestBackend>, text: &str) {
+        for c in text.chars() {
+            press(reader, KeyCode::Char(c));
+        }
+    }
+
+    fn row(reader: &Reader<TestBackend>) -> usize {
+        reader.app_state().reading_state.row
+    }
+
+    fn window(reader: &Reader<TestBackend>) -> WindowType {
+        reader.app_state().ui_state.active_window.clone()
+    }
+
+    fn screen(reader: &mut Reader<TestBackend>) -> String {
+        reader.draw().expect("draw");
+        format!("{}", reader.backend())
+    }
+
+    #[test]
+    fn keys_drive_a_reader_without_a_terminal() {
+        let fixture = format!("{}/tests/fixtures/small.epub", env!("CARGO_MANIFEST_DIR"));
+        let mut reader = headless_reader(&fixture);
+        let start = row(&reader);
+
+        press(&mut reader, KeyCode::PageDown);
+        let paged = row(&reader);
+        assert!(paged > start);
+        press(&mut reader, KeyCode::PageUp);
+        assert_eq!(row(&reader), start);
+
+        press(&mut reader, KeyCode::Char('?'));
+        let screen = screen(&mut reader);
+        assert!(screen.contains("Page Down"), "{screen}");
+    }
+
+    #[test]
+    fn page_keys_step_a_screen_at_a_time() {
+        let (_dir, mut reader) = generated_book();
+        press(&mut reader, KeyCode::PageDown);
+        let first = row(&reader);
+        press(&mut reader, KeyCode::PageDown);
+        assert_eq!(row(&reader) - first, PAGE_ROWS);
+        press(&mut reader, KeyCode::PageUp);
+        assert_eq!(row(&reader), first);
+
+        // A count repeats the motion.
+        press(&mut reader, KeyCode::Char('g'));
+        type_str(&mut reader, "3j");
+        assert_eq!(row(&reader), 3);
+    }
+
+    #[test]
+    fn chapter_and_book_keys_land_on_the_right_text() {
+        let (_dir, mut reader) = generated_book();
+        press(&mut reader, KeyCode::Char('L'));
+        assert!(screen(&mut reader).contains("Chapter 2 line 1."));
+        press(&mut reader, KeyCode::Char('L'));
+        assert!(screen(&mut reader).contains("Chapter 3 line 1."));
+        press(&mut reader, KeyCode::Char('H'));
+        let page = screen(&mut reader);
+        assert!(page.contains("Chapter 2 line 1."), "{page}");
+        assert!(!page.contains("Chapter 3"), "{page}");
+
+        press(&mut reader, KeyCode::Char('G'));
+        assert!(screen(&mut reader).contains("Chapter 2 line 30."));
+        press(&mut reader, KeyCode::Char('g'));
+        assert!(screen(&mut reader).contains("Chapter 2 line 1."));
+
+        press(&mut reader, KeyCode::End);
+        assert!(screen(&mut reader).contains("Chapter 3 line 30."));
+        press(&mut reader, KeyCode::Home);
+        assert_eq!(row(&reader), 0);
+        assert!(screen(&mut reader).contains("Chapter 1 line 1."));
+    }
+
+    #[test]
+    fn windows_open_and_close_over_the_reader() {
+        let (_dir, mut reader) = generated_book();
+        press(&mut reader, KeyCode::Char('t'));
+        assert_eq!(window(&reader), WindowType::Toc);
+        press(&mut reader, KeyCode::Char('j'));
+        press(&mut reader, KeyCode::Enter);
+        assert_eq!(window(&reader), WindowType::Reader);
+        assert!(screen(&mut reader).contains("Chapter 2 line 1."));
+
+        press(&mut reader, KeyCode::Char('?'));
+        assert_eq!(window(&reader), WindowType::Help);
+        press(&mut reader, KeyCode::Esc);
+        assert_eq!(window(&reader), WindowType::Reader);
+
+        // Ctrl+o returns to where the TOC jump started.
+        reader
+            .handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL))
+            .expect("key handling failed");
+        assert_eq!(row(&reader), 0);
+    }
+
+    #[test]
+    fn search_finds_every_chapter_and_steps_through_hits() {
+        let (_dir, mut reader) = generated_book();
+        press(&mut reader, KeyCode::Char('/'));
+        assert_eq!(window(&reader), WindowType::Search);
+        type_str(&mut reader, "line 17");
+        press(&mut reader, KeyCode::Enter);
+        assert_eq!(reader.app_state().ui_state.search_results.len(), 3);
+
+        press(&mut reader, KeyCode::Enter);
+        assert_eq!(window(&reader), WindowType::Reader);
+        assert!(screen(&mut reader).contains("Chapter 1 line 17."));
+        press(&mut reader, KeyCode::Char('n'));
+        assert!(screen(&mut reader).contains("Chapter 2 line 17."));
+        press(&mut reader, KeyCode::Char('p'));
+        assert!(screen(&mut reader).contains("Chapter 1 line 17."));
+    }
+
+    #[test]
+    fn rpc_commands_move_the_reader_and_reply_with_its_state() {
+        let (_dir, mut reader) = generated_book();
+        let reply = reader.handle_rpc_line(r#"{"cmd":"next_chapter"}"#);
+        assert_eq!(reply["ok"], true, "{reply}");
+        assert_eq!(reply["row"], row(&reader));
+        assert!(screen(&mut reader).contains("Chapter 2 line 1."));
+
+        let reply = reader.handle_rpc_line(r#"{"cmd":"goto","row":5}"#);
+        assert_eq!(reply["row"], 5);
+        assert_eq!(row(&reader), 5);
+        assert!(reply["total_rows"].as_u64().unwrap() >= 90, "{reply}");
+
+        reader.handle_rpc_line(r#"{"cmd":"goto","chapter":3}"#);
+        assert!(screen(&mut reader).contains("Chapter 3 line 1."));
+        let reply = reader.handle_rpc_line(r#"{"cmd":"end"}"#);
+        assert_eq!(
+            reply["row"].as_u64().unwrap() + 1,
+            reply["total_rows"].as_u64().unwrap()
+        );
+        assert!(screen(&mut reader).contains("Chapter 3 line 30."));
+
+        let reply = reader.handle_rpc_line(r#"{"cmd":"goto","row":1,"chapter":2}"#);
+        assert_eq!(reply["ok"], false, "{reply}");
+        let reply = reader.handle_rpc_line("not json");
+        assert_eq!(reply["ok"], false, "{reply}");
+
+        let reply = reader.handle_rpc_line(r#"{"cmd":"quit"}"#);
+        assert_eq!(reply["quitting"], true, "{reply}");
+        assert!(reader.app_state().should_quit);
+    }
+
+    #[test]
+    fn j_writes_the_reading_state_as_json_without_moving() {
+        // Reopen the generated book with `state_file` set.
+        let (dir, _) = generated_book();
+        let state_file = dir.path().join("now-reading.json");
+        let mut settings = Settings::default();
+        settings.state_file = Some(state_file.to_string_lossy().into_owned());
+        let config = Config::with_settings(settings, CfgDefaultKeymaps::default()).expect("config");
+        let state = State::in_memory().expect("in-memory state");
+        let mut reader = Reader::with_backend(config, TestBackend::new(80, 24), state, None)
+            .expect("headless reader");
+        reader
+            .load_ebook(dir.path().to_str().expect("utf-8 path"))
+            .expect("load book");
+
+        press(&mut reader, KeyCode::Char('L'));
+        let before = row(&reader);
+        press(&mut reader, KeyCode::Char('J'));
+        assert_eq!(row(&reader), before);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&state_file).expect("state file"))
+                .expect("valid JSON");
+        assert_eq!(json["row"], before);
+        assert_eq!(
+            json["textwidth"],
+            reader.app_state().reading_state.textwidth
+        );
+        let text = json["visible_text"].as_str().expect("visible text");
+        assert!(text.contains("Chapter 2 line 1."), "{text}");
+        assert!(!text.contains("Chapter 1 line"), "{text}");
+    }
+
+    #[test]
+    fn scrolling_a_wide_table_keeps_its_columns_aligned() {
+        // Text never wraps narrower than 20 columns, so with line numbers on
+        // a 20-column screen both rows overflow, the first by more.
+        let dir = tempfile::tempdir().expect("temp dir");
+        std::fs::write(
+            dir.path().join("table.html"),
+            "<html><body><pre>abcdefghij|ABCDEFGHI\nabcdefghij|ABCDEFG</pre></body></html>",
+        )
+        .expect("write chapter");
+        let settings = Settings {
+            show_line_numbers: true,
+            ..Settings::default()
+        };
+        let config = Config::with_settings(settings, CfgDefaultKeymaps::default()).expect("config");
+        let state = State::in_memory().expect("in-memory state");
+        let mut reader = Reader::with_backend(config, TestBackend::new(20, 24), state, None)
+            .expect("headless reader");
+        reader
+            .load_ebook(dir.path().to_str().expect("utf-8 path"))
+            .expect("load book");
+
+        let columns = |screen: &str| -> Vec<usize> {
+            screen.lines().filter_map(|line| line.find('|')).collect()
+        };
+        let before = columns(&screen(&mut reader));
+        assert_eq!(before.len(), 2);
+        press(&mut reader, KeyCode::Char('>'));
+        let screen = screen(&mut reader);
+        let after = columns(&screen);
+        assert_eq!(after.len(), 2, "{screen}");
+        assert!(after[0] < before[0], "{screen}");
+        assert_eq!(after[0], after[1], "{screen}");
+    }
+}
@@ -0,0 +1,129 @@
+//! A hung-up terminal must not cost the reading position: after SIGHUP every
+//! draw fails, and the reader still has to save before giving up.
+//!
+//! This lives in its own test binary because it installs the real signal
+//! handlers and leaves the process-wide termination flag set.
+#![cfg(unix)]
+
+use std::cell::Cell;
+use std::io;
+use std::rc::Rc;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::backend::{Backend, ClearType, TestBackend, WindowSize};
+use ratatui::buffer::Cell as BufferCell;
+use ratatui::layout::{Position, Size};
+use repy::config::Config;
+use repy::settings::{CfgDefaultKeymaps, Settings};
+use repy::state::State;
+use repy::ui::reader::Reader;
+use repy::ui::terminal;
+
+/// A `TestBackend` whose terminal hangs up on the next draw once `hang_up`
+/// is set: the draw raises SIGHUP and fails, like a closed terminal window.
+struct HangingUpBackend {
+    inner: TestBackend,
+    hang_up: Rc<Cell<bool>>,
+}
+
+impl Backend for HangingUpBackend {
+    type Error = io::Error;
+
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a BufferCell)>,
+    {
+        if self.hang_up.get() {
+            // SAFETY: the handler installed by the test only sets a flag.
+            unsafe {
+                libc::raise(libc::SIGHUP);
+            }
+            return Err(io::Error::from_raw_os_error(libc::EIO));
+        }
+        self.inner.draw(content).map_err(io::Error::other)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.inner.hide_cursor().map_err(io::Error::other)
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.inner.show_cursor().map_err(io::Error::other)
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        self.inner.get_cursor_position().map_err(io::Error::other)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.inner
+            .set_cursor_position(position)
+            .map_err(io::Error::other)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.inner.clear().map_err(io::Error::other)
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.inner
+            .clear_region(clear_type)
+            .map_err(io::Error::other)
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        self.inner.size().map_err(io::Error::other)
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.inner.window_size().map_err(io::Error::other)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush().map_err(io::Error::other)
+    }
+}
+
+#[test]
+fn hang_up_with_a_failing_terminal_still_saves_the_position() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let book_dir = dir.path().join("book");
+    std::fs::create_dir(&book_dir).expect("book dir");
+    let body: String = (1..=120).map(|n| format!("<p>Line {n}.</p>")).collect();
+    std::fs::write(
+        book_dir.join("ch1.html"),
+        format!("<html><head><title>One</title></head><body>{body}</body></html>"),
+    )
+    .expect("write chapter");
+    let book = book_dir.to_str().expect("utf-8 path");
+    let db_path = dir.path().join("states.db");
+
+    let config = Config::with_settings(Settings::default(), CfgDefaultKeymaps::default())
+        .expect("test config");
+    let state = State::new_at(&db_path).expect("state");
+    let hang_up = Rc::new(Cell::new(false));
+    let backend = HangingUpBackend {
+        inner: TestBackend::new(80, 24),
+        hang_up: Rc::clone(&hang_up),
+    };
+    let mut reader = Reader::with_backend(config, backend, state, None).expect("reader");
+    reader.load_ebook(book).expect("load book");
+    reader
+        .handle_key_event(KeyEvent::new(KeyCode::PageDown, KeyModifiers::NONE))
+        .expect("page down");
+    let row = reader.app_state().reading_state.row;
+    assert!(row > 0);
+
+    terminal::install_termination_handler();
+    hang_up.set(true);
+    reader.run_event_loop().expect("quit after the hang-up");
+    assert!(terminal::termination_requested());
+
+    let ebook = repy::formats::open(book).expect("reopen book");
+    let saved = State::new_at(&db_path)
+        .expect("reopen state")
+        .get_last_reading_state(ebook.as_ref())
+        .expect("query")
+        .expect("position was saved");
+    assert_eq!(saved.row, row);
+}